@@ -0,0 +1,210 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::DataConfig;
+use crate::rooch_client;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+
+/// A BRC20 balance entry of the ord-style export.
+/// The export is a tsv file with the columns `tick address overall available transferable`,
+/// the amounts are in human readable decimal format, such as `1000.5`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrdBRC20Balance {
+    pub tick: String,
+    pub address: String,
+    pub overall: String,
+    pub available: String,
+    pub transferable: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BRC20BalanceMismatch {
+    pub tick: String,
+    pub address: String,
+    /// The field which does not match, `tick`, `balance`, `available` or `transferable`.
+    /// `balance` means Rooch returned no balance for the (tick, address).
+    pub field: String,
+    pub ord_value: String,
+    pub rooch_value: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BRC20VerifyReport {
+    pub total: u64,
+    pub matched: u64,
+    pub mismatches: Vec<BRC20BalanceMismatch>,
+}
+
+pub fn read_ord_brc20_balances(file_path: &str) -> Result<Vec<OrdBRC20Balance>> {
+    let file = File::open(file_path)?;
+    let reader = io::BufReader::new(file);
+    let mut balances = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() >= 5 {
+            balances.push(OrdBRC20Balance {
+                tick: parts[0].to_lowercase(),
+                address: parts[1].to_string(),
+                overall: parts[2].to_string(),
+                available: parts[3].to_string(),
+                transferable: parts[4].to_string(),
+            });
+        }
+    }
+
+    Ok(balances)
+}
+
+/// Compare the BRC20 balances of the ord-style export with the balances on Rooch,
+/// the export is grouped by address so that every address only needs one query,
+/// and the returned balances are matched to the export by (tick, address), not by position.
+pub fn verify_brc20_balances(
+    config: &DataConfig,
+    ord_export_path: &str,
+) -> Result<BRC20VerifyReport> {
+    let ord_balances = read_ord_brc20_balances(ord_export_path)?;
+    let mut report = BRC20VerifyReport::default();
+
+    let mut decimals = BTreeMap::<String, Option<u8>>::new();
+    let mut balances_by_address = BTreeMap::<String, Vec<OrdBRC20Balance>>::new();
+    for balance in ord_balances {
+        balances_by_address
+            .entry(balance.address.clone())
+            .or_default()
+            .push(balance);
+    }
+
+    for (address, ord_balances) in balances_by_address {
+        let ticks = ord_balances
+            .iter()
+            .map(|balance| balance.tick.clone())
+            .collect::<Vec<_>>();
+        for tick in ticks.iter() {
+            if !decimals.contains_key(tick) {
+                let dec = rooch_client::query_brc20_tick(config, tick)?.map(|info| info.dec);
+                decimals.insert(tick.clone(), dec);
+            }
+        }
+
+        let rooch_balances = match rooch_client::query_brc20_balances(config, &address, &ticks) {
+            Ok(balances) => balances,
+            Err(err) => {
+                println!(
+                    "[STAT] verify_brc20_balances address: {} occurs error {}",
+                    address, err
+                );
+                continue;
+            }
+        };
+
+        let rooch_balances = rooch_balances
+            .into_iter()
+            .map(|balance| (balance.tick.to_lowercase(), balance))
+            .collect::<BTreeMap<_, _>>();
+
+        for ord_balance in ord_balances {
+            report.total += 1;
+            let dec = match decimals.get(&ord_balance.tick).cloned().flatten() {
+                Some(dec) => dec,
+                None => {
+                    report.mismatches.push(BRC20BalanceMismatch {
+                        tick: ord_balance.tick.clone(),
+                        address: address.clone(),
+                        field: "tick".to_string(),
+                        ord_value: ord_balance.tick,
+                        rooch_value: None,
+                    });
+                    continue;
+                }
+            };
+            let rooch_balance = match rooch_balances.get(&ord_balance.tick) {
+                Some(rooch_balance) => rooch_balance,
+                None => {
+                    report.mismatches.push(BRC20BalanceMismatch {
+                        tick: ord_balance.tick.clone(),
+                        address: address.clone(),
+                        field: "balance".to_string(),
+                        ord_value: ord_balance.overall,
+                        rooch_value: None,
+                    });
+                    continue;
+                }
+            };
+
+            let mut is_match = true;
+            for (field, ord_value, rooch_value) in [
+                (
+                    "available",
+                    &ord_balance.available,
+                    rooch_balance.available.0.to_string(),
+                ),
+                (
+                    "transferable",
+                    &ord_balance.transferable,
+                    rooch_balance.transferable.0.to_string(),
+                ),
+            ] {
+                if scale_decimal_amount(ord_value, dec).as_deref() != Some(rooch_value.as_str()) {
+                    is_match = false;
+                    report.mismatches.push(BRC20BalanceMismatch {
+                        tick: ord_balance.tick.clone(),
+                        address: address.clone(),
+                        field: field.to_string(),
+                        ord_value: ord_value.clone(),
+                        rooch_value: Some(rooch_value),
+                    });
+                }
+            }
+            if is_match {
+                report.matched += 1;
+            }
+        }
+    }
+
+    println!(
+        "[STAT] verify_brc20_balances total: {}, matched: {}, mismatched: {}",
+        report.total,
+        report.matched,
+        report.mismatches.len()
+    );
+    Ok(report)
+}
+
+/// Scale a human readable decimal amount to the integer amount stored on chain,
+/// the same as `string_utils::parse_decimal_option` in Move. Return `None` if the amount is invalid.
+pub fn scale_decimal_amount(amount: &str, dec: u8) -> Option<String> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let dec = dec as usize;
+    if fraction.len() > dec {
+        return None;
+    }
+    let scaled = format!(
+        "{}{}{}",
+        integer,
+        fraction,
+        "0".repeat(dec - fraction.len())
+    );
+    let scaled = scaled.trim_start_matches('0');
+    if scaled.is_empty() {
+        Some("0".to_string())
+    } else {
+        Some(scaled.to_string())
+    }
+}
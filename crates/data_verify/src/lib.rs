@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod brc20_helper;
+pub mod brc20_verify;
 pub mod config;
 pub mod data_export;
 pub mod inscription;
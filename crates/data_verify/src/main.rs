@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use data_verify::config::DataConfig;
-use data_verify::{data_export, ord_verify};
+use data_verify::{brc20_verify, data_export, ord_verify};
 
 //flight
 fn main() {
@@ -24,6 +24,15 @@ fn main() {
         &ord_inscription_succ_json,
         &ord_inscription_fail_json,
     );
+    let ord_brc20_balances_tsv = format!("{}/ord_brc20_balances.tsv", config.ord_data_path);
+    if std::path::Path::new(&ord_brc20_balances_tsv).exists() {
+        if let Err(err) = brc20_verify::verify_brc20_balances(&config, &ord_brc20_balances_tsv) {
+            eprintln!(
+                "Error verify brc20 balances {}, error: {}",
+                ord_brc20_balances_tsv, err
+            );
+        };
+    }
     // let ord_inscription_json = format!("{}/ord_inscription.json", config.ord_data_path);
     // let _ = brc20_helper::process_transactions(&config, &ord_inscription_json);
     println!("Indexer ord data verify successfully!")
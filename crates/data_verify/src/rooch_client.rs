@@ -4,6 +4,7 @@
 use crate::config::DataConfig;
 use crate::inscription::Transaction;
 use anyhow::Result;
+use rooch_rpc_api::jsonrpc_types::btc::brc20::{BRC20BalanceView, BRC20TickInfoView};
 use rooch_rpc_api::jsonrpc_types::btc::ord::InscriptionStateView;
 use std::fs::File;
 use std::io;
@@ -53,6 +54,45 @@ pub fn query_inscription(
     }
 }
 
+pub fn query_brc20_tick(config: &DataConfig, tick: &str) -> Result<Option<BRC20TickInfoView>> {
+    let params = format!("[\"{}\"]", tick);
+    let json_value = rooch_rpc_request(config, "btc_getBRC20Tick", &params)?;
+    match json_value {
+        serde_json::Value::Null => Ok(None),
+        _ => Ok(Some(serde_json::from_value(json_value)?)),
+    }
+}
+
+pub fn query_brc20_balances(
+    config: &DataConfig,
+    address: &str,
+    ticks: &[String],
+) -> Result<Vec<BRC20BalanceView>> {
+    let params = format!("[\"{}\", {}]", address, serde_json::to_string(ticks)?);
+    let json_value = rooch_rpc_request(config, "btc_getBRC20Balances", &params)?;
+    Ok(serde_json::from_value(json_value)?)
+}
+
+fn rooch_rpc_request(config: &DataConfig, method: &str, params: &str) -> Result<serde_json::Value> {
+    let rooch_cmd = format!(
+        "{}rooch rpc request --method {} --params '{}'",
+        config.ord_cli_path, method, params
+    );
+
+    let output = Command::new("sh").arg("-c").arg(rooch_cmd).output()?;
+    if output.status.success() {
+        let response = String::from_utf8_lossy(&output.stdout);
+        Ok(serde_json::from_str(&response)?)
+    } else {
+        let error_message = String::from_utf8_lossy(&output.stderr);
+        Err(anyhow::anyhow!(
+            "rooch rpc request {} execute error {}",
+            method,
+            error_message.to_string()
+        ))
+    }
+}
+
 // #[derive(Debug, Serialize, Deserialize)]
 // pub struct Transaction {
 //     id: String,
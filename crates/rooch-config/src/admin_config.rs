@@ -0,0 +1,104 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_ADMIN_HOST: &str = "127.0.0.1";
+
+/// The admin JSON-RPC server, it is disabled unless `admin-port` is set.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct AdminConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "admin-port",
+        long,
+        help = "The port of the admin JSON-RPC server, the admin server is disabled if not set"
+    )]
+    pub admin_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "admin-host",
+        long,
+        help = "The host of the admin JSON-RPC server, default is 127.0.0.1"
+    )]
+    pub admin_host: Option<String>,
+    // keep the token out of the printed config
+    #[serde(skip_serializing)]
+    #[clap(
+        name = "admin-token",
+        long,
+        env = "ROOCH_ADMIN_TOKEN",
+        hide_env_values = true,
+        help = "The bearer token required by the admin JSON-RPC server"
+    )]
+    pub admin_token: Option<String>,
+}
+
+impl AdminConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.admin_port.is_some()
+    }
+
+    pub fn admin_host(&self) -> String {
+        self.admin_host
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ADMIN_HOST.to_string())
+    }
+
+    /// The token is required to enable the admin server.
+    pub fn admin_token(&self) -> anyhow::Result<String> {
+        match self.admin_token.as_deref() {
+            Some(token) if !token.is_empty() => Ok(token.to_string()),
+            _ => Err(anyhow::anyhow!(
+                "The admin token is required by the admin server, set it by --admin-token or ROOCH_ADMIN_TOKEN"
+            )),
+        }
+    }
+}
+
+impl Config for AdminConfig {}
+
+impl std::fmt::Display for AdminConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for AdminConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: AdminConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_token() {
+        let mut config = AdminConfig {
+            admin_port: Some(6768),
+            ..Default::default()
+        };
+        assert!(config.is_enabled());
+        // refuse to start the admin server without a token
+        assert!(config.admin_token().is_err());
+        config.admin_token = Some("".to_string());
+        assert!(config.admin_token().is_err());
+        config.admin_token = Some("secret".to_string());
+        assert_eq!(config.admin_token().unwrap(), "secret");
+        // the token is not printed
+        assert!(!config.to_string().contains("secret"));
+    }
+}
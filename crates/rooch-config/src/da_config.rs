@@ -20,6 +20,9 @@ const DEFAULT_OPENDA_FS_DIR: &str = "openda-fs";
 // Only a few database operations are needed to catch up with the latest block numbers after a restart,
 // so it's okay to have a small interval.
 pub const DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL: u64 = 15;
+// The reconciler reads the submitted segments back from the backends, it's more expensive than submitting,
+// so it runs less frequently.
+pub const DEFAULT_DA_RECONCILE_INTERVAL: u64 = 300;
 
 /// This enum specifies the strategy for submitting DA data.
 ///
@@ -28,7 +31,8 @@ pub const DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL: u64 = 15;
 /// `Number(n)` means at least `n` backends must submit.
 ///
 /// No matter what the strategy is, an independent process will sync all the data to all backends.
-/// Eventual consistency is guaranteed.
+/// Eventual consistency is guaranteed: the background reconciler checks the segments of the submitted blocks
+/// in each backend and resubmits the missing or corrupted ones.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DASubmitStrategy {
@@ -179,6 +183,14 @@ pub struct DAConfig {
     /// If not set, the default value is `DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub background_submit_interval: Option<u64>,
+    /// Specifies the interval for background reconciliation in seconds, 0 disables the reconciliation.
+    /// If not set, the default value is `DEFAULT_DA_RECONCILE_INTERVAL`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconcile_interval: Option<u64>,
+    /// The interval to make a new block, in milliseconds.
+    /// If not set, the default value is `ROOCH_BATCH_INTERVAL`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_interval: Option<u64>,
 
     /// Internal reference to the base configuration.
     ///
@@ -216,6 +228,8 @@ impl DAConfig {
 
         self.background_submit_interval
             .get_or_insert(DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL);
+        self.reconcile_interval
+            .get_or_insert(DEFAULT_DA_RECONCILE_INTERVAL);
 
         let default_fs_root = self.get_openda_fs_dir();
 
@@ -486,6 +500,8 @@ mod tests {
             }),
             da_min_block_to_submit: Some(340282366920938463463374607431768211455),
             background_submit_interval: None,
+            reconcile_interval: None,
+            batch_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -509,6 +525,8 @@ mod tests {
             }),
             da_min_block_to_submit: None,
             background_submit_interval: None,
+            reconcile_interval: None,
+            batch_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -565,6 +583,8 @@ mod tests {
             }),
             da_min_block_to_submit: Some(1023),
             background_submit_interval: None,
+            reconcile_interval: None,
+            batch_interval: None,
             base: None,
         };
         match DAConfig::from_str(da_config_str) {
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_HEALTH_MAX_BTC_LAG: u64 = 6;
+pub const DEFAULT_HEALTH_MAX_DA_LAG: u64 = 100;
+pub const DEFAULT_HEALTH_MAX_INDEXER_LAG: u64 = 1000;
+
+/// The thresholds of the readiness conditions reported by `/health/ready`.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct HealthConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "health-max-btc-lag",
+        long,
+        help = "The max blocks the Bitcoin relayer can be behind the Bitcoin tip to be ready, default is 6"
+    )]
+    pub max_btc_lag: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "health-max-da-lag",
+        long,
+        help = "The max blocks the DA background submitter can be behind the last block to be ready, default is 100"
+    )]
+    pub max_da_lag: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "health-max-indexer-lag",
+        long,
+        help = "The max tx orders the indexer can be behind the sequencer to be ready, default is 1000"
+    )]
+    pub max_indexer_lag: Option<u64>,
+}
+
+impl HealthConfig {
+    pub fn max_btc_lag(&self) -> u64 {
+        self.max_btc_lag.unwrap_or(DEFAULT_HEALTH_MAX_BTC_LAG)
+    }
+
+    pub fn max_da_lag(&self) -> u64 {
+        self.max_da_lag.unwrap_or(DEFAULT_HEALTH_MAX_DA_LAG)
+    }
+
+    pub fn max_indexer_lag(&self) -> u64 {
+        self.max_indexer_lag
+            .unwrap_or(DEFAULT_HEALTH_MAX_INDEXER_LAG)
+    }
+}
+
+impl Config for HealthConfig {}
+
+impl std::fmt::Display for HealthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for HealthConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: HealthConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
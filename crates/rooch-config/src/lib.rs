@@ -1,14 +1,19 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::admin_config::AdminConfig;
 use crate::da_config::DAConfig;
+use crate::health_config::HealthConfig;
+use crate::mempool_config::MempoolConfig;
 use crate::proposer_config::ProposerConfig;
 use crate::store_config::StoreConfig;
+use crate::trace_config::TraceConfig;
 use anyhow::Result;
 use clap::Parser;
 use moveos_config::{temp_dir, DataDirPath};
 use once_cell::sync::Lazy;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::randomness::RandomnessKeyPair;
 use rooch_types::genesis_config::GenesisConfig;
 use rooch_types::rooch_network::{BuiltinChainID, RoochChainID, RoochNetwork};
 use rooch_types::service_status::ServiceStatus;
@@ -20,12 +25,16 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::{fmt::Debug, path::Path, path::PathBuf};
 
+pub mod admin_config;
 pub mod config;
 pub mod da_config;
+pub mod health_config;
+pub mod mempool_config;
 pub mod proposer_config;
 pub mod server_config;
 pub mod settings;
 pub mod store_config;
+pub mod trace_config;
 
 pub const ROOCH_DIR: &str = ".rooch";
 pub const ROOCH_CONFIR_DIR: &str = "rooch_config";
@@ -138,11 +147,19 @@ pub struct RoochOpt {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub btc_reorg_aware_height: Option<usize>,
+    /// The local directory to relay Bitcoin blocks from instead of the Bitcoin RPC.
+    /// It can be a Bitcoin Core `blocks` directory with `blk*.dat` files,
+    /// or a directory of raw serialized blocks, one block per file in binary or hex format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "BTC_BLOCK_SOURCE_DIR", conflicts_with = "btc_rpc_url")]
+    pub btc_block_source_dir: Option<PathBuf>,
 
     /// The address of the sequencer account
     #[clap(long)]
     pub sequencer_account: Option<String>,
     /// The address of the proposer account
+    /// The proposer submits the blocks to the on-chain SCC with L2 transactions, the account pays the gas,
+    /// so it must hold enough gas coin on the non-dev chains.
     #[clap(long)]
     pub proposer_account: Option<String>,
 
@@ -152,6 +169,18 @@ pub struct RoochOpt {
     #[clap(flatten)]
     pub proposer: ProposerConfig,
 
+    #[clap(flatten)]
+    pub mempool: MempoolConfig,
+
+    #[clap(flatten)]
+    pub trace: TraceConfig,
+
+    #[clap(flatten)]
+    pub health: HealthConfig,
+
+    #[clap(flatten)]
+    pub admin: AdminConfig,
+
     #[clap(long, default_value_t, value_enum)]
     pub service_status: ServiceStatus,
 
@@ -172,6 +201,12 @@ pub struct RoochOpt {
     #[clap(long, default_value_t, value_enum)]
     pub service_type: ServiceType,
 
+    /// Enable the randomness beacon, the sequencer commits a VRF output to every randomness block.
+    /// The VRF keypair is stored in the keystore of the sequencer account and generated on the first start,
+    /// its public key is registered on chain by the sequencer on start, the sequencer account pays the gas.
+    #[clap(long)]
+    pub randomness_beacon: bool,
+
     #[serde(skip)]
     #[clap(skip)]
     base: Option<Arc<BaseConfig>>,
@@ -203,15 +238,21 @@ impl RoochOpt {
             btc_sync_block_interval: None,
             btc_reorg_aware_block_store_dir: None,
             btc_reorg_aware_height: None,
+            btc_block_source_dir: None,
             sequencer_account: None,
             proposer_account: None,
             da: DAConfig::default(),
             proposer: ProposerConfig::default(),
+            mempool: MempoolConfig::default(),
+            trace: TraceConfig::default(),
+            health: HealthConfig::default(),
+            admin: AdminConfig::default(),
             service_status: ServiceStatus::default(),
             traffic_per_second: None,
             traffic_burst_size: None,
             base: None,
             service_type: ServiceType::default(),
+            randomness_beacon: false,
         };
         opt.init()?;
         Ok(opt)
@@ -267,12 +308,24 @@ impl RoochOpt {
         Ok(())
     }
 
-    pub fn bitcoin_relayer_config(&self) -> Option<BitcoinRelayerConfig> {
-        self.btc_rpc_url.as_ref()?;
-        Some(BitcoinRelayerConfig {
-            btc_rpc_url: self.btc_rpc_url.clone().unwrap(),
-            btc_rpc_user_name: self.btc_rpc_username.clone().unwrap(),
-            btc_rpc_password: self.btc_rpc_password.clone().unwrap(),
+    /// Return an error if the Bitcoin RPC url is set without the RPC username or password,
+    /// the relayer and the RPC service both use the credentials to access the Bitcoin RPC.
+    pub fn bitcoin_relayer_config(&self) -> Result<Option<BitcoinRelayerConfig>> {
+        if self.btc_rpc_url.is_none() && self.btc_block_source_dir.is_none() {
+            return Ok(None);
+        }
+        if self.btc_rpc_url.is_some()
+            && (self.btc_rpc_username.is_none() || self.btc_rpc_password.is_none())
+        {
+            anyhow::bail!(
+                "btc-rpc-username and btc-rpc-password are required when btc-rpc-url is set"
+            );
+        }
+        Ok(Some(BitcoinRelayerConfig {
+            btc_rpc_url: self.btc_rpc_url.clone(),
+            btc_rpc_user_name: self.btc_rpc_username.clone(),
+            btc_rpc_password: self.btc_rpc_password.clone(),
+            btc_block_source_dir: self.btc_block_source_dir.clone(),
             btc_end_block_height: self.btc_end_block_height,
             btc_sync_block_interval: self.btc_sync_block_interval,
             btc_reorg_aware_block_store_dir: self
@@ -286,7 +339,7 @@ impl RoochOpt {
             btc_reorg_aware_height: self
                 .btc_reorg_aware_height
                 .unwrap_or(DEFAULT_BTC_REORG_AWARE_HEIGHT),
-        })
+        }))
     }
 
     pub fn port(&self) -> u16 {
@@ -344,9 +397,11 @@ pub struct EthereumRelayerConfig {
 
 #[derive(Debug, Clone)]
 pub struct BitcoinRelayerConfig {
-    pub btc_rpc_url: String,
-    pub btc_rpc_user_name: String,
-    pub btc_rpc_password: String,
+    pub btc_rpc_url: Option<String>,
+    pub btc_rpc_user_name: Option<String>,
+    pub btc_rpc_password: Option<String>,
+    /// If set, the relayer reads blocks from the local block files instead of the Bitcoin RPC.
+    pub btc_block_source_dir: Option<PathBuf>,
     pub btc_end_block_height: Option<u64>,
     pub btc_sync_block_interval: Option<u64>,
     pub btc_reorg_aware_block_store_dir: PathBuf,
@@ -397,6 +452,8 @@ pub struct ServerOpt {
     /// Sequencer, proposer and relayer keypair
     pub sequencer_keypair: Option<RoochKeyPair>,
     pub proposer_keypair: Option<RoochKeyPair>,
+    /// The randomness beacon VRF keypair, only set if the randomness beacon is enabled
+    pub randomness_keypair: Option<RandomnessKeyPair>,
     pub active_env: Option<String>,
 }
 
@@ -415,6 +472,7 @@ impl ServerOpt {
         ServerOpt {
             sequencer_keypair: None,
             proposer_keypair: None,
+            randomness_keypair: None,
             active_env: None,
         }
     }
@@ -522,4 +580,22 @@ mod tests {
             );
         }
     }
+
+    mod bitcoin_relayer_config_tests {
+        use super::*;
+
+        #[test]
+        fn returns_none_without_bitcoin_source() {
+            let opt = RoochOpt::new_with_temp_store().unwrap();
+            assert!(opt.bitcoin_relayer_config().unwrap().is_none());
+        }
+
+        #[test]
+        fn fails_when_rpc_url_is_set_without_credentials() {
+            let mut opt = RoochOpt::new_with_temp_store().unwrap();
+            opt.btc_rpc_url = Some("http://127.0.0.1:18443".to_string());
+            opt.btc_rpc_username = Some("roochuser".to_string());
+            assert!(opt.bitcoin_relayer_config().is_err());
+        }
+    }
 }
@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_MEMPOOL_MAX_SIZE: usize = 10_000;
+pub const DEFAULT_MEMPOOL_MAX_TXS_PER_SENDER: usize = 64;
+pub const DEFAULT_MEMPOOL_TX_TTL_SECONDS: u64 = 600;
+
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct MempoolConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "mempool-max-size",
+        long,
+        help = "The max number of pending transactions in the mempool, default is 10000"
+    )]
+    pub max_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "mempool-max-txs-per-sender",
+        long,
+        help = "The max number of pending transactions of one sender in the mempool, default is 64"
+    )]
+    pub max_txs_per_sender: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "mempool-tx-ttl",
+        long,
+        help = "The seconds a pending transaction can stay in the mempool, default is 600"
+    )]
+    pub tx_ttl: Option<u64>,
+}
+
+impl MempoolConfig {
+    pub fn max_size(&self) -> usize {
+        self.max_size.unwrap_or(DEFAULT_MEMPOOL_MAX_SIZE)
+    }
+
+    pub fn max_txs_per_sender(&self) -> usize {
+        self.max_txs_per_sender
+            .unwrap_or(DEFAULT_MEMPOOL_MAX_TXS_PER_SENDER)
+    }
+
+    pub fn tx_ttl(&self) -> u64 {
+        self.tx_ttl.unwrap_or(DEFAULT_MEMPOOL_TX_TTL_SECONDS)
+    }
+}
+
+impl Config for MempoolConfig {}
+
+impl std::fmt::Display for MempoolConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for MempoolConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: MempoolConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
@@ -23,6 +23,13 @@ pub struct ProposerConfig {
         help = "The proposer check avail block to propose interval"
     )]
     pub interval: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "proposer-submit-retries",
+        long,
+        help = "The max retries to submit a block to the on-chain SCC in one check interval"
+    )]
+    pub submit_retries: Option<u32>,
 }
 
 impl Config for ProposerConfig {}
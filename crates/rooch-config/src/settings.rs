@@ -7,3 +7,7 @@
 pub const ROOCH_BATCH_INTERVAL: u64 = 1000 * 60 * 15;
 // 5 seconds, check avail block to propose interval
 pub const PROPOSER_CHECK_INTERVAL: u64 = 5;
+// the max retries to submit a block to the on-chain SCC in one check interval
+pub const PROPOSER_SUBMIT_RETRIES: u32 = 3;
+// 1 second, check the mempool to evict expired txs and promote ready txs interval
+pub const MEMPOOL_TICK_INTERVAL: u64 = 1;
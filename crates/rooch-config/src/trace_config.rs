@@ -0,0 +1,85 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub const DEFAULT_TRACE_SERVICE_NAME: &str = "rooch";
+pub const DEFAULT_TRACE_SAMPLE_RATIO: f64 = 1.0;
+
+/// The OpenTelemetry trace exporting of the transaction pipeline, disabled if neither the
+/// OTLP endpoint nor the trace file is set.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct TraceConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "trace-otlp-endpoint",
+        long,
+        env = "ROOCH_TRACE_OTLP_ENDPOINT",
+        help = "The OTLP/HTTP endpoint to export the traces to, e.g. http://localhost:4318/v1/traces"
+    )]
+    pub otlp_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "trace-file",
+        long,
+        conflicts_with = "trace-otlp-endpoint",
+        help = "Export the traces to the local file as JSON lines instead of an OTLP endpoint"
+    )]
+    pub file: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "trace-service-name",
+        long,
+        help = "The service name of the exported traces, default is rooch"
+    )]
+    pub service_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "trace-sample-ratio",
+        long,
+        help = "The ratio of the requests to trace, in [0.0, 1.0], default is 1.0"
+    )]
+    pub sample_ratio: Option<f64>,
+}
+
+impl TraceConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.otlp_endpoint.is_some() || self.file.is_some()
+    }
+
+    pub fn service_name(&self) -> String {
+        self.service_name
+            .clone()
+            .unwrap_or_else(|| DEFAULT_TRACE_SERVICE_NAME.to_string())
+    }
+
+    pub fn sample_ratio(&self) -> f64 {
+        self.sample_ratio.unwrap_or(DEFAULT_TRACE_SAMPLE_RATIO)
+    }
+}
+
+impl Config for TraceConfig {}
+
+impl std::fmt::Display for TraceConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for TraceConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: TraceConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
@@ -5,8 +5,12 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::ops::Bound;
 
-use cosmwasm_std::{Binary, ContractResult, Order, Record, SystemResult};
+use cosmwasm_std::{
+    from_json, BankQuery, Binary, Coin, ContractResult, Empty, Order, QueryRequest, Record,
+    SystemError, SystemResult,
+};
 use cosmwasm_vm::{Backend, BackendApi, BackendError, BackendResult, GasInfo, Querier, Storage};
+use serde::Deserialize;
 
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::vm_status::StatusCode;
@@ -263,21 +267,88 @@ impl BackendApi for MoveBackendApi {
     }
 }
 
+/// The balances of an account which are visible to the contract via the bank querier
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountBalance {
+    pub address: String,
+    pub amount: Vec<Coin>,
+}
+
+/// The balances snapshot passed from Move
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuerierBalances {
+    pub balances: Vec<AccountBalance>,
+}
+
 // Implement Querier
-#[derive(Clone)]
-pub struct MoveBackendQuerier;
+#[derive(Clone, Default)]
+pub struct MoveBackendQuerier {
+    /// A snapshot of the balances taken by the Move caller before the contract call,
+    /// the contract can only query the balances of the accounts in the snapshot.
+    balances: BTreeMap<String, Vec<Coin>>,
+}
+
+impl MoveBackendQuerier {
+    pub fn new(balances: Vec<AccountBalance>) -> Self {
+        MoveBackendQuerier {
+            balances: balances
+                .into_iter()
+                .map(|balance| (balance.address, balance.amount))
+                .collect(),
+        }
+    }
+
+    fn query_bank(&self, request: BankQuery) -> SystemResult<ContractResult<Binary>> {
+        match request {
+            BankQuery::Balance { address, denom } => {
+                let amount = self
+                    .balances
+                    .get(&address)
+                    .and_then(|coins| coins.iter().find(|coin| coin.denom == denom))
+                    .map(|coin| coin.amount)
+                    .unwrap_or_default();
+                let response = serde_json::json!({
+                    "amount": {
+                        "denom": denom,
+                        "amount": amount.to_string(),
+                    }
+                });
+                SystemResult::Ok(ContractResult::Ok(Binary::from(
+                    response.to_string().into_bytes(),
+                )))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "bank".to_string(),
+            }),
+        }
+    }
+}
 
 impl Querier for MoveBackendQuerier {
     fn query_raw(
         &self,
-        _request: &[u8],
-        gas_limit: u64,
+        request: &[u8],
+        _gas_limit: u64,
     ) -> BackendResult<SystemResult<ContractResult<Binary>>> {
-        // Implement query logic
-        (
-            Ok(SystemResult::Ok(ContractResult::Ok(Binary::from(vec![])))),
-            GasInfo::with_externally_used(gas_limit),
-        )
+        let request = match from_json::<QueryRequest<Empty>>(request) {
+            Ok(request) => request,
+            Err(e) => {
+                return (
+                    Ok(SystemResult::Err(SystemError::InvalidRequest {
+                        error: e.to_string(),
+                        request: Binary::from(request),
+                    })),
+                    GasInfo::with_externally_used(1),
+                )
+            }
+        };
+        let result = match request {
+            QueryRequest::Bank(bank_query) => self.query_bank(bank_query),
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "only bank balance query is supported".to_string(),
+            }),
+        };
+        (Ok(result), GasInfo::with_externally_used(1))
     }
 }
 
@@ -289,13 +360,13 @@ pub fn build_move_backend<'a, 'b: 'a>(
     Backend {
         api: MoveBackendApi,
         storage: MoveStorage::new(object, layout_loader, resolver),
-        querier: MoveBackendQuerier,
+        querier: MoveBackendQuerier::default(),
     }
 }
 
 pub struct MockStorage {
     data: BTreeMap<Vec<u8>, Vec<u8>>,
-    iterators: Vec<Vec<(Vec<u8>, Vec<u8>)>>,
+    iterators: Vec<std::vec::IntoIter<Record>>,
 }
 
 impl MockStorage {
@@ -305,6 +376,19 @@ impl MockStorage {
             iterators: Vec::new(),
         }
     }
+
+    /// Create a storage preloaded with the contract state
+    pub fn from_data(data: BTreeMap<Vec<u8>, Vec<u8>>) -> Self {
+        MockStorage {
+            data,
+            iterators: Vec::new(),
+        }
+    }
+
+    /// Consume the storage and return the contract state
+    pub fn into_data(self) -> BTreeMap<Vec<u8>, Vec<u8>> {
+        self.data
+    }
 }
 
 impl Storage for MockStorage {
@@ -347,7 +431,7 @@ impl Storage for MockStorage {
             items.reverse();
         }
 
-        self.iterators.push(items);
+        self.iterators.push(items.into_iter());
         let result = Ok((self.iterators.len() - 1) as u32);
         let gas_info = GasInfo::free();
         (result, gas_info)
@@ -364,7 +448,7 @@ impl Storage for MockStorage {
             }
         };
 
-        let result = Ok(iterator.pop());
+        let result = Ok(iterator.next());
         let gas_info = GasInfo::free();
         (result, gas_info)
     }
@@ -374,6 +458,70 @@ pub fn build_mock_backend() -> Backend<MoveBackendApi, MockStorage, MoveBackendQ
     Backend {
         api: MoveBackendApi,
         storage: MockStorage::new(),
-        querier: MoveBackendQuerier,
+        querier: MoveBackendQuerier::default(),
+    }
+}
+
+/// Build a backend which runs the contract on the given state and answers the bank queries
+/// from the given balances snapshot.
+pub fn build_contract_backend(
+    state: BTreeMap<Vec<u8>, Vec<u8>>,
+    balances: Vec<AccountBalance>,
+) -> Backend<MoveBackendApi, MockStorage, MoveBackendQuerier> {
+    Backend {
+        api: MoveBackendApi,
+        storage: MockStorage::from_data(state),
+        querier: MoveBackendQuerier::new(balances),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_storage_scan_order() {
+        let mut storage = MockStorage::new();
+        for key in [b"a", b"b", b"c"] {
+            let key = key.as_slice();
+            storage.set(key, key).0.unwrap();
+        }
+
+        let id = storage.scan(None, None, Order::Ascending).0.unwrap();
+        let keys: Vec<_> = std::iter::from_fn(|| storage.next(id).0.unwrap())
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys, vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]);
+
+        let id = storage
+            .scan(Some(b"b".as_slice()), None, Order::Descending)
+            .0
+            .unwrap();
+        let keys: Vec<_> = std::iter::from_fn(|| storage.next(id).0.unwrap())
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys, vec![b"c".to_vec(), b"b".to_vec()]);
+
+        let data = storage.into_data();
+        assert_eq!(data.len(), 3);
+    }
+
+    #[test]
+    fn test_querier_bank_balance() {
+        let querier = MoveBackendQuerier::new(vec![AccountBalance {
+            address: "alice".to_string(),
+            amount: vec![Coin::new(100u128, "rgas")],
+        }]);
+        let request = br#"{"bank":{"balance":{"address":"alice","denom":"rgas"}}}"#;
+        let (result, _) = querier.query_raw(request, 0);
+        let binary = result.unwrap().unwrap().unwrap();
+        let response: serde_json::Value = serde_json::from_slice(binary.as_slice()).unwrap();
+        assert_eq!(response["amount"]["amount"], "100");
+
+        let request = br#"{"bank":{"balance":{"address":"bob","denom":"rgas"}}}"#;
+        let (result, _) = querier.query_raw(request, 0);
+        let binary = result.unwrap().unwrap().unwrap();
+        let response: serde_json::Value = serde_json::from_slice(binary.as_slice()).unwrap();
+        assert_eq!(response["amount"]["amount"], "0");
     }
 }
@@ -16,6 +16,11 @@ impl Message for GetServerStatusMessage {
 pub struct AppendTransactionMessage {
     pub tx_order: u64,
     pub tx_timestamp: u64,
+    /// The state commitment chain submission of the proposer, it does not close a block
+    pub is_proposer_tx: bool,
+    /// The span of the caller, the parent of the append span
+    #[serde(skip, default = "tracing::Span::none")]
+    pub span: tracing::Span,
 }
 
 impl Message for AppendTransactionMessage {
@@ -23,10 +28,12 @@ impl Message for AppendTransactionMessage {
 }
 
 impl AppendTransactionMessage {
-    pub fn new(tx_order: u64, tx_timestamp: u64) -> Self {
+    pub fn new(tx_order: u64, tx_timestamp: u64, is_proposer_tx: bool) -> Self {
         Self {
             tx_order,
             tx_timestamp,
+            is_proposer_tx,
+            span: tracing::Span::current(),
         }
     }
 }
@@ -39,3 +46,14 @@ pub struct RevertTransactionMessage {
 impl Message for RevertTransactionMessage {
     type Result = anyhow::Result<()>;
 }
+
+/// Pause or resume submitting the batches to the DA backends at runtime, the blocks are still made
+/// and will be submitted after resuming.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PauseSubmitMessage {
+    pub paused: bool,
+}
+
+impl Message for PauseSubmitMessage {
+    type Result = anyhow::Result<()>;
+}
@@ -1,6 +1,6 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-mod errors;
+pub(crate) mod errors;
 pub mod messages;
 pub mod server;
@@ -3,27 +3,31 @@
 
 use crate::actor::errors::SubmitBatchError;
 use crate::actor::messages::{
-    AppendTransactionMessage, GetServerStatusMessage, RevertTransactionMessage,
+    AppendTransactionMessage, GetServerStatusMessage, PauseSubmitMessage, RevertTransactionMessage,
 };
 use crate::backend::openda::AdapterSubmitStat;
 use crate::backend::{DABackend, DABackends};
 use crate::batcher::BatchMaker;
+use crate::reconciler::Reconciler;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::Handler;
 use coerce::actor::Actor;
 use rooch_common::vec::validate_and_extract;
-use rooch_config::da_config::{DAConfig, DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL};
+use rooch_config::da_config::{
+    DAConfig, DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL, DEFAULT_DA_RECONCILE_INTERVAL,
+};
+use rooch_config::settings::ROOCH_BATCH_INTERVAL;
 use rooch_store::da_store::DAMetaStore;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::da::batch::{BlockRange, DABatch, SignedDABatchMeta};
-use rooch_types::da::status::DAServerStatus;
+use rooch_types::da::status::{DABackendReconcileState, DAServerStatus};
 use rooch_types::transaction::LedgerTransaction;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time;
 use std::time::{Duration, SystemTime};
 use tokio::sync::broadcast;
@@ -36,6 +40,9 @@ pub struct DAServerActor {
     last_block_update_time: u64,
     background_last_block_update_time: Arc<AtomicU64>,
     batch_maker: BatchMaker,
+    reconcile_states: Arc<RwLock<Vec<DABackendReconcileState>>>,
+    // shared with the background submitter and the reconciler
+    submit_paused: Arc<AtomicBool>,
 }
 
 impl Actor for DAServerActor {}
@@ -49,9 +56,13 @@ impl DAServerActor {
         shutdown_rx: broadcast::Receiver<()>,
     ) -> anyhow::Result<Self> {
         let min_block_to_submit = da_config.da_min_block_to_submit;
+        let batch_interval = da_config.batch_interval.unwrap_or(ROOCH_BATCH_INTERVAL);
         let background_submit_interval = da_config
             .background_submit_interval
             .unwrap_or(DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL);
+        let reconcile_interval = da_config
+            .reconcile_interval
+            .unwrap_or(DEFAULT_DA_RECONCILE_INTERVAL);
 
         let DABackends {
             backends,
@@ -67,8 +78,20 @@ impl DAServerActor {
             .map(|backend| backend.get_adapter_stats())
             .collect();
 
+        let reconcile_states = Arc::new(RwLock::new(
+            backends
+                .iter()
+                .map(|backend| DABackendReconcileState {
+                    identifier: backend.get_identifier(),
+                    verifiable: backend.is_verifiable(),
+                    ..Default::default()
+                })
+                .collect(),
+        ));
+
         let last_block_number = rooch_store.get_last_block_number()?;
         let background_last_block_update_time = Arc::new(AtomicU64::new(0));
+        let submit_paused = Arc::new(AtomicBool::new(false));
         let server = DAServerActor {
             rooch_store: rooch_store.clone(),
             backend_identifiers,
@@ -76,10 +99,23 @@ impl DAServerActor {
             last_block_number,
             last_block_update_time: 0,
             background_last_block_update_time: background_last_block_update_time.clone(),
-            batch_maker: BatchMaker::new(rooch_store.clone()),
+            batch_maker: BatchMaker::new(rooch_store.clone(), batch_interval),
+            reconcile_states: reconcile_states.clone(),
+            submit_paused: submit_paused.clone(),
         };
 
         if submit_threshold != 0 {
+            if reconcile_interval != 0 {
+                Reconciler::new(
+                    rooch_store.clone(),
+                    sequencer_key.copy(),
+                    backends.clone(),
+                    min_block_to_submit.unwrap_or(0),
+                    reconcile_states,
+                    submit_paused.clone(),
+                )?
+                .run(reconcile_interval, shutdown_rx.resubscribe());
+            }
             Self::run_background_submitter(
                 rooch_store,
                 sequencer_key,
@@ -88,6 +124,7 @@ impl DAServerActor {
                 background_last_block_update_time,
                 min_block_to_submit,
                 background_submit_interval,
+                submit_paused,
                 shutdown_rx,
             );
         }
@@ -139,6 +176,12 @@ impl DAServerActor {
             avail_backends.push((identifier, result));
         }
 
+        let backend_reconcile_states = self
+            .reconcile_states
+            .read()
+            .map_err(|e| anyhow!("fail to read reconcile states: {}", e))?
+            .clone();
+
         Ok(DAServerStatus {
             last_block_number: self.last_block_number,
             last_tx_order,
@@ -147,6 +190,7 @@ impl DAServerActor {
             last_avail_tx_order,
             last_avail_block_update_time,
             avail_backends,
+            backend_reconcile_states,
         })
     }
 
@@ -156,8 +200,20 @@ impl DAServerActor {
     ) -> anyhow::Result<()> {
         let tx_order = msg.tx_order;
         let tx_timestamp = msg.tx_timestamp;
-        let block_number_opt = self.batch_maker.append_transaction(tx_order, tx_timestamp);
+        let is_proposer_tx = msg.is_proposer_tx;
+        let span = tracing::info_span!(
+            parent: &msg.span,
+            "da.append_tx",
+            tx_order,
+            block_number = tracing::field::Empty
+        );
+        let block_number_opt = span.in_scope(|| {
+            self.batch_maker
+                .append_transaction(tx_order, tx_timestamp, is_proposer_tx)
+        });
         if let Some(block_number) = block_number_opt {
+            // the tx closes a block, which will be submitted as a batch
+            span.record("block_number", tracing::field::display(block_number));
             self.last_block_number = Some(block_number);
             self.last_block_update_time = SystemTime::now()
                 .duration_since(time::UNIX_EPOCH)?
@@ -166,6 +222,11 @@ impl DAServerActor {
         Ok(())
     }
 
+    pub fn pause_submit(&mut self, paused: bool) {
+        tracing::info!("da: set background submission paused to {}", paused);
+        self.submit_paused.store(paused, Ordering::Relaxed);
+    }
+
     pub async fn revert_transaction(
         &mut self,
         msg: RevertTransactionMessage,
@@ -185,6 +246,7 @@ impl DAServerActor {
         background_last_block_update_time: Arc<AtomicU64>,
         min_block_to_submit_opt: Option<u128>,
         background_submit_interval: u64,
+        submit_paused: Arc<AtomicBool>,
         mut shutdown_rx: broadcast::Receiver<()>,
     ) {
         tokio::spawn(async move {
@@ -209,6 +271,10 @@ impl DAServerActor {
                      break;
                  }
                  _ = ticker.tick() => {
+                     if submit_paused.load(Ordering::Relaxed) {
+                         tracing::debug!("da: background submission is paused, skip");
+                         continue;
+                     }
                      match rooch_store.get_last_block_number() {
                          Ok(Some(last_block_number)) => {
                              if let Some(block_number_for_last_job) = old_last_block_number {
@@ -286,6 +352,18 @@ impl Handler<RevertTransactionMessage> for DAServerActor {
     }
 }
 
+#[async_trait]
+impl Handler<PauseSubmitMessage> for DAServerActor {
+    async fn handle(
+        &mut self,
+        msg: PauseSubmitMessage,
+        _ctx: &mut ActorContext,
+    ) -> anyhow::Result<()> {
+        self.pause_submit(msg.paused);
+        Ok(())
+    }
+}
+
 pub(crate) struct Submitter {
     sequencer_key: RoochKeyPair,
     rooch_store: RoochStore,
@@ -380,6 +458,28 @@ impl Submitter {
     }
 }
 
+// collect tx from start to end of the block for rooch_store
+pub(crate) fn get_block_tx_list(
+    rooch_store: &RoochStore,
+    block_range: &BlockRange,
+) -> anyhow::Result<Vec<LedgerTransaction>, SubmitBatchError> {
+    let tx_orders: Vec<u64> = (block_range.tx_order_start..=block_range.tx_order_end).collect();
+    let tx_hashes_opt = rooch_store
+        .get_tx_hashes(tx_orders.clone())
+        .map_err(SubmitBatchError::Recoverable)?;
+    let tx_hashes = validate_and_extract(tx_orders, tx_hashes_opt, |tx_order| {
+        format!("Fail to get tx hash by tx_order: {}", tx_order)
+    })
+    .map_err(SubmitBatchError::DatabaseInconsistent)?;
+    let tx_list_opt = rooch_store
+        .get_transactions_by_hash(tx_hashes.clone())
+        .map_err(SubmitBatchError::Recoverable)?;
+    validate_and_extract(tx_hashes, tx_list_opt, |tx_hash| {
+        format!("Fail to get tx by tx_hash: {:?}", tx_hash)
+    })
+    .map_err(SubmitBatchError::DatabaseInconsistent)
+}
+
 struct BackgroundSubmitter {
     rooch_store: RoochStore,
     submitter: Submitter,
@@ -418,26 +518,7 @@ impl BackgroundSubmitter {
         let mut max_block_number_submitted: u128 = 0;
         for unsubmitted_block_range in unsubmitted_blocks {
             let block_number = unsubmitted_block_range.block_number;
-            let tx_order_start = unsubmitted_block_range.tx_order_start;
-            let tx_order_end = unsubmitted_block_range.tx_order_end;
-            // collect tx from start to end for rooch_store
-            let tx_orders: Vec<u64> = (tx_order_start..=tx_order_end).collect();
-            let tx_hashes_opt = self
-                .rooch_store
-                .get_tx_hashes(tx_orders.clone())
-                .map_err(SubmitBatchError::Recoverable)?;
-            let tx_hashes = validate_and_extract(tx_orders, tx_hashes_opt, |tx_order| {
-                format!("Fail to get tx hash by tx_order: {}", tx_order)
-            })
-            .map_err(SubmitBatchError::DatabaseInconsistent)?;
-            let tx_list_opt = self
-                .rooch_store
-                .get_transactions_by_hash(tx_hashes.clone())
-                .map_err(SubmitBatchError::Recoverable)?;
-            let tx_list = validate_and_extract(tx_hashes, tx_list_opt, |tx_hash| {
-                format!("Fail to get tx by tx_hash: {:?}", tx_hash)
-            })
-            .map_err(SubmitBatchError::DatabaseInconsistent)?;
+            let tx_list = get_block_tx_list(&self.rooch_store, &unsubmitted_block_range)?;
 
             self.submitter
                 .submit_batch_raw(unsubmitted_block_range, tx_list)
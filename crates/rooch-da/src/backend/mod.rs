@@ -22,11 +22,25 @@ pub const BACKENDS_PRIORITY: [&str; 5] = [
 ];
 pub const UNKNOWN_BACKEND_PRIORITY: usize = usize::MAX;
 
+/// The result of reconciling a batch in a backend
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchReconcileResult {
+    /// The segments which are missing or have mismatched checksums in the backend
+    pub missing_segments: u64,
+    /// The segments resubmitted to the backend
+    pub resubmitted_segments: u64,
+}
+
 #[async_trait]
 pub trait DABackend: Sync + Send {
     async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()>;
     fn get_identifier(&self) -> String;
     fn get_adapter_stats(&self) -> AdapterSubmitStat;
+    /// Whether the submitted segments could be read back to reconcile.
+    fn is_verifiable(&self) -> bool;
+    /// Check the presence and checksum of every segment of the batch,
+    /// and resubmit the missing or corrupted segments.
+    async fn reconcile_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<BatchReconcileResult>;
 }
 
 pub struct DABackends {
@@ -30,6 +30,19 @@ pub(crate) trait OpenDAAdapter: Sync + Send {
         segment_bytes: &[u8],
         is_last_segment: bool,
     ) -> anyhow::Result<()>;
+
+    /// Whether the submitted segments could be read back by `read_segment`.
+    fn is_readable(&self) -> bool {
+        false
+    }
+
+    /// Read the segment back from the backend, None if it's not found.
+    async fn read_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        Err(anyhow!(
+            "read segment {} is not supported by the backend",
+            segment_id
+        ))
+    }
 }
 
 #[derive(Clone)]
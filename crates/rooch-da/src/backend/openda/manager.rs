@@ -3,11 +3,12 @@
 
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter, OpenDAAdapterConfig};
 use crate::backend::openda::derive_identifier;
-use crate::backend::DABackend;
+use crate::backend::{BatchReconcileResult, DABackend};
 use async_trait::async_trait;
 use rooch_config::da_config::DABackendOpenDAConfig;
 use rooch_types::da::batch::DABatch;
 use rooch_types::da::chunk::{Chunk, ChunkV0};
+use rooch_types::da::segment::SegmentV0;
 use std::sync::Arc;
 
 /// manage OpenDA backends while integrating specific adapter logic
@@ -77,4 +78,162 @@ impl DABackend for OpenDABackendManager {
     fn get_adapter_stats(&self) -> AdapterSubmitStat {
         self.adapter_stats.clone()
     }
+
+    fn is_verifiable(&self) -> bool {
+        self.adapter.is_readable()
+    }
+
+    async fn reconcile_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<BatchReconcileResult> {
+        let chunk: ChunkV0 = (*batch).clone().into();
+        // the segments are the same as the submitted ones if max_segment_size is not changed,
+        // otherwise all the segments will be resubmitted.
+        let segments = chunk.to_segments(self.adapter_config.max_segment_size);
+        let segment_count = segments.len() as u64;
+
+        let mut result = BatchReconcileResult::default();
+        for segment in segments {
+            let segment_id = segment.get_id();
+            let bytes = segment.to_bytes();
+            let stored = self.adapter.read_segment(segment_id).await?;
+            if stored
+                .as_ref()
+                .is_some_and(|stored| is_same_segment(stored, &bytes))
+            {
+                continue;
+            }
+            result.missing_segments += 1;
+            tracing::warn!(
+                "segment is {} in {:?}, resubmitting, segment_id: {:?}",
+                if stored.is_some() {
+                    "corrupted"
+                } else {
+                    "missing"
+                },
+                self.get_identifier(),
+                segment_id,
+            );
+            let is_last_segment = segment_id.segment_number == segment_count - 1;
+            self.adapter
+                .submit_segment(segment_id, &bytes, is_last_segment)
+                .await?;
+            result.resubmitted_segments += 1;
+        }
+
+        Ok(result)
+    }
+}
+
+// compare the checksums of the segments, the stored segment is verified by its own checksums while decoding.
+fn is_same_segment(stored: &[u8], expected: &[u8]) -> bool {
+    match (
+        SegmentV0::from_bytes(stored),
+        SegmentV0::from_bytes(expected),
+    ) {
+        (Ok(stored), Ok(expected)) => {
+            stored.checksum == expected.checksum && stored.data_checksum == expected.data_checksum
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::openda::adapter::OpenDAAdapter;
+    use rooch_config::da_config::OpenDAScheme;
+    use rooch_types::da::batch::DABatchMeta;
+    use rooch_types::da::segment::SegmentID;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default, Clone)]
+    struct MemoryAdapter {
+        segments: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    }
+
+    #[async_trait]
+    impl OpenDAAdapter for MemoryAdapter {
+        async fn submit_segment(
+            &self,
+            segment_id: SegmentID,
+            segment_bytes: &[u8],
+            _is_last_segment: bool,
+        ) -> anyhow::Result<()> {
+            self.segments
+                .lock()
+                .unwrap()
+                .insert(segment_id.to_string(), segment_bytes.to_vec());
+            Ok(())
+        }
+
+        fn is_readable(&self) -> bool {
+            true
+        }
+
+        async fn read_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(self
+                .segments
+                .lock()
+                .unwrap()
+                .get(&segment_id.to_string())
+                .cloned())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_batch() {
+        let adapter = MemoryAdapter::default();
+        let manager = OpenDABackendManager {
+            identifier: "openda-memory".to_string(),
+            adapter_stats: AdapterSubmitStat::new(),
+            adapter_config: OpenDAAdapterConfig {
+                namespace: "test".to_string(),
+                max_segment_size: 64,
+                max_retries: 0,
+                scheme: OpenDAScheme::Fs,
+                scheme_config: HashMap::new(),
+            },
+            adapter: Box::new(adapter.clone()),
+        };
+        let batch = Arc::new(DABatch {
+            meta: DABatchMeta::new(1, 0, 0, Default::default()),
+            meta_signature: vec![1; 64],
+            tx_list_bytes: (0..=255).collect(),
+        });
+        assert!(manager.is_verifiable());
+
+        manager.submit_batch(batch.clone()).await.unwrap();
+        let segment_count = adapter.segments.lock().unwrap().len() as u64;
+        assert!(segment_count > 1);
+        let result = manager.reconcile_batch(batch.clone()).await.unwrap();
+        assert_eq!(result, BatchReconcileResult::default());
+
+        // remove a segment and corrupt another one
+        let segment_id = |segment_number| SegmentID {
+            chunk_id: 1,
+            segment_number,
+        };
+        {
+            let mut segments = adapter.segments.lock().unwrap();
+            segments.remove(&segment_id(0).to_string());
+            if let Some(byte) = segments
+                .get_mut(&segment_id(1).to_string())
+                .unwrap()
+                .last_mut()
+            {
+                *byte ^= 0xff;
+            }
+        }
+        let result = manager.reconcile_batch(batch.clone()).await.unwrap();
+        assert_eq!(
+            result,
+            BatchReconcileResult {
+                missing_segments: 2,
+                resubmitted_segments: 2,
+            }
+        );
+        assert_eq!(adapter.segments.lock().unwrap().len() as u64, segment_count);
+        let result = manager.reconcile_batch(batch).await.unwrap();
+        assert_eq!(result, BatchReconcileResult::default());
+    }
 }
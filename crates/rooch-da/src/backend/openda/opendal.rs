@@ -4,7 +4,7 @@
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter};
 use async_trait::async_trait;
 use opendal::layers::{LoggingLayer, RetryLayer};
-use opendal::{Operator, Scheme};
+use opendal::{ErrorKind, Operator, Scheme};
 use rooch_config::da_config::OpenDAScheme;
 use rooch_types::da::segment::SegmentID;
 use std::collections::HashMap;
@@ -66,4 +66,16 @@ impl OpenDAAdapter for OpenDalAdapter {
             Err(error) => Err(error),
         }
     }
+
+    fn is_readable(&self) -> bool {
+        true
+    }
+
+    async fn read_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        match self.operator.read(&segment_id.to_string()).await {
+            Ok(buffer) => Ok(Some(buffer.to_vec())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
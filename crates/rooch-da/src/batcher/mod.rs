@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::anyhow;
-use rooch_config::settings::ROOCH_BATCH_INTERVAL;
 use rooch_store::da_store::DAMetaStore;
 use rooch_store::RoochStore;
 
@@ -10,19 +9,22 @@ struct InProgressBatch {
     tx_order_start: u64,
     tx_order_end: u64,
     start_timestamp: u64,
+    // the interval to make a new batch, in milliseconds
+    batch_interval: u64,
 }
 
 impl InProgressBatch {
-    fn init() -> Self {
+    fn init(batch_interval: u64) -> Self {
         Self {
             tx_order_start: 0,
             tx_order_end: 0,
             start_timestamp: 0,
+            batch_interval,
         }
     }
 
     fn reset(&mut self) {
-        *self = Self::init();
+        *self = Self::init(self.batch_interval);
     }
 
     // create a new batch with the first transaction
@@ -42,7 +44,14 @@ impl InProgressBatch {
     // 2. If the batch is not empty, check if the transaction is in the interval:
     //  1. If the transaction is in the interval, update tx_order_end
     //  2. If the transaction is not in the interval, return tx range and wait for reset
-    fn append_transaction(&mut self, tx_order: u64, tx_timestamp: u64) -> Option<(u64, u64)> {
+    // The proposer transaction never closes the batch, it is made after a block,
+    // so the proposer transactions alone can not produce new blocks on an idle chain.
+    fn append_transaction(
+        &mut self,
+        tx_order: u64,
+        tx_timestamp: u64,
+        is_proposer_tx: bool,
+    ) -> Option<(u64, u64)> {
         if self.start_timestamp == 0 {
             self.begin_with(tx_order, tx_timestamp);
             return None;
@@ -60,8 +69,12 @@ impl InProgressBatch {
 
         self.tx_order_end = tx_order;
 
+        if is_proposer_tx {
+            return None;
+        }
+
         if tx_timestamp < self.start_timestamp ||        // backwards checking first, avoid overflow
-            tx_timestamp - self.start_timestamp < ROOCH_BATCH_INTERVAL
+            tx_timestamp - self.start_timestamp < self.batch_interval
         {
             return None;
         }
@@ -79,6 +92,7 @@ pub struct BatchMaker {
 struct PendingTx {
     tx_order: u64,
     tx_timestamp: u64,
+    is_proposer_tx: bool,
 }
 
 impl PendingTx {
@@ -86,6 +100,7 @@ impl PendingTx {
         Self {
             tx_order: 0,
             tx_timestamp: 0,
+            is_proposer_tx: false,
         }
     }
 
@@ -100,29 +115,37 @@ impl PendingTx {
         }
         self.tx_order = 0;
         self.tx_timestamp = 0;
+        self.is_proposer_tx = false;
         Ok(())
     }
 
-    fn push(&mut self, tx_order: u64, tx_timestamp: u64) -> Option<PendingTx> {
+    fn push(
+        &mut self,
+        tx_order: u64,
+        tx_timestamp: u64,
+        is_proposer_tx: bool,
+    ) -> Option<PendingTx> {
         let old = if self.tx_order == 0 {
             None
         } else {
             Some(PendingTx {
                 tx_order: self.tx_order,
                 tx_timestamp: self.tx_timestamp,
+                is_proposer_tx: self.is_proposer_tx,
             })
         };
         self.tx_order = tx_order;
         self.tx_timestamp = tx_timestamp;
+        self.is_proposer_tx = is_proposer_tx;
         old
     }
 }
 
 impl BatchMaker {
-    pub fn new(rooch_store: RoochStore) -> Self {
+    pub fn new(rooch_store: RoochStore, batch_interval: u64) -> Self {
         Self {
             pending_tx: PendingTx::new(),
-            in_progress_batch: InProgressBatch::init(),
+            in_progress_batch: InProgressBatch::init(batch_interval),
             rooch_store,
         }
     }
@@ -130,9 +153,16 @@ impl BatchMaker {
     // append transaction:
     // 1. push the new transaction to pending_tx return the old one if it has
     // 2. add the old transaction to the batch, return block number if a new batch is made
-    pub fn append_transaction(&mut self, tx_order: u64, tx_timestamp: u64) -> Option<u128> {
-        if let Some(old) = self.pending_tx.push(tx_order, tx_timestamp) {
-            if let Some(block_number) = self.add_to_batch(old.tx_order, old.tx_timestamp) {
+    pub fn append_transaction(
+        &mut self,
+        tx_order: u64,
+        tx_timestamp: u64,
+        is_proposer_tx: bool,
+    ) -> Option<u128> {
+        if let Some(old) = self.pending_tx.push(tx_order, tx_timestamp, is_proposer_tx) {
+            if let Some(block_number) =
+                self.add_to_batch(old.tx_order, old.tx_timestamp, old.is_proposer_tx)
+            {
                 return Some(block_number);
             }
         }
@@ -145,10 +175,15 @@ impl BatchMaker {
     }
 
     // add transaction to the batch, return block number if a new batch is made
-    fn add_to_batch(&mut self, tx_order: u64, tx_timestamp: u64) -> Option<u128> {
-        let order_range = self
-            .in_progress_batch
-            .append_transaction(tx_order, tx_timestamp);
+    fn add_to_batch(
+        &mut self,
+        tx_order: u64,
+        tx_timestamp: u64,
+        is_proposer_tx: bool,
+    ) -> Option<u128> {
+        let order_range =
+            self.in_progress_batch
+                .append_transaction(tx_order, tx_timestamp, is_proposer_tx);
         if let Some((tx_order_start, tx_order_end)) = order_range {
             match self
                 .rooch_store
@@ -176,45 +211,85 @@ impl BatchMaker {
 #[cfg(test)]
 mod test {
     use super::*;
+    use rooch_config::settings::ROOCH_BATCH_INTERVAL;
 
     #[test]
     fn test_in_progress_batch() {
-        let mut in_progress_batch = InProgressBatch::init();
-        assert_eq!(in_progress_batch.append_transaction(1, 1), None);
+        let mut in_progress_batch = InProgressBatch::init(ROOCH_BATCH_INTERVAL);
+        assert_eq!(in_progress_batch.append_transaction(1, 1, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(2, 2), None);
+        assert_eq!(in_progress_batch.append_transaction(2, 2, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(3, 3), None);
+        assert_eq!(in_progress_batch.append_transaction(3, 3, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(4, 4), None);
+        assert_eq!(in_progress_batch.append_transaction(4, 4, false), None);
 
         assert_eq!(
-            in_progress_batch.append_transaction(5, 1 + ROOCH_BATCH_INTERVAL),
+            in_progress_batch.append_transaction(5, 1 + ROOCH_BATCH_INTERVAL, false),
             Some((1, 5))
         );
 
-        assert_eq!(in_progress_batch.append_transaction(6, 6), None);
+        assert_eq!(in_progress_batch.append_transaction(6, 6, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(7, 7), None);
+        assert_eq!(in_progress_batch.append_transaction(7, 7, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(8, 8), None);
+        assert_eq!(in_progress_batch.append_transaction(8, 8, false), None);
 
         assert_eq!(
-            in_progress_batch.append_transaction(9, 6 + ROOCH_BATCH_INTERVAL),
+            in_progress_batch.append_transaction(9, 6 + ROOCH_BATCH_INTERVAL, false),
             Some((1, 9))
         );
 
         in_progress_batch.reset();
 
-        assert_eq!(in_progress_batch.append_transaction(6, 6), None);
+        assert_eq!(in_progress_batch.append_transaction(6, 6, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(7, 7), None);
+        assert_eq!(in_progress_batch.append_transaction(7, 7, false), None);
 
-        assert_eq!(in_progress_batch.append_transaction(8, 8), None);
+        assert_eq!(in_progress_batch.append_transaction(8, 8, false), None);
 
         assert_eq!(
-            in_progress_batch.append_transaction(9, 6 + ROOCH_BATCH_INTERVAL),
+            in_progress_batch.append_transaction(9, 6 + ROOCH_BATCH_INTERVAL, false),
             Some((6, 9))
         );
     }
+
+    #[test]
+    fn test_batch_maker_idle_chain() {
+        let (rooch_store, _tmpdir) = RoochStore::mock_rooch_store().unwrap();
+        let mut batch_maker = BatchMaker::new(rooch_store, ROOCH_BATCH_INTERVAL);
+
+        // The user transactions make a block
+        assert_eq!(batch_maker.append_transaction(1, 1, false), None);
+        assert_eq!(batch_maker.append_transaction(2, 2, false), None);
+        assert_eq!(
+            batch_maker.append_transaction(3, 2 + ROOCH_BATCH_INTERVAL, false),
+            None
+        );
+        assert_eq!(
+            batch_maker.append_transaction(4, 3 + ROOCH_BATCH_INTERVAL, false),
+            Some(0)
+        );
+
+        // Only the proposer submits the blocks, no more block is made however long the chain is idle
+        let mut tx_timestamp = 3 + ROOCH_BATCH_INTERVAL;
+        for tx_order in 5..10 {
+            tx_timestamp += ROOCH_BATCH_INTERVAL;
+            assert_eq!(
+                batch_maker.append_transaction(tx_order, tx_timestamp, true),
+                None
+            );
+        }
+
+        // The next user transaction closes the block with the proposer transactions
+        tx_timestamp += ROOCH_BATCH_INTERVAL;
+        assert_eq!(
+            batch_maker.append_transaction(10, tx_timestamp, false),
+            None
+        );
+        assert_eq!(
+            batch_maker.append_transaction(11, tx_timestamp + 1, false),
+            Some(1)
+        );
+    }
 }
@@ -5,3 +5,4 @@ pub mod actor;
 pub mod backend;
 mod batcher;
 pub mod proxy;
+mod reconciler;
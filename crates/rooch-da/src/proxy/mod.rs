@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    AppendTransactionMessage, GetServerStatusMessage, RevertTransactionMessage,
+    AppendTransactionMessage, GetServerStatusMessage, PauseSubmitMessage, RevertTransactionMessage,
 };
 use crate::actor::server::DAServerActor;
 use coerce::actor::ActorRef;
@@ -29,4 +29,8 @@ impl DAServerProxy {
     pub async fn revert_tx(&self, msg: RevertTransactionMessage) -> anyhow::Result<()> {
         self.actor.send(msg).await?
     }
+
+    pub async fn pause_submit(&self, paused: bool) -> anyhow::Result<()> {
+        self.actor.send(PauseSubmitMessage { paused }).await?
+    }
 }
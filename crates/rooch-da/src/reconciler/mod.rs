@@ -0,0 +1,266 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::errors::SubmitBatchError;
+use crate::actor::server::get_block_tx_list;
+use crate::backend::{BatchReconcileResult, DABackend};
+use anyhow::anyhow;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::RoochStore;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::da::batch::DABatch;
+use rooch_types::da::status::DABackendReconcileState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time;
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+
+// max blocks to check in a reconciliation job, the rest will be checked in the next jobs
+const MAX_BLOCKS_PER_JOB: u128 = 128;
+
+#[derive(Default)]
+struct BackendJobResult {
+    reconciled: BatchReconcileResult,
+    error: Option<String>,
+}
+
+/// Reconciler walks the submitted blocks, checks every segment of them in each verifiable backend,
+/// and resubmits the missing or corrupted segments, so all backends catch up eventually
+/// no matter which submit strategy is used.
+pub(crate) struct Reconciler {
+    rooch_store: RoochStore,
+    sequencer_key: RoochKeyPair,
+    backends: Vec<Arc<dyn DABackend>>,
+    min_block_to_submit: u128,
+    // the next block to reconcile of each backend, blocks before it are verified.
+    // it's persisted in the DA meta store after every job, the reconciliation resumes from it after restart.
+    cursors: Vec<u128>,
+    states: Arc<RwLock<Vec<DABackendReconcileState>>>,
+    // the reconciler resubmits segments, so it's paused with the background submitter
+    submit_paused: Arc<AtomicBool>,
+}
+
+impl Reconciler {
+    pub(crate) fn new(
+        rooch_store: RoochStore,
+        sequencer_key: RoochKeyPair,
+        backends: Vec<Arc<dyn DABackend>>,
+        min_block_to_submit: u128,
+        states: Arc<RwLock<Vec<DABackendReconcileState>>>,
+        submit_paused: Arc<AtomicBool>,
+    ) -> anyhow::Result<Self> {
+        let last_avail_block_number = rooch_store.get_background_submit_block_cursor()?;
+        let cursors = backends
+            .iter()
+            .map(|backend| {
+                let cursor = rooch_store
+                    .get_reconcile_block_cursor(&backend.get_identifier())?
+                    .unwrap_or(min_block_to_submit)
+                    .max(min_block_to_submit);
+                // the blocks may be rolled back after the cursor is saved, check them again
+                Ok(match last_avail_block_number {
+                    Some(last_avail_block_number) => cursor.min(last_avail_block_number + 1),
+                    None => min_block_to_submit,
+                })
+            })
+            .collect::<anyhow::Result<Vec<u128>>>()?;
+        Ok(Self {
+            rooch_store,
+            sequencer_key,
+            backends,
+            min_block_to_submit,
+            cursors,
+            states,
+            submit_paused,
+        })
+    }
+
+    pub(crate) fn run(mut self, reconcile_interval: u64, mut shutdown_rx: broadcast::Receiver<()>) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(reconcile_interval));
+            loop {
+                tokio::select! {
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("DA Reconciler thread received shutdown signal, exiting...");
+                        break;
+                    }
+                    _ = ticker.tick() => {
+                        if self.submit_paused.load(Ordering::Relaxed) {
+                            continue;
+                        }
+                        if let Err(e) = self.start_job().await {
+                            match e {
+                                SubmitBatchError::Recoverable(_) => {
+                                    tracing::warn!("da: reconciler failed: {}", e);
+                                }
+                                SubmitBatchError::DatabaseInconsistent(_) => {
+                                    tracing::error!("da: reconciler failed, will exit reconciliation: {}", e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    // reconcile the blocks confirmed by the background submitter: [min cursor, last avail block number]
+    async fn start_job(&mut self) -> anyhow::Result<(), SubmitBatchError> {
+        let last_avail_block_number = match self
+            .rooch_store
+            .get_background_submit_block_cursor()
+            .map_err(SubmitBatchError::Recoverable)?
+        {
+            Some(block_number) => block_number,
+            None => return Ok(()),
+        };
+
+        let mut results: Vec<BackendJobResult> = self
+            .backends
+            .iter()
+            .map(|_| BackendJobResult::default())
+            .collect();
+        let ret = self
+            .reconcile_blocks(last_avail_block_number, &mut results)
+            .await;
+        self.save_cursors()?;
+        self.update_states(last_avail_block_number, results)?;
+        ret
+    }
+
+    fn save_cursors(&self) -> anyhow::Result<(), SubmitBatchError> {
+        for (backend, cursor) in self.backends.iter().zip(self.cursors.iter().copied()) {
+            if !backend.is_verifiable() {
+                continue;
+            }
+            self.rooch_store
+                .set_reconcile_block_cursor(&backend.get_identifier(), cursor)
+                .map_err(SubmitBatchError::Recoverable)?;
+        }
+        Ok(())
+    }
+
+    async fn reconcile_blocks(
+        &mut self,
+        last_avail_block_number: u128,
+        results: &mut [BackendJobResult],
+    ) -> anyhow::Result<(), SubmitBatchError> {
+        let verifiable: Vec<usize> = (0..self.backends.len())
+            .filter(|i| self.backends[*i].is_verifiable())
+            .collect();
+        let start = match verifiable.iter().map(|i| self.cursors[*i]).min() {
+            Some(start) => start,
+            None => return Ok(()),
+        };
+        let end = last_avail_block_number.min(start.saturating_add(MAX_BLOCKS_PER_JOB - 1));
+
+        let mut block_number = start;
+        while block_number <= end {
+            // a backend stops at the first block failed to reconcile, retry in the next job
+            let pending: Vec<usize> = verifiable
+                .iter()
+                .copied()
+                .filter(|i| self.cursors[*i] == block_number && results[*i].error.is_none())
+                .collect();
+            if pending.is_empty() {
+                block_number += 1;
+                continue;
+            }
+
+            let batch = Arc::new(self.load_batch(block_number)?);
+            for i in pending {
+                let backend = &self.backends[i];
+                match backend.reconcile_batch(batch.clone()).await {
+                    Ok(reconciled) => {
+                        results[i].reconciled.missing_segments += reconciled.missing_segments;
+                        results[i].reconciled.resubmitted_segments +=
+                            reconciled.resubmitted_segments;
+                        self.cursors[i] = block_number + 1;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "da: fail to reconcile block {} in {}: {:?}",
+                            block_number,
+                            backend.get_identifier(),
+                            e
+                        );
+                        results[i].error = Some(format!("block {}: {}", block_number, e));
+                    }
+                }
+            }
+            block_number += 1;
+        }
+        Ok(())
+    }
+
+    // rebuild the batch of the submitted block, it must be the same as the submitted one
+    fn load_batch(&self, block_number: u128) -> anyhow::Result<DABatch, SubmitBatchError> {
+        let block_state = self
+            .rooch_store
+            .get_block_state(block_number)
+            .map_err(SubmitBatchError::Recoverable)?;
+        if !block_state.done {
+            return Err(SubmitBatchError::Recoverable(anyhow!(
+                "block {} is not submitted yet",
+                block_number
+            )));
+        }
+        let block_range = block_state.block_range;
+        let tx_list = get_block_tx_list(&self.rooch_store, &block_range)?;
+        let batch = DABatch::new(
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            &tx_list,
+            &self.sequencer_key,
+        )
+        .map_err(SubmitBatchError::DatabaseInconsistent)?;
+        let batch_hash = batch.get_hash();
+        if batch_hash != block_state.batch_hash {
+            return Err(SubmitBatchError::DatabaseInconsistent(anyhow!(
+                "batch hash mismatch for block {}: exp {:?}, act {:?}",
+                block_number,
+                block_state.batch_hash,
+                batch_hash
+            )));
+        }
+        Ok(batch)
+    }
+
+    fn update_states(
+        &self,
+        last_avail_block_number: u128,
+        results: Vec<BackendJobResult>,
+    ) -> anyhow::Result<(), SubmitBatchError> {
+        let now = SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .map_err(|e| SubmitBatchError::Recoverable(e.into()))?
+            .as_secs();
+        let mut states = self
+            .states
+            .write()
+            .map_err(|e| SubmitBatchError::Recoverable(anyhow!("{}", e)))?;
+        for ((state, result), cursor) in states
+            .iter_mut()
+            .zip(results)
+            .zip(self.cursors.iter().copied())
+        {
+            if !state.verifiable {
+                continue;
+            }
+            state.verified_block_number = if cursor > self.min_block_to_submit {
+                Some(cursor - 1)
+            } else {
+                None
+            };
+            state.lag = (last_avail_block_number + 1).saturating_sub(cursor);
+            state.missing_segments += result.reconciled.missing_segments;
+            state.resubmitted_segments += result.reconciled.resubmitted_segments;
+            state.last_reconcile_time = Some(now);
+            state.last_error = result.error;
+        }
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ use moveos_types::function_return_value::FunctionResult;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::option::MoveOption;
 use moveos_types::moveos_std::object::ObjectMeta;
-use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::moveos_std::tx_context::{GasPaymentAccount, TxContext};
 use moveos_types::moveos_std::tx_meta::TxMeta;
 use moveos_types::state::{ObjectState, StateChangeSetExt};
 use moveos_types::state_resolver::RootObjectResolver;
@@ -35,6 +35,7 @@ use rooch_notify::messages::{GasUpgradeMessage, NotifyActorSubscribeMessage};
 use rooch_store::state_store::StateStore;
 use rooch_store::RoochStore;
 use rooch_types::address::{BitcoinAddress, MultiChainAddress};
+use rooch_types::bitcoin::light_client::{header_error_message, LightClientModule};
 use rooch_types::bitcoin::transaction_validator::TransactionValidator as L1TransactionValidator;
 use rooch_types::bitcoin::BitcoinModule;
 use rooch_types::error::RoochError;
@@ -48,7 +49,7 @@ use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::authenticator::AUTH_PAYLOAD_SIZE;
 use rooch_types::transaction::{
     AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, RoochTransaction,
-    RoochTransactionData,
+    RoochTransactionData, TransactionExpiration,
 };
 use std::str::FromStr;
 use std::sync::Arc;
@@ -182,7 +183,6 @@ impl ExecutorActor {
         let tx_size = l1_block.block.tx_size();
         let ctx = TxContext::new_system_call_ctx(tx_hash, tx_size);
         //TODO we should call the contract to validate the l1 block has been executed
-        //In the future, we should verify the block PoW difficulty or PoS validator signature before the sequencer decentralized
         let L1BlockWithBody {
             block:
                 L1Block {
@@ -194,6 +194,23 @@ impl ExecutorActor {
         } = l1_block;
         let result = match RoochMultiChainID::try_from(chain_id.id())? {
             RoochMultiChainID::Bitcoin => {
+                // Validate the block header PoW and difficulty via the light client contract
+                let block = rooch_types::bitcoin::types::Block::decode(&block_body)?;
+                let light_client = self.as_module_binding::<LightClientModule>();
+                let error_code = light_client.validate_header(
+                    block_height,
+                    AccountAddress::from_bytes(&block_hash)?,
+                    &block.header,
+                )?;
+                if error_code != 0 {
+                    return Err(RoochError::L1BlockValidationError(format!(
+                        "bitcoin block at height {}: {}",
+                        block_height,
+                        header_error_message(error_code)
+                    ))
+                    .into());
+                }
+
                 let action = VerifiedMoveAction::Function {
                     call: BitcoinModule::create_execute_l1_block_call_bytes(
                         block_height,
@@ -285,9 +302,40 @@ impl ExecutorActor {
         tracing::debug!("executor validate_l2_tx: {:?}, sender: {}", tx_hash, sender);
 
         let authenticator = tx.authenticator_info();
+        let fee_payer = tx.fee_payer();
+        let fee_payer_authenticator = tx.fee_payer_authenticator_info();
         let mut moveos_tx: MoveOSTransaction = tx.into_moveos_transaction(self.root.clone());
         let tx_size = moveos_tx.ctx.tx_size;
-        let tx_result = self.validate_authenticator(&moveos_tx.ctx, authenticator);
+        // The fee payer of a sponsored transaction is validated before the sender
+        let tx_result = match fee_payer {
+            Some(fee_payer) => {
+                let fee_payer_authenticator = fee_payer_authenticator.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The sponsored transaction is not signed by the fee payer {}",
+                        fee_payer
+                    )
+                })?;
+                // The fee payer can only sign with the Bitcoin auth validator, the session key is not supported
+                let fee_payer_auth_validator_id =
+                    fee_payer_authenticator.authenticator.auth_validator_id();
+                if fee_payer_auth_validator_id != BuiltinAuthValidator::Bitcoin.flag() as u64 {
+                    return Err(anyhow::anyhow!(
+                        "The fee payer {} must sign with the Bitcoin auth validator, got auth validator {}",
+                        fee_payer,
+                        fee_payer_auth_validator_id
+                    ));
+                }
+                match self.validate_fee_payer(
+                    &moveos_tx.ctx,
+                    fee_payer.into(),
+                    fee_payer_authenticator,
+                )? {
+                    Ok(()) => self.validate_authenticator(&moveos_tx.ctx, authenticator),
+                    Err(vm_status) => Ok(Err(vm_status)),
+                }
+            }
+            None => self.validate_authenticator(&moveos_tx.ctx, authenticator),
+        };
         let result = match tx_result {
             Ok(vm_result) => match vm_result {
                 Ok(tx_validate_result) => {
@@ -379,6 +427,18 @@ impl ExecutorActor {
         Ok(vm_result)
     }
 
+    pub fn validate_fee_payer(
+        &self,
+        ctx: &TxContext,
+        fee_payer: AccountAddress,
+        authenticator: AuthenticatorInfo,
+    ) -> Result<Result<(), VMStatus>> {
+        let tx_validator = self.as_module_binding::<TransactionValidator>();
+        Ok(tx_validator
+            .validate_fee_payer(ctx, fee_payer, authenticator)?
+            .into_result())
+    }
+
     pub fn convert_to_verified_tx_for_dry_run(
         &self,
         tx_data: RoochTransactionData,
@@ -399,6 +459,17 @@ impl ExecutorActor {
 
         let tx_metadata = TxMeta::new_from_move_action(&tx_data.action);
         tx_ctx.add(tx_metadata).unwrap();
+        if let Some(fee_payer) = tx_data.fee_payer() {
+            tx_ctx.add(GasPaymentAccount {
+                account: fee_payer.into(),
+                pay_gas_by_module_account: false,
+            })?;
+        }
+        if let Some(expiration_timestamp_secs) = tx_data.expiration_timestamp_secs() {
+            tx_ctx.add(TransactionExpiration {
+                expiration_timestamp_secs,
+            })?;
+        }
 
         let mut bitcoin_address = BitcoinAddress::from_str("18cBEMRxXHqzWWCxZNtU91F5sbUNKhL5PX")?;
 
@@ -426,6 +497,15 @@ impl ExecutorActor {
                 module_bundle,
                 init_function_modules: vec![],
             },
+            // The MultiCall arguments and results are only checked in the verification
+            MoveAction::MultiCall(multi_call) => {
+                let moveos_tx = MoveOSTransaction {
+                    root: root.clone(),
+                    ctx: tx_ctx.clone(),
+                    action: MoveAction::MultiCall(multi_call),
+                };
+                self.moveos.verify(moveos_tx)?.action
+            }
         };
 
         Ok(VerifiedMoveOSTransaction::new(
@@ -467,7 +547,8 @@ impl Handler<ValidateL2TxMessage> for ExecutorActor {
         msg: ValidateL2TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l2_tx(msg.tx)
+        let span = tracing::info_span!(parent: &msg.span, "executor.validate_l2_tx");
+        span.in_scope(|| self.validate_l2_tx(msg.tx))
     }
 }
 
@@ -478,7 +559,8 @@ impl Handler<ValidateL1BlockMessage> for ExecutorActor {
         msg: ValidateL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l1_block(msg.l1_block)
+        let span = tracing::info_span!(parent: &msg.span, "executor.validate_l1_block");
+        span.in_scope(|| self.validate_l1_block(msg.l1_block))
     }
 }
 
@@ -489,7 +571,8 @@ impl Handler<ValidateL1TxMessage> for ExecutorActor {
         msg: ValidateL1TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l1_tx(msg.l1_tx)
+        let span = tracing::info_span!(parent: &msg.span, "executor.validate_l1_tx");
+        span.in_scope(|| self.validate_l1_tx(msg.l1_tx))
     }
 }
 
@@ -500,7 +583,12 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
         msg: ExecuteTransactionMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResult> {
-        self.execute(msg.tx)
+        let span = tracing::info_span!(
+            parent: &msg.span,
+            "executor.execute",
+            tx_hash = ?msg.tx.ctx.tx_hash()
+        );
+        span.in_scope(|| self.execute(msg.tx))
     }
 }
 
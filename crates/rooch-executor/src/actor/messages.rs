@@ -24,6 +24,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 pub struct ValidateL2TxMessage {
     pub tx: RoochTransaction,
+    /// The span of the caller, the parent of the validate span
+    pub span: tracing::Span,
 }
 
 impl Message for ValidateL2TxMessage {
@@ -33,6 +35,8 @@ impl Message for ValidateL2TxMessage {
 #[derive(Debug)]
 pub struct ValidateL1BlockMessage {
     pub l1_block: L1BlockWithBody,
+    /// The span of the caller, the parent of the validate span
+    pub span: tracing::Span,
 }
 
 impl Message for ValidateL1BlockMessage {
@@ -42,6 +46,8 @@ impl Message for ValidateL1BlockMessage {
 #[derive(Debug)]
 pub struct ValidateL1TxMessage {
     pub l1_tx: L1Transaction,
+    /// The span of the caller, the parent of the validate span
+    pub span: tracing::Span,
 }
 
 impl Message for ValidateL1TxMessage {
@@ -51,6 +57,8 @@ impl Message for ValidateL1TxMessage {
 #[derive(Debug)]
 pub struct ExecuteTransactionMessage {
     pub tx: VerifiedMoveOSTransaction,
+    /// The span of the caller, the parent of the execute span
+    pub span: tracing::Span,
 }
 
 #[derive(Debug)]
@@ -4,3 +4,4 @@
 pub mod actor;
 pub mod metrics;
 pub mod proxy;
+pub mod witness;
@@ -61,18 +61,33 @@ impl ExecutorProxy {
     }
 
     pub async fn validate_l2_tx(&self, tx: RoochTransaction) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL2TxMessage { tx }).await?
+        self.actor
+            .send(ValidateL2TxMessage {
+                tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn validate_l1_block(
         &self,
         l1_block: L1BlockWithBody,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL1BlockMessage { l1_block }).await?
+        self.actor
+            .send(ValidateL1BlockMessage {
+                l1_block,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn validate_l1_tx(&self, l1_tx: L1Transaction) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL1TxMessage { l1_tx }).await?
+        self.actor
+            .send(ValidateL1TxMessage {
+                l1_tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn convert_to_verified_tx(
@@ -91,7 +106,10 @@ impl ExecutorProxy {
     ) -> Result<(TransactionOutput, TransactionExecutionInfo)> {
         let result = self
             .actor
-            .send(crate::actor::messages::ExecuteTransactionMessage { tx })
+            .send(crate::actor::messages::ExecuteTransactionMessage {
+                tx,
+                span: tracing::Span::current(),
+            })
             .await??;
         Ok((result.output, result.transaction_info))
     }
@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::executor::ExecutorActor;
+use anyhow::{bail, ensure, Result};
+use moveos_store::state_store::NodeRecorder;
+use moveos_store::MoveOSStore;
+use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::state::{FieldKey, ObjectState};
+use prometheus::Registry;
+use rooch_store::RoochStore;
+use rooch_types::transaction::{LedgerTransaction, LedgerTxData, TransactionWitness};
+
+/// Re-execute the transaction on the full state at `pre_root`, and record the state nodes read
+/// during the execution as the witness of the transaction.
+pub fn generate_witness(
+    moveos_store: &MoveOSStore,
+    rooch_store: RoochStore,
+    pre_root: ObjectMeta,
+    transaction: LedgerTransaction,
+) -> Result<TransactionWitness> {
+    let recorder = NodeRecorder::new();
+    let recording_store = moveos_store.with_node_recorder(recorder.clone(), &Registry::new());
+    let post_root = execute_ledger_transaction(
+        recording_store,
+        rooch_store,
+        pre_root.clone(),
+        transaction.clone(),
+    )?;
+    Ok(TransactionWitness::new(
+        transaction,
+        pre_root.state_root(),
+        pre_root.size,
+        post_root.state_root(),
+        post_root.size,
+        recorder.nodes(),
+    ))
+}
+
+/// Replay the transaction from the witness alone, returns the post state root of the execution.
+/// Every witness node is checked against its hash, so an invalid witness can not forge the state,
+/// and a witness missing nodes makes the execution fail.
+pub fn replay_witness(witness: &TransactionWitness) -> Result<ObjectMeta> {
+    for (hash, node) in &witness.nodes {
+        smt::verify_node::<FieldKey, ObjectState>(hash, node)?;
+    }
+    // The temp stores only hold the witness nodes, hold the dirs until the execution finished.
+    let (moveos_store, _moveos_store_dir) = MoveOSStore::mock_moveos_store()?;
+    let (rooch_store, _rooch_store_dir) = RoochStore::mock_rooch_store()?;
+    moveos_store
+        .get_state_node_store()
+        .write_nodes(witness.nodes.clone())?;
+    execute_ledger_transaction(
+        moveos_store,
+        rooch_store,
+        witness.pre_root(),
+        witness.transaction.clone(),
+    )
+}
+
+/// Replay the transaction from the witness and check the post state root is the same as the witness.
+pub fn verify_witness(witness: &TransactionWitness) -> Result<()> {
+    let post_root = replay_witness(witness)?;
+    ensure!(
+        post_root.state_root() == witness.post_state_root && post_root.size == witness.post_size,
+        "Replay state root is not equal to the witness: tx_order: {}, exp: {:?}({}), act: {:?}({})",
+        witness.tx_order(),
+        witness.post_state_root,
+        witness.post_size,
+        post_root.state_root(),
+        post_root.size
+    );
+    Ok(())
+}
+
+/// Execute the transaction the same as the pipeline processor, but do not write the state.
+fn execute_ledger_transaction(
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    pre_root: ObjectMeta,
+    transaction: LedgerTransaction,
+) -> Result<ObjectMeta> {
+    let executor = ExecutorActor::new(
+        pre_root,
+        moveos_store.clone(),
+        rooch_store,
+        &Registry::new(),
+        None,
+    )?;
+    let mut moveos_tx = match &transaction.data {
+        LedgerTxData::L1Block(block) => bail!(
+            "Re-execute the L1 block at height {} is not supported, the block body is not in the ledger",
+            block.block_height
+        ),
+        LedgerTxData::L1Tx(l1_tx) => executor.validate_l1_tx(l1_tx.clone())?,
+        LedgerTxData::L2Tx(l2_tx) => executor.validate_l2_tx(l2_tx.clone())?,
+    };
+    if let Some(tx_randomness) = &transaction.sequence_info.tx_randomness {
+        moveos_tx.ctx.add(tx_randomness.clone())?;
+    }
+    moveos_tx.ctx.add(transaction.sequence_info)?;
+
+    let (raw_output, _) = executor.moveos().execute_only(moveos_tx)?;
+    let mut changeset = raw_output.changeset;
+    moveos_store
+        .get_state_store()
+        .change_set_to_nodes(&mut changeset)?;
+    Ok(ObjectMeta::root_metadata(
+        changeset.state_root,
+        changeset.global_size,
+    ))
+}
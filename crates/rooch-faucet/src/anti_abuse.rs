@@ -0,0 +1,358 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ClaimProof, FaucetError};
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::fmt::Debug;
+use std::path::PathBuf;
+
+/// The max difficulty of the proof-of-work, in leading zero bits of the hash.
+pub const MAX_POW_DIFFICULTY: u8 = 32;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntiAbuseType {
+    /// No challenge, only the cooldowns and quotas
+    None,
+    /// The claimer should solve a proof-of-work challenge issued by `/challenge`
+    Pow,
+    /// The claimer should provide a captcha token signed by the portal with the shared secret
+    Captcha,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[clap(rename_all = "kebab-case")]
+pub struct AntiAbuseConfig {
+    /// The anti-abuse check of the `/claim` endpoint
+    #[clap(long, value_enum, default_value_t = AntiAbuseType::None)]
+    pub anti_abuse: AntiAbuseType,
+
+    /// The secret to sign the proof-of-work challenges or to verify the captcha tokens.
+    /// A random secret is used for the proof-of-work if not set, the issued challenges are invalid after restart.
+    #[clap(long, env = "ROOCH_FAUCET_ANTI_ABUSE_SECRET")]
+    pub anti_abuse_secret: Option<String>,
+
+    /// The leading zero bits required of the proof-of-work hash
+    #[clap(long, default_value_t = 20)]
+    pub pow_difficulty: u8,
+
+    /// How long the proof-of-work challenge is valid, in seconds
+    #[clap(long, default_value_t = 300)]
+    pub challenge_ttl_secs: u64,
+
+    /// An address can claim once in this period, in seconds. 0 means no cooldown.
+    #[clap(long, default_value_t = 86400)]
+    pub address_cooldown_secs: u64,
+
+    /// The max claims from an IP in the quota window. 0 means no quota.
+    #[clap(long, default_value_t = 5)]
+    pub ip_quota: u64,
+
+    /// The IP quota window, in seconds
+    #[clap(long, default_value_t = 86400)]
+    pub ip_quota_window_secs: u64,
+
+    /// Use the first IP of the `X-Forwarded-For` header as the client IP, enable it only behind a trusted proxy
+    #[clap(long)]
+    pub trust_forwarded_for: bool,
+
+    /// The dir of the db to persist the cooldowns and quotas, default is `faucet_db` in the rooch config dir
+    #[clap(long, env = "ROOCH_FAUCET_DB")]
+    pub faucet_db: Option<PathBuf>,
+}
+
+/// The challenge for the claimer to solve before claiming
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClaimChallenge {
+    pub challenge: String,
+    /// The leading zero bits required of `sha3_256("{challenge}:{claimer}:{nonce}")`,
+    /// the claimer is the rooch address of the claimer.
+    pub difficulty: u8,
+    /// The challenge is invalid after this time, in seconds
+    pub expires_at: u64,
+}
+
+/// A pluggable check to protect the claim from abuse.
+/// The proof is bound to the rooch address of the claimer, so it can not be reused for other addresses.
+pub trait ClaimGuard: Debug + Send + Sync {
+    /// Issue a challenge for the claimer to solve, None if the guard does not need a challenge.
+    fn challenge(&self, now: u64) -> Option<ClaimChallenge>;
+
+    fn verify(&self, claimer: &str, proof: &ClaimProof, now: u64) -> Result<(), FaucetError>;
+}
+
+pub fn new_claim_guard(config: &AntiAbuseConfig) -> anyhow::Result<Box<dyn ClaimGuard>> {
+    Ok(match config.anti_abuse {
+        AntiAbuseType::None => Box::new(NoneGuard),
+        AntiAbuseType::Pow => {
+            anyhow::ensure!(
+                config.pow_difficulty <= MAX_POW_DIFFICULTY,
+                "pow difficulty should not be greater than {}",
+                MAX_POW_DIFFICULTY
+            );
+            let secret = config
+                .anti_abuse_secret
+                .clone()
+                .unwrap_or_else(|| hex::encode(rand::random::<[u8; 32]>()));
+            Box::new(ProofOfWorkGuard::new(
+                secret,
+                config.pow_difficulty,
+                config.challenge_ttl_secs,
+            ))
+        }
+        AntiAbuseType::Captcha => {
+            let secret = config.anti_abuse_secret.clone().ok_or_else(|| {
+                anyhow::anyhow!("--anti-abuse-secret is required for the captcha check")
+            })?;
+            Box::new(CaptchaGuard::new(secret))
+        }
+    })
+}
+
+/// `sha3_256(secret || message)`, SHA3 is not vulnerable to the length extension attack,
+/// so the keyed hash can be used as a MAC.
+fn mac(secret: &str, message: &str) -> String {
+    let mut hasher = Sha3_256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(message.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Compare the strings in constant time, to not leak the MAC by timing.
+pub(crate) fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+fn leading_zero_bits(hash: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in hash {
+        if *byte == 0 {
+            bits += 8;
+        } else {
+            bits += byte.leading_zeros();
+            break;
+        }
+    }
+    bits
+}
+
+#[derive(Debug)]
+pub struct NoneGuard;
+
+impl ClaimGuard for NoneGuard {
+    fn challenge(&self, _now: u64) -> Option<ClaimChallenge> {
+        None
+    }
+
+    fn verify(&self, _claimer: &str, _proof: &ClaimProof, _now: u64) -> Result<(), FaucetError> {
+        Ok(())
+    }
+}
+
+/// The challenge is `{expires_at}.{salt}.{mac}`, so the guard is stateless and the challenge can not be forged.
+/// The claimer finds a `nonce` that `sha3_256("{challenge}:{claimer}:{nonce}")` has `difficulty` leading zero bits.
+pub struct ProofOfWorkGuard {
+    secret: String,
+    difficulty: u8,
+    ttl: u64,
+}
+
+impl Debug for ProofOfWorkGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProofOfWorkGuard")
+            .field("difficulty", &self.difficulty)
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+impl ProofOfWorkGuard {
+    pub fn new(secret: String, difficulty: u8, ttl: u64) -> Self {
+        Self {
+            secret,
+            difficulty,
+            ttl,
+        }
+    }
+
+    pub fn pow_hash(challenge: &str, claimer: &str, nonce: &str) -> Vec<u8> {
+        Sha3_256::digest(format!("{}:{}:{}", challenge, claimer, nonce).as_bytes()).to_vec()
+    }
+
+    /// Solve the challenge, it is used by the tests and the clients written in Rust.
+    pub fn solve(challenge: &str, claimer: &str, difficulty: u8) -> String {
+        (0u64..)
+            .map(|nonce| nonce.to_string())
+            .find(|nonce| {
+                leading_zero_bits(&Self::pow_hash(challenge, claimer, nonce)) >= difficulty as u32
+            })
+            .expect("should find a nonce")
+    }
+}
+
+impl ClaimGuard for ProofOfWorkGuard {
+    fn challenge(&self, now: u64) -> Option<ClaimChallenge> {
+        let expires_at = now.saturating_add(self.ttl);
+        let payload = format!("{}.{}", expires_at, hex::encode(rand::random::<[u8; 16]>()));
+        let challenge = format!("{}.{}", payload, mac(&self.secret, &payload));
+        Some(ClaimChallenge {
+            challenge,
+            difficulty: self.difficulty,
+            expires_at,
+        })
+    }
+
+    fn verify(&self, claimer: &str, proof: &ClaimProof, now: u64) -> Result<(), FaucetError> {
+        let (challenge, nonce) = match (&proof.challenge, &proof.nonce) {
+            (Some(challenge), Some(nonce)) => (challenge, nonce),
+            _ => {
+                return Err(FaucetError::InvalidProof(
+                    "challenge and nonce are required".to_string(),
+                ))
+            }
+        };
+        let (payload, challenge_mac) = challenge
+            .rsplit_once('.')
+            .ok_or_else(|| FaucetError::InvalidProof("invalid challenge".to_string()))?;
+        if !constant_time_eq(&mac(&self.secret, payload), challenge_mac) {
+            return Err(FaucetError::InvalidProof("invalid challenge".to_string()));
+        }
+        let expires_at = payload
+            .split_once('.')
+            .and_then(|(expires_at, _)| expires_at.parse::<u64>().ok())
+            .ok_or_else(|| FaucetError::InvalidProof("invalid challenge".to_string()))?;
+        if expires_at < now {
+            return Err(FaucetError::InvalidProof("challenge expired".to_string()));
+        }
+        let hash = Self::pow_hash(challenge, claimer, nonce);
+        if leading_zero_bits(&hash) < self.difficulty as u32 {
+            return Err(FaucetError::InvalidProof(
+                "proof-of-work does not meet the difficulty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The portal verifies the captcha, and issues the token `{expires_at}.{mac}` to the claimer,
+/// the mac is `sha3_256(secret || "{claimer}.{expires_at}")` with the secret shared with the faucet.
+pub struct CaptchaGuard {
+    secret: String,
+}
+
+impl Debug for CaptchaGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CaptchaGuard").finish()
+    }
+}
+
+impl CaptchaGuard {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    pub fn sign_token(secret: &str, claimer: &str, expires_at: u64) -> String {
+        format!(
+            "{}.{}",
+            expires_at,
+            mac(secret, &format!("{}.{}", claimer, expires_at))
+        )
+    }
+}
+
+impl ClaimGuard for CaptchaGuard {
+    fn challenge(&self, _now: u64) -> Option<ClaimChallenge> {
+        None
+    }
+
+    fn verify(&self, claimer: &str, proof: &ClaimProof, now: u64) -> Result<(), FaucetError> {
+        let token = proof
+            .captcha_token
+            .as_ref()
+            .ok_or_else(|| FaucetError::InvalidProof("captcha token is required".to_string()))?;
+        let (expires_at, token_mac) = token
+            .split_once('.')
+            .ok_or_else(|| FaucetError::InvalidProof("invalid captcha token".to_string()))?;
+        if !constant_time_eq(
+            &mac(&self.secret, &format!("{}.{}", claimer, expires_at)),
+            token_mac,
+        ) {
+            return Err(FaucetError::InvalidProof(
+                "invalid captcha token".to_string(),
+            ));
+        }
+        let expires_at = expires_at
+            .parse::<u64>()
+            .map_err(|_| FaucetError::InvalidProof("invalid captcha token".to_string()))?;
+        if expires_at < now {
+            return Err(FaucetError::InvalidProof(
+                "captcha token expired".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLAIMER: &str = "rooch1claimer";
+
+    #[test]
+    fn test_proof_of_work() {
+        let guard = ProofOfWorkGuard::new("secret".to_string(), 8, 300);
+        let challenge = guard.challenge(1000).unwrap();
+        assert_eq!(challenge.expires_at, 1300);
+        let nonce = ProofOfWorkGuard::solve(&challenge.challenge, CLAIMER, challenge.difficulty);
+        let proof = ClaimProof {
+            challenge: Some(challenge.challenge.clone()),
+            nonce: Some(nonce),
+            captcha_token: None,
+        };
+        guard.verify(CLAIMER, &proof, 1000).unwrap();
+        // The proof is bound to the claimer
+        assert!(guard.verify("rooch1other", &proof, 1000).is_err());
+        // Expired
+        assert!(guard.verify(CLAIMER, &proof, 1301).is_err());
+        // The challenge issued by another secret
+        let other_guard = ProofOfWorkGuard::new("other".to_string(), 8, 300);
+        assert!(other_guard.verify(CLAIMER, &proof, 1000).is_err());
+        // Forge the expiration
+        let forged = ClaimProof {
+            challenge: Some(challenge.challenge.replacen("1300", "9999", 1)),
+            ..proof
+        };
+        assert!(guard.verify(CLAIMER, &forged, 1000).is_err());
+        assert!(guard.verify(CLAIMER, &ClaimProof::default(), 1000).is_err());
+    }
+
+    #[test]
+    fn test_captcha_token() {
+        let guard = CaptchaGuard::new("secret".to_string());
+        let proof = ClaimProof {
+            captcha_token: Some(CaptchaGuard::sign_token("secret", CLAIMER, 2000)),
+            ..Default::default()
+        };
+        guard.verify(CLAIMER, &proof, 1000).unwrap();
+        assert!(guard.verify("rooch1other", &proof, 1000).is_err());
+        assert!(guard.verify(CLAIMER, &proof, 2001).is_err());
+        let proof = ClaimProof {
+            captcha_token: Some(CaptchaGuard::sign_token("other", CLAIMER, 2000)),
+            ..Default::default()
+        };
+        assert!(guard.verify(CLAIMER, &proof, 1000).is_err());
+    }
+
+    #[test]
+    fn test_leading_zero_bits() {
+        assert_eq!(leading_zero_bits(&[0, 0x10, 0xff]), 11);
+        assert_eq!(leading_zero_bits(&[0x80]), 0);
+        assert_eq!(leading_zero_bits(&[0, 0]), 16);
+    }
+}
@@ -2,11 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    faucet_proxy::FaucetProxy, DiscordConfig, FaucetError, FaucetRequest, FaucetRequestWithInviter,
+    anti_abuse::{ClaimChallenge, ClaimGuard},
+    faucet_proxy::FaucetProxy,
+    quota::ClaimQuota,
+    AdminBalanceResponse, ClaimRequest, DiscordConfig, FaucetError, FaucetRequest,
+    FaucetRequestWithInviter,
 };
 use move_core_types::u256::U256;
-use rooch_rpc_api::jsonrpc_types::UnitedAddressView;
+use rooch_rpc_api::jsonrpc_types::{StrView, UnitedAddressView};
+use std::net::IpAddr;
 use std::sync::{atomic::AtomicBool, Arc};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::{mpsc::Receiver, RwLock};
 
 #[derive(Clone, Debug)]
@@ -15,6 +21,12 @@ pub struct App {
     pub err_receiver: Arc<RwLock<Receiver<FaucetError>>>,
     pub discord_config: DiscordConfig,
     pub is_loop_running: Arc<AtomicBool>,
+    pub claim_guard: Arc<dyn ClaimGuard>,
+    pub claim_quota: Arc<ClaimQuota>,
+    /// The bearer token of the admin API, the admin API is disabled if it is None
+    pub admin_token: Option<String>,
+    /// Use the `X-Forwarded-For` header as the client IP
+    pub trust_forwarded_for: bool,
 }
 
 impl App {
@@ -22,15 +34,92 @@ impl App {
         faucet_proxy: FaucetProxy,
         err_receiver: Receiver<FaucetError>,
         discord_config: DiscordConfig,
+        claim_guard: Box<dyn ClaimGuard>,
+        claim_quota: ClaimQuota,
+        admin_token: Option<String>,
+        trust_forwarded_for: bool,
     ) -> Self {
         Self {
             faucet_proxy,
             err_receiver: Arc::new(RwLock::new(err_receiver)),
             discord_config,
             is_loop_running: Arc::new(AtomicBool::new(false)),
+            claim_guard: claim_guard.into(),
+            claim_quota: Arc::new(claim_quota),
+            admin_token,
+            trust_forwarded_for,
         }
     }
 
+    pub fn challenge(&self) -> Option<ClaimChallenge> {
+        self.claim_guard.challenge(now_secs())
+    }
+
+    /// Claim RGas for any address bound to a bitcoin address, protected by the anti-abuse check,
+    /// the cooldown of the bitcoin address and the quota of the client IP.
+    pub async fn claim(
+        &self,
+        request: ClaimRequest,
+        ip: Option<IpAddr>,
+    ) -> Result<U256, FaucetError> {
+        let now = now_secs();
+        let rooch_address = request.claimer.0.rooch_address.to_string();
+        self.claim_guard
+            .verify(&rooch_address, &request.proof, now)?;
+
+        let claimer = self
+            .faucet_proxy
+            .resolve_bitcoin_address(request.claimer)
+            .await
+            .map_err(|e| {
+                e.downcast::<FaucetError>()
+                    .unwrap_or_else(FaucetError::custom)
+            })?;
+        let bitcoin_address = claimer
+            .0
+            .bitcoin_address
+            .as_ref()
+            .map(|address| address.to_string())
+            .ok_or_else(|| FaucetError::InvalidAddress(rooch_address))?;
+
+        let ip = ip.map(|ip| ip.to_string());
+        let permit = self
+            .claim_quota
+            .acquire(&bitcoin_address, ip.as_deref(), now)?;
+        match self.faucet_proxy.claim(claimer).await {
+            Ok(amount) => Ok(amount),
+            Err(e) => {
+                if let Err(release_err) = self.claim_quota.release(permit) {
+                    tracing::warn!(
+                        "Failed to release the claim quota of {}: {:?}",
+                        bitcoin_address,
+                        release_err
+                    );
+                }
+                Err(FaucetError::custom(e))
+            }
+        }
+    }
+
+    pub async fn admin_balance(&self) -> Result<AdminBalanceResponse, FaucetError> {
+        let balance = self.check_gas_balance().await?;
+        let alert_threshold = self.discord_config.notify_threshold;
+        Ok(AdminBalanceResponse {
+            balance: StrView(balance),
+            alert_threshold: StrView(alert_threshold),
+            low_balance: balance < alert_threshold,
+        })
+    }
+
+    pub async fn refill(&self, amount: U256) -> Result<U256, FaucetError> {
+        let balance = self
+            .faucet_proxy
+            .refill(amount)
+            .await
+            .map_err(FaucetError::custom)?;
+        Ok(balance)
+    }
+
     pub async fn request(&self, request: FaucetRequest) -> Result<U256, FaucetError> {
         let amount = self
             .faucet_proxy
@@ -109,3 +198,10 @@ impl App {
         Ok(address.to_rooch_address().to_string())
     }
 }
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_secs()
+}
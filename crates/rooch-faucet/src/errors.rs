@@ -32,6 +32,15 @@ pub enum FaucetError {
     #[error("Coin amounts sent are incorrect:`{0}`")]
     CoinAmountTransferredIncorrect(String),
 
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
+    #[error("Invalid anti-abuse proof: {0}")]
+    InvalidProof(String),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
     #[error("{0}")]
     Custom(String),
 }
@@ -18,7 +18,9 @@ use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::transaction::{FunctionCall, MoveAction};
 use prometheus::Registry;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::UTXOFilterView;
-use rooch_rpc_api::jsonrpc_types::{KeptVMStatusView, UnitedAddressView, VMStatusView};
+use rooch_rpc_api::jsonrpc_types::{
+    KeptVMStatusView, StrView, UnitedAddress, UnitedAddressView, VMStatusView,
+};
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_rpc_client::Client;
 use rooch_types::address::{BitcoinAddress, ParsedAddress, RoochAddress};
@@ -120,6 +122,40 @@ impl Handler<BalanceMessage> for Faucet {
     }
 }
 
+pub struct ResolveBitcoinAddressMessage {
+    pub address: UnitedAddressView,
+}
+
+impl Message for ResolveBitcoinAddressMessage {
+    type Result = Result<UnitedAddressView>;
+}
+
+#[async_trait]
+impl Handler<ResolveBitcoinAddressMessage> for Faucet {
+    async fn handle(
+        &mut self,
+        msg: ResolveBitcoinAddressMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<UnitedAddressView> {
+        self.resolve_bitcoin_address(msg.address).await
+    }
+}
+
+pub struct RefillMessage {
+    pub amount: U256,
+}
+
+impl Message for RefillMessage {
+    type Result = Result<U256>;
+}
+
+#[async_trait]
+impl Handler<RefillMessage> for Faucet {
+    async fn handle(&mut self, msg: RefillMessage, _ctx: &mut ActorContext) -> Result<U256> {
+        self.refill(msg.amount).await
+    }
+}
+
 pub struct FetchTweetMessage {
     pub tweet_id: String,
 }
@@ -333,6 +369,61 @@ impl Faucet {
         }
     }
 
+    /// Fill the bitcoin address of the claimer, resolve it from the rooch address if it is not given.
+    async fn resolve_bitcoin_address(
+        &self,
+        address: UnitedAddressView,
+    ) -> Result<UnitedAddressView> {
+        if address.0.bitcoin_address.is_some() {
+            return Ok(address);
+        }
+        let client = self.context.get_client().await?;
+        let rooch_address = address.0.rooch_address;
+        let bitcoin_address = client
+            .rooch
+            .resolve_bitcoin_address(rooch_address)
+            .await?
+            .ok_or_else(|| {
+                FaucetError::InvalidAddress(format!(
+                    "{} is not bound to a bitcoin address",
+                    rooch_address
+                ))
+            })?;
+        Ok(StrView(UnitedAddress {
+            rooch_address,
+            bitcoin_address: Some(bitcoin_address),
+            nostr_public_key: None,
+        }))
+    }
+
+    /// Deposit RGas from the faucet sender to the faucet, and return the balance after the refill.
+    async fn refill(&self, amount: U256) -> Result<U256> {
+        let function_call = faucet_module::deposit_rgas_coin_function_call(
+            self.faucet_module_address,
+            self.faucet_object_id.clone(),
+            amount,
+        );
+        let tx_data = self
+            .context
+            .build_tx_data(
+                self.faucet_sender,
+                MoveAction::Function(function_call),
+                None,
+            )
+            .await?;
+        let response = self
+            .context
+            .sign_and_execute(self.faucet_sender, tx_data)
+            .await?;
+        match response.execution_info.status {
+            KeptVMStatusView::Executed => {
+                tracing::info!("Refill the faucet with {} RGas", amount);
+                self.balance().await
+            }
+            status => bail!("Refill failed, Unexpected VM status: {:?}", status),
+        }
+    }
+
     async fn check_claim(
         client: &Client,
         faucet_module_address: AccountAddress,
@@ -3,7 +3,7 @@
 
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::ModuleId,
-    value::MoveValue,
+    u256::U256, value::MoveValue,
 };
 use moveos_types::{
     move_types::FunctionId, moveos_std::object::ObjectID, state::MoveState,
@@ -16,6 +16,8 @@ pub const CHECK_CLAIM_FUNCTION: &IdentStr = ident_str!("check_claim");
 
 pub const CLAIM_FUNCTION: &IdentStr = ident_str!("claim");
 
+pub const DEPOSIT_RGAS_COIN_FUNCTION: &IdentStr = ident_str!("deposit_rgas_coin");
+
 pub fn check_claim_function_call(
     module_address: AccountAddress,
     faucet_object_id: ObjectID,
@@ -60,6 +62,24 @@ pub fn claim_function_call(
     }
 }
 
+pub fn deposit_rgas_coin_function_call(
+    module_address: AccountAddress,
+    faucet_object_id: ObjectID,
+    amount: U256,
+) -> FunctionCall {
+    FunctionCall {
+        function_id: FunctionId::new(
+            ModuleId::new(module_address, MODULE_NAME.to_owned()),
+            DEPOSIT_RGAS_COIN_FUNCTION.to_owned(),
+        ),
+        ty_args: vec![],
+        args: vec![
+            faucet_object_id.to_move_value().simple_serialize().unwrap(),
+            MoveValue::U256(amount).simple_serialize().unwrap(),
+        ],
+    }
+}
+
 pub fn balance_call(module_address: AccountAddress, faucet_object_id: ObjectID) -> FunctionCall {
     FunctionCall {
         function_id: FunctionId::new(
@@ -46,6 +46,19 @@ impl FaucetProxy {
         self.actor.send(crate::BalanceMessage).await?
     }
 
+    pub async fn resolve_bitcoin_address(
+        &self,
+        address: UnitedAddressView,
+    ) -> Result<UnitedAddressView> {
+        self.actor
+            .send(crate::ResolveBitcoinAddressMessage { address })
+            .await?
+    }
+
+    pub async fn refill(&self, amount: U256) -> Result<U256> {
+        self.actor.send(crate::RefillMessage { amount }).await?
+    }
+
     pub async fn fetch_tweet(&self, tweet_id: String) -> Result<ObjectID> {
         self.actor
             .send(crate::FetchTweetMessage { tweet_id })
@@ -35,3 +35,9 @@ mod twitter_account_module;
 
 mod invitation_module;
 pub mod server;
+
+pub mod anti_abuse;
+pub use anti_abuse::{AntiAbuseConfig, AntiAbuseType};
+
+pub mod quota;
+pub mod store;
@@ -0,0 +1,199 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::store::{ClaimRecord, FaucetStore};
+use crate::FaucetError;
+use std::sync::Mutex;
+
+/// The claim reserved by `ClaimQuota::acquire`, keep the previous records to release the claim if it fails.
+#[derive(Debug)]
+pub struct ClaimPermit {
+    address: String,
+    ip: Option<String>,
+    prev_address_record: Option<ClaimRecord>,
+    prev_ip_record: Option<ClaimRecord>,
+}
+
+/// The per-address cooldown and the per-IP quota of the claims, persisted in the `FaucetStore`.
+pub struct ClaimQuota {
+    store: FaucetStore,
+    /// In seconds
+    address_cooldown: u64,
+    ip_quota: u64,
+    /// In seconds
+    ip_quota_window: u64,
+    /// Serialize the check and the update of the records
+    lock: Mutex<()>,
+}
+
+impl std::fmt::Debug for ClaimQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClaimQuota")
+            .field("address_cooldown", &self.address_cooldown)
+            .field("ip_quota", &self.ip_quota)
+            .field("ip_quota_window", &self.ip_quota_window)
+            .finish()
+    }
+}
+
+impl ClaimQuota {
+    pub fn new(
+        store: FaucetStore,
+        address_cooldown: u64,
+        ip_quota: u64,
+        ip_quota_window: u64,
+    ) -> Self {
+        Self {
+            store,
+            address_cooldown,
+            ip_quota,
+            ip_quota_window,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Check the cooldown of the address and the quota of the IP at `now` in seconds, and reserve a claim.
+    /// The claim should be released if it fails, so the claimer can retry.
+    pub fn acquire(
+        &self,
+        address: &str,
+        ip: Option<&str>,
+        now: u64,
+    ) -> Result<ClaimPermit, FaucetError> {
+        let _guard = self.lock.lock().expect("lock should not be poisoned");
+        let prev_address_record = self
+            .store
+            .get_address_claim(address)
+            .map_err(FaucetError::custom)?;
+        if let Some(record) = &prev_address_record {
+            let next_claim = record.last_claim.saturating_add(self.address_cooldown);
+            if self.address_cooldown > 0 && next_claim > now {
+                return Err(FaucetError::TooManyRequests(format!(
+                    "{} can claim again after {} seconds",
+                    address,
+                    next_claim - now
+                )));
+            }
+        }
+
+        let prev_ip_record = match ip {
+            Some(ip) if self.ip_quota > 0 => {
+                let prev_ip_record = self.store.get_ip_claim(ip).map_err(FaucetError::custom)?;
+                let record = self.next_ip_record(prev_ip_record.clone(), now);
+                if record.claims > self.ip_quota {
+                    return Err(FaucetError::TooManyRequests(format!(
+                        "{} exceeds the quota of {} claims, try again after {} seconds",
+                        ip,
+                        self.ip_quota,
+                        (record.window_start + self.ip_quota_window).saturating_sub(now)
+                    )));
+                }
+                self.store
+                    .save_ip_claim(ip, record)
+                    .map_err(FaucetError::custom)?;
+                prev_ip_record
+            }
+            _ => None,
+        };
+
+        let address_record = ClaimRecord {
+            window_start: now,
+            claims: prev_address_record
+                .as_ref()
+                .map(|record| record.claims)
+                .unwrap_or_default()
+                + 1,
+            last_claim: now,
+        };
+        self.store
+            .save_address_claim(address, address_record)
+            .map_err(FaucetError::custom)?;
+
+        Ok(ClaimPermit {
+            address: address.to_string(),
+            ip: ip.filter(|_| self.ip_quota > 0).map(|ip| ip.to_string()),
+            prev_address_record,
+            prev_ip_record,
+        })
+    }
+
+    /// Restore the records before the failed claim.
+    pub fn release(&self, permit: ClaimPermit) -> Result<(), FaucetError> {
+        let _guard = self.lock.lock().expect("lock should not be poisoned");
+        match permit.prev_address_record {
+            Some(record) => self.store.save_address_claim(&permit.address, record),
+            None => self.store.remove_address_claim(&permit.address),
+        }
+        .map_err(FaucetError::custom)?;
+        if let Some(ip) = permit.ip {
+            match permit.prev_ip_record {
+                Some(record) => self.store.save_ip_claim(&ip, record),
+                None => self.store.remove_ip_claim(&ip),
+            }
+            .map_err(FaucetError::custom)?;
+        }
+        Ok(())
+    }
+
+    fn next_ip_record(&self, record: Option<ClaimRecord>, now: u64) -> ClaimRecord {
+        match record {
+            Some(record) if now < record.window_start.saturating_add(self.ip_quota_window) => {
+                ClaimRecord {
+                    window_start: record.window_start,
+                    claims: record.claims + 1,
+                    last_claim: now,
+                }
+            }
+            _ => ClaimRecord {
+                window_start: now,
+                claims: 1,
+                last_claim: now,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::Registry;
+
+    #[test]
+    fn test_claim_quota() {
+        let tmpdir = moveos_config::temp_dir();
+        let store = FaucetStore::new(tmpdir.path(), &Registry::new()).unwrap();
+        let quota = ClaimQuota::new(store.clone(), 100, 2, 1000);
+
+        let permit = quota.acquire("addr1", Some("127.0.0.1"), 10).unwrap();
+        // The address is in cooldown
+        assert!(matches!(
+            quota.acquire("addr1", Some("127.0.0.2"), 50),
+            Err(FaucetError::TooManyRequests(_))
+        ));
+        // Release the failed claim
+        quota.release(permit).unwrap();
+        assert!(store.get_address_claim("addr1").unwrap().is_none());
+        assert!(store.get_ip_claim("127.0.0.1").unwrap().is_none());
+
+        quota.acquire("addr1", Some("127.0.0.1"), 10).unwrap();
+        quota.acquire("addr2", Some("127.0.0.1"), 20).unwrap();
+        // The IP exceeds the quota
+        assert!(matches!(
+            quota.acquire("addr3", Some("127.0.0.1"), 30),
+            Err(FaucetError::TooManyRequests(_))
+        ));
+        // The cooldown is over, but the IP quota window is not
+        assert!(quota.acquire("addr1", Some("127.0.0.1"), 200).is_err());
+        assert!(quota.acquire("addr1", None, 200).is_ok());
+        // The IP quota window is over
+        quota.acquire("addr3", Some("127.0.0.1"), 1010).unwrap();
+
+        // The records are persisted
+        drop(quota);
+        drop(store);
+        let store = FaucetStore::new(tmpdir.path(), &Registry::new()).unwrap();
+        assert_eq!(store.get_address_claim("addr1").unwrap().unwrap().claims, 2);
+        let quota = ClaimQuota::new(store, 100, 2, 1000);
+        assert!(quota.acquire("addr3", None, 1020).is_err());
+    }
+}
@@ -1,7 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use rooch_rpc_api::jsonrpc_types::UnitedAddressView;
+use move_core_types::u256::U256;
+use rooch_rpc_api::jsonrpc_types::{StrView, UnitedAddressView};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -18,6 +19,29 @@ pub struct FaucetRequestWithInviter {
     pub message: String,
 }
 
+/// The proof of the anti-abuse check, which fields are required depends on the configured check.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ClaimProof {
+    /// The proof-of-work challenge issued by `/challenge`
+    pub challenge: Option<String>,
+    /// The nonce solving the proof-of-work challenge
+    pub nonce: Option<String>,
+    /// The captcha token issued by the portal
+    pub captcha_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClaimRequest {
+    pub claimer: UnitedAddressView,
+    #[serde(flatten)]
+    pub proof: ClaimProof,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefillRequest {
+    pub amount: StrView<U256>,
+}
+
 impl FaucetRequest {
     pub fn recipient(&self) -> UnitedAddressView {
         self.claimer.clone()
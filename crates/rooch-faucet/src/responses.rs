@@ -59,6 +59,14 @@ impl From<U256> for InfoResponse {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AdminBalanceResponse {
+    pub balance: StrView<U256>,
+    pub alert_threshold: StrView<U256>,
+    /// The balance is below the alert threshold
+    pub low_balance: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ResultResponse<V> {
     pub ok: Option<V>,
@@ -1,7 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::anti_abuse::{new_claim_guard, AntiAbuseConfig};
 use crate::faucet_proxy::FaucetProxy;
+use crate::quota::ClaimQuota;
+use crate::store::{FaucetStore, FAUCET_DB_DIR};
 use crate::*;
 use coerce::actor::{system::ActorSystem, IntoActor};
 use prometheus::Registry;
@@ -18,10 +21,28 @@ pub async fn start(
     web_config: WebConfig,
     faucet_config: FaucetConfig,
     discord_config: DiscordConfig,
+    anti_abuse_config: AntiAbuseConfig,
 ) -> anyhow::Result<String> {
     let registry = Registry::new();
     let actor_system = ActorSystem::global_system();
 
+    let faucet_db = match anti_abuse_config.faucet_db.clone() {
+        Some(faucet_db) => faucet_db,
+        None => wallet_context
+            .client_config
+            .path()
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("Invalid rooch config path"))?
+            .join(FAUCET_DB_DIR),
+    };
+    let claim_guard = new_claim_guard(&anti_abuse_config)?;
+    let claim_quota = ClaimQuota::new(
+        FaucetStore::new(&faucet_db, &registry)?,
+        anti_abuse_config.address_cooldown_secs,
+        anti_abuse_config.ip_quota,
+        anti_abuse_config.ip_quota_window_secs,
+    );
+
     let (err_sender, err_receiver) = mpsc::channel(faucet_config.max_request_queue_length as usize);
 
     let faucet = Faucet::new(&registry, wallet_context, faucet_config, err_sender)?;
@@ -31,7 +52,15 @@ pub async fn start(
         .await?;
     let faucet_proxy = FaucetProxy::new(faucet_actor_ref.into());
 
-    let app = App::new(faucet_proxy, err_receiver, discord_config.clone());
+    let app = App::new(
+        faucet_proxy,
+        err_receiver,
+        discord_config.clone(),
+        claim_guard,
+        claim_quota,
+        web_config.admin_token.clone(),
+        anti_abuse_config.trust_forwarded_for,
+    );
 
     let discord_client = if let Some(token) = discord_config
         .discord_token
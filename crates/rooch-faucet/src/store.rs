@@ -0,0 +1,99 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use moveos_config::store_config::RocksdbConfig;
+use once_cell::sync::Lazy;
+use prometheus::Registry;
+use raw_store::metrics::DBMetrics;
+use raw_store::rocks::RocksDB;
+use raw_store::{derive_store, CodecKVStore, ColumnFamilyName, StoreInstance};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const FAUCET_DB_DIR: &str = "faucet_db";
+
+pub const ADDRESS_CLAIM_COLUMN_FAMILY_NAME: ColumnFamilyName = "faucet_address_claim";
+pub const IP_CLAIM_COLUMN_FAMILY_NAME: ColumnFamilyName = "faucet_ip_claim";
+
+static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
+    vec![
+        ADDRESS_CLAIM_COLUMN_FAMILY_NAME,
+        IP_CLAIM_COLUMN_FAMILY_NAME,
+    ]
+});
+
+/// The claims of an address or an IP in the current quota window.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimRecord {
+    /// The start of the current window, in seconds
+    pub window_start: u64,
+    /// How many claims in the current window
+    pub claims: u64,
+    /// The time of the last claim, in seconds
+    pub last_claim: u64,
+}
+
+derive_store!(
+    AddressClaimStore,
+    String,
+    ClaimRecord,
+    ADDRESS_CLAIM_COLUMN_FAMILY_NAME
+);
+
+derive_store!(
+    IPClaimStore,
+    String,
+    ClaimRecord,
+    IP_CLAIM_COLUMN_FAMILY_NAME
+);
+
+/// Persist the claim records in RocksDB, so the cooldowns and quotas survive restarts.
+#[derive(Clone)]
+pub struct FaucetStore {
+    address_claim_store: AddressClaimStore,
+    ip_claim_store: IPClaimStore,
+}
+
+impl FaucetStore {
+    pub fn new(db_path: &Path, registry: &Registry) -> Result<Self> {
+        let db_metrics = DBMetrics::get_or_init(registry).clone();
+        let instance = StoreInstance::new_db_instance(
+            RocksDB::new(
+                db_path,
+                VEC_COLUMN_FAMILY_NAME.to_vec(),
+                RocksdbConfig::default(),
+            )?,
+            db_metrics,
+        );
+        Ok(Self {
+            address_claim_store: AddressClaimStore::new(instance.clone()),
+            ip_claim_store: IPClaimStore::new(instance),
+        })
+    }
+
+    pub fn get_address_claim(&self, address: &str) -> Result<Option<ClaimRecord>> {
+        self.address_claim_store.kv_get(address.to_string())
+    }
+
+    pub fn save_address_claim(&self, address: &str, record: ClaimRecord) -> Result<()> {
+        self.address_claim_store
+            .put_sync(address.to_string(), record)
+    }
+
+    pub fn remove_address_claim(&self, address: &str) -> Result<()> {
+        self.address_claim_store.remove(address.to_string())
+    }
+
+    pub fn get_ip_claim(&self, ip: &str) -> Result<Option<ClaimRecord>> {
+        self.ip_claim_store.kv_get(ip.to_string())
+    }
+
+    pub fn save_ip_claim(&self, ip: &str, record: ClaimRecord) -> Result<()> {
+        self.ip_claim_store.put_sync(ip.to_string(), record)
+    }
+
+    pub fn remove_ip_claim(&self, ip: &str) -> Result<()> {
+        self.ip_claim_store.remove(ip.to_string())
+    }
+}
@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    App, FaucetRequest, FaucetRequestWithInviter, FaucetResponse, FetchTweetRequest, InfoResponse,
-    ResultResponse, VerifyAndBindingTwitterAccountRequest,
-    VerifyAndBindingTwitterAccountWithInviter,
+    anti_abuse::{constant_time_eq, ClaimChallenge},
+    AdminBalanceResponse, App, ClaimRequest, FaucetError, FaucetRequest, FaucetRequestWithInviter,
+    FaucetResponse, FetchTweetRequest, InfoResponse, RefillRequest, ResultResponse,
+    VerifyAndBindingTwitterAccountRequest, VerifyAndBindingTwitterAccountWithInviter,
 };
 use axum::{
     error_handling::HandleErrorLayer,
-    http::Method,
-    http::StatusCode,
+    extract::ConnectInfo,
+    http::{header, HeaderMap, Method, StatusCode},
     response::IntoResponse,
     routing::{get, post},
     BoxError, Extension, Json, Router,
@@ -42,6 +43,10 @@ pub struct WebConfig {
 
     #[clap(long, default_value_t = 10)]
     pub max_request_per_second: u64,
+
+    /// The bearer token of the `/admin` API, the admin API is disabled if not set
+    #[clap(long, env = "ROOCH_FAUCET_ADMIN_TOKEN")]
+    pub admin_token: Option<String>,
 }
 
 impl Default for WebConfig {
@@ -50,6 +55,7 @@ impl Default for WebConfig {
             port: 50052,
             request_buffer_size: 10,
             max_request_per_second: 10,
+            admin_token: None,
         }
     }
 }
@@ -74,6 +80,10 @@ pub async fn serve(app: App, web_config: WebConfig) -> Result<(), anyhow::Error>
         .route("/info", get(request_info))
         .route("/faucet", post(request_faucet))
         .route("/faucet-inviter", post(request_faucet_with_inviter))
+        .route("/challenge", get(request_challenge))
+        .route("/claim", post(claim))
+        .route("/admin/balance", get(admin_balance))
+        .route("/admin/refill", post(admin_refill))
         .route("/fetch-tweet", post(fetch_tweet))
         .route(
             "/verify-and-binding-twitter-account",
@@ -102,7 +112,7 @@ pub async fn serve(app: App, web_config: WebConfig) -> Result<(), anyhow::Error>
     let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), web_config.port);
 
     axum_server::bind(addr)
-        .serve(router.into_make_service())
+        .serve(router.into_make_service_with_connect_info::<SocketAddr>())
         .await?;
 
     Ok(())
@@ -177,6 +187,115 @@ async fn request_faucet_with_inviter(
     }
 }
 
+fn error_status(e: &FaucetError) -> StatusCode {
+    match e {
+        FaucetError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+        FaucetError::InvalidProof(_) => StatusCode::FORBIDDEN,
+        FaucetError::Unauthorized => StatusCode::UNAUTHORIZED,
+        FaucetError::InvalidAddress(_) | FaucetError::NotSupport(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn client_ip(app: &App, remote_addr: SocketAddr, headers: &HeaderMap) -> IpAddr {
+    if app.trust_forwarded_for {
+        if let Some(ip) = headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+        {
+            return ip;
+        }
+    }
+    remote_addr.ip()
+}
+
+fn check_admin(app: &App, headers: &HeaderMap) -> Result<(), FaucetError> {
+    let admin_token = app.admin_token.as_ref().ok_or(FaucetError::Unauthorized)?;
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(FaucetError::Unauthorized)?;
+    if constant_time_eq(token, admin_token) {
+        Ok(())
+    } else {
+        Err(FaucetError::Unauthorized)
+    }
+}
+
+async fn request_challenge(Extension(app): Extension<App>) -> impl IntoResponse {
+    let result = app
+        .challenge()
+        .ok_or_else(|| FaucetError::NotSupport("challenge".to_string()));
+    let status = result
+        .as_ref()
+        .err()
+        .map(error_status)
+        .unwrap_or(StatusCode::OK);
+    (status, Json(ResultResponse::<ClaimChallenge>::from(result)))
+}
+
+async fn claim(
+    Extension(app): Extension<App>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(payload): Json<ClaimRequest>,
+) -> impl IntoResponse {
+    let recipient = payload.claimer.to_string();
+    let ip = client_ip(&app, remote_addr, &headers);
+
+    tracing::info!("claim gas payload: {:?}, ip: {}", recipient, ip);
+
+    match app.claim(payload, Some(ip)).await {
+        Ok(amount) => {
+            tracing::info!("claim gas success: {}", recipient);
+            (StatusCode::CREATED, Json(FaucetResponse::from(amount)))
+        }
+        Err(e) => {
+            tracing::info!("claim gas error: {}, {:?}", recipient, e);
+            (error_status(&e), Json(FaucetResponse::from(e)))
+        }
+    }
+}
+
+async fn admin_balance(Extension(app): Extension<App>, headers: HeaderMap) -> impl IntoResponse {
+    let result = match check_admin(&app, &headers) {
+        Ok(()) => app.admin_balance().await,
+        Err(e) => Err(e),
+    };
+    let status = result
+        .as_ref()
+        .err()
+        .map(error_status)
+        .unwrap_or(StatusCode::OK);
+    (
+        status,
+        Json(ResultResponse::<AdminBalanceResponse>::from(result)),
+    )
+}
+
+async fn admin_refill(
+    Extension(app): Extension<App>,
+    headers: HeaderMap,
+    Json(payload): Json<RefillRequest>,
+) -> impl IntoResponse {
+    let result = match check_admin(&app, &headers) {
+        Ok(()) => {
+            tracing::info!("refill faucet: {}", payload.amount);
+            app.refill(payload.amount.0).await
+        }
+        Err(e) => Err(e),
+    };
+    let status = result
+        .as_ref()
+        .err()
+        .map(error_status)
+        .unwrap_or(StatusCode::OK);
+    (status, Json(ResultResponse::<InfoResponse>::from(result)))
+}
+
 async fn request_info(Extension(app): Extension<App>) -> impl IntoResponse {
     let result = app.check_gas_balance().await;
 
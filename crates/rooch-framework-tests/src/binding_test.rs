@@ -152,6 +152,15 @@ impl RustBindingTest {
         // transfer RGas from rooch dao account to addr
         let function_call =
             TransferModule::create_transfer_coin_action(RGas::struct_tag(), addr, amount);
+        self.execute_via_rooch_dao(function_call)?;
+        Ok(())
+    }
+
+    /// Execute the action with the rooch dao account, the rooch dao is the admin of the framework.
+    pub fn execute_via_rooch_dao(
+        &mut self,
+        action: MoveAction,
+    ) -> Result<ExecuteTransactionResult> {
         let sender = self
             .network
             .genesis_config
@@ -164,15 +173,14 @@ impl RustBindingTest {
             sequence_number,
             self.network.chain_id.id,
             GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
-            function_call,
+            action,
         );
         //RoochDao is a multisign account, so we need to sign the tx with the multisign account
         //In test env, it is a 1-of-1 multisign account, so we can sign with the only key
         let first_signature = BitcoinAuthenticator::sign(&self.kp, &tx_data);
         let authenticator = Authenticator::bitcoin_multisign(vec![first_signature])?;
         let tx = RoochTransaction::new(tx_data, authenticator);
-        self.execute(tx)?;
-        Ok(())
+        self.execute(tx)
     }
 
     //TODO let the module bundle to execute the function
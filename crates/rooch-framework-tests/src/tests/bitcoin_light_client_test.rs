@@ -0,0 +1,203 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test::RustBindingTest;
+use crate::tests::bitcoin_data::load_block;
+use bitcoin::absolute::LockTime;
+use bitcoin::block::Header as BitcoinHeader;
+use bitcoin::consensus::deserialize;
+use bitcoin::hashes::Hash;
+use bitcoin::transaction::Version;
+use bitcoin::{
+    Amount, Block, BlockHash, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness,
+};
+use framework_builder::stdlib_version::StdlibVersion;
+use hex::FromHex;
+use move_core_types::u256::U256;
+use moveos_types::moveos_std::{module_store::ModuleStore, timestamp::Timestamp};
+use moveos_types::state::{MoveStructType, ObjectState};
+use rooch_types::bitcoin::light_client::{
+    LightClientModule, ERROR_BLOCK_HASH_MISMATCH, ERROR_INSUFFICIENT_PROOF_OF_WORK,
+    ERROR_INVALID_BLOCK_HEIGHT, ERROR_PREV_BLOCK_NOT_FOUND,
+};
+use rooch_types::bitcoin::network::Network;
+use rooch_types::error::RoochError;
+use rooch_types::genesis_config;
+use rooch_types::into_address::IntoAddress;
+use rooch_types::multichain_id::RoochMultiChainID;
+use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
+use rooch_types::transaction::{L1Block, L1BlockWithBody};
+use std::str::FromStr;
+
+// Mainnet genesis block 000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f
+const MAINNET_HEADER_0: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c";
+// Mainnet block 1 00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048
+const MAINNET_HEADER_1: &str = "010000006fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000982051fd1e4ba744bbbe680e1fee14677ba1a3c3540bf7b1cdb606e857233e0e61bc6649ffff001d01e36299";
+// Mainnet block 2 000000006a625f06636b8bb6ac7b960a8d03705d1ace08b1a19da3fdcc99ddbd
+const MAINNET_HEADER_2: &str = "010000004860eb18bf1b1620e37e9490fc8a427514416fd75159ab86688e9a8300000000d5fdcc541e25de1c7a5addedf24858b8bb665c9f36ef744ee42c316022c90f9bb0bc6649ffff001d08d2bd61";
+
+fn load_header(hex: &str) -> BitcoinHeader {
+    deserialize(&Vec::<u8>::from_hex(hex).unwrap()).unwrap()
+}
+
+/// Start a mainnet chain from the Bitcoin genesis block, so the light client enforces
+/// the difficulty rule and the previous block continuity
+fn mainnet_binding_test() -> RustBindingTest {
+    let genesis_header = load_header(MAINNET_HEADER_0);
+    let timestamp_milliseconds = (genesis_header.time as u64) * 1000;
+    let mut genesis_config = genesis_config::G_MAIN_CONFIG.clone();
+    genesis_config.bitcoin_block_hash = genesis_header.block_hash();
+    genesis_config.bitcoin_block_height = 0;
+    genesis_config.bitcoin_reorg_block_count = 0;
+    genesis_config.timestamp = timestamp_milliseconds;
+    genesis_config.stdlib_version = StdlibVersion::Latest;
+    genesis_config.genesis_objects = vec![
+        (
+            ObjectState::new_timestamp(Timestamp {
+                milliseconds: timestamp_milliseconds,
+            }),
+            Timestamp::type_layout(),
+        ),
+        (
+            ObjectState::genesis_module_store(),
+            ModuleStore::type_layout(),
+        ),
+    ];
+    let network = RoochNetwork::new(BuiltinChainID::Main.chain_id(), genesis_config);
+    RustBindingTest::new_with_network(network).unwrap()
+}
+
+/// The light client only validates the header, so we build the block body with a dummy coinbase
+fn build_block(height: u64, header: BitcoinHeader) -> L1BlockWithBody {
+    let coinbase = Transaction {
+        version: Version::ONE,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::from_bytes(height.to_le_bytes().to_vec()),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::from_int_btc(50),
+            // OP_RETURN
+            script_pubkey: ScriptBuf::from_bytes(vec![0x6a]),
+        }],
+    };
+    let block = Block {
+        header,
+        txdata: vec![coinbase],
+    };
+    L1BlockWithBody {
+        block: L1Block {
+            chain_id: RoochMultiChainID::Bitcoin.multichain_id(),
+            block_height: height,
+            block_hash: header.block_hash().to_byte_array().to_vec(),
+        },
+        block_body: rooch_types::bitcoin::types::Block::from(block).encode(),
+    }
+}
+
+#[tokio::test]
+async fn test_light_client_mainnet_headers() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = mainnet_binding_test();
+
+    let header0 = load_header(MAINNET_HEADER_0);
+    let header1 = load_header(MAINNET_HEADER_1);
+    let header2 = load_header(MAINNET_HEADER_2);
+    assert_eq!(header1.prev_blockhash, header0.block_hash());
+    assert_eq!(
+        header1.block_hash(),
+        BlockHash::from_str("00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048")
+            .unwrap()
+    );
+    assert_eq!(header2.prev_blockhash, header1.block_hash());
+
+    // The first header is accepted as checkpoint, the next header is checked against it
+    binding_test
+        .execute_l1_block_and_tx(build_block(1, header1))
+        .unwrap();
+    binding_test
+        .execute_l1_block_and_tx(build_block(2, header2))
+        .unwrap();
+
+    let light_client = binding_test.as_module_binding::<LightClientModule>();
+    let header_info = light_client
+        .get_header_info(header2.block_hash().into_address())
+        .unwrap()
+        .unwrap();
+    assert_eq!(header_info.height, 2);
+    // The work of the 0x1d00ffff target is 0x100010001
+    assert_eq!(header_info.chain_work, U256::from(0x100010001u64 * 2));
+    let best_block = light_client.get_best_block().unwrap().unwrap();
+    assert_eq!(best_block.block_height, 2);
+    assert_eq!(best_block.block_hash, header2.block_hash().into_address());
+}
+
+#[tokio::test]
+async fn test_light_client_invalid_headers() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = mainnet_binding_test();
+
+    let header1 = load_header(MAINNET_HEADER_1);
+    let header2 = load_header(MAINNET_HEADER_2);
+    binding_test
+        .execute_l1_block_and_tx(build_block(1, header1))
+        .unwrap();
+
+    let light_client = binding_test.as_module_binding::<LightClientModule>();
+    let header2_move = rooch_types::bitcoin::types::Header::from(header2);
+
+    // The block hash does not match the header
+    assert_eq!(
+        light_client
+            .validate_header(2, header1.block_hash().into_address(), &header2_move)
+            .unwrap(),
+        ERROR_BLOCK_HASH_MISMATCH
+    );
+
+    // The block height does not follow the previous block
+    assert_eq!(
+        light_client
+            .validate_header(3, header2.block_hash().into_address(), &header2_move)
+            .unwrap(),
+        ERROR_INVALID_BLOCK_HEIGHT
+    );
+
+    // Tamper the nonce, the block hash does not meet the target
+    let mut tampered = header2;
+    tampered.nonce += 1;
+    assert_eq!(
+        light_client
+            .validate_header(
+                2,
+                tampered.block_hash().into_address(),
+                &rooch_types::bitcoin::types::Header::from(tampered)
+            )
+            .unwrap(),
+        ERROR_INSUFFICIENT_PROOF_OF_WORK
+    );
+
+    // The block 818677 does not follow the checkpoint
+    let header818677 = load_block(Network::Bitcoin, 818677).header;
+    assert_eq!(
+        light_client
+            .validate_header(
+                2,
+                header818677.block_hash().into_address(),
+                &rooch_types::bitcoin::types::Header::from(header818677)
+            )
+            .unwrap(),
+        ERROR_PREV_BLOCK_NOT_FOUND
+    );
+
+    // The invalid block is rejected by the ValidateL1BlockMessage
+    let err = binding_test
+        .execute_l1_block(build_block(2, tampered))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<RoochError>(),
+        Some(RoochError::L1BlockValidationError(_))
+    ));
+}
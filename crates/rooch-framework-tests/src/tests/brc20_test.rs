@@ -1,14 +1,65 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::binding_test::{self, RustBindingTest};
+use bitcoin::absolute::LockTime;
+use bitcoin::block::{Header as BlockHeader, Version};
 use bitcoin::consensus::Decodable;
+use bitcoin::hashes::Hash;
+use bitcoin::script::{Builder, Instruction, PushBytesBuf};
+use bitcoin::{
+    transaction, Amount, Block, BlockHash, CompactTarget, OutPoint, Sequence, Transaction, TxIn,
+    TxMerkleNode, TxOut, Witness,
+};
 use hex::FromHex;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::access_path::AccessPath;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::state_resolver::StateReader;
+use rooch_types::bitcoin::brc20::BRC20Module;
+use rooch_types::bitcoin::ord::{self, InscriptionID};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::into_address::IntoAddress;
+use rooch_types::transaction::{L1BlockWithBody, RoochTransactionData};
 use tracing::debug;
 
-fn decode_tx(btx_tx_hex: &str) {
+//inscribe mint
+//https://ordinals.com/inscription/24f2585e667e345c7b72a4969b4c70eb0e2106727d876217497c6cf86a8a354ci0
+//https://mempool.space/api/tx/24f2585e667e345c7b72a4969b4c70eb0e2106727d876217497c6cf86a8a354c/hex
+// {
+//     "p": "brc-20",
+//     "op": "mint",
+//     "tick": "ordi",
+//     "amt": "1000"
+//   }
+const INSCRIBE_MINT_TX_HEX: &str = "0100000000010168fc0bd080cf62a7bb04a5e3fc1140df4dd34c244edf23e9027d3966f086f25f0000000000fdffffff01102700000000000022512037679ea62eab55ebfd442c53c4ad46b6b75e45d8a8fa9cb31a87d0df268b029a03409baed731180a79d18ac9f54d2ab448e3c1c78df128ba71f471cf75ed5be4db6431d824a1c254bede0d7482ad05a53468b3c737e9f6b4bfe90ba0c064166dd3188d205f308d3670e9d71da3c2d913a44fa0f6daa57f07263b25a23dd3124832753263ac0063036f7264010118746578742f706c61696e3b636861727365743d7574662d3800477b200a20202270223a20226272632d3230222c0a2020226f70223a20226d696e74222c0a2020227469636b223a20226f726469222c0a202022616d74223a202231303030220a7d6821c15f308d3670e9d71da3c2d913a44fa0f6daa57f07263b25a23dd312483275326300000000";
+
+//inscribe transfer
+//https://ordinals.com/inscription/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbbi0
+//https://mempool.space/api/tx/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbb/hex
+// {
+//     "p": "brc-20",
+//     "op": "transfer",
+//     "tick": "ordi",
+//     "amt": "100",
+//     "to": "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+//     "fee": "1337"
+//   }
+const INSCRIBE_TRANSFER_TX_HEX: &str = "01000000000101eb9aadb9ece84438be35112b4681d1001206a504fd28b17a620fc719429eb1230000000000fdffffff01102700000000000022512037679ea62eab55ebfd442c53c4ad46b6b75e45d8a8fa9cb31a87d0df268b029a034091a14e1b53acfec21ea0d3ea0ce6562435e50a20255c73a7234b44c3d6914fc1fc772b481349d20abba86de21420ebb511db9ba12dbaba24cd727677305434edd02052885ab09f6495885e6a1d6cb51e691e3469f1c2e86d8d2442fb44c22253b637ac0063036f7264010118746578742f706c61696e3b636861727365743d7574662d38004c897b200a20202270223a20226272632d3230222c0a2020226f70223a20227472616e73666572222c0a2020227469636b223a20226f726469222c0a202022616d74223a2022313030222c0a202022746f223a20223141317a5031655035514765666932444d505466544c35534c6d7637446976664e61222c0a202022666565223a202231333337220a7d6821c152885ab09f6495885e6a1d6cb51e691e3469f1c2e86d8d2442fb44c22253b63700000000";
+
+//transfer
+//https://ordinals.com/inscription/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbbi0
+//https://mempool.space/api/tx/628f019c4e3c30ccc0fd9aae872cb3720294a255127292bf61c38fbee39462fe/hex
+const TRANSFER_TX_HEX: &str = "02000000000102bb5b2ae5dbf13f9b1ab69c74c44ca1e5063a5c3fe36348c5d1b57b5c054154880000000000ffffffff8eca9f7d2e369e650f439153f503e81dd9960f1030bfb54f9043884a4c63c8bc11000000171600141c6e0ecb1a039c8df94a664ddf130f6e3be90ba5ffffffff0210270000000000001976a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac073d0f000000000017a9142b5fd9fed263646d296cb196bc07747b4c41fdc787014091e2afbe0bf24467275bf90b2fa281e105c6cd0344cd1f0846a89a5369246634d12476f579271a7cadbf918f73de306486f70cc2368274740ea7779a9de4e6c402473044022024e761eeaf29864b4b9bef52f457d2c0302fa5c6b4003c67681cdb006d118404022023aef89d49e6700bca374d2059509cdcacba213c0b8b77a635095c79a63a124f012103b06845003ff20c9e8a1c529003fb32edb1a9d8894e7f2cd37a192c0cbd76fb8f00000000";
+
+fn tx_from_hex(btx_tx_hex: &str) -> Transaction {
     let btc_tx_bytes = Vec::from_hex(btx_tx_hex).unwrap();
-    let btc_tx: bitcoin::Transaction =
-        Decodable::consensus_decode(&mut btc_tx_bytes.as_slice()).unwrap();
+    Decodable::consensus_decode(&mut btc_tx_bytes.as_slice()).unwrap()
+}
+
+fn decode_tx(btx_tx_hex: &str) {
+    let btc_tx = tx_from_hex(btx_tx_hex);
     let txid = btc_tx.compute_txid();
     debug!("tx_id: {}", txid);
     for (i, input) in btc_tx.input.iter().enumerate() {
@@ -22,49 +73,181 @@ fn decode_tx(btx_tx_hex: &str) {
             output.script_pubkey.p2wpkh_script_code()
         );
     }
-
-    //let binding_test = binding_test::RustBindingTest::new().unwrap();
-    //let brc20_module = binding_test.as_module_binding::<rooch_types::bitcoin::brc20::BRC20Module>();
-    //let move_btc_tx: rooch_types::bitcoin::types::Transaction = btc_tx.into();
-    //let ops_from_move = brc20_module.get_tick_info(&move_btc_tx).unwrap();
-    //debug!("ops_from_move: {:?}", ops_from_move);
-    //ops_from_move
 }
 
 #[test]
 fn test_from_transaction() {
     let _ = tracing_subscriber::fmt::try_init();
+    decode_tx(INSCRIBE_MINT_TX_HEX);
+    decode_tx(INSCRIBE_TRANSFER_TX_HEX);
+    decode_tx(TRANSFER_TX_HEX);
+}
+
+/// The regtest chain does not have the mainnet inputs of the real transactions,
+/// a missing input is mocked with zero value and the inscription would be unbound,
+/// so the real transactions are relinked to the outputs of a funding coinbase.
+fn relink_input(mut tx: Transaction, input_index: usize, previous_output: OutPoint) -> Transaction {
+    tx.input[input_index].previous_output = previous_output;
+    tx
+}
+
+/// Replace the inscription body in the reveal witness of the first input
+fn replace_inscription_body(mut tx: Transaction, body: &[u8]) -> Transaction {
+    let tapscript = tx.input[0].witness.tapscript().unwrap();
+    let mut builder = Builder::new();
+    for instruction in tapscript.instructions() {
+        builder = match instruction.unwrap() {
+            Instruction::PushBytes(bytes) if bytes.as_bytes().starts_with(b"{") => {
+                builder.push_slice(PushBytesBuf::try_from(body.to_vec()).unwrap())
+            }
+            Instruction::PushBytes(bytes) => builder.push_slice(bytes),
+            Instruction::Op(opcode) => builder.push_opcode(opcode),
+        };
+    }
+    let mut witness = tx.input[0].witness.to_vec();
+    let tapscript_index = witness.len() - 2;
+    witness[tapscript_index] = builder.into_script().into_bytes();
+    tx.input[0].witness = Witness::from_slice(&witness);
+    tx
+}
+
+fn coinbase_tx(height: u64, outputs: Vec<TxOut>) -> Transaction {
+    Transaction {
+        version: transaction::Version::ONE,
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new().push_int(height as i64).into_script(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }],
+        output: outputs,
+    }
+}
+
+fn mine_block(prev_blockhash: BlockHash, time: u32, txdata: Vec<Transaction>) -> Block {
+    let mut block = Block {
+        header: BlockHeader {
+            version: Version::TWO,
+            prev_blockhash,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time,
+            bits: CompactTarget::from_consensus(0x207fffff),
+            nonce: 0,
+        },
+        txdata,
+    };
+    block.header.merkle_root = block.compute_merkle_root().unwrap();
+    while block.header.validate_pow(block.header.target()).is_err() {
+        block.header.nonce += 1;
+    }
+    block
+}
+
+fn inscription_owner(binding_test: &RustBindingTest, txid: bitcoin::Txid) -> AccountAddress {
+    let inscription_id = InscriptionID::new(txid.into_address(), 0);
+    let object_id = ord::derive_inscription_id(&inscription_id);
+    binding_test
+        .resolver()
+        .get_states(AccessPath::object(object_id))
+        .unwrap()
+        .pop()
+        .unwrap()
+        .unwrap()
+        .metadata
+        .owner
+}
+
+#[test]
+fn test_inscribe_transfer_and_transfer() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let mint_tx = tx_from_hex(INSCRIBE_MINT_TX_HEX);
+    let inscribe_transfer_tx = tx_from_hex(INSCRIBE_TRANSFER_TX_HEX);
+    let transfer_tx = tx_from_hex(TRANSFER_TX_HEX);
+    // The mint and the transfer inscription are inscribed to the same address
+    let inscriber_script = mint_tx.output[0].script_pubkey.clone();
+    assert_eq!(
+        inscriber_script,
+        inscribe_transfer_tx.output[0].script_pubkey
+    );
+
+    // Block 1 funds the inscriber
+    let funding_tx = coinbase_tx(
+        1,
+        (0..3)
+            .map(|_| TxOut {
+                value: Amount::from_sat(10000),
+                script_pubkey: inscriber_script.clone(),
+            })
+            .collect(),
+    );
+    let funding_txid = funding_tx.compute_txid();
+    let block1 = mine_block(BlockHash::all_zeros(), 1700000000, vec![funding_tx]);
+
+    // Block 2 deploys ordi, mints 1000 and inscribes a transfer of 100
+    let deploy_tx = replace_inscription_body(
+        relink_input(mint_tx.clone(), 0, OutPoint::new(funding_txid, 0)),
+        br#"{"p":"brc-20","op":"deploy","tick":"ordi","max":"21000000","lim":"1000"}"#,
+    );
+    let mint_tx = relink_input(mint_tx, 0, OutPoint::new(funding_txid, 1));
+    let inscribe_transfer_tx =
+        relink_input(inscribe_transfer_tx, 0, OutPoint::new(funding_txid, 2));
+    let inscribe_transfer_txid = inscribe_transfer_tx.compute_txid();
+    let mint_txid = mint_tx.compute_txid();
+    let block2 = mine_block(
+        block1.block_hash(),
+        1700000600,
+        vec![
+            coinbase_tx(2, vec![]),
+            deploy_tx,
+            mint_tx,
+            inscribe_transfer_tx,
+        ],
+    );
+
+    // Block 3 sends the transfer inscription
+    let transfer_tx = relink_input(transfer_tx, 0, OutPoint::new(inscribe_transfer_txid, 0));
+    let block3 = mine_block(
+        block2.block_hash(),
+        1700001200,
+        vec![coinbase_tx(3, vec![]), transfer_tx],
+    );
+
+    for (height, block) in [(1, block1), (2, block2), (3, block3)] {
+        binding_test
+            .execute_l1_block_and_tx(L1BlockWithBody::new_bitcoin_block(height, block))
+            .unwrap();
+    }
+
+    let inscriber = inscription_owner(&binding_test, mint_txid);
+    let receiver = inscription_owner(&binding_test, inscribe_transfer_txid);
+    assert_ne!(inscriber, receiver);
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+    let action = BRC20Module::create_process_brc20_event_action(10);
+    let tx = RoochTransactionData::new_for_test(sender, 0, action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    let brc20_module = binding_test.as_module_binding::<BRC20Module>();
+    let tick_info = brc20_module
+        .get_tick_info("ordi".to_string())
+        .unwrap()
+        .unwrap();
+    let decimal = U256::from(10u128.pow(18));
+    assert_eq!(tick_info.supply, U256::from(1000u64) * decimal);
+
+    let inscriber_balance = brc20_module
+        .get_balances("ordi".to_string(), inscriber)
+        .unwrap();
+    assert_eq!(inscriber_balance.available, U256::from(900u64) * decimal);
+    assert_eq!(inscriber_balance.transferable, U256::zero());
 
-    //inscribe mint
-    //https://ordinals.com/inscription/24f2585e667e345c7b72a4969b4c70eb0e2106727d876217497c6cf86a8a354ci0
-    //https://mempool.space/api/tx/24f2585e667e345c7b72a4969b4c70eb0e2106727d876217497c6cf86a8a354c/hex
-    // {
-    //     "p": "brc-20",
-    //     "op": "mint",
-    //     "tick": "ordi",
-    //     "amt": "1000"
-    //   }
-    let btc_tx_hex = "0100000000010168fc0bd080cf62a7bb04a5e3fc1140df4dd34c244edf23e9027d3966f086f25f0000000000fdffffff01102700000000000022512037679ea62eab55ebfd442c53c4ad46b6b75e45d8a8fa9cb31a87d0df268b029a03409baed731180a79d18ac9f54d2ab448e3c1c78df128ba71f471cf75ed5be4db6431d824a1c254bede0d7482ad05a53468b3c737e9f6b4bfe90ba0c064166dd3188d205f308d3670e9d71da3c2d913a44fa0f6daa57f07263b25a23dd3124832753263ac0063036f7264010118746578742f706c61696e3b636861727365743d7574662d3800477b200a20202270223a20226272632d3230222c0a2020226f70223a20226d696e74222c0a2020227469636b223a20226f726469222c0a202022616d74223a202231303030220a7d6821c15f308d3670e9d71da3c2d913a44fa0f6daa57f07263b25a23dd312483275326300000000";
-    decode_tx(btc_tx_hex);
-
-    //inscribe transfer
-    //https://ordinals.com/inscription/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbbi0
-    //https://mempool.space/api/tx/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbb/hex
-    // {
-    //     "p": "brc-20",
-    //     "op": "transfer",
-    //     "tick": "ordi",
-    //     "amt": "100",
-    //     "to": "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
-    //     "fee": "1337"
-    //   }
-    let btc_tx_hex = "01000000000101eb9aadb9ece84438be35112b4681d1001206a504fd28b17a620fc719429eb1230000000000fdffffff01102700000000000022512037679ea62eab55ebfd442c53c4ad46b6b75e45d8a8fa9cb31a87d0df268b029a034091a14e1b53acfec21ea0d3ea0ce6562435e50a20255c73a7234b44c3d6914fc1fc772b481349d20abba86de21420ebb511db9ba12dbaba24cd727677305434edd02052885ab09f6495885e6a1d6cb51e691e3469f1c2e86d8d2442fb44c22253b637ac0063036f7264010118746578742f706c61696e3b636861727365743d7574662d38004c897b200a20202270223a20226272632d3230222c0a2020226f70223a20227472616e73666572222c0a2020227469636b223a20226f726469222c0a202022616d74223a2022313030222c0a202022746f223a20223141317a5031655035514765666932444d505466544c35534c6d7637446976664e61222c0a202022666565223a202231333337220a7d6821c152885ab09f6495885e6a1d6cb51e691e3469f1c2e86d8d2442fb44c22253b63700000000";
-    decode_tx(btc_tx_hex);
-
-    //transfer
-    //https://ordinals.com/inscription/885441055c7bb5d1c54863e33f5c3a06e5a14cc4749cb61a9b3ff1dbe52a5bbbi0
-    //https://mempool.space/api/tx/628f019c4e3c30ccc0fd9aae872cb3720294a255127292bf61c38fbee39462fe/hex
-
-    let btc_tx_hex = "02000000000102bb5b2ae5dbf13f9b1ab69c74c44ca1e5063a5c3fe36348c5d1b57b5c054154880000000000ffffffff8eca9f7d2e369e650f439153f503e81dd9960f1030bfb54f9043884a4c63c8bc11000000171600141c6e0ecb1a039c8df94a664ddf130f6e3be90ba5ffffffff0210270000000000001976a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac073d0f000000000017a9142b5fd9fed263646d296cb196bc07747b4c41fdc787014091e2afbe0bf24467275bf90b2fa281e105c6cd0344cd1f0846a89a5369246634d12476f579271a7cadbf918f73de306486f70cc2368274740ea7779a9de4e6c402473044022024e761eeaf29864b4b9bef52f457d2c0302fa5c6b4003c67681cdb006d118404022023aef89d49e6700bca374d2059509cdcacba213c0b8b77a635095c79a63a124f012103b06845003ff20c9e8a1c529003fb32edb1a9d8894e7f2cd37a192c0cbd76fb8f00000000";
-    decode_tx(btc_tx_hex);
+    let receiver_balance = brc20_module
+        .get_balances("ordi".to_string(), receiver)
+        .unwrap();
+    assert_eq!(receiver_balance.available, U256::from(100u64) * decimal);
+    assert_eq!(receiver_balance.transferable, U256::zero());
 }
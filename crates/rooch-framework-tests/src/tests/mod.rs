@@ -3,6 +3,7 @@
 
 mod bbn_test;
 mod bitcoin_data;
+mod bitcoin_light_client_test;
 mod bitcoin_multisign_validator_tests;
 mod bitcoin_test;
 mod bitcoin_tester_test;
@@ -12,7 +13,12 @@ mod chain_id_test;
 mod check_tx_size;
 mod empty_tests;
 mod ethereum_test;
+mod multi_call_test;
 mod multisign_account_tests;
+mod oracle_test;
 mod ord_test;
 mod session_validator_tests;
+mod sponsored_tx_test;
+mod tx_expiration_test;
 mod view_function_gas;
+mod witness_test;
@@ -0,0 +1,127 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test::{self, RustBindingTest};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::ident_str;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::u256::U256;
+use move_core_types::value::MoveValue;
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::state::MoveStructType;
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::{MoveAction, MultiCallArgument, MultiCallFunction};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::coin::CoinModule;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::transaction::RoochTransactionData;
+
+fn rgas_balance(binding_test: &RustBindingTest, addr: AccountAddress) -> U256 {
+    let coin_store_id = AccountCoinStoreModule::account_coin_store_id(addr, RGas::struct_tag());
+    binding_test
+        .get_object(&coin_store_id)
+        .unwrap()
+        .map(|state| CoinStoreInfo::try_from(state).unwrap().balance())
+        .unwrap_or(U256::zero())
+}
+
+fn withdraw_call(amount: u64) -> MultiCallFunction {
+    MultiCallFunction::new(
+        AccountCoinStoreModule::function_id(ident_str!("withdraw")),
+        vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+        vec![MultiCallArgument::Pure(
+            MoveValue::U256(U256::from(amount))
+                .simple_serialize()
+                .unwrap(),
+        )],
+    )
+}
+
+fn extract_call(coin: MultiCallArgument, amount: u64) -> MultiCallFunction {
+    MultiCallFunction::new(
+        CoinModule::function_id(ident_str!("extract")),
+        vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+        vec![
+            coin,
+            MultiCallArgument::Pure(
+                MoveValue::U256(U256::from(amount))
+                    .simple_serialize()
+                    .unwrap(),
+            ),
+        ],
+    )
+}
+
+fn deposit_call(recipient: AccountAddress, coin: MultiCallArgument) -> MultiCallFunction {
+    MultiCallFunction::new(
+        AccountCoinStoreModule::function_id(ident_str!("deposit")),
+        vec![TypeTag::Struct(Box::new(RGas::struct_tag()))],
+        vec![
+            MultiCallArgument::Pure(MoveValue::Address(recipient).simple_serialize().unwrap()),
+            coin,
+        ],
+    )
+}
+
+#[tokio::test]
+async fn test_multi_call() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+    let recipient = AccountAddress::random();
+
+    // Create the sender account, the local chain gives the new account some RGas
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx = RoochTransactionData::new_for_test(sender, 0, action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    // The withdrawn coin has no drop ability, it must be consumed by a later call
+    let action = MoveAction::new_multi_call(vec![withdraw_call(100)]);
+    let tx = RoochTransactionData::new_for_test(sender, 1, action).sign(&kp);
+    assert!(binding_test.executor().validate_l2_tx(tx).is_err());
+
+    // The result can only reference the previous calls
+    let action = MoveAction::new_multi_call(vec![
+        deposit_call(recipient, MultiCallArgument::new_result(1, 0)),
+        withdraw_call(100),
+    ]);
+    let tx = RoochTransactionData::new_for_test(sender, 1, action).sign(&kp);
+    assert!(binding_test.executor().validate_l2_tx(tx).is_err());
+
+    // The result can not be moved twice
+    let action = MoveAction::new_multi_call(vec![
+        withdraw_call(100),
+        deposit_call(recipient, MultiCallArgument::new_result(0, 0)),
+        deposit_call(recipient, MultiCallArgument::new_result(0, 0)),
+    ]);
+    let tx = RoochTransactionData::new_for_test(sender, 1, action).sign(&kp);
+    assert!(binding_test.executor().validate_l2_tx(tx).is_err());
+
+    let action = MoveAction::new_multi_call(vec![
+        withdraw_call(100),
+        withdraw_call(200),
+        deposit_call(recipient, MultiCallArgument::new_result(1, 0)),
+        deposit_call(recipient, MultiCallArgument::new_result(0, 0)),
+    ]);
+    let tx = RoochTransactionData::new_for_test(sender, 1, action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    assert_eq!(rgas_balance(&binding_test, recipient), U256::from(300u64));
+
+    // The coin changed by the mutable reference must be passed to the later call with the new value
+    let action = MoveAction::new_multi_call(vec![
+        withdraw_call(100),
+        extract_call(MultiCallArgument::new_result(0, 0), 60),
+        deposit_call(recipient, MultiCallArgument::new_result(1, 0)),
+        deposit_call(recipient, MultiCallArgument::new_result(0, 0)),
+    ]);
+    let tx = RoochTransactionData::new_for_test(sender, 2, action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    assert_eq!(rgas_balance(&binding_test, recipient), U256::from(400u64));
+}
@@ -0,0 +1,124 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test::{self, RustBindingTest};
+use move_core_types::u256::U256;
+use moveos_types::moveos_std::decimal_value::DecimalValue;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::MoveStructType;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::oracle::{NewOracleEvent, OracleModule, PriceFeedModule};
+use rooch_types::transaction::RoochTransactionData;
+
+const TICKER: &str = "BTCUSD";
+
+fn create_oracle(
+    binding_test: &mut RustBindingTest,
+    kp: &RoochKeyPair,
+    sender: RoochAddress,
+    value: u64,
+) -> ObjectID {
+    let sequence_number = binding_test
+        .get_account_sequence_number(sender.into())
+        .unwrap();
+    let action = OracleModule::create_oracle_action(
+        "test".to_string(),
+        "https://rooch.network".to_string(),
+        "test oracle".to_string(),
+    );
+    let tx = RoochTransactionData::new_for_test(sender, sequence_number, action).sign(kp);
+    let result = binding_test.execute(tx).unwrap();
+    let event = result
+        .output
+        .events
+        .iter()
+        .find(|event| event.event_type == NewOracleEvent::struct_tag())
+        .expect("NewOracleEvent should be emitted");
+    let event: NewOracleEvent = bcs::from_bytes(&event.event_data).unwrap();
+
+    let action = OracleModule::submit_decimal_data_action(
+        event.oracle_id.clone(),
+        TICKER.to_string(),
+        U256::from(value),
+        2,
+        "test".to_string(),
+        1000,
+        event.admin_id,
+    );
+    let tx = RoochTransactionData::new_for_test(sender, sequence_number + 1, action).sign(kp);
+    binding_test.execute(tx).unwrap();
+    event.oracle_id
+}
+
+#[tokio::test]
+async fn test_price_feed() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+    let oracle1 = create_oracle(&mut binding_test, &kp, sender, 6000000);
+    let oracle2 = create_oracle(&mut binding_test, &kp, sender, 6001000);
+
+    let price_feed_module = binding_test.as_module_binding::<PriceFeedModule>();
+    assert!(price_feed_module
+        .get_price(TICKER.to_string(), None)
+        .unwrap()
+        .is_none());
+
+    // Only the admin can register the oracle
+    let action = PriceFeedModule::register_oracle_action(TICKER.to_string(), oracle1.clone());
+    let tx = RoochTransactionData::new_for_test(
+        sender,
+        binding_test
+            .get_account_sequence_number(sender.into())
+            .unwrap(),
+        action.clone(),
+    )
+    .sign(&kp);
+    assert!(binding_test.execute(tx).is_err());
+
+    binding_test.execute_via_rooch_dao(action).unwrap();
+    binding_test
+        .execute_via_rooch_dao(PriceFeedModule::register_oracle_action(
+            TICKER.to_string(),
+            oracle2,
+        ))
+        .unwrap();
+
+    let price_feed_module = binding_test.as_module_binding::<PriceFeedModule>();
+    let price = price_feed_module
+        .get_price(TICKER.to_string(), None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(price.ticker.to_string(), TICKER);
+    assert_eq!(price.value, DecimalValue::new(U256::from(6000500u64), 2));
+    assert_eq!(price.oracle_count, 2);
+    assert!(price_feed_module
+        .get_price(TICKER.to_string(), Some(0))
+        .unwrap()
+        .is_some());
+    assert!(price_feed_module
+        .get_price("ETHUSD".to_string(), None)
+        .unwrap()
+        .is_none());
+    // No data is archived
+    assert!(price_feed_module
+        .get_twap(TICKER.to_string(), 60000, 1000)
+        .unwrap()
+        .is_none());
+
+    binding_test
+        .execute_via_rooch_dao(PriceFeedModule::set_feed_config_action(
+            TICKER.to_string(),
+            60000,
+            3,
+        ))
+        .unwrap();
+    let price_feed_module = binding_test.as_module_binding::<PriceFeedModule>();
+    assert!(price_feed_module
+        .get_price(TICKER.to_string(), None)
+        .unwrap()
+        .is_none());
+}
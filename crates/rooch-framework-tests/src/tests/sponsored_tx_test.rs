@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test::{self, RustBindingTest};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::state::MoveStructType;
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::MoveAction;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::transaction::{Authenticator, RoochTransactionData};
+
+fn rgas_balance(binding_test: &RustBindingTest, addr: AccountAddress) -> U256 {
+    let coin_store_id = AccountCoinStoreModule::account_coin_store_id(addr, RGas::struct_tag());
+    binding_test
+        .get_object(&coin_store_id)
+        .unwrap()
+        .map(|state| CoinStoreInfo::try_from(state).unwrap().balance())
+        .unwrap_or(U256::zero())
+}
+
+#[tokio::test]
+async fn test_sponsored_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let sender_kp = RoochKeyPair::generate_secp256k1();
+    let fee_payer_kp = RoochKeyPair::generate_secp256k1();
+    let sender = sender_kp
+        .public()
+        .bitcoin_address()
+        .unwrap()
+        .to_rooch_address();
+    let fee_payer = fee_payer_kp
+        .public()
+        .bitcoin_address()
+        .unwrap()
+        .to_rooch_address();
+
+    // Create the fee payer account, the local chain gives the new account some RGas
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx = RoochTransactionData::new_for_test(fee_payer, 0, action.clone()).sign(&fee_payer_kp);
+    binding_test.execute(tx).unwrap();
+    let fee_payer_balance_before = rgas_balance(&binding_test, fee_payer.into());
+
+    // The sponsored transaction without the fee payer's signature is rejected
+    let tx_data =
+        RoochTransactionData::new_for_test(sender, 0, action.clone()).with_fee_payer(fee_payer);
+    let tx = tx_data.sign(&sender_kp);
+    assert!(binding_test.executor().validate_l2_tx(tx.clone()).is_err());
+
+    // The fee payer's signature must be signed by the fee payer
+    let mut invalid_tx = tx.clone();
+    invalid_tx.sign_as_fee_payer(&sender_kp).unwrap();
+    assert!(binding_test.executor().validate_l2_tx(invalid_tx).is_err());
+
+    // The fee payer can not sign with a session key
+    let session_kp = RoochKeyPair::generate_ed25519();
+    let mut session_tx = tx.clone();
+    session_tx.fee_payer_authenticator = Some(Authenticator::session(&session_kp, &tx.data));
+    let err = binding_test
+        .executor()
+        .validate_l2_tx(session_tx)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("must sign with the Bitcoin auth validator"));
+
+    let mut tx = tx;
+    tx.sign_as_fee_payer(&fee_payer_kp).unwrap();
+    assert!(tx.is_fully_signed());
+    binding_test.execute(tx).unwrap();
+
+    // The gas is paid by the fee payer, the sender only gets the initial RGas of the local chain
+    let fee_payer_balance_after = rgas_balance(&binding_test, fee_payer.into());
+    assert!(fee_payer_balance_after < fee_payer_balance_before);
+    let sender_balance = rgas_balance(&binding_test, sender.into());
+    assert_eq!(sender_balance, U256::from(1_000_000_000_000u64));
+    assert_eq!(
+        binding_test
+            .get_account_sequence_number(sender.into())
+            .unwrap(),
+        1
+    );
+}
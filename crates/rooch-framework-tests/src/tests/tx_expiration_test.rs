@@ -0,0 +1,29 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::transaction::MoveAction;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::RoochTransactionData;
+
+#[tokio::test]
+async fn test_tx_expiration() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+
+    // The on-chain timestamp of the test genesis is 0, so the transaction expired at 0 is rejected
+    let expired_tx = RoochTransactionData::new_for_test(sender, 0, action.clone())
+        .with_expiration_timestamp_secs(0)
+        .sign(&kp);
+    assert!(binding_test.executor().validate_l2_tx(expired_tx).is_err());
+
+    let tx = RoochTransactionData::new_for_test(sender, 0, action)
+        .with_expiration_timestamp_secs(u64::MAX)
+        .sign(&kp);
+    binding_test.execute(tx).unwrap();
+}
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use rooch_executor::witness::{generate_witness, verify_witness};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::{
+    LedgerTransaction, RoochTransactionData, TransactionSequenceInfo, TransactionWitness,
+};
+
+#[tokio::test]
+async fn test_transaction_witness() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx = RoochTransactionData::new_for_test(sender, 0, action).sign(&kp);
+    let ledger_tx = LedgerTransaction::new_l2_tx(tx, TransactionSequenceInfo::random());
+
+    let rooch_db = binding_test.rooch_db();
+    let witness = generate_witness(
+        &rooch_db.moveos_store,
+        rooch_db.rooch_store.clone(),
+        binding_test.root().clone(),
+        ledger_tx,
+    )
+    .unwrap();
+    assert_eq!(witness.pre_state_root, binding_test.root().state_root());
+    assert_ne!(witness.pre_state_root, witness.post_state_root);
+    assert!(witness.nodes.contains_key(&witness.pre_state_root));
+
+    let witness = TransactionWitness::decode(&witness.encode().unwrap()).unwrap();
+    verify_witness(&witness).unwrap();
+
+    // The witness with an unexpected post state root
+    let mut invalid_witness = witness.clone();
+    invalid_witness.post_state_root = H256::random();
+    assert!(verify_witness(&invalid_witness).is_err());
+
+    // The witness without the root node
+    let mut incomplete_witness = witness.clone();
+    incomplete_witness.nodes.remove(&witness.pre_state_root);
+    assert!(verify_witness(&incomplete_witness).is_err());
+
+    // The witness with a forged node
+    let mut forged_witness = witness.clone();
+    forged_witness
+        .nodes
+        .insert(witness.pre_state_root, b"forged".to_vec());
+    assert!(verify_witness(&forged_witness).is_err());
+}
@@ -555,6 +555,7 @@ impl RoochGenesisV2 {
             genesis_tx_order,
             vec![],
             genesis_tx_accmulator_info.clone(),
+            None,
         );
         let sequencer_info = SequencerInfo::new(genesis_tx_order, genesis_tx_accmulator_info);
         rooch_db.rooch_store.save_sequenced_tx(
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS brc20_balances;
\ No newline at end of file
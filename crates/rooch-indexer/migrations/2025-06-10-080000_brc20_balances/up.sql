@@ -0,0 +1,18 @@
+CREATE TABLE brc20_balances
+(
+    tick               VARCHAR      NOT NULL,
+    owner              VARCHAR      NOT NULL,
+    available          VARCHAR      NOT NULL,
+    transferable       VARCHAR      NOT NULL,
+
+    tx_hash            VARCHAR      NOT NULL,
+    tx_order           BIGINT       NOT NULL,
+    event_index        BIGINT       NOT NULL,
+    created_at         BIGINT       NOT NULL,
+    -- Constraints
+    PRIMARY KEY (tx_order, event_index)
+);
+
+CREATE INDEX idx_brc20_balances_owner ON brc20_balances (owner, tx_order, event_index);
+CREATE INDEX idx_brc20_balances_tick ON brc20_balances (tick, tx_order, event_index);
+CREATE INDEX idx_brc20_balances_tick_and_owner ON brc20_balances (tick, owner, tx_order, event_index);
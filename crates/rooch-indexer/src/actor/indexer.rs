@@ -16,6 +16,7 @@ use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::state_resolver::RootObjectResolver;
 use moveos_types::transaction::MoveAction;
 use rooch_notify::actor::NotifyActor;
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{handle_field_change, IndexerFieldChanges};
 use rooch_types::indexer::state::{
@@ -64,9 +65,17 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
             moveos_tx,
             events,
             state_change_set,
+            span,
         } = msg;
         self.root = state_change_set.root_metadata();
         let tx_order = ledger_transaction.sequence_info.tx_order;
+        let span = tracing::info_span!(
+            parent: &span,
+            "indexer.update",
+            tx_hash = ?execution_info.tx_hash,
+            tx_order
+        );
+        let _enter = span.enter();
 
         // 1. update indexer transaction
         let move_action = MoveAction::from(moveos_tx.action);
@@ -79,7 +88,18 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
         let transactions = vec![indexer_transaction];
         self.indexer_store.persist_transactions(transactions)?;
 
-        // 2. update indexer event
+        // 2. update indexer event and the brc20 balance history derived from events
+        let mut ledger_tx = ledger_transaction.clone();
+        let brc20_balance_changes = events
+            .iter()
+            .map(|event| IndexerBRC20BalanceChange::try_from_event(event, &mut ledger_tx))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        self.indexer_store
+            .persist_brc20_balance_changes(brc20_balance_changes)?;
+
         let events: Vec<_> = events
             .into_iter()
             .map(|event| {
@@ -169,7 +189,15 @@ impl Handler<IndexerTransactionMessage> for IndexerActor {
             execution_info,
             move_action,
             tx_context,
+            span,
         } = msg;
+        let span = tracing::info_span!(
+            parent: &span,
+            "indexer.transaction",
+            tx_hash = ?execution_info.tx_hash,
+            tx_order = ledger_transaction.sequence_info.tx_order
+        );
+        let _enter = span.enter();
 
         let indexer_transaction =
             IndexerTransaction::new(ledger_transaction, execution_info, move_action, tx_context)?;
@@ -189,6 +217,17 @@ impl Handler<IndexerEventsMessage> for IndexerActor {
             tx_context,
         } = msg;
 
+        let mut ledger_tx = ledger_transaction.clone();
+        let brc20_balance_changes = events
+            .iter()
+            .map(|event| IndexerBRC20BalanceChange::try_from_event(event, &mut ledger_tx))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        self.indexer_store
+            .persist_brc20_balance_changes(brc20_balance_changes)?;
+
         let events: Vec<_> = events
             .into_iter()
             .map(|event| IndexerEvent::new(event, ledger_transaction.clone(), tx_context.clone()))
@@ -279,8 +318,10 @@ impl Handler<IndexerRevertMessage> for IndexerActor {
         self.indexer_store
             .delete_transactions(vec![revert_tx_order])?;
 
-        // 2. revert indexer event
+        // 2. revert indexer event and brc20 balance history
         self.indexer_store.delete_events(vec![revert_tx_order])?;
+        self.indexer_store
+            .delete_brc20_balance_changes(vec![revert_tx_order])?;
 
         // 3. revert indexer full object state, including object_states, utxos and inscriptions
         // indexer object state index generator
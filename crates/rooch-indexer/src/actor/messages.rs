@@ -8,6 +8,7 @@ use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{StateChangeSet, StateChangeSetExt};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::field::{FieldFilter, IndexerField};
 use rooch_types::indexer::state::{
@@ -27,6 +28,8 @@ pub struct UpdateIndexerMessage {
     pub moveos_tx: VerifiedMoveOSTransaction,
     pub events: Vec<Event>,
     pub state_change_set: StateChangeSet,
+    /// The span of the caller, the parent of the indexer span
+    pub span: tracing::Span,
 }
 
 impl Message for UpdateIndexerMessage {
@@ -40,6 +43,9 @@ pub struct IndexerTransactionMessage {
     pub execution_info: TransactionExecutionInfo,
     pub move_action: MoveAction,
     pub tx_context: TxContext,
+    /// The span of the caller, the parent of the indexer span
+    #[serde(skip, default = "tracing::Span::none")]
+    pub span: tracing::Span,
 }
 
 impl Message for IndexerTransactionMessage {
@@ -99,6 +105,20 @@ impl Message for QueryIndexerEventsMessage {
     type Result = Result<Vec<IndexerEvent>>;
 }
 
+/// Query Indexer BRC20 balance history Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerBRC20BalanceHistoryMessage {
+    pub filter: BRC20BalanceHistoryFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerEventID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerBRC20BalanceHistoryMessage {
+    type Result = Result<Vec<IndexerBRC20BalanceChange>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryIndexerObjectIdsMessage {
     pub filter: ObjectStateFilter,
@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    QueryIndexerEventsMessage, QueryIndexerFieldsMessage, QueryIndexerTransactionsMessage,
-    QueryLastStateIndexByTxOrderMessage,
+    QueryIndexerBRC20BalanceHistoryMessage, QueryIndexerEventsMessage, QueryIndexerFieldsMessage,
+    QueryIndexerTransactionsMessage, QueryLastStateIndexByTxOrderMessage,
 };
 use crate::indexer_reader::IndexerReader;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use moveos_types::moveos_std::object::ObjectID;
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::IndexerField;
 use rooch_types::indexer::state::IndexerStateID;
@@ -67,6 +68,30 @@ impl Handler<QueryIndexerEventsMessage> for IndexerReaderActor {
     }
 }
 
+#[async_trait]
+impl Handler<QueryIndexerBRC20BalanceHistoryMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerBRC20BalanceHistoryMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerBRC20BalanceChange>> {
+        let QueryIndexerBRC20BalanceHistoryMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        self.indexer_reader
+            .query_brc20_balance_history_with_filter(filter, cursor, limit, descending_order)
+            .map_err(|e| {
+                anyhow!(format!(
+                    "Failed to query indexer brc20 balance history: {:?}",
+                    e
+                ))
+            })
+    }
+}
+
 #[async_trait]
 impl Handler<QueryIndexerObjectIdsMessage> for IndexerReaderActor {
     async fn handle(
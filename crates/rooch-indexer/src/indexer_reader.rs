@@ -3,27 +3,30 @@
 
 use crate::errors::IndexerError;
 use crate::metrics::IndexerReaderMetrics;
+use crate::models::brc20_balances::StoredBRC20BalanceChange;
 use crate::models::events::StoredEvent;
 use crate::models::fields::StoredField;
 use crate::models::states::{StoredObjectStateInfo, StoredStateID};
 use crate::models::transactions::StoredTransaction;
-use crate::schema::{events, transactions};
+use crate::schema::{brc20_balances, events, transactions};
 use crate::utils::escape_sql_string;
 use crate::{
     IndexerResult, IndexerStoreMeta, IndexerTableName, SqliteConnectionConfig,
     SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
-    INDEXER_EVENTS_TABLE_NAME, INDEXER_FIELDS_TABLE_NAME, INDEXER_OBJECT_STATES_TABLE_NAME,
-    INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
-    INDEXER_TRANSACTIONS_TABLE_NAME,
+    INDEXER_BRC20_BALANCES_TABLE_NAME, INDEXER_EVENTS_TABLE_NAME, INDEXER_FIELDS_TABLE_NAME,
+    INDEXER_OBJECT_STATES_TABLE_NAME, INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+    INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME, INDEXER_TRANSACTIONS_TABLE_NAME,
 };
 use anyhow::{anyhow, Result};
 use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    r2d2::ConnectionManager, Connection, ExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl, SqliteConnection,
 };
 use function_name::named;
 use move_core_types::language_storage::StructTag;
 use moveos_types::moveos_std::object::ObjectID;
 use prometheus::Registry;
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::field::{FieldFilter, IndexerField};
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
@@ -54,6 +57,9 @@ pub const STATE_INDEX_STR: &str = "state_index";
 pub const STATE_OBJECT_TYPE_STR: &str = "object_type";
 pub const STATE_OWNER_STR: &str = "owner";
 
+pub const BRC20_TICK_STR: &str = "tick";
+pub const BRC20_OWNER_STR: &str = "owner";
+
 pub const PARENT_OBJECT_ID_STR: &str = "parent_id";
 pub const SORT_KEY_STR: &str = "sort_key";
 
@@ -425,6 +431,120 @@ impl IndexerReader {
         Ok(result)
     }
 
+    #[named]
+    pub fn query_brc20_balance_history_with_filter(
+        &self,
+        filter: BRC20BalanceHistoryFilter,
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerBRC20BalanceChange>> {
+        let start = Instant::now();
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .indexer_reader_query_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let (tx_order, event_index) = if let Some(cursor) = cursor {
+            let IndexerEventID {
+                tx_order,
+                event_index,
+            } = cursor;
+            (tx_order as i64, event_index as i64)
+        } else if descending_order {
+            let last: Option<(i64, i64)> = self
+                .get_inner_indexer_reader(INDEXER_BRC20_BALANCES_TABLE_NAME)?
+                .run_query_with_timeout(|conn| {
+                    brc20_balances::dsl::brc20_balances
+                        .select((brc20_balances::tx_order, brc20_balances::event_index))
+                        .order_by((
+                            brc20_balances::tx_order.desc(),
+                            brc20_balances::event_index.desc(),
+                        ))
+                        .first::<(i64, i64)>(conn)
+                        .optional()
+                })?;
+            // no balance change has been indexed yet
+            let Some((max_tx_order, event_index)) = last else {
+                return Ok(vec![]);
+            };
+            (max_tx_order, event_index + 1)
+        } else {
+            (-1, 0)
+        };
+
+        let main_where_clause = match filter {
+            BRC20BalanceHistoryFilter::Owner(owner) => {
+                format!("{BRC20_OWNER_STR} = \"{}\"", owner.to_hex_literal())
+            }
+            BRC20BalanceHistoryFilter::Tick(tick) => {
+                format!(
+                    "{BRC20_TICK_STR} = '{}'",
+                    escape_sql_string(tick.to_lowercase())
+                )
+            }
+            BRC20BalanceHistoryFilter::TickWithOwner { tick, owner } => {
+                format!(
+                    "{BRC20_TICK_STR} = '{}' AND {BRC20_OWNER_STR} = \"{}\"",
+                    escape_sql_string(tick.to_lowercase()),
+                    owner.to_hex_literal()
+                )
+            }
+        };
+
+        let cursor_clause = if descending_order {
+            format!(
+                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} < {}))",
+                tx_order, tx_order, event_index
+            )
+        } else {
+            format!(
+                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} > {}))",
+                tx_order, tx_order, event_index
+            )
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {EVENT_INDEX_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM brc20_balances \
+                WHERE {} {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            main_where_clause, cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!("Query brc20 balance history: {}", query);
+        let stored_balance_changes = self
+            .get_inner_indexer_reader(INDEXER_BRC20_BALANCES_TABLE_NAME)?
+            .run_query_with_timeout(|conn| {
+                diesel::sql_query(query).load::<StoredBRC20BalanceChange>(conn)
+            })?;
+
+        let result = stored_balance_changes
+            .into_iter()
+            .map(|v| v.try_into_indexer_brc20_balance_change())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer brc20 balance changes failed: {:?}",
+                    e
+                ))
+            })?;
+        tracing::debug!(
+            "Query brc20 balance history time elapsed: {:?}",
+            start.elapsed()
+        );
+
+        Ok(result)
+    }
+
     fn query_stored_object_state_infos_with_filter(
         &self,
         filter: ObjectStateFilter,
@@ -18,6 +18,7 @@ use moveos_types::state_resolver::{RootObjectResolver, StateResolver};
 use once_cell::sync::Lazy;
 use prometheus::Registry;
 use rooch_types::framework::indexer::{FieldIndexerData, IndexerModule};
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
 use rooch_types::indexer::state::{
@@ -54,6 +55,7 @@ pub const INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME: IndexerTableName = "utxos";
 pub const INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME: IndexerTableName = "inscriptions";
 pub const INDEXER_TRANSACTIONS_TABLE_NAME: IndexerTableName = "transactions";
 pub const INDEXER_FIELDS_TABLE_NAME: IndexerTableName = "fields";
+pub const INDEXER_BRC20_BALANCES_TABLE_NAME: IndexerTableName = "brc20_balances";
 
 /// Please note that adding new indexer table needs to be added in vec simultaneously.
 static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
@@ -64,6 +66,7 @@ static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
         INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
         INDEXER_TRANSACTIONS_TABLE_NAME,
         INDEXER_FIELDS_TABLE_NAME,
+        INDEXER_BRC20_BALANCES_TABLE_NAME,
     ]
 });
 
@@ -252,6 +255,19 @@ impl IndexerStoreTrait for IndexerStore {
         self.delete_fields(field_changes.remove_fields)?;
         self.delete_fields_by_parent_id(field_changes.remove_fields_by_parent_id)
     }
+
+    fn persist_brc20_balance_changes(
+        &self,
+        balance_changes: Vec<IndexerBRC20BalanceChange>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_BRC20_BALANCES_TABLE_NAME)?
+            .persist_brc20_balance_changes(balance_changes)
+    }
+
+    fn delete_brc20_balance_changes(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_BRC20_BALANCES_TABLE_NAME)?
+            .delete_brc20_balance_changes(tx_orders)
+    }
 }
 
 impl IndexerStore {
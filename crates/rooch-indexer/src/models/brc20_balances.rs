@@ -0,0 +1,78 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::brc20_balances;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = brc20_balances)]
+pub struct StoredBRC20BalanceChange {
+    /// The lower case tick of the BRC20
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tick: String,
+    /// The rooch address of the balance owner
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub owner: String,
+    /// The available balance after the change.
+    /// SQLite doesn't have a native u256 type, so the balance is stored as a decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub available: String,
+    /// The transferable balance after the change
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub transferable: String,
+
+    /// the hash of this transaction.
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tx_hash: String,
+    /// the tx order of this transaction.
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// event index in the transaction events
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub event_index: i64,
+
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+}
+
+impl From<IndexerBRC20BalanceChange> for StoredBRC20BalanceChange {
+    fn from(balance_change: IndexerBRC20BalanceChange) -> Self {
+        Self {
+            tick: balance_change.tick,
+            owner: balance_change.owner.to_hex_literal(),
+            available: balance_change.available.to_string(),
+            transferable: balance_change.transferable.to_string(),
+            tx_hash: format!("{:?}", balance_change.tx_hash),
+            tx_order: balance_change.tx_order as i64,
+            event_index: balance_change.event_index as i64,
+            created_at: balance_change.created_at as i64,
+        }
+    }
+}
+
+impl StoredBRC20BalanceChange {
+    pub fn try_into_indexer_brc20_balance_change(
+        &self,
+    ) -> Result<IndexerBRC20BalanceChange, anyhow::Error> {
+        let owner = AccountAddress::from_str(self.owner.as_str())?;
+        let available = U256::from_str(self.available.as_str())?;
+        let transferable = U256::from_str(self.transferable.as_str())?;
+        let tx_hash = H256::from_str(self.tx_hash.as_str())?;
+
+        Ok(IndexerBRC20BalanceChange {
+            tick: self.tick.clone(),
+            owner,
+            available,
+            transferable,
+            tx_order: self.tx_order as u64,
+            event_index: self.event_index as u64,
+            tx_hash,
+            created_at: self.created_at as u64,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod brc20_balances;
 pub mod events;
 pub mod fields;
 pub mod inscriptions;
@@ -5,8 +5,8 @@ use crate::actor::indexer::IndexerActor;
 use crate::actor::messages::{
     IndexerApplyObjectStatesMessage, IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
     IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage, IndexerStatesMessage,
-    IndexerTransactionMessage, QueryIndexerEventsMessage, QueryIndexerFieldsMessage,
-    QueryIndexerObjectIdsMessage, QueryIndexerTransactionsMessage,
+    IndexerTransactionMessage, QueryIndexerBRC20BalanceHistoryMessage, QueryIndexerEventsMessage,
+    QueryIndexerFieldsMessage, QueryIndexerObjectIdsMessage, QueryIndexerTransactionsMessage,
     QueryLastStateIndexByTxOrderMessage, UpdateIndexerMessage,
 };
 use crate::actor::reader_indexer::IndexerReaderActor;
@@ -17,6 +17,7 @@ use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{StateChangeSet, StateChangeSetExt};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::field::{FieldFilter, IndexerField};
 use rooch_types::indexer::state::{
@@ -56,6 +57,7 @@ impl IndexerProxy {
                 moveos_tx,
                 events,
                 state_change_set,
+                span: tracing::Span::current(),
             })
             .await?;
         Ok(())
@@ -91,6 +93,7 @@ impl IndexerProxy {
                 execution_info,
                 move_action,
                 tx_context,
+                span: tracing::Span::current(),
             })
             .await?
     }
@@ -146,6 +149,24 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn query_brc20_balance_history(
+        &self,
+        filter: BRC20BalanceHistoryFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerBRC20BalanceChange>> {
+        self.reader_actor
+            .send(QueryIndexerBRC20BalanceHistoryMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
     pub async fn query_object_ids(
         &self,
         filter: ObjectStateFilter,
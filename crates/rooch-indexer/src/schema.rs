@@ -76,6 +76,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    brc20_balances (tx_order, event_index) {
+        tick -> Text,
+        owner -> Text,
+        available -> Text,
+        transferable -> Text,
+        tx_hash -> Text,
+        tx_order -> BigInt,
+        event_index -> BigInt,
+        created_at -> BigInt,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     events,
     object_states,
@@ -83,4 +96,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     inscriptions,
     transactions,
     fields,
+    brc20_balances,
 );
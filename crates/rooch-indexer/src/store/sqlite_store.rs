@@ -6,19 +6,23 @@ use anyhow::Result;
 use diesel::QueryDsl;
 use diesel::{ExpressionMethods, RunQueryDsl};
 use function_name::named;
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::IndexerField;
 use rooch_types::indexer::state::IndexerObjectState;
 use rooch_types::indexer::transaction::IndexerTransaction;
 use std::sync::Arc;
 
+use crate::models::brc20_balances::StoredBRC20BalanceChange;
 use crate::models::events::StoredEvent;
 use crate::models::fields::StoredField;
 use crate::models::inscriptions::StoredInscription;
 use crate::models::states::StoredObjectState;
 use crate::models::transactions::{escape_transaction, StoredTransaction};
 use crate::models::utxos::StoredUTXO;
-use crate::schema::{events, fields, inscriptions, object_states, transactions, utxos};
+use crate::schema::{
+    brc20_balances, events, fields, inscriptions, object_states, transactions, utxos,
+};
 use crate::store::metrics::IndexerDBMetrics;
 use crate::utils::escape_sql_string;
 use crate::{get_sqlite_pool_connection, SqliteConnectionPool};
@@ -424,6 +428,63 @@ impl SqliteIndexerStore {
         Ok(())
     }
 
+    #[named]
+    pub fn persist_brc20_balance_changes(
+        &self,
+        balance_changes: Vec<IndexerBRC20BalanceChange>,
+    ) -> Result<(), IndexerError> {
+        if balance_changes.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let balance_changes = balance_changes
+            .into_iter()
+            .map(StoredBRC20BalanceChange::from)
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(brc20_balances::table)
+            .values(balance_changes.as_slice())
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to write brc20 balance changes to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_brc20_balance_changes(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(
+            brc20_balances::table.filter(brc20_balances::tx_order.eq_any(tx_orders.as_slice())),
+        )
+        .execute(&mut connection)
+        .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+        .context("Failed to delete brc20 balance changes to SQLiteDB")?;
+
+        Ok(())
+    }
+
     #[named]
     pub fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
         if fields.is_empty() {
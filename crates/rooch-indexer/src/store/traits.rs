@@ -3,6 +3,7 @@
 
 use crate::errors::IndexerError;
 use anyhow::Result;
+use rooch_types::indexer::brc20::IndexerBRC20BalanceChange;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
 use rooch_types::indexer::state::{IndexerObjectState, IndexerObjectStateChangeSet};
@@ -53,4 +54,11 @@ pub trait IndexerStoreTrait: Send + Sync {
     fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError>;
 
     fn apply_fields(&self, field_changes: IndexerFieldChanges) -> Result<(), IndexerError>;
+
+    fn persist_brc20_balance_changes(
+        &self,
+        balance_changes: Vec<IndexerBRC20BalanceChange>,
+    ) -> Result<(), IndexerError>;
+
+    fn delete_brc20_balance_changes(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError>;
 }
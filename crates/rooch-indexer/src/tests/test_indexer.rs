@@ -7,6 +7,7 @@ use crate::IndexerStore;
 use anyhow::Result;
 use metrics::RegistryService;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::object::{ObjectEntity, ObjectID, ObjectMeta};
@@ -17,6 +18,7 @@ use rand::random;
 use rooch_config::store_config::DEFAULT_DB_INDEXER_SUBDIR;
 use rooch_types::framework::coin_store::CoinStore;
 use rooch_types::framework::gas_coin::RGas;
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent};
 use rooch_types::indexer::field::FieldFilter;
 use rooch_types::indexer::state::{IndexerObjectState, ObjectStateFilter, ObjectStateType};
@@ -105,6 +107,55 @@ async fn test_event_store() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_brc20_balance_store() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store =
+        IndexerStore::new(indexer_db.clone(), &registry_service.default_registry())?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry_service.default_registry())?;
+
+    let owner = AccountAddress::random();
+    // an empty table returns an empty page instead of an error
+    let history = indexer_reader.query_brc20_balance_history_with_filter(
+        BRC20BalanceHistoryFilter::Owner(owner),
+        None,
+        10,
+        true,
+    )?;
+    assert!(history.is_empty());
+
+    let balance_changes = (0..3u64)
+        .map(|i| IndexerBRC20BalanceChange {
+            tick: "ordi".to_string(),
+            owner,
+            available: U256::from(1000u64 - i * 100),
+            transferable: U256::from(i * 100),
+            tx_order: 10 + i,
+            event_index: 0,
+            tx_hash: H256::random(),
+            created_at: i,
+        })
+        .collect::<Vec<_>>();
+    indexer_store.persist_brc20_balance_changes(balance_changes.clone())?;
+
+    let filter = BRC20BalanceHistoryFilter::TickWithOwner {
+        tick: "ORDI".to_string(),
+        owner,
+    };
+    let history = indexer_reader.query_brc20_balance_history_with_filter(filter, None, 10, true)?;
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0], balance_changes[2]);
+
+    indexer_store.delete_brc20_balance_changes(vec![12])?;
+    let filter = BRC20BalanceHistoryFilter::Owner(owner);
+    let history =
+        indexer_reader.query_brc20_balance_history_with_filter(filter, None, 10, false)?;
+    assert_eq!(history, balance_changes[0..2].to_vec());
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_state_store() -> Result<()> {
     let registry_service = RegistryService::default();
@@ -3,6 +3,7 @@
 
 use super::types::LocalAccount;
 use crate::key_derive::{generate_derivation_path, generate_new_key_pair};
+use rooch_types::framework::randomness::RandomnessKeyPair;
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
@@ -181,4 +182,17 @@ pub trait AccountKeystore {
         authentication_key: &AuthenticationKey,
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error>;
+
+    /// Generate the randomness beacon VRF keypair of the address, it replaces the existing one
+    fn generate_vrf_key_pair(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<RandomnessKeyPair, anyhow::Error>;
+
+    fn get_vrf_key_pair(
+        &self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<Option<RandomnessKeyPair>, anyhow::Error>;
 }
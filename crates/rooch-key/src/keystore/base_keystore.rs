@@ -4,6 +4,7 @@
 use super::types::{LocalAccount, LocalSessionKey};
 use crate::keystore::account_keystore::AccountKeystore;
 use anyhow::{ensure, Ok};
+use rooch_types::framework::randomness::RandomnessKeyPair;
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::to_bech32::ToBech32;
@@ -33,6 +34,8 @@ pub(crate) struct BaseKeyStore {
     #[serde_as(as = "BTreeMap<DisplayFromStr, BTreeMap<DisplayFromStr, _>>")]
     pub(crate) session_keys: BTreeMap<RoochAddress, BTreeMap<AuthenticationKey, LocalSessionKey>>,
     #[serde(default)]
+    pub(crate) vrf_keys: BTreeMap<RoochAddress, EncryptionData>,
+    #[serde(default)]
     pub(crate) password_hash: Option<String>,
     #[serde(default)]
     pub(crate) is_password_empty: bool,
@@ -44,6 +47,7 @@ impl BaseKeyStore {
             keys: BTreeMap::new(),
             mnemonic: None,
             session_keys: BTreeMap::new(),
+            vrf_keys: BTreeMap::new(),
             password_hash: None,
             is_password_empty: true,
         }
@@ -237,6 +241,28 @@ impl AccountKeystore for BaseKeyStore {
         Ok(RoochTransaction::new(msg, auth))
     }
 
+    fn generate_vrf_key_pair(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<RandomnessKeyPair, anyhow::Error> {
+        let kp = RandomnessKeyPair::generate();
+        let private_key_encryption = EncryptionData::encrypt_with_type(&kp, password)?;
+        self.vrf_keys.insert(*address, private_key_encryption);
+        Ok(kp)
+    }
+
+    fn get_vrf_key_pair(
+        &self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<Option<RandomnessKeyPair>, anyhow::Error> {
+        self.vrf_keys
+            .get(address)
+            .map(|encryption| encryption.decrypt_with_type::<RandomnessKeyPair>(password))
+            .transpose()
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         // Create an empty Vec to store the addresses.
         let mut addresses = Vec::with_capacity(self.keys.len() + self.session_keys.len());
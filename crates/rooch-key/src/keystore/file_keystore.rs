@@ -152,6 +152,24 @@ impl AccountKeystore for FileBasedKeystore {
             .sign_transaction_via_session_key(address, msg, authentication_key, password)
     }
 
+    fn generate_vrf_key_pair(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<rooch_types::framework::randomness::RandomnessKeyPair, anyhow::Error> {
+        let kp = self.keystore.generate_vrf_key_pair(address, password)?;
+        self.save()?;
+        Ok(kp)
+    }
+
+    fn get_vrf_key_pair(
+        &self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<Option<rooch_types::framework::randomness::RandomnessKeyPair>, anyhow::Error> {
+        self.keystore.get_vrf_key_pair(address, password)
+    }
+
     fn set_password_hash_with_indicator(
         &mut self,
         password_hash: String,
@@ -221,6 +221,30 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn generate_vrf_key_pair(
+        &mut self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<rooch_types::framework::randomness::RandomnessKeyPair, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.generate_vrf_key_pair(address, password),
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.generate_vrf_key_pair(address, password)
+            }
+        }
+    }
+
+    fn get_vrf_key_pair(
+        &self,
+        address: &RoochAddress,
+        password: Option<String>,
+    ) -> Result<Option<rooch_types::framework::randomness::RandomnessKeyPair>, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.get_vrf_key_pair(address, password),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.get_vrf_key_pair(address, password),
+        }
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         match self {
             Keystore::File(file_keystore) => file_keystore.addresses(),
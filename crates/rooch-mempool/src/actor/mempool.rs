@@ -0,0 +1,244 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::{
+    GetPendingTransactionsMessage, MempoolTick, PromoteTxsMessage, SubmitTxMessage,
+};
+use crate::metrics::MempoolMetrics;
+use crate::pool::{PendingTransaction, TxPool};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use moveos_types::transaction::VerifiedMoveOSTransaction;
+use prometheus::Registry;
+use rooch_config::mempool_config::MempoolConfig;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::sync::oneshot;
+use tracing::{debug, warn};
+
+const EVICT_REASON_EXPIRED: &str = "expired";
+const EVICT_REASON_STALE: &str = "stale";
+
+type ExecuteResultSender = oneshot::Sender<Result<ExecuteTransactionResponse>>;
+
+/// MempoolActor sits in front of the sequencer.
+/// The transactions with future sequence numbers wait in the per-sender queues,
+/// and are promoted to the pipeline processor once the sequence number gap is filled.
+pub struct MempoolActor {
+    pool: TxPool,
+    processor: PipelineProcessorProxy,
+    executor: ExecutorProxy,
+    /// The result senders of the queued transactions, keyed by tx hash
+    waiters: HashMap<H256, ExecuteResultSender>,
+    /// The max time a transaction can stay in the mempool, in milliseconds
+    tx_ttl: u64,
+    metrics: Arc<MempoolMetrics>,
+}
+
+impl MempoolActor {
+    pub fn new(
+        processor: PipelineProcessorProxy,
+        executor: ExecutorProxy,
+        config: MempoolConfig,
+        registry: &Registry,
+    ) -> Self {
+        Self {
+            pool: TxPool::new(config.max_size(), config.max_txs_per_sender()),
+            processor,
+            executor,
+            waiters: HashMap::new(),
+            tx_ttl: config.tx_ttl().saturating_mul(1000),
+            metrics: Arc::new(MempoolMetrics::new(registry)),
+        }
+    }
+
+    fn now_millis() -> Result<u64> {
+        Ok(SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)?
+            .as_millis() as u64)
+    }
+
+    /// Dispatch the validated transaction, or queue the future transaction until the gap is filled.
+    fn submit(
+        &mut self,
+        msg: SubmitTxMessage,
+        ctx: &ActorContext,
+    ) -> Result<oneshot::Receiver<Result<ExecuteTransactionResponse>>> {
+        let SubmitTxMessage {
+            mut tx,
+            verified_tx,
+            account_sequence_number,
+        } = msg;
+        let (sender_tx, receiver) = oneshot::channel();
+        if verified_tx.is_some() {
+            self.dispatch(tx, verified_tx, Some(sender_tx), ctx);
+        } else {
+            let sender: AccountAddress = tx.sender().into();
+            let tx_hash = tx.tx_hash();
+            let pending_tx = PendingTransaction::new(tx, Self::now_millis()?);
+            self.pool.insert(pending_tx, account_sequence_number)?;
+            self.waiters.insert(tx_hash, sender_tx);
+            self.metrics.mempool_admitted_txs.inc();
+            debug!(
+                "Queued tx {:?} of sender {}, account sequence number: {}",
+                tx_hash, sender, account_sequence_number
+            );
+        }
+        self.update_gauges();
+        Ok(receiver)
+    }
+
+    /// Execute the queued transaction of the sender if its sequence number matches the account.
+    /// The next one is promoted after the transaction is executed.
+    fn promote(
+        &mut self,
+        sender: AccountAddress,
+        account_sequence_number: u64,
+        ctx: &ActorContext,
+    ) {
+        for stale_tx in self.pool.remove_stale(&sender, account_sequence_number) {
+            self.metrics
+                .mempool_evicted_txs
+                .with_label_values(&[EVICT_REASON_STALE])
+                .inc();
+            self.notify(
+                stale_tx.tx_hash,
+                Err(anyhow!(
+                    "Transaction sequence number {} is behind the account sequence number {}",
+                    stale_tx.sequence_number,
+                    account_sequence_number
+                )),
+            );
+        }
+        if let Some(pending_tx) = self.pool.pop_ready(&sender, account_sequence_number) {
+            self.metrics.mempool_promoted_txs.inc();
+            let waiter = self.waiters.remove(&pending_tx.tx_hash);
+            // The queued transaction is validated again by the pipeline processor
+            self.dispatch(pending_tx.tx, None, waiter, ctx);
+        }
+    }
+
+    /// Execute the transaction in the background, so the mempool does not wait for the execution.
+    fn dispatch(
+        &self,
+        tx: RoochTransaction,
+        verified_tx: Option<VerifiedMoveOSTransaction>,
+        waiter: Option<ExecuteResultSender>,
+        ctx: &ActorContext,
+    ) {
+        let processor = self.processor.clone();
+        let actor_ref: LocalActorRef<Self> = ctx.actor_ref();
+        let sender: AccountAddress = tx.sender().into();
+        let account_sequence_number = tx.sequence_number() + 1;
+        tokio::spawn(async move {
+            let result = processor.execute_l2_tx(tx, verified_tx).await;
+            let executed = result.is_ok();
+            if let Some(waiter) = waiter {
+                // The receiver may be dropped if the client does not wait for the result
+                let _ = waiter.send(result);
+            }
+            if executed {
+                if let Err(e) = actor_ref.notify(PromoteTxsMessage {
+                    sender,
+                    account_sequence_number,
+                }) {
+                    warn!(
+                        "Failed to notify the mempool to promote transactions of sender {}: {:?}",
+                        sender, e
+                    );
+                }
+            }
+        });
+    }
+
+    fn evict_expired(&mut self) -> Result<()> {
+        let expired_before = Self::now_millis()?.saturating_sub(self.tx_ttl);
+        for expired_tx in self.pool.evict_expired(expired_before) {
+            self.metrics
+                .mempool_evicted_txs
+                .with_label_values(&[EVICT_REASON_EXPIRED])
+                .inc();
+            self.notify(
+                expired_tx.tx_hash,
+                Err(anyhow!(
+                    "Transaction {:?} expired in the mempool, the sequence number gap is not filled",
+                    expired_tx.tx_hash
+                )),
+            );
+        }
+        Ok(())
+    }
+
+    fn notify(&mut self, tx_hash: H256, result: Result<ExecuteTransactionResponse>) {
+        if let Some(waiter) = self.waiters.remove(&tx_hash) {
+            // The receiver may be dropped if the client does not wait for the result
+            let _ = waiter.send(result);
+        }
+    }
+
+    fn update_gauges(&self) {
+        self.metrics.mempool_pending_txs.set(self.pool.len() as i64);
+        self.metrics
+            .mempool_pending_senders
+            .set(self.pool.sender_count() as i64);
+    }
+}
+
+impl Actor for MempoolActor {}
+
+#[async_trait]
+impl Handler<SubmitTxMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        msg: SubmitTxMessage,
+        ctx: &mut ActorContext,
+    ) -> Result<oneshot::Receiver<Result<ExecuteTransactionResponse>>> {
+        self.submit(msg, ctx)
+    }
+}
+
+#[async_trait]
+impl Handler<PromoteTxsMessage> for MempoolActor {
+    async fn handle(&mut self, msg: PromoteTxsMessage, ctx: &mut ActorContext) {
+        self.promote(msg.sender, msg.account_sequence_number, ctx);
+        self.update_gauges();
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTransactionsMessage> for MempoolActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<PendingTransaction>> {
+        Ok(self.pool.pending_transactions(msg.sender))
+    }
+}
+
+#[async_trait]
+impl Handler<MempoolTick> for MempoolActor {
+    async fn handle(&mut self, _msg: MempoolTick, ctx: &mut ActorContext) {
+        if let Err(e) = self.evict_expired() {
+            warn!("Failed to evict expired transactions: {:?}", e);
+        }
+        // The account sequence number may be changed by other paths, try to promote all senders
+        for sender in self.pool.senders() {
+            match self.executor.get_sequence_number(sender).await {
+                Ok(account_sequence_number) => self.promote(sender, account_sequence_number, ctx),
+                Err(e) => warn!(
+                    "Failed to get the sequence number of sender {}: {:?}",
+                    sender, e
+                ),
+            }
+        }
+        self.update_gauges();
+    }
+}
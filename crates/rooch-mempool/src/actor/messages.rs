@@ -0,0 +1,57 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::pool::PendingTransaction;
+use anyhow::Result;
+use coerce::actor::{message::Message, scheduler::timer::TimerTick};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::transaction::VerifiedMoveOSTransaction;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use tokio::sync::oneshot;
+
+/// Submit a validated transaction to the mempool.
+/// The transaction is executed in the background if it passes the validation,
+/// otherwise its sequence number is too new and it waits in the mempool until the gap is filled.
+/// The execution result is sent to the returned receiver.
+#[derive(Debug)]
+pub struct SubmitTxMessage {
+    pub tx: RoochTransaction,
+    /// The validation result, None if the sequence number of the transaction is too new
+    pub verified_tx: Option<VerifiedMoveOSTransaction>,
+    /// The sequence number of the sender's account when the transaction is validated
+    pub account_sequence_number: u64,
+}
+
+impl Message for SubmitTxMessage {
+    type Result = Result<oneshot::Receiver<Result<ExecuteTransactionResponse>>>;
+}
+
+#[derive(Debug)]
+pub struct GetPendingTransactionsMessage {
+    pub sender: Option<AccountAddress>,
+}
+
+impl Message for GetPendingTransactionsMessage {
+    type Result = Result<Vec<PendingTransaction>>;
+}
+
+/// Promote the queued transactions of the sender, sent after a transaction of the sender is executed.
+#[derive(Debug)]
+pub struct PromoteTxsMessage {
+    pub sender: AccountAddress,
+    /// The sequence number of the sender's account after the transaction is executed
+    pub account_sequence_number: u64,
+}
+
+impl Message for PromoteTxsMessage {
+    type Result = ();
+}
+
+#[derive(Clone)]
+pub struct MempoolTick {}
+
+impl Message for MempoolTick {
+    type Result = ();
+}
+
+impl TimerTick for MempoolTick {}
@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, IntCounter, IntCounterVec, IntGauge, Registry,
+};
+
+#[derive(Debug)]
+pub struct MempoolMetrics {
+    pub mempool_pending_txs: IntGauge,
+    pub mempool_pending_senders: IntGauge,
+    pub mempool_admitted_txs: IntCounter,
+    pub mempool_promoted_txs: IntCounter,
+    pub mempool_evicted_txs: IntCounterVec,
+}
+
+impl MempoolMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        MempoolMetrics {
+            mempool_pending_txs: register_int_gauge_with_registry!(
+                "mempool_pending_txs",
+                "Number of transactions waiting in the mempool",
+                registry,
+            )
+            .unwrap(),
+            mempool_pending_senders: register_int_gauge_with_registry!(
+                "mempool_pending_senders",
+                "Number of senders with transactions waiting in the mempool",
+                registry,
+            )
+            .unwrap(),
+            mempool_admitted_txs: register_int_counter_with_registry!(
+                "mempool_admitted_txs",
+                "Number of transactions queued in the mempool",
+                registry,
+            )
+            .unwrap(),
+            mempool_promoted_txs: register_int_counter_with_registry!(
+                "mempool_promoted_txs",
+                "Number of queued transactions promoted to the sequencer",
+                registry,
+            )
+            .unwrap(),
+            mempool_evicted_txs: register_int_counter_vec_with_registry!(
+                "mempool_evicted_txs",
+                "Number of transactions evicted from the mempool",
+                &["reason"],
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_types::transaction::RoochTransaction;
+use std::collections::{BTreeMap, HashMap};
+
+/// A transaction waiting in the mempool for the previous sequence numbers of the sender
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    pub tx_hash: H256,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    /// The time when the transaction is received, in milliseconds
+    pub received_at: u64,
+    pub tx: RoochTransaction,
+}
+
+impl PendingTransaction {
+    pub fn new(mut tx: RoochTransaction, received_at: u64) -> Self {
+        Self {
+            tx_hash: tx.tx_hash(),
+            sender: tx.sender().into(),
+            sequence_number: tx.sequence_number(),
+            received_at,
+            tx,
+        }
+    }
+}
+
+/// TxPool holds the pending transactions in per-sender queues ordered by sequence number.
+#[derive(Debug)]
+pub struct TxPool {
+    max_size: usize,
+    max_txs_per_sender: usize,
+    queues: HashMap<AccountAddress, BTreeMap<u64, PendingTransaction>>,
+    size: usize,
+}
+
+impl TxPool {
+    pub fn new(max_size: usize, max_txs_per_sender: usize) -> Self {
+        Self {
+            max_size,
+            max_txs_per_sender,
+            queues: HashMap::new(),
+            size: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn sender_count(&self) -> usize {
+        self.queues.len()
+    }
+
+    pub fn senders(&self) -> Vec<AccountAddress> {
+        self.queues.keys().cloned().collect()
+    }
+
+    /// Queue a transaction whose sequence number is ahead of the sender's account sequence number.
+    pub fn insert(&mut self, tx: PendingTransaction, account_sequence_number: u64) -> Result<()> {
+        if tx.sequence_number <= account_sequence_number {
+            bail!(
+                "The transaction sequence number {} is not ahead of the account sequence number {}",
+                tx.sequence_number,
+                account_sequence_number
+            );
+        }
+        // The max gap is limited by the per sender limit, the transactions beyond the gap can never be promoted in time
+        if tx.sequence_number - account_sequence_number > self.max_txs_per_sender as u64 {
+            bail!(
+                "The transaction sequence number {} is too far ahead of the account sequence number {}",
+                tx.sequence_number,
+                account_sequence_number
+            );
+        }
+        if self.size >= self.max_size {
+            bail!("The mempool is full, max size: {}", self.max_size);
+        }
+        let queue = self.queues.entry(tx.sender).or_default();
+        if queue.contains_key(&tx.sequence_number) {
+            bail!(
+                "The transaction with sequence number {} of sender {} is already in the mempool",
+                tx.sequence_number,
+                tx.sender
+            );
+        }
+        if queue.len() >= self.max_txs_per_sender {
+            bail!(
+                "The sender {} has too many pending transactions, max: {}",
+                tx.sender,
+                self.max_txs_per_sender
+            );
+        }
+        queue.insert(tx.sequence_number, tx);
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Take the transaction of the sender which matches the account sequence number.
+    pub fn pop_ready(
+        &mut self,
+        sender: &AccountAddress,
+        account_sequence_number: u64,
+    ) -> Option<PendingTransaction> {
+        let queue = self.queues.get_mut(sender)?;
+        let tx = queue.remove(&account_sequence_number);
+        if tx.is_some() {
+            self.size -= 1;
+        }
+        if queue.is_empty() {
+            self.queues.remove(sender);
+        }
+        tx
+    }
+
+    /// Remove the transactions of the sender whose sequence number is behind the account sequence number,
+    /// they can never be executed.
+    pub fn remove_stale(
+        &mut self,
+        sender: &AccountAddress,
+        account_sequence_number: u64,
+    ) -> Vec<PendingTransaction> {
+        let Some(queue) = self.queues.get_mut(sender) else {
+            return vec![];
+        };
+        let remaining = queue.split_off(&account_sequence_number);
+        let stale = std::mem::replace(queue, remaining);
+        if queue.is_empty() {
+            self.queues.remove(sender);
+        }
+        self.size -= stale.len();
+        stale.into_values().collect()
+    }
+
+    /// Remove the transactions received before `expired_before`.
+    pub fn evict_expired(&mut self, expired_before: u64) -> Vec<PendingTransaction> {
+        let mut expired = vec![];
+        self.queues.retain(|_, queue| {
+            queue.retain(|_, tx| {
+                if tx.received_at < expired_before {
+                    expired.push(tx.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+            !queue.is_empty()
+        });
+        self.size -= expired.len();
+        expired
+    }
+
+    /// Get the pending transactions ordered by sender and sequence number.
+    pub fn pending_transactions(&self, sender: Option<AccountAddress>) -> Vec<PendingTransaction> {
+        let mut txs = match sender {
+            Some(sender) => self
+                .queues
+                .get(&sender)
+                .map(|queue| queue.values().cloned().collect())
+                .unwrap_or_default(),
+            None => self
+                .queues
+                .values()
+                .flat_map(|queue| queue.values().cloned())
+                .collect::<Vec<_>>(),
+        };
+        txs.sort_by(|a, b| {
+            a.sender
+                .cmp(&b.sender)
+                .then(a.sequence_number.cmp(&b.sequence_number))
+        });
+        txs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::transaction::MoveAction;
+    use rooch_types::address::RoochAddress;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::framework::empty::Empty;
+    use rooch_types::transaction::RoochTransactionData;
+
+    fn pending_tx(
+        sender: RoochAddress,
+        sequence_number: u64,
+        received_at: u64,
+    ) -> PendingTransaction {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+        let tx = RoochTransactionData::new_for_test(sender, sequence_number, action).sign(&kp);
+        PendingTransaction::new(tx, received_at)
+    }
+
+    #[test]
+    fn test_tx_pool_ordering() {
+        let sender = RoochAddress::random();
+        let mut pool = TxPool::new(10, 4);
+
+        pool.insert(pending_tx(sender, 3, 0), 1).unwrap();
+        pool.insert(pending_tx(sender, 2, 0), 1).unwrap();
+        assert_eq!(pool.len(), 2);
+        // duplicate sequence number
+        assert!(pool.insert(pending_tx(sender, 2, 0), 1).is_err());
+        // not ahead of the account sequence number
+        assert!(pool.insert(pending_tx(sender, 1, 0), 1).is_err());
+        // too far ahead
+        assert!(pool.insert(pending_tx(sender, 6, 0), 1).is_err());
+
+        assert!(pool.pop_ready(&sender.into(), 1).is_none());
+        assert_eq!(
+            pool.pop_ready(&sender.into(), 2).unwrap().sequence_number,
+            2
+        );
+        assert_eq!(
+            pool.pop_ready(&sender.into(), 3).unwrap().sequence_number,
+            3
+        );
+        assert!(pool.is_empty());
+        assert_eq!(pool.sender_count(), 0);
+    }
+
+    #[test]
+    fn test_tx_pool_limits_and_eviction() {
+        let sender1 = RoochAddress::random();
+        let sender2 = RoochAddress::random();
+        let mut pool = TxPool::new(3, 2);
+
+        pool.insert(pending_tx(sender1, 1, 100), 0).unwrap();
+        pool.insert(pending_tx(sender1, 2, 200), 0).unwrap();
+        // per sender limit
+        assert!(pool.insert(pending_tx(sender1, 3, 200), 1).is_err());
+        pool.insert(pending_tx(sender2, 5, 300), 4).unwrap();
+        // pool size limit
+        assert!(pool.insert(pending_tx(sender2, 6, 300), 4).is_err());
+
+        let pending = pool.pending_transactions(Some(sender1.into()));
+        assert_eq!(
+            pending
+                .iter()
+                .map(|tx| tx.sequence_number)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        let stale = pool.remove_stale(&sender1.into(), 2);
+        assert_eq!(stale.len(), 1);
+        assert_eq!(pool.len(), 2);
+
+        let expired = pool.evict_expired(250);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].sequence_number, 2);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.sender_count(), 1);
+        assert_eq!(pool.pending_transactions(None).len(), 1);
+    }
+}
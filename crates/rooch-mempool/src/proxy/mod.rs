@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::mempool::MempoolActor;
+use crate::actor::messages::{GetPendingTransactionsMessage, SubmitTxMessage};
+use crate::pool::PendingTransaction;
+use anyhow::Result;
+use coerce::actor::ActorRef;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::VMStatus;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_types::framework::auth_validator::ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use tokio::sync::oneshot;
+
+#[derive(Clone)]
+pub struct MempoolProxy {
+    pub actor: ActorRef<MempoolActor>,
+    executor: ExecutorProxy,
+}
+
+impl MempoolProxy {
+    pub fn new(actor: ActorRef<MempoolActor>, executor: ExecutorProxy) -> Self {
+        Self { actor, executor }
+    }
+
+    /// Submit the transaction, the returned receiver resolves when the transaction is executed or evicted.
+    /// The transaction is validated before it is sent to the mempool actor, so the validation does not block the actor,
+    /// the validation error is returned directly.
+    pub async fn submit_tx(
+        &self,
+        tx: RoochTransaction,
+    ) -> Result<oneshot::Receiver<Result<ExecuteTransactionResponse>>> {
+        let msg = match self.executor.validate_l2_tx(tx.clone()).await {
+            Ok(verified_tx) => SubmitTxMessage {
+                account_sequence_number: tx.sequence_number(),
+                tx,
+                verified_tx: Some(verified_tx),
+            },
+            // The future transaction passes all the validation checks except the sequence number
+            Err(e) if is_sequence_number_too_new(&e) => {
+                let account_sequence_number = self
+                    .executor
+                    .get_sequence_number(tx.sender().into())
+                    .await?;
+                SubmitTxMessage {
+                    tx,
+                    verified_tx: None,
+                    account_sequence_number,
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        self.actor.send(msg).await?
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.actor
+            .send(GetPendingTransactionsMessage { sender })
+            .await?
+    }
+}
+
+fn is_sequence_number_too_new(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<VMStatus>(),
+        Some(VMStatus::MoveAbort(_, code)) if *code == ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW
+    )
+}
@@ -86,11 +86,18 @@ impl Handler<ProcessTxWithEventsMessage> for NotifyActor {
         _ctx: &mut ActorContext,
     ) -> anyhow::Result<()> {
         tracing::debug!("NotifyActor receive message {:?}", message);
-        self.subscription_handler.process_tx_with_events(
-            message.tx,
-            message.events,
-            message.ctx,
-        )?;
+        let span = tracing::info_span!(
+            parent: &message.span,
+            "notify.process_tx",
+            tx_order = message.tx.transaction.sequence_info.tx_order
+        );
+        span.in_scope(|| {
+            self.subscription_handler.process_tx_with_events(
+                message.tx,
+                message.events,
+                message.ctx,
+            )
+        })?;
         Ok(())
     }
 }
@@ -53,6 +53,8 @@ pub struct ProcessTxWithEventsMessage {
     pub tx: TransactionWithInfo,
     pub events: Vec<Event>,
     pub ctx: TxContext,
+    /// The span of the caller, the parent of the notify span
+    pub span: tracing::Span,
 }
 
 impl Message for ProcessTxWithEventsMessage {
@@ -7,13 +7,15 @@ use futures::stream::Stream;
 use futures::task::{Context, Poll};
 use move_core_types::u256::U256;
 use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fmt;
 use std::pin::Pin;
 use std::str::FromStr;
 use tracing::warn;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AggregateStrategy {
     /// Calculate the average of the data
     #[default]
@@ -48,51 +50,51 @@ impl fmt::Display for AggregateStrategy {
 }
 
 impl AggregateStrategy {
-    pub fn aggregate(&self, data: Vec<OracleDecimalData>) -> OracleDecimalData {
-        match self {
+    /// Aggregate the data, returns None if the data is empty.
+    /// The values are converted to the max decimal of the data before aggregation,
+    /// and the timestamp of the result is the latest timestamp of the data.
+    pub fn aggregate(&self, data: Vec<OracleDecimalData>) -> Option<OracleDecimalData> {
+        let decimal = data.iter().map(|d| d.decimal).max()?;
+        let timestamp = data.iter().map(|d| d.timestamp).max()?;
+        let values = data
+            .iter()
+            .map(|d| d.to_decimal(decimal).value)
+            .collect::<Vec<_>>();
+        let value = match self {
             AggregateStrategy::Average => {
                 let mut sum: U256 = U256::zero();
-                for d in data.iter() {
-                    sum += d.value;
-                }
-                let avg = sum / U256::from(data.len() as u64);
-                let last_data = data.last().unwrap();
-                OracleDecimalData {
-                    value: avg,
-                    decimal: last_data.decimal,
-                    timestamp: last_data.timestamp,
+                for v in values.iter() {
+                    sum += *v;
                 }
+                sum / U256::from(values.len() as u64)
             }
             AggregateStrategy::Median => {
-                let mut sorted_data = data.clone();
-                sorted_data.sort_by(|a, b| a.value.cmp(&b.value));
-                let mid = sorted_data.len() / 2;
-                let median = if sorted_data.len() % 2 == 0 {
-                    (sorted_data[mid].value + sorted_data[mid - 1].value) / U256::from(2u64)
+                let mut sorted_values = values;
+                sorted_values.sort();
+                let mid = sorted_values.len() / 2;
+                if sorted_values.len() % 2 == 0 {
+                    (sorted_values[mid] + sorted_values[mid - 1]) / U256::from(2u64)
                 } else {
-                    sorted_data[mid].value
-                };
-                let last_data = data.last().unwrap();
-                OracleDecimalData {
-                    value: median,
-                    decimal: last_data.decimal,
-                    timestamp: last_data.timestamp,
+                    sorted_values[mid]
                 }
             }
             AggregateStrategy::Mode => {
                 let mut freq_map = std::collections::HashMap::new();
-                for d in data.iter() {
-                    *freq_map.entry(d.value).or_insert(0) += 1;
-                }
-                let mode = freq_map.iter().max_by_key(|&(_, count)| count).unwrap().0;
-                let last_data = data.last().unwrap();
-                OracleDecimalData {
-                    value: *mode,
-                    decimal: last_data.decimal,
-                    timestamp: last_data.timestamp,
+                for v in values.iter() {
+                    *freq_map.entry(*v).or_insert(0) += 1;
                 }
+                // Prefer the smaller value if the frequencies are the same, so the result is deterministic
+                *freq_map
+                    .iter()
+                    .max_by(|(v1, c1), (v2, c2)| c1.cmp(c2).then_with(|| v2.cmp(v1)))?
+                    .0
             }
-        }
+        };
+        Some(OracleDecimalData {
+            value,
+            decimal,
+            timestamp,
+        })
     }
 }
 
@@ -137,11 +139,9 @@ where
             }
         }
 
-        if !this.buffer.is_empty() {
-            let result = this.buffer.drain(..).collect();
-            Poll::Ready(Some(this.strategy.aggregate(result)))
-        } else {
-            Poll::Pending
+        match this.strategy.aggregate(this.buffer.drain(..).collect()) {
+            Some(data) => Poll::Ready(Some(data)),
+            None => Poll::Pending,
         }
     }
 }
@@ -186,19 +186,43 @@ mod tests {
             }, //two 100s
         ];
 
-        let avg = AggregateStrategy::Average.aggregate(data.clone());
+        let avg = AggregateStrategy::Average.aggregate(data.clone()).unwrap();
         assert_eq!(avg.value, U256::from(266u64));
         assert_eq!(avg.decimal, 2);
 
-        let median = AggregateStrategy::Median.aggregate(data.clone());
+        let median = AggregateStrategy::Median.aggregate(data.clone()).unwrap();
         assert_eq!(median.value, U256::from(250u64));
         assert_eq!(median.decimal, 2);
 
-        let mode = AggregateStrategy::Mode.aggregate(data.clone());
+        let mode = AggregateStrategy::Mode.aggregate(data.clone()).unwrap();
         assert_eq!(mode.value, U256::from(100u64));
         assert_eq!(mode.decimal, 2);
     }
 
+    #[test]
+    fn test_agg_empty_and_mixed_decimal() {
+        assert!(AggregateStrategy::Average.aggregate(vec![]).is_none());
+        assert!(AggregateStrategy::Median.aggregate(vec![]).is_none());
+        assert!(AggregateStrategy::Mode.aggregate(vec![]).is_none());
+
+        let data = vec![
+            OracleDecimalData {
+                value: U256::from(100u64),
+                decimal: 0,
+                timestamp: 2,
+            },
+            OracleDecimalData {
+                value: U256::from(30000u64),
+                decimal: 2,
+                timestamp: 1,
+            },
+        ];
+        let avg = AggregateStrategy::Average.aggregate(data).unwrap();
+        assert_eq!(avg.value, U256::from(20000u64));
+        assert_eq!(avg.decimal, 2);
+        assert_eq!(avg.timestamp, 2);
+    }
+
     #[tokio::test]
     async fn test_agg_stream() {
         let data_stream = futures::stream::iter(vec![
@@ -0,0 +1,221 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::aggregator_stream::AggregateStrategy;
+use crate::datasource::{DataSourceType, Ticker};
+use anyhow::{anyhow, ensure, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+pub const DEFAULT_MAX_STALENESS_SECS: u64 = 60;
+pub const DEFAULT_MIN_SOURCES: usize = 1;
+
+fn default_max_staleness_secs() -> u64 {
+    DEFAULT_MAX_STALENESS_SECS
+}
+
+fn default_min_sources() -> usize {
+    DEFAULT_MIN_SOURCES
+}
+
+/// The data source of the reporter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceConfig {
+    /// The identifier of the source: `binance`, `okx` or `pyth`
+    pub source: String,
+    /// Override the default endpoint of the source,
+    /// the `{symbol}` in the url is replaced by the symbol of the ticker for `binance` and `pyth`.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// The symbol of the ticker on the source, such as `BTCUSD: btcusdt` for `binance`.
+    pub symbols: BTreeMap<Ticker, String>,
+}
+
+/// The config of the oracle reporter, it can be loaded from a YAML or JSON file:
+///
+/// ```yaml
+/// sources:
+///   - source: binance
+///     symbols:
+///       BTCUSD: btcusdt
+///       ETHUSD: ethusdt
+///   - source: okx
+///     symbols:
+///       BTCUSD: BTC-USDT
+/// aggregate_strategy: median
+/// max_deviation_bps: 100
+/// max_staleness_secs: 60
+/// min_sources: 2
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReporterConfig {
+    pub sources: Vec<SourceConfig>,
+    /// The strategy to aggregate the data of a ticker from all the sources
+    #[serde(default)]
+    pub aggregate_strategy: AggregateStrategy,
+    /// The data deviating from the median of all the sources more than this basis points is rejected as an outlier
+    #[serde(default)]
+    pub max_deviation_bps: Option<u64>,
+    /// The data older than this is ignored
+    #[serde(default = "default_max_staleness_secs")]
+    pub max_staleness_secs: u64,
+    /// The ticker is not reported if the number of the valid sources is less than this
+    #[serde(default = "default_min_sources")]
+    pub min_sources: usize,
+}
+
+impl ReporterConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read config file {:?}: {}", path, e))?;
+        let config: ReporterConfig = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse config file {:?}: {}", path, e))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Build the config from the sources and tickers with the builtin symbols of the sources.
+    pub fn new_with_default_symbols(
+        sources: Vec<DataSourceType>,
+        tickers: Vec<Ticker>,
+        aggregate_strategy: AggregateStrategy,
+    ) -> Result<Self> {
+        let sources = sources
+            .into_iter()
+            .map(|source| {
+                let symbols = tickers
+                    .iter()
+                    .map(|ticker| {
+                        let symbol = source.default_symbol(ticker).ok_or_else(|| {
+                            anyhow!(
+                                "No builtin symbol of ticker {} for source {}, please config it",
+                                ticker,
+                                source
+                            )
+                        })?;
+                        Ok((ticker.clone(), symbol.to_string()))
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()?;
+                Ok(SourceConfig {
+                    source: source.identifier().to_string(),
+                    url: None,
+                    symbols,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let config = ReporterConfig {
+            sources,
+            aggregate_strategy,
+            max_deviation_bps: None,
+            max_staleness_secs: DEFAULT_MAX_STALENESS_SECS,
+            min_sources: DEFAULT_MIN_SOURCES,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        ensure!(!self.sources.is_empty(), "At least one source is required");
+        ensure!(self.min_sources > 0, "min_sources should be greater than 0");
+        ensure!(
+            self.min_sources <= self.sources.len(),
+            "min_sources {} is greater than the number of the sources {}",
+            self.min_sources,
+            self.sources.len()
+        );
+        for source in &self.sources {
+            DataSourceType::new(&source.source, None)?;
+            ensure!(
+                !source.symbols.is_empty(),
+                "No ticker configured for source {}",
+                source.source
+            );
+        }
+        Ok(())
+    }
+
+    /// All the tickers of the sources
+    pub fn tickers(&self) -> BTreeSet<Ticker> {
+        self.sources
+            .iter()
+            .flat_map(|source| source.symbols.keys().cloned())
+            .collect()
+    }
+
+    /// The subscriptions of every ticker on every source: (source, ticker, symbol)
+    pub fn subscriptions(&self) -> Result<Vec<(DataSourceType, Ticker, String)>> {
+        let mut subscriptions = vec![];
+        for source in &self.sources {
+            let data_source = DataSourceType::new(&source.source, source.url.clone())?;
+            for (ticker, symbol) in &source.symbols {
+                subscriptions.push((data_source.clone(), ticker.clone(), symbol.clone()));
+            }
+        }
+        Ok(subscriptions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_reporter_config() {
+        let config: ReporterConfig = serde_yaml::from_str(
+            r#"
+sources:
+  - source: binance
+    symbols:
+      BTCUSD: btcusdt
+      ETHUSD: ethusdt
+  - source: okx
+    url: ws://127.0.0.1:8080
+    symbols:
+      BTCUSD: BTC-USDT
+aggregate_strategy: median
+max_deviation_bps: 100
+min_sources: 2
+"#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+        assert_eq!(config.aggregate_strategy, AggregateStrategy::Median);
+        assert_eq!(config.max_staleness_secs, DEFAULT_MAX_STALENESS_SECS);
+        assert_eq!(config.tickers().len(), 2);
+        assert_eq!(config.subscriptions().unwrap().len(), 3);
+
+        let mut invalid_config = config.clone();
+        invalid_config.min_sources = 3;
+        assert!(invalid_config.validate().is_err());
+        invalid_config.min_sources = 1;
+        invalid_config.sources[0].source = "unknown".to_string();
+        assert!(invalid_config.validate().is_err());
+    }
+
+    #[test]
+    fn test_default_symbols() {
+        let sources = vec![
+            DataSourceType::from_str("binance").unwrap(),
+            DataSourceType::from_str("pyth").unwrap(),
+        ];
+        let config = ReporterConfig::new_with_default_symbols(
+            sources.clone(),
+            vec![Ticker::default()],
+            AggregateStrategy::Average,
+        )
+        .unwrap();
+        assert_eq!(config.sources.len(), 2);
+        assert_eq!(
+            config.sources[0].symbols.get(&Ticker::default()),
+            Some(&"btcusdt".to_string())
+        );
+        assert!(ReporterConfig::new_with_default_symbols(
+            sources,
+            vec![Ticker::from_str("UNKNOWN").unwrap()],
+            AggregateStrategy::Average,
+        )
+        .is_err());
+    }
+}
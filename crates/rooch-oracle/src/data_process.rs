@@ -18,6 +18,7 @@ use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, warn};
 
 use crate::datasource::OracleDecimalData;
+use crate::multi_source::AggregatedData;
 
 pub fn subscribe_websocket(
     url: String,
@@ -228,3 +229,49 @@ pub async fn execute_submit_data_tx(
     };
     Ok(())
 }
+
+/// Submit the aggregated data of many tickers in one transaction.
+pub async fn execute_submit_batch_data_tx(
+    wallet_context: &WalletContext,
+    sender: RoochAddress,
+    oracle_id: ObjectID,
+    data: Vec<AggregatedData>,
+    admin_obj: ObjectID,
+) -> Result<()> {
+    let tickers = data
+        .iter()
+        .map(|d| d.ticker.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let action = OracleModule::submit_decimal_data_batch_action(
+        oracle_id,
+        data.into_iter()
+            .map(|d| {
+                let identifier = d.identifier();
+                (
+                    d.ticker.to_string(),
+                    d.data.value,
+                    d.data.decimal,
+                    identifier,
+                    d.data.timestamp,
+                )
+            })
+            .collect(),
+        admin_obj,
+    );
+    let tx_data = wallet_context.build_tx_data(sender, action, None).await?;
+    let tx = wallet_context
+        .sign_and_execute(sender, tx_data)
+        .await
+        .map_err(|e| anyhow::anyhow!("Execute submit batch function error {:?}", e))?;
+    match tx.execution_info.status {
+        KeptVMStatusView::Executed => {
+            info!(
+                "Submit data of tickers: {}, tx_hash: {:?}, gas_used:{}",
+                tickers, tx.execution_info.tx_hash, tx.execution_info.gas_used
+            );
+            Ok(())
+        }
+        status => bail!("Execute submit batch function error {:?}", status),
+    }
+}
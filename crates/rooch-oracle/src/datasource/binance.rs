@@ -10,28 +10,44 @@ use serde_json::Value;
 use std::pin::Pin;
 use tokio_stream::StreamExt;
 
-const URL_TEMPLATE: &str = "wss://stream.binance.com:9443/ws/{ticker}@ticker";
+const URL_TEMPLATE: &str = "wss://stream.binance.com:9443/ws/{symbol}@ticker";
 
 #[derive(Debug, Clone)]
-pub struct BinanceSource;
+pub struct BinanceSource {
+    /// The websocket url template, the `{symbol}` is replaced by the symbol of the ticker
+    url_template: String,
+}
+
+impl BinanceSource {
+    pub fn new(url_template: String) -> Self {
+        Self { url_template }
+    }
+}
+
+impl Default for BinanceSource {
+    fn default() -> Self {
+        Self::new(URL_TEMPLATE.to_string())
+    }
+}
 
 impl DataSource for BinanceSource {
     const IDENTIFIER: &'static str = "binance";
     fn subscribe(
         self,
-        ticker: Ticker,
+        symbol: String,
     ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let bticker = ticker_mapping(ticker);
-        let url = URL_TEMPLATE.replace("{ticker}", bticker);
+        let url = self.url_template.replace("{symbol}", &symbol);
         let stream = data_process::subscribe_websocket(url, None);
         let stream = stream.map(|result| result.and_then(parse_data));
         Box::pin(stream)
     }
-}
 
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "btcusdt",
+    fn default_symbol(ticker: &Ticker) -> Option<&'static str> {
+        match ticker.as_str() {
+            "BTCUSD" => Some("btcusdt"),
+            "ETHUSD" => Some("ethusdt"),
+            _ => None,
+        }
     }
 }
 
@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The mocked websocket and http stream servers, the sources can subscribe the mocked data
+//! by overriding the source url with the url of the server.
+
+use futures_util::SinkExt;
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Start a websocket server which sends the `messages` to every connection, returns the url of the server.
+pub async fn websocket_server(messages: Vec<Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let messages = messages.clone();
+            tokio::spawn(async move {
+                let mut ws_stream = match tokio_tungstenite::accept_async(stream).await {
+                    Ok(ws_stream) => ws_stream,
+                    Err(_) => return,
+                };
+                for message in messages {
+                    if ws_stream
+                        .send(Message::Text(message.to_string()))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                // Keep the connection open, so the client does not reconnect
+                futures_util::future::pending::<()>().await;
+            });
+        }
+    });
+    url
+}
+
+/// Start a http server which responds the `messages` as a server-sent event stream, returns the url of the server.
+/// The http stream client emits a message when the next message arrives, so the last message is not emitted.
+pub async fn http_stream_server(messages: Vec<Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let messages = messages.clone();
+            tokio::spawn(async move {
+                let mut request = [0u8; 1024];
+                if stream.read(&mut request).await.is_err() {
+                    return;
+                }
+                let mut response = String::from(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n",
+                );
+                for message in messages {
+                    response.push_str(&format!("data:{}\n\n", message));
+                }
+                let _ = stream.write_all(response.as_bytes()).await;
+                futures_util::future::pending::<()>().await;
+            });
+        }
+    });
+    url
+}
@@ -7,34 +7,64 @@ use futures_util::Stream;
 use move_core_types::u256::U256;
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
     fmt::{self, Display},
     pin::Pin,
     str::FromStr,
 };
 
-#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum Ticker {
-    #[default]
-    BTCUSD,
+/// The ticker of the price data, such as `BTCUSD`.
+/// The sources use their own symbols for the ticker, the symbols are configured per source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Ticker(String);
+
+impl Ticker {
+    pub const BTCUSD: &'static str = "BTCUSD";
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Ticker {
+    fn default() -> Self {
+        Ticker(Self::BTCUSD.to_string())
+    }
 }
 
 impl FromStr for Ticker {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "BTCUSD" => Ok(Ticker::BTCUSD),
-            _ => Err(anyhow::anyhow!("Invalid ticker")),
+        if s.is_empty()
+            || !s
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '/')
+        {
+            return Err(anyhow::anyhow!("Invalid ticker: {}", s));
         }
+        Ok(Ticker(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for Ticker {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Ticker::from_str(&s)
+    }
+}
+
+impl From<Ticker> for String {
+    fn from(ticker: Ticker) -> Self {
+        ticker.0
     }
 }
 
 impl Display for Ticker {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Ticker::BTCUSD => write!(f, "BTCUSD"),
-        }
+        write!(f, "{}", self.0)
     }
 }
 
@@ -42,23 +72,51 @@ impl Display for Ticker {
 pub struct OracleDecimalData {
     pub value: U256,
     pub decimal: u8,
+    /// The timestamp of the data from the source, in milliseconds
     pub timestamp: u64,
 }
 
+impl OracleDecimalData {
+    /// Convert the value to the `decimal`, the value is truncated if the `decimal` is smaller.
+    pub fn to_decimal(&self, decimal: u8) -> OracleDecimalData {
+        let value = match decimal.cmp(&self.decimal) {
+            Ordering::Greater => self.value * pow10(decimal - self.decimal),
+            Ordering::Less => self.value / pow10(self.decimal - decimal),
+            Ordering::Equal => self.value,
+        };
+        OracleDecimalData {
+            value,
+            decimal,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+fn pow10(exp: u8) -> U256 {
+    (0..exp).fold(U256::from(1u64), |acc, _| acc * U256::from(10u64))
+}
+
 #[async_trait]
 pub trait DataSource {
     const IDENTIFIER: &'static str;
+
+    /// Subscribe the price data of the `symbol`, the symbol is the name of the ticker on the source.
     fn subscribe(
         self,
-        ticker: Ticker,
+        symbol: String,
     ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>>;
 
+    /// The builtin symbol of the ticker on the source, it is used if the symbol is not configured.
+    fn default_symbol(ticker: &Ticker) -> Option<&'static str>;
+
     fn identifier(&self) -> &'static str {
         Self::IDENTIFIER
     }
 }
 
 pub mod binance;
+#[cfg(test)]
+pub mod mock;
 pub mod okx;
 pub mod pyth;
 
@@ -70,6 +128,23 @@ pub enum DataSourceType {
 }
 
 impl DataSourceType {
+    /// Create the source by the identifier, the `url` overrides the default endpoint of the source.
+    pub fn new(identifier: &str, url: Option<String>) -> Result<Self> {
+        let source = match identifier {
+            okx::OKXSource::IDENTIFIER => {
+                DataSourceType::OKX(url.map(okx::OKXSource::new).unwrap_or_default())
+            }
+            binance::BinanceSource::IDENTIFIER => {
+                DataSourceType::Binance(url.map(binance::BinanceSource::new).unwrap_or_default())
+            }
+            pyth::PythSource::IDENTIFIER => {
+                DataSourceType::Pyth(url.map(pyth::PythSource::new).unwrap_or_default())
+            }
+            _ => return Err(anyhow::anyhow!("Invalid DataSourceType: {}", identifier)),
+        };
+        Ok(source)
+    }
+
     pub fn identifier(&self) -> &'static str {
         match self {
             DataSourceType::OKX(ds) => ds.identifier(),
@@ -78,14 +153,22 @@ impl DataSourceType {
         }
     }
 
+    pub fn default_symbol(&self, ticker: &Ticker) -> Option<&'static str> {
+        match self {
+            DataSourceType::OKX(_) => okx::OKXSource::default_symbol(ticker),
+            DataSourceType::Binance(_) => binance::BinanceSource::default_symbol(ticker),
+            DataSourceType::Pyth(_) => pyth::PythSource::default_symbol(ticker),
+        }
+    }
+
     pub fn subscribe(
         self,
-        ticker: Ticker,
+        symbol: String,
     ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
         match self {
-            DataSourceType::OKX(ds) => ds.subscribe(ticker),
-            DataSourceType::Binance(ds) => ds.subscribe(ticker),
-            DataSourceType::Pyth(ds) => ds.subscribe(ticker),
+            DataSourceType::OKX(ds) => ds.subscribe(symbol),
+            DataSourceType::Binance(ds) => ds.subscribe(symbol),
+            DataSourceType::Pyth(ds) => ds.subscribe(symbol),
         }
     }
 }
@@ -94,14 +177,7 @@ impl FromStr for DataSourceType {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s {
-            okx::OKXSource::IDENTIFIER => Ok(DataSourceType::OKX(okx::OKXSource)),
-            binance::BinanceSource::IDENTIFIER => {
-                Ok(DataSourceType::Binance(binance::BinanceSource))
-            }
-            pyth::PythSource::IDENTIFIER => Ok(DataSourceType::Pyth(pyth::PythSource)),
-            _ => Err(anyhow::anyhow!("Invalid DataSourceType")),
-        }
+        DataSourceType::new(s, None)
     }
 }
 
@@ -114,12 +190,14 @@ impl fmt::Display for DataSourceType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
     use tokio_stream::StreamExt;
     use tracing::{info, warn};
 
-    async fn test_datasource(ds: impl DataSource) {
+    async fn test_datasource<D: DataSource>(ds: D) {
         let identifier = ds.identifier();
-        let mut stream = ds.subscribe(Ticker::BTCUSD);
+        let symbol = D::default_symbol(&Ticker::default()).unwrap();
+        let mut stream = ds.subscribe(symbol.to_string());
         //consume 10 items
         let mut ok_count = 0;
         for _ in 0..10 {
@@ -144,18 +222,82 @@ mod tests {
     #[tokio::test(flavor = "multi_thread")]
     async fn test_okx_datasource() {
         let _trace = tracing_subscriber::fmt().try_init();
-        test_datasource(okx::OKXSource).await;
+        test_datasource(okx::OKXSource::default()).await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn test_binance_datasource() {
         let _trace = tracing_subscriber::fmt().try_init();
-        test_datasource(binance::BinanceSource).await;
+        test_datasource(binance::BinanceSource::default()).await;
     }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn test_pyth_datasource() {
         let _trace = tracing_subscriber::fmt().try_init();
-        test_datasource(pyth::PythSource).await;
+        test_datasource(pyth::PythSource::default()).await;
+    }
+
+    async fn first_data(source: DataSourceType, symbol: &str) -> OracleDecimalData {
+        source
+            .subscribe(symbol.to_string())
+            .next()
+            .await
+            .expect("should receive data")
+            .expect("should parse data")
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_binance_mock_source() {
+        let url =
+            mock::websocket_server(vec![json!({"s": "ETHUSDT", "c": "3000.5", "E": 1000})]).await;
+        let source = DataSourceType::new("binance", Some(url)).unwrap();
+        let data = first_data(source, "ethusdt").await;
+        assert_eq!(data.value, U256::from(300050000000u64));
+        assert_eq!(data.decimal, 8);
+        assert_eq!(data.timestamp, 1000);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_okx_mock_source() {
+        let url = mock::websocket_server(vec![
+            json!({"event": "subscribe"}),
+            json!({"data": [{"instId": "ETH-USDT", "last": "3001", "ts": "2000"}]}),
+        ])
+        .await;
+        let source = DataSourceType::new("okx", Some(url)).unwrap();
+        let data = first_data(source, "ETH-USDT").await;
+        assert_eq!(data.value, U256::from(300100000000u64));
+        assert_eq!(data.timestamp, 2000);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_pyth_mock_source() {
+        let price = |price: &str, expo: i64| json!({"parsed": [{"ema_price": {"price": price, "expo": expo, "publish_time": 3}}]});
+        let url = mock::http_stream_server(vec![price("300200", -2), price("1", 0)]).await;
+        let source = DataSourceType::new("pyth", Some(url)).unwrap();
+        let data = first_data(source, "0x01").await;
+        assert_eq!(data.value, U256::from(300200000000u64));
+        assert_eq!(data.decimal, 8);
+        assert_eq!(data.timestamp, 3000);
+    }
+
+    #[test]
+    fn test_ticker() {
+        assert_eq!(Ticker::default().as_str(), Ticker::BTCUSD);
+        assert_eq!(Ticker::from_str("ETHUSD").unwrap().to_string(), "ETHUSD");
+        assert!(Ticker::from_str("").is_err());
+        assert!(Ticker::from_str("BTC USD").is_err());
+    }
+
+    #[test]
+    fn test_to_decimal() {
+        let data = OracleDecimalData {
+            value: U256::from(12345u64),
+            decimal: 2,
+            timestamp: 0,
+        };
+        assert_eq!(data.to_decimal(4).value, U256::from(1234500u64));
+        assert_eq!(data.to_decimal(1).value, U256::from(1234u64));
+        assert_eq!(data.to_decimal(2), data);
     }
 }
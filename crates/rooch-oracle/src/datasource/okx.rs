@@ -13,33 +13,47 @@ use tokio_stream::StreamExt;
 const URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
 
 #[derive(Debug, Clone)]
-pub struct OKXSource;
+pub struct OKXSource {
+    url: String,
+}
+
+impl OKXSource {
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+}
+
+impl Default for OKXSource {
+    fn default() -> Self {
+        Self::new(URL.to_string())
+    }
+}
 
 impl DataSource for OKXSource {
     const IDENTIFIER: &'static str = "okx";
     fn subscribe(
         self,
-        ticker: Ticker,
+        symbol: String,
     ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let inst_id = ticker_mapping(ticker);
         let subscribe_msg = json!({
             "op": "subscribe",
             "args": [{
                 "channel": "tickers",
-                "instId": inst_id.to_string()
+                "instId": symbol
             }]
         });
-        let url = URL.to_string();
-        let stream = data_process::subscribe_websocket(url, Some(subscribe_msg));
+        let stream = data_process::subscribe_websocket(self.url, Some(subscribe_msg));
         //skip the first message
         let stream = stream.skip(1).map(|result| result.and_then(parse_data));
         Box::pin(stream)
     }
-}
 
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "BTC-USDT",
+    fn default_symbol(ticker: &Ticker) -> Option<&'static str> {
+        match ticker.as_str() {
+            "BTCUSD" => Some("BTC-USDT"),
+            "ETHUSD" => Some("ETH-USDT"),
+            _ => None,
+        }
     }
 }
 
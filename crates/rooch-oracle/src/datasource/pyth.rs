@@ -11,29 +11,49 @@ use std::pin::Pin;
 use tokio_stream::StreamExt;
 
 const URL_TEMPLATE: &str =
-    "https://hermes.pyth.network/v2/updates/price/stream?ids[]={ticker}&parsed=true";
+    "https://hermes.pyth.network/v2/updates/price/stream?ids[]={symbol}&parsed=true";
+
+/// The decimal of the reported data, the Pyth price is converted from its exponent to this decimal.
+const DECIMAL: u8 = 8;
 
 #[derive(Debug, Clone)]
-pub struct PythSource;
+pub struct PythSource {
+    /// The http stream url template, the `{symbol}` is replaced by the price feed id of the ticker
+    url_template: String,
+}
+
+impl PythSource {
+    pub fn new(url_template: String) -> Self {
+        Self { url_template }
+    }
+}
+
+impl Default for PythSource {
+    fn default() -> Self {
+        Self::new(URL_TEMPLATE.to_string())
+    }
+}
 
 impl DataSource for PythSource {
     const IDENTIFIER: &'static str = "pyth";
     fn subscribe(
         self,
-        ticker: Ticker,
+        symbol: String,
     ) -> Pin<Box<dyn Stream<Item = Result<OracleDecimalData>> + Send + 'static>> {
-        let url = URL_TEMPLATE.replace("{ticker}", ticker_mapping(ticker));
+        let url = self.url_template.replace("{symbol}", &symbol);
 
         let stream = data_process::subscribe_http_stream(url);
         let stream = stream.map(|result| result.and_then(parse_data));
         Box::pin(stream)
     }
-}
 
-//https://www.pyth.network/developers/price-feed-ids
-fn ticker_mapping(ticker: Ticker) -> &'static str {
-    match ticker {
-        Ticker::BTCUSD => "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43",
+    //https://www.pyth.network/developers/price-feed-ids
+    fn default_symbol(ticker: &Ticker) -> Option<&'static str> {
+        match ticker.as_str() {
+            "BTCUSD" => Some("0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
+            "ETHUSD" => Some("0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace"),
+            _ => None,
+        }
     }
 }
 
@@ -42,12 +62,19 @@ fn parse_data(response: Value) -> Result<OracleDecimalData> {
         .as_str()
         .ok_or_else(|| anyhow!("price field not found in response: {}", response))?
         .parse::<U256>()?;
+    // The price is `price * 10^expo`, the expo of the most feeds is -8
+    let expo = response["parsed"][0]["ema_price"]["expo"]
+        .as_i64()
+        .unwrap_or(-(DECIMAL as i64));
+    let decimal = u8::try_from(-expo)
+        .map_err(|_| anyhow!("unsupported expo {} in response: {}", expo, response))?;
     let publish_time = response["parsed"][0]["ema_price"]["publish_time"]
         .as_u64()
         .ok_or_else(|| anyhow!("publish_time field not found in response: {}", response))?;
     Ok(OracleDecimalData {
         value: price,
-        decimal: 8,
+        decimal,
         timestamp: publish_time * 1000,
-    })
+    }
+    .to_decimal(DECIMAL))
 }
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod aggregator_stream;
+pub mod config;
 pub mod data_process;
 pub mod datasource;
+pub mod multi_source;
 pub mod reporter;
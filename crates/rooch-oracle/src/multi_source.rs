@@ -0,0 +1,212 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::aggregator_stream::AggregateStrategy;
+use crate::config::ReporterConfig;
+use crate::datasource::{OracleDecimalData, Ticker};
+use move_core_types::u256::U256;
+use std::collections::BTreeMap;
+use tracing::{debug, warn};
+
+const BPS_BASE: u64 = 10000;
+
+/// The aggregated data of a ticker from multiple sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedData {
+    pub ticker: Ticker,
+    pub data: OracleDecimalData,
+    /// The identifiers of the sources used in the aggregation
+    pub sources: Vec<String>,
+}
+
+impl AggregatedData {
+    /// The identifier submitted with the data, it is the sources of the data.
+    pub fn identifier(&self) -> String {
+        self.sources.join(",")
+    }
+}
+
+/// Keep the latest data of every ticker from every source,
+/// and aggregate the data of a ticker across the sources with outlier and staleness rejection.
+pub struct MultiSourceAggregator {
+    strategy: AggregateStrategy,
+    max_deviation_bps: Option<u64>,
+    /// In milliseconds
+    max_staleness: u64,
+    min_sources: usize,
+    latest: BTreeMap<Ticker, BTreeMap<String, OracleDecimalData>>,
+}
+
+impl MultiSourceAggregator {
+    pub fn new(
+        strategy: AggregateStrategy,
+        max_deviation_bps: Option<u64>,
+        max_staleness_secs: u64,
+        min_sources: usize,
+    ) -> Self {
+        Self {
+            strategy,
+            max_deviation_bps,
+            max_staleness: max_staleness_secs.saturating_mul(1000),
+            min_sources,
+            latest: BTreeMap::new(),
+        }
+    }
+
+    pub fn new_with_config(config: &ReporterConfig) -> Self {
+        Self::new(
+            config.aggregate_strategy,
+            config.max_deviation_bps,
+            config.max_staleness_secs,
+            config.min_sources,
+        )
+    }
+
+    /// Update the latest data of the ticker from the source, the older data is ignored.
+    pub fn update(&mut self, source: &str, ticker: Ticker, data: OracleDecimalData) {
+        let sources = self.latest.entry(ticker).or_default();
+        match sources.get(source) {
+            Some(latest) if latest.timestamp > data.timestamp => {}
+            _ => {
+                sources.insert(source.to_string(), data);
+            }
+        }
+    }
+
+    /// Aggregate the data of every ticker at `now` in milliseconds.
+    /// The ticker without enough valid sources is skipped.
+    pub fn aggregate(&self, now: u64) -> Vec<AggregatedData> {
+        self.latest
+            .iter()
+            .filter_map(|(ticker, sources)| self.aggregate_ticker(ticker, sources, now))
+            .collect()
+    }
+
+    fn aggregate_ticker(
+        &self,
+        ticker: &Ticker,
+        sources: &BTreeMap<String, OracleDecimalData>,
+        now: u64,
+    ) -> Option<AggregatedData> {
+        let fresh = sources
+            .iter()
+            .filter(|(source, data)| {
+                let is_fresh = now.saturating_sub(data.timestamp) <= self.max_staleness;
+                if !is_fresh {
+                    debug!(
+                        "Reject stale data of {} from {}, timestamp: {}, now: {}",
+                        ticker, source, data.timestamp, now
+                    );
+                }
+                is_fresh
+            })
+            .collect::<Vec<_>>();
+        let valid = match self.max_deviation_bps {
+            Some(max_deviation_bps) => Self::reject_outliers(ticker, fresh, max_deviation_bps),
+            None => fresh,
+        };
+        if valid.len() < self.min_sources {
+            warn!(
+                "Skip ticker {}, valid sources {} is less than {}",
+                ticker,
+                valid.len(),
+                self.min_sources
+            );
+            return None;
+        }
+        let data = self
+            .strategy
+            .aggregate(valid.iter().map(|(_, data)| (*data).clone()).collect())?;
+        Some(AggregatedData {
+            ticker: ticker.clone(),
+            data,
+            sources: valid
+                .into_iter()
+                .map(|(source, _)| source.clone())
+                .collect(),
+        })
+    }
+
+    /// Reject the data deviating from the median of all the data more than `max_deviation_bps`.
+    fn reject_outliers<'a>(
+        ticker: &Ticker,
+        data: Vec<(&'a String, &'a OracleDecimalData)>,
+        max_deviation_bps: u64,
+    ) -> Vec<(&'a String, &'a OracleDecimalData)> {
+        let median = match AggregateStrategy::Median
+            .aggregate(data.iter().map(|(_, data)| (*data).clone()).collect())
+        {
+            Some(median) if median.value != U256::zero() => median,
+            _ => return data,
+        };
+        data.into_iter()
+            .filter(|(source, data)| {
+                let value = data.to_decimal(median.decimal).value;
+                let diff = if value > median.value {
+                    value - median.value
+                } else {
+                    median.value - value
+                };
+                let is_valid =
+                    diff * U256::from(BPS_BASE) <= median.value * U256::from(max_deviation_bps);
+                if !is_valid {
+                    warn!(
+                        "Reject outlier data of {} from {}, value: {}, median: {}",
+                        ticker, source, value, median.value
+                    );
+                }
+                is_valid
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(value: u64, timestamp: u64) -> OracleDecimalData {
+        OracleDecimalData {
+            value: U256::from(value),
+            decimal: 2,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_multi_source_aggregate() {
+        let btc = Ticker::default();
+        let eth: Ticker = "ETHUSD".parse().unwrap();
+        let mut aggregator =
+            MultiSourceAggregator::new(AggregateStrategy::Median, Some(100), 10, 2);
+        aggregator.update("binance", btc.clone(), data(10000, 1000));
+        aggregator.update("okx", btc.clone(), data(10050, 1000));
+        // The outlier
+        aggregator.update("pyth", btc.clone(), data(12000, 1000));
+        // The older data is ignored
+        aggregator.update("okx", btc.clone(), data(1, 500));
+        aggregator.update("binance", eth.clone(), data(300, 1000));
+
+        let result = aggregator.aggregate(2000);
+        // ETHUSD only has one source
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].ticker, btc);
+        assert_eq!(result[0].data.value, U256::from(10025u64));
+        assert_eq!(result[0].identifier(), "binance,okx");
+
+        // All the data is stale
+        assert!(aggregator.aggregate(20000).is_empty());
+    }
+
+    #[test]
+    fn test_multi_source_without_outlier_rejection() {
+        let btc = Ticker::default();
+        let mut aggregator = MultiSourceAggregator::new(AggregateStrategy::Average, None, 10, 1);
+        assert!(aggregator.aggregate(0).is_empty());
+        aggregator.update("binance", btc.clone(), data(10000, 1000));
+        aggregator.update("pyth", btc.clone(), data(12000, 1000));
+        let result = aggregator.aggregate(1000);
+        assert_eq!(result[0].data.value, U256::from(11000u64));
+        assert_eq!(result[0].sources.len(), 2);
+    }
+}
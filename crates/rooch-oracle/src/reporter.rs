@@ -1,17 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{
-    aggregator_stream::{AggregateStrategy, AggregatorStream},
-    data_process,
-    datasource::{DataSourceType, Ticker},
-};
-use anyhow::Result;
-use futures::stream::StreamExt;
+use crate::{config::ReporterConfig, data_process, multi_source::MultiSourceAggregator};
+use anyhow::{bail, Result};
+use futures::stream::{self, StreamExt};
 use moveos_types::moveos_std::object::ObjectID;
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_types::address::RoochAddress;
-use tracing::error;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{error, warn};
 
 pub struct Reporter {
     pub wallet_context: WalletContext,
@@ -19,9 +16,7 @@ pub struct Reporter {
     pub oracle_id: ObjectID,
     pub oracle_admin_id: ObjectID,
     pub report_interval: u64,
-    pub ticker: Ticker,
-    pub aggregate_strategy: AggregateStrategy,
-    pub datasource: DataSourceType,
+    pub config: ReporterConfig,
     pub stop_on_error: bool,
     pub stop_after_report_times: Option<u64>,
 }
@@ -33,9 +28,7 @@ impl Reporter {
         oracle_id: ObjectID,
         oracle_admin_id: ObjectID,
         report_interval: u64,
-        ticker: Ticker,
-        datasource: DataSourceType,
-        aggregate_strategy: AggregateStrategy,
+        config: ReporterConfig,
         stop_on_error: bool,
         stop_after_report_times: Option<u64>,
     ) -> Self {
@@ -45,9 +38,7 @@ impl Reporter {
             oracle_id,
             oracle_admin_id,
             report_interval,
-            ticker,
-            datasource,
-            aggregate_strategy,
+            config,
             stop_on_error,
             stop_after_report_times,
         }
@@ -60,41 +51,62 @@ impl Reporter {
             oracle_admin_id,
             wallet_context,
             report_interval,
-            ticker,
-            datasource,
-            aggregate_strategy,
+            config,
             stop_on_error,
             stop_after_report_times,
         } = self;
-        let identifier = datasource.identifier().to_owned();
-        let stream = datasource.subscribe(ticker);
-        let mut aggregator = AggregatorStream::new(stream, aggregate_strategy);
+        config.validate()?;
+        let mut aggregator = MultiSourceAggregator::new_with_config(&config);
+        // Fan in the data of every ticker from every source
+        let streams = config
+            .subscriptions()?
+            .into_iter()
+            .map(|(source, ticker, symbol)| {
+                let identifier = source.identifier();
+                source
+                    .subscribe(symbol)
+                    .map(move |result| (identifier, ticker.clone(), result))
+            })
+            .collect::<Vec<_>>();
+        let mut data_stream = stream::select_all(streams);
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(report_interval));
         let mut report_times = 0u64;
         loop {
-            let _ = interval.tick().await;
-            if let Some(data) = aggregator.next().await {
-                let result = data_process::execute_submit_data_tx(
-                    &wallet_context,
-                    sender,
-                    oracle_id.clone(),
-                    ticker.to_string(),
-                    data,
-                    identifier.clone(),
-                    oracle_admin_id.clone(),
-                )
-                .await;
-                if let Err(e) = result {
-                    if stop_on_error {
-                        return Err(e);
-                    } else {
-                        error!("Failed to submit data: {}", e);
+            tokio::select! {
+                item = data_stream.next() => match item {
+                    Some((identifier, ticker, Ok(data))) => aggregator.update(identifier, ticker, data),
+                    Some((identifier, ticker, Err(e))) => {
+                        warn!("Error in stream of {} from {}: {}", ticker, identifier, e);
                     }
-                }
-                report_times += 1;
-                if let Some(stop_after_report_times) = stop_after_report_times {
-                    if report_times >= stop_after_report_times {
-                        break;
+                    None => bail!("All the data sources are closed"),
+                },
+                _ = interval.tick() => {
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+                    let data = aggregator.aggregate(now);
+                    if data.is_empty() {
+                        warn!("No valid data to report");
+                        continue;
+                    }
+                    let result = data_process::execute_submit_batch_data_tx(
+                        &wallet_context,
+                        sender,
+                        oracle_id.clone(),
+                        data,
+                        oracle_admin_id.clone(),
+                    )
+                    .await;
+                    if let Err(e) = result {
+                        if stop_on_error {
+                            return Err(e);
+                        } else {
+                            error!("Failed to submit data: {}", e);
+                        }
+                    }
+                    report_times += 1;
+                    if let Some(stop_after_report_times) = stop_after_report_times {
+                        if report_times >= stop_after_report_times {
+                            break;
+                        }
                     }
                 }
             }
@@ -3,3 +3,4 @@
 
 pub mod event;
 pub mod ord_client;
+pub mod verify;
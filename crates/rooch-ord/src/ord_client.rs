@@ -36,6 +36,8 @@ pub struct InscriptionInfo {
     pub fee: u64,
     pub height: u32,
     pub id: InscriptionID,
+    #[serde(default)]
+    pub metaprotocol: Option<String>,
     pub next: Option<InscriptionID>,
     pub number: i32,
     pub parents: Vec<InscriptionID>,
@@ -115,6 +117,22 @@ impl OrdClient {
         })
     }
 
+    /// Get the raw content of the inscription, return None if the inscription has no content.
+    pub async fn get_inscription_content(&self, id: &InscriptionID) -> Result<Option<Vec<u8>>> {
+        let url = format!("{}/content/{}", self.ord_rpc_url, id);
+        debug!("GET {}", url);
+        self.retry(|| async {
+            let resp = self.http_client.get(&url).send().await?;
+            if resp.status() == StatusCode::NOT_FOUND {
+                Ok(None)
+            } else {
+                let resp = resp.error_for_status()?;
+                Ok(Some(resp.bytes().await?.to_vec()))
+            }
+        })
+        .await
+    }
+
     async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>> {
         let url = format!("{}/{}", self.ord_rpc_url, path);
         debug!("GET {}", url);
@@ -0,0 +1,606 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Check the parity of the inscriptions between ord and Rooch.
+//!
+//! The expected inscription states are streamed from an [`OrdInscriptionSource`], such as the ord events file
+//! or an ord server(or a local stand-in serving the same API), and compared with the inscriptions on Rooch
+//! read by a [`RoochInscriptionReader`]. The mismatches are appended to the report file, and the progress is saved to
+//! the checkpoint file after every batch, so an interrupted verification can be resumed.
+
+use crate::event::Event;
+use crate::ord_client::OrdClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use rooch_types::bitcoin::ord::{Inscription, InscriptionID, SatPoint};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+pub const REPORT_FILE_NAME: &str = "mismatches.jsonl";
+pub const CHECKPOINT_FILE_NAME: &str = "checkpoint.json";
+
+/// The inscription state expected by ord, the `None` fields are unknown by the source and not compared.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrdInscriptionState {
+    pub id: InscriptionID,
+    pub block_height: u32,
+    pub sequence_number: Option<u32>,
+    pub number: Option<i32>,
+    pub location: Option<SatPoint>,
+    pub parents: Option<Vec<InscriptionID>>,
+    pub metaprotocol: Option<String>,
+    /// The hex encoded sha256 hash of the inscription content
+    pub content_hash: Option<String>,
+}
+
+impl OrdInscriptionState {
+    fn new(id: InscriptionID, block_height: u32) -> Self {
+        Self {
+            id,
+            block_height,
+            sequence_number: None,
+            number: None,
+            location: None,
+            parents: None,
+            metaprotocol: None,
+            content_hash: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrdInscriptionBatch {
+    /// The position of the last inscription in the batch, the source resumes after it.
+    pub cursor: u64,
+    pub inscriptions: Vec<OrdInscriptionState>,
+}
+
+#[async_trait]
+pub trait OrdInscriptionSource: Send {
+    /// Return the next batch after the cursor, `None` if the source is exhausted.
+    async fn next_batch(&mut self, cursor: Option<u64>) -> Result<Option<OrdInscriptionBatch>>;
+}
+
+#[async_trait]
+pub trait RoochInscriptionReader: Send + Sync {
+    /// Get the inscriptions on Rooch, the result is in the same order as the ids.
+    async fn get_inscriptions(&self, ids: &[InscriptionID]) -> Result<Vec<Option<Inscription>>>;
+}
+
+/// Fold the ord events file into the final inscription states, the cursor is the sequence number.
+pub struct OrdEventFileSource {
+    path: PathBuf,
+    batch_size: usize,
+    states: Option<Vec<OrdInscriptionState>>,
+}
+
+impl OrdEventFileSource {
+    pub fn new(path: PathBuf, batch_size: usize) -> Self {
+        Self {
+            path,
+            batch_size,
+            states: None,
+        }
+    }
+
+    fn load_states(&self) -> Result<Vec<OrdInscriptionState>> {
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut states: HashMap<InscriptionID, OrdInscriptionState> = HashMap::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<Event>(&line)? {
+                Event::InscriptionCreated {
+                    block_height,
+                    inscription_id,
+                    location,
+                    parent_inscription_ids,
+                    sequence_number,
+                    ..
+                } => {
+                    let state = states
+                        .entry(inscription_id)
+                        .or_insert_with(|| OrdInscriptionState::new(inscription_id, block_height));
+                    state.block_height = block_height;
+                    state.sequence_number = Some(sequence_number);
+                    state.location = location;
+                    state.parents = Some(parent_inscription_ids);
+                }
+                Event::InscriptionTransferred {
+                    block_height,
+                    inscription_id,
+                    new_location,
+                    sequence_number,
+                    ..
+                } => {
+                    let state = states
+                        .entry(inscription_id)
+                        .or_insert_with(|| OrdInscriptionState::new(inscription_id, block_height));
+                    state.sequence_number = Some(sequence_number);
+                    state.location = Some(new_location);
+                }
+            }
+        }
+        let mut states = states.into_values().collect::<Vec<_>>();
+        states.sort_by_key(|state| state.sequence_number);
+        Ok(states)
+    }
+}
+
+#[async_trait]
+impl OrdInscriptionSource for OrdEventFileSource {
+    async fn next_batch(&mut self, cursor: Option<u64>) -> Result<Option<OrdInscriptionBatch>> {
+        if self.states.is_none() {
+            self.states = Some(self.load_states()?);
+        }
+        let states = self.states.as_ref().expect("states should be loaded");
+        let start = match cursor {
+            Some(cursor) => states.partition_point(|state| {
+                state.sequence_number.unwrap_or_default() as u64 <= cursor
+            }),
+            None => 0,
+        };
+        let inscriptions = states
+            .iter()
+            .skip(start)
+            .take(self.batch_size)
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(inscriptions.last().map(|last| OrdInscriptionBatch {
+            cursor: last.sequence_number.unwrap_or_default() as u64,
+            inscriptions,
+        }))
+    }
+}
+
+/// Fetch the inscriptions block by block from the ord server, the cursor is the block height.
+pub struct OrdServerSource {
+    client: OrdClient,
+    start_block_height: u64,
+    end_block_height: u64,
+}
+
+impl OrdServerSource {
+    pub fn new(client: OrdClient, start_block_height: u64, end_block_height: u64) -> Self {
+        Self {
+            client,
+            start_block_height,
+            end_block_height,
+        }
+    }
+}
+
+#[async_trait]
+impl OrdInscriptionSource for OrdServerSource {
+    async fn next_batch(&mut self, cursor: Option<u64>) -> Result<Option<OrdInscriptionBatch>> {
+        let height = cursor
+            .map(|cursor| cursor + 1)
+            .unwrap_or(self.start_block_height)
+            .max(self.start_block_height);
+        if height > self.end_block_height {
+            return Ok(None);
+        }
+        let ids = self.client.get_inscriptions_by_block(height).await?;
+        let mut inscriptions = Vec::with_capacity(ids.len());
+        for id in ids {
+            let info = self
+                .client
+                .get_inscription(&id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Inscription {} not found in ord", id))?;
+            let content_hash = self
+                .client
+                .get_inscription_content(&id)
+                .await?
+                .map(|content| content_hash(&content));
+            inscriptions.push(OrdInscriptionState {
+                id,
+                block_height: info.height,
+                sequence_number: None,
+                number: Some(info.number),
+                location: Some(info.satpoint),
+                parents: Some(info.parents),
+                metaprotocol: info.metaprotocol,
+                content_hash,
+            });
+        }
+        Ok(Some(OrdInscriptionBatch {
+            cursor: height,
+            inscriptions,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MismatchKind {
+    Missing,
+    Number,
+    Location,
+    Parents,
+    Metaprotocol,
+    ContentHash,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionMismatch {
+    pub id: InscriptionID,
+    pub block_height: u32,
+    pub kind: MismatchKind,
+    pub ord: Option<String>,
+    pub rooch: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VerifyCheckpoint {
+    pub cursor: Option<u64>,
+    pub verified: u64,
+    pub matched: u64,
+    pub mismatched: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerifyReport {
+    pub checkpoint: VerifyCheckpoint,
+    /// The mismatches found in this run, all the mismatches are in the report file.
+    pub mismatches: Vec<InscriptionMismatch>,
+}
+
+pub struct OrdVerifier<R> {
+    reader: R,
+    output_dir: PathBuf,
+}
+
+impl<R: RoochInscriptionReader> OrdVerifier<R> {
+    pub fn new(reader: R, output_dir: PathBuf) -> Self {
+        Self { reader, output_dir }
+    }
+
+    pub fn report_path(&self) -> PathBuf {
+        self.output_dir.join(REPORT_FILE_NAME)
+    }
+
+    pub fn checkpoint_path(&self) -> PathBuf {
+        self.output_dir.join(CHECKPOINT_FILE_NAME)
+    }
+
+    /// Verify all the inscriptions of the source, resume from the checkpoint if `resume` is true.
+    pub async fn verify<S: OrdInscriptionSource>(
+        &self,
+        source: &mut S,
+        resume: bool,
+    ) -> Result<VerifyReport> {
+        std::fs::create_dir_all(&self.output_dir)?;
+        let mut checkpoint = if resume {
+            load_checkpoint(&self.checkpoint_path())?.unwrap_or_default()
+        } else {
+            VerifyCheckpoint::default()
+        };
+        let mut report_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume)
+            .truncate(!resume)
+            .open(self.report_path())?;
+        if let Some(cursor) = checkpoint.cursor {
+            info!("Resume ord verification after cursor {}", cursor);
+        }
+
+        let mut mismatches = vec![];
+        while let Some(batch) = source.next_batch(checkpoint.cursor).await? {
+            let ids = batch
+                .inscriptions
+                .iter()
+                .map(|state| state.id)
+                .collect::<Vec<_>>();
+            let rooch_inscriptions = self.reader.get_inscriptions(&ids).await?;
+            for (expected, actual) in batch.inscriptions.iter().zip(rooch_inscriptions) {
+                let inscription_mismatches = compare_inscription(expected, actual.as_ref());
+                checkpoint.verified += 1;
+                if inscription_mismatches.is_empty() {
+                    checkpoint.matched += 1;
+                } else {
+                    checkpoint.mismatched += 1;
+                }
+                for mismatch in inscription_mismatches {
+                    writeln!(report_file, "{}", serde_json::to_string(&mismatch)?)?;
+                    mismatches.push(mismatch);
+                }
+            }
+            report_file.flush()?;
+            checkpoint.cursor = Some(batch.cursor);
+            save_checkpoint(&self.checkpoint_path(), &checkpoint)?;
+            info!(
+                "Ord verification progress, cursor: {}, verified: {}, mismatched: {}",
+                batch.cursor, checkpoint.verified, checkpoint.mismatched
+            );
+        }
+        Ok(VerifyReport {
+            checkpoint,
+            mismatches,
+        })
+    }
+}
+
+/// Compare the expected ord inscription state with the inscription on Rooch.
+pub fn compare_inscription(
+    expected: &OrdInscriptionState,
+    actual: Option<&Inscription>,
+) -> Vec<InscriptionMismatch> {
+    let mismatch = |kind, ord: Option<String>, rooch: Option<String>| InscriptionMismatch {
+        id: expected.id,
+        block_height: expected.block_height,
+        kind,
+        ord,
+        rooch,
+    };
+    let actual = match actual {
+        Some(actual) => actual,
+        None => {
+            return vec![mismatch(
+                MismatchKind::Missing,
+                Some(expected.id.to_string()),
+                None,
+            )]
+        }
+    };
+
+    let mut mismatches = vec![];
+    if let Some(number) = expected.number {
+        if number != actual.inscription_number() {
+            mismatches.push(mismatch(
+                MismatchKind::Number,
+                Some(number.to_string()),
+                Some(actual.inscription_number().to_string()),
+            ));
+        }
+    }
+    if let Some(location) = &expected.location {
+        if location != &actual.location {
+            mismatches.push(mismatch(
+                MismatchKind::Location,
+                Some(location.to_string()),
+                Some(actual.location.to_string()),
+            ));
+        }
+    }
+    if let Some(parents) = &expected.parents {
+        if parents.as_slice() != actual.parents() {
+            mismatches.push(mismatch(
+                MismatchKind::Parents,
+                Some(format_ids(parents)),
+                Some(format_ids(actual.parents())),
+            ));
+        }
+    }
+    if let Some(metaprotocol) = &expected.metaprotocol {
+        if Some(metaprotocol.as_str()) != actual.metaprotocol() {
+            mismatches.push(mismatch(
+                MismatchKind::Metaprotocol,
+                Some(metaprotocol.clone()),
+                actual.metaprotocol().map(ToString::to_string),
+            ));
+        }
+    }
+    if let Some(expected_hash) = &expected.content_hash {
+        let actual_hash = content_hash(&actual.body);
+        if !expected_hash.eq_ignore_ascii_case(&actual_hash) {
+            mismatches.push(mismatch(
+                MismatchKind::ContentHash,
+                Some(expected_hash.clone()),
+                Some(actual_hash),
+            ));
+        }
+    }
+    mismatches
+}
+
+pub fn content_hash(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+fn format_ids(ids: &[InscriptionID]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn load_checkpoint(path: &Path) -> Result<Option<VerifyCheckpoint>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&content)?))
+}
+
+fn save_checkpoint(path: &Path, checkpoint: &VerifyCheckpoint) -> Result<()> {
+    // write to a temp file then rename, avoid a broken checkpoint if interrupted
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, serde_json::to_vec_pretty(checkpoint)?)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::account_address::AccountAddress;
+    use rooch_types::bitcoin::types::OutPoint;
+
+    fn inscription_id(index: u32) -> InscriptionID {
+        InscriptionID::new(AccountAddress::random(), index)
+    }
+
+    fn sat_point(offset: u64) -> SatPoint {
+        SatPoint {
+            outpoint: OutPoint::new(AccountAddress::random(), 0),
+            offset,
+        }
+    }
+
+    struct MockReader {
+        inscriptions: HashMap<InscriptionID, Inscription>,
+    }
+
+    #[async_trait]
+    impl RoochInscriptionReader for MockReader {
+        async fn get_inscriptions(
+            &self,
+            ids: &[InscriptionID],
+        ) -> Result<Vec<Option<Inscription>>> {
+            Ok(ids
+                .iter()
+                .map(|id| self.inscriptions.get(id).cloned())
+                .collect())
+        }
+    }
+
+    fn rooch_inscription(
+        id: InscriptionID,
+        location: SatPoint,
+        sequence_number: u32,
+    ) -> Inscription {
+        Inscription {
+            id,
+            location,
+            sequence_number,
+            inscription_number: sequence_number,
+            is_cursed: false,
+            charms: 0,
+            body: b"hello".to_vec(),
+            content_encoding: None.into(),
+            content_type: None.into(),
+            metadata: vec![],
+            metaprotocol: None.into(),
+            parents: vec![],
+            pointer: None.into(),
+            rune: None.into(),
+        }
+    }
+
+    #[test]
+    fn test_compare_inscription() {
+        let id = inscription_id(0);
+        let location = sat_point(0);
+        let parent = inscription_id(1);
+        let mut actual = rooch_inscription(id, location.clone(), 1);
+        actual.parents = vec![parent];
+        let expected = OrdInscriptionState {
+            id,
+            block_height: 1,
+            sequence_number: Some(1),
+            number: Some(1),
+            location: Some(location),
+            parents: Some(vec![parent]),
+            metaprotocol: None,
+            content_hash: Some(content_hash(b"hello")),
+        };
+        assert!(compare_inscription(&expected, Some(&actual)).is_empty());
+
+        let mut expected_mismatch = expected.clone();
+        expected_mismatch.number = Some(-1);
+        expected_mismatch.parents = Some(vec![]);
+        expected_mismatch.metaprotocol = Some("brc-20".to_string());
+        expected_mismatch.content_hash = Some(content_hash(b"world"));
+        let kinds = compare_inscription(&expected_mismatch, Some(&actual))
+            .into_iter()
+            .map(|mismatch| mismatch.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                MismatchKind::Number,
+                MismatchKind::Parents,
+                MismatchKind::Metaprotocol,
+                MismatchKind::ContentHash
+            ]
+        );
+        assert_eq!(
+            compare_inscription(&expected, None)[0].kind,
+            MismatchKind::Missing
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_events_file_with_resume() {
+        let dir = tempfile::tempdir().unwrap();
+        let events_path = dir.path().join("events.jsonl");
+        let ids = (0..3).map(inscription_id).collect::<Vec<_>>();
+        let created_locations = (0..3).map(sat_point).collect::<Vec<_>>();
+        let transferred_location = sat_point(100);
+        let mut events = ids
+            .iter()
+            .zip(created_locations.iter())
+            .enumerate()
+            .map(|(i, (id, location))| Event::InscriptionCreated {
+                block_height: 1,
+                charms: 0,
+                inscription_id: *id,
+                location: Some(location.clone()),
+                parent_inscription_ids: vec![],
+                sequence_number: i as u32,
+            })
+            .collect::<Vec<_>>();
+        events.push(Event::InscriptionTransferred {
+            block_height: 2,
+            inscription_id: ids[1],
+            new_location: transferred_location.clone(),
+            old_location: created_locations[1].clone(),
+            sequence_number: 1,
+        });
+        let content = events
+            .iter()
+            .map(|event| serde_json::to_string(event).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(&events_path, content).unwrap();
+
+        // the second inscription is not transferred on Rooch, the third one is missing
+        let mut inscriptions = HashMap::new();
+        inscriptions.insert(
+            ids[0],
+            rooch_inscription(ids[0], created_locations[0].clone(), 0),
+        );
+        inscriptions.insert(
+            ids[1],
+            rooch_inscription(ids[1], created_locations[1].clone(), 1),
+        );
+        let verifier = OrdVerifier::new(MockReader { inscriptions }, dir.path().join("report"));
+
+        let mut source = OrdEventFileSource::new(events_path.clone(), 2);
+        let report = verifier.verify(&mut source, false).await.unwrap();
+        assert_eq!(report.checkpoint.verified, 3);
+        assert_eq!(report.checkpoint.matched, 1);
+        assert_eq!(report.checkpoint.cursor, Some(2));
+        let kinds = report
+            .mismatches
+            .iter()
+            .map(|mismatch| mismatch.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, vec![MismatchKind::Location, MismatchKind::Missing]);
+        assert_eq!(
+            report.mismatches[0].ord,
+            Some(transferred_location.to_string())
+        );
+
+        // resume from the checkpoint, nothing left to verify
+        let mut source = OrdEventFileSource::new(events_path, 2);
+        let report = verifier.verify(&mut source, true).await.unwrap();
+        assert!(report.mismatches.is_empty());
+        assert_eq!(report.checkpoint.verified, 3);
+        let report_lines = std::fs::read_to_string(verifier.report_path()).unwrap();
+        assert_eq!(report_lines.lines().count(), 2);
+        let checkpoint = load_checkpoint(&verifier.checkpoint_path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(checkpoint.mismatched, 2);
+    }
+}
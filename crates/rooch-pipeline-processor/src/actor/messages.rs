@@ -3,6 +3,7 @@
 
 use anyhow::Result;
 use coerce::actor::message::Message;
+use moveos_types::transaction::VerifiedMoveOSTransaction;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, RoochTransaction},
@@ -11,6 +12,10 @@ use rooch_types::{
 #[derive(Clone)]
 pub struct ExecuteL2TxMessage {
     pub tx: RoochTransaction,
+    /// The validation result of the caller, the transaction is validated again if the state is changed
+    pub verified_tx: Option<VerifiedMoveOSTransaction>,
+    /// The span of the caller, the parent of the pipeline span
+    pub span: tracing::Span,
 }
 
 impl Message for ExecuteL2TxMessage {
@@ -20,6 +25,8 @@ impl Message for ExecuteL2TxMessage {
 #[derive(Clone)]
 pub struct ExecuteL1BlockMessage {
     pub tx: L1BlockWithBody,
+    /// The span of the caller, the parent of the pipeline span
+    pub span: tracing::Span,
 }
 
 impl Message for ExecuteL1BlockMessage {
@@ -29,6 +36,8 @@ impl Message for ExecuteL1BlockMessage {
 #[derive(Clone)]
 pub struct ExecuteL1TxMessage {
     pub tx: L1Transaction,
+    /// The span of the caller, the parent of the pipeline span
+    pub span: tracing::Span,
 }
 
 impl Message for ExecuteL1TxMessage {
@@ -41,3 +50,14 @@ pub struct GetServiceStatusMessage {}
 impl Message for GetServiceStatusMessage {
     type Result = Result<ServiceStatus>;
 }
+
+/// Switch the service status at runtime, the status is broadcast to the other actors via the notify actor.
+/// Returns the previous status.
+#[derive(Clone)]
+pub struct SetServiceStatusMessage {
+    pub status: ServiceStatus,
+}
+
+impl Message for SetServiceStatusMessage {
+    type Result = Result<ServiceStatus>;
+}
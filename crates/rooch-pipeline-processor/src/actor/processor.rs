@@ -3,6 +3,7 @@
 
 use super::messages::{
     ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+    SetServiceStatusMessage,
 };
 use crate::metrics::PipelineProcessorMetrics;
 use anyhow::{anyhow, Error, Result};
@@ -27,6 +28,7 @@ use rooch_notify::actor::NotifyActor;
 use rooch_notify::messages::{ProcessTxWithEventsMessage, UpdateServiceStatusMessage};
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::bitcoin::types::Block as BitcoinBlock;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
 use rooch_types::transaction::TransactionWithInfo;
 use rooch_types::{
     service_status::ServiceStatus,
@@ -37,7 +39,19 @@ use rooch_types::{
 };
 use std::io;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, Instrument};
+
+// The span of a transaction in the pipeline, `tx_hash` and `tx_order` are recorded once known
+macro_rules! tx_span {
+    ($parent:expr, $name:expr) => {
+        tracing::info_span!(
+            parent: $parent,
+            $name,
+            tx_hash = tracing::field::Empty,
+            tx_order = tracing::field::Empty
+        )
+    };
+}
 
 /// PipelineProcessor aggregates the executor, sequencer, proposer, and indexer to process transactions.
 pub struct PipelineProcessorActor {
@@ -151,7 +165,7 @@ impl PipelineProcessorActor {
                 LedgerTxData::L2Tx(l2_tx) => {
                     debug!("process_sequenced_tx_on_startup l2_tx: {:?}", l2_tx);
 
-                    match self.execute_l2_tx(l2_tx.clone(), Some(tx_hash)).await {
+                    match self.execute_l2_tx(l2_tx.clone(), Some(tx_hash), None).await {
                         Ok(_v) => {}
                         Err(err) => {
                             tracing::error!(
@@ -169,6 +183,10 @@ impl PipelineProcessorActor {
 
     // sequence tx and public tx to DA
     async fn sequence_and_public_tx(&mut self, tx_data: LedgerTxData) -> Result<LedgerTransaction> {
+        let is_proposer_tx = matches!(
+            &tx_data,
+            LedgerTxData::L2Tx(tx) if StateCommitmentChainModule::is_submit_block_action(tx.action())
+        );
         let ledger_tx_ret = self.sequencer.sequence_transaction(tx_data).await;
         let mut ledger_tx = match ledger_tx_ret {
             Ok(v) => v,
@@ -191,6 +209,8 @@ impl PipelineProcessorActor {
             .append_tx(AppendTransactionMessage {
                 tx_order: ledger_tx.sequence_info.tx_order,
                 tx_timestamp: ledger_tx.sequence_info.tx_timestamp,
+                is_proposer_tx,
+                span: tracing::Span::current(),
             })
             .await;
         match public_ret {
@@ -290,6 +310,7 @@ impl PipelineProcessorActor {
 
         let tx_order = ledger_tx.sequence_info.tx_order;
         let tx_hash = ledger_tx.tx_hash();
+        record_tx_span(tx_hash, tx_order);
         let size = moveos_tx.ctx.tx_size;
 
         let result = match self.execute_tx(ledger_tx, moveos_tx).await {
@@ -346,6 +367,7 @@ impl PipelineProcessorActor {
         let size = moveos_tx.ctx.tx_size;
         let tx_order = ledger_tx.sequence_info.tx_order;
         let tx_hash = ledger_tx.tx_hash();
+        record_tx_span(tx_hash, tx_order);
         let result = match self.execute_tx(ledger_tx, moveos_tx).await {
             Ok(v) => v,
             Err(err) => {
@@ -371,6 +393,7 @@ impl PipelineProcessorActor {
         &mut self,
         mut tx: RoochTransaction,
         sequenced_tx_hash: Option<H256>,
+        verified_tx: Option<VerifiedMoveOSTransaction>,
     ) -> Result<ExecuteTransactionResponse> {
         let fn_name = function_name!();
         let _timer = self
@@ -380,7 +403,15 @@ impl PipelineProcessorActor {
             .start_timer();
 
         let tx_hash = tx.tx_hash(); // cache tx_hash
-        let moveos_tx = self.executor.validate_l2_tx(tx.clone()).await?;
+        let moveos_tx = match verified_tx {
+            // The transaction is validated by the mempool, reuse the result if the state is not changed since then
+            Some(moveos_tx)
+                if moveos_tx.ctx.tx_hash() == tx_hash && self.is_latest_root(&moveos_tx)? =>
+            {
+                moveos_tx
+            }
+            _ => self.executor.validate_l2_tx(tx.clone()).await?,
+        };
         let ledger_tx = if let Some(tx_hash) = sequenced_tx_hash {
             self.rooch_db
                 .rooch_store
@@ -393,6 +424,7 @@ impl PipelineProcessorActor {
         };
 
         let tx_order = ledger_tx.sequence_info.tx_order;
+        record_tx_span(tx_hash, tx_order);
         let size = moveos_tx.ctx.tx_size;
 
         let result = match self.execute_tx(ledger_tx, moveos_tx).await {
@@ -423,6 +455,15 @@ impl PipelineProcessorActor {
         Ok(result)
     }
 
+    /// Whether the transaction is validated against the latest state root
+    fn is_latest_root(&self, moveos_tx: &VerifiedMoveOSTransaction) -> Result<bool> {
+        Ok(self
+            .rooch_db
+            .latest_root()?
+            .map(|root| root.state_root() == moveos_tx.root.state_root())
+            .unwrap_or(false))
+    }
+
     #[named]
     pub async fn execute_tx(
         &mut self,
@@ -438,6 +479,10 @@ impl PipelineProcessorActor {
 
         // Add sequence info to tx context, let the Move contract can get the sequence info
         moveos_tx.ctx.add(tx.sequence_info.clone())?;
+        // Add the randomness committed by the sequencer, let the `randomness` module can verify it
+        if let Some(tx_randomness) = &tx.sequence_info.tx_randomness {
+            moveos_tx.ctx.add(tx_randomness.clone())?;
+        }
 
         // Then execute
         let size = moveos_tx.ctx.tx_size;
@@ -515,13 +560,25 @@ impl PipelineProcessorActor {
     ) -> Result<()> {
         if let Some(notify_actor) = self.notify_actor.clone() {
             return notify_actor
-                .notify(ProcessTxWithEventsMessage { tx, events, ctx })
+                .notify(ProcessTxWithEventsMessage {
+                    tx,
+                    events,
+                    ctx,
+                    span: tracing::Span::current(),
+                })
                 .map_err(|e| anyhow!(format!("Process subscription notify error: {:?}", e)));
         }
         Ok(())
     }
 }
 
+// Record the attributes of the transaction to the current pipeline span
+fn record_tx_span(tx_hash: H256, tx_order: u64) {
+    let span = tracing::Span::current();
+    span.record("tx_hash", tracing::field::debug(tx_hash));
+    span.record("tx_order", tx_order);
+}
+
 #[async_trait]
 impl Actor for PipelineProcessorActor {}
 
@@ -532,7 +589,10 @@ impl Handler<ExecuteL2TxMessage> for PipelineProcessorActor {
         msg: ExecuteL2TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l2_tx(msg.tx, None).await
+        let span = tx_span!(&msg.span, "pipeline.execute_l2_tx");
+        self.execute_l2_tx(msg.tx, None, msg.verified_tx)
+            .instrument(span)
+            .await
     }
 }
 
@@ -543,7 +603,8 @@ impl Handler<ExecuteL1BlockMessage> for PipelineProcessorActor {
         msg: ExecuteL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l1_block(msg.tx, None).await
+        let span = tx_span!(&msg.span, "pipeline.execute_l1_block");
+        self.execute_l1_block(msg.tx, None).instrument(span).await
     }
 }
 
@@ -554,7 +615,8 @@ impl Handler<ExecuteL1TxMessage> for PipelineProcessorActor {
         msg: ExecuteL1TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l1_tx(msg.tx, None).await
+        let span = tx_span!(&msg.span, "pipeline.execute_l1_tx");
+        self.execute_l1_tx(msg.tx, None).instrument(span).await
     }
 }
 
@@ -569,6 +631,23 @@ impl Handler<GetServiceStatusMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<SetServiceStatusMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: SetServiceStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ServiceStatus> {
+        let previous = self.service_status;
+        info!(
+            "PipelineProcessor set service status from {:?} to {:?}",
+            previous, msg.status
+        );
+        self.update_service_status(msg.status).await;
+        Ok(previous)
+    }
+}
+
 pub fn is_vm_panic_error(error: &Error) -> bool {
     if let Some(vm_error) = error.downcast_ref::<VMPanicError>() {
         match vm_error {
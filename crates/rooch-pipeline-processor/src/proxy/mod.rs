@@ -4,11 +4,13 @@
 use crate::actor::{
     messages::{
         ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+        SetServiceStatusMessage,
     },
     processor::PipelineProcessorActor,
 };
 use anyhow::Result;
 use coerce::actor::ActorRef;
+use moveos_types::transaction::VerifiedMoveOSTransaction;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
@@ -26,24 +28,49 @@ impl PipelineProcessorProxy {
         Self { actor }
     }
 
-    pub async fn execute_l2_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL2TxMessage { tx }).await?
+    /// Execute the L2 transaction, pass the validation result if the transaction is already validated.
+    pub async fn execute_l2_tx(
+        &self,
+        tx: RoochTransaction,
+        verified_tx: Option<VerifiedMoveOSTransaction>,
+    ) -> Result<ExecuteTransactionResponse> {
+        self.actor
+            .send(ExecuteL2TxMessage {
+                tx,
+                verified_tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn execute_l1_block(
         &self,
         tx: L1BlockWithBody,
     ) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL1BlockMessage { tx }).await?
+        self.actor
+            .send(ExecuteL1BlockMessage {
+                tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn execute_l1_tx(&self, tx: L1Transaction) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL1TxMessage { tx }).await?
+        self.actor
+            .send(ExecuteL1TxMessage {
+                tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn get_service_status(&self) -> Result<ServiceStatus> {
         self.actor.send(GetServiceStatusMessage {}).await?
     }
+
+    pub async fn set_service_status(&self, status: ServiceStatus) -> Result<ServiceStatus> {
+        self.actor.send(SetServiceStatusMessage { status }).await?
+    }
 }
 
 impl From<ActorRef<PipelineProcessorActor>> for PipelineProcessorProxy {
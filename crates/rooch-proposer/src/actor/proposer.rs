@@ -4,21 +4,45 @@
 use super::messages::ProposeBlock;
 use crate::metrics::ProposerMetrics;
 use crate::scc::StateCommitmentChain;
+use anyhow::{anyhow, bail, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use move_core_types::vm_status::KeptVMStatus;
 use moveos_store::MoveOSStore;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use prometheus::Registry;
 use rooch_config::proposer_config::ProposerConfig;
+use rooch_config::settings::PROPOSER_SUBMIT_RETRIES;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_mempool::proxy::MempoolProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_store::proposer_store::ProposerStore;
 use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
+use rooch_types::block::Block;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::transaction::RoochTransactionData;
 use std::sync::Arc;
+use std::time::Duration;
 
 const PROPOSE_BLOCK_FN_NAME: &str = "propose_block";
 
+/// The proposer submits every block to the on-chain SCC with a transaction signed by the proposer key,
+/// the submission pays the gas like the other L2 transactions, so the proposer account must be funded
+/// on the non-dev chains, otherwise the submissions fail and the blocks stay pending.
+/// The submissions do not close a DA block, so an idle chain stops producing blocks.
 pub struct ProposerActor {
     proposer_key: RoochKeyPair,
+    proposer_address: RoochAddress,
     scc: StateCommitmentChain,
+    executor: ExecutorProxy,
+    processor: PipelineProcessorProxy,
+    mempool: MempoolProxy,
+    submit_retries: u32,
+    /// The block is proposed but not committed to the on-chain SCC yet, it will be resubmitted at the next tick.
+    pending_block: Option<Block>,
     metrics: Arc<ProposerMetrics>,
 }
 
@@ -27,6 +51,9 @@ impl ProposerActor {
         proposer_key: RoochKeyPair,
         moveos_store: MoveOSStore,
         rooch_store: RoochStore,
+        executor: ExecutorProxy,
+        processor: PipelineProcessorProxy,
+        mempool: MempoolProxy,
         registry: &Registry,
         config: ProposerConfig,
     ) -> anyhow::Result<Self> {
@@ -44,13 +71,133 @@ impl ProposerActor {
         };
 
         let scc = StateCommitmentChain::new(rooch_store, moveos_store)?;
+        let proposer_address = proposer_key.public().rooch_address()?;
 
         Ok(Self {
             proposer_key,
+            proposer_address,
             scc,
+            executor,
+            processor,
+            mempool,
+            submit_retries: config.submit_retries.unwrap_or(PROPOSER_SUBMIT_RETRIES),
+            pending_block: None,
             metrics: Arc::new(ProposerMetrics::new(registry)),
         })
     }
+
+    async fn propose_and_commit_block(&mut self) -> Result<()> {
+        // Only the active node submits blocks to the on-chain SCC
+        let service_status = self.processor.get_service_status().await?;
+        if !service_status.is_active() {
+            tracing::debug!(
+                "[ProposeBlock] service status is {:?}, skip propose block",
+                service_status
+            );
+            return Ok(());
+        }
+
+        let block = match self.pending_block.take() {
+            Some(block) => block,
+            None => match self.scc.propose_block().await? {
+                Some(block) => block,
+                None => {
+                    tracing::debug!("[ProposeBlock] no transaction to propose block");
+                    return Ok(());
+                }
+            },
+        };
+
+        if let Err(e) = self.commit_block(&block).await {
+            self.pending_block = Some(block);
+            return Err(e);
+        }
+        tracing::info!("[ProposeBlock] done. block_number: {}", block.block_number);
+        self.metrics
+            .proposer_committed_block_number
+            .set(block.block_number as i64);
+        self.metrics
+            .proposer_propose_block_batch_size
+            .set(block.batch_size as i64);
+        Ok(())
+    }
+
+    /// Submit the block to the on-chain SCC with retries, then record it as the last proposed block.
+    async fn commit_block(&self, block: &Block) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            match self.submit_block(block).await {
+                Ok(()) => break,
+                Err(e) => {
+                    self.metrics.proposer_submit_block_failures.inc();
+                    if attempt >= self.submit_retries {
+                        return Err(e);
+                    }
+                    attempt += 1;
+                    tracing::warn!(
+                        "[ProposeBlock] submit block {} failed, retry: {}, error: {:?}",
+                        block.block_number,
+                        attempt,
+                        e
+                    );
+                    tokio::time::sleep(Duration::from_secs(attempt as u64)).await;
+                }
+            }
+        }
+        self.scc.set_last_proposed(block.block_number)
+    }
+
+    async fn submit_block(&self, block: &Block) -> Result<()> {
+        // The block may be committed before the proposer restarts, do not submit it again
+        let scc_module = self
+            .executor
+            .as_module_binding::<StateCommitmentChainModule>();
+        match scc_module.get_block(block.block_number)? {
+            Some(committed_block) if &committed_block == block => {
+                tracing::info!(
+                    "[ProposeBlock] block {} is already committed",
+                    block.block_number
+                );
+                return Ok(());
+            }
+            Some(committed_block) => bail!(
+                "block {} conflicts with the committed block: {:?}",
+                block.block_number,
+                committed_block
+            ),
+            None => {}
+        }
+
+        // Always use the on-chain sequence number, the failed submission does not consume it
+        let sequence_number = self
+            .executor
+            .get_sequence_number(self.proposer_address.into())
+            .await?;
+        let chain_id = self.executor.chain_id().await?.id;
+        let tx_data = RoochTransactionData::new(
+            self.proposer_address,
+            sequence_number,
+            chain_id,
+            GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+            StateCommitmentChainModule::create_submit_block_action(block),
+        );
+        let tx = tx_data.sign(&self.proposer_key);
+        // Submit via the mempool like the other L2 transactions
+        let response = self
+            .mempool
+            .submit_tx(tx)
+            .await?
+            .await
+            .map_err(|_| anyhow!("The submit block transaction is dropped by the mempool"))??;
+        match response.execution_info.status {
+            KeptVMStatus::Executed => Ok(()),
+            status => bail!(
+                "execute submit block {} transaction failed, status: {:?}",
+                block.block_number,
+                status
+            ),
+        }
+    }
 }
 
 impl Actor for ProposerActor {}
@@ -64,39 +211,8 @@ impl Handler<ProposeBlock> for ProposerActor {
             .proposer_propose_block_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let block = self.scc.propose_block().await;
-        match block {
-            Ok(block) => {
-                match block {
-                    Some(block) => {
-                        // TODO submit to the on-chain SCC contract use the proposer key
-                        let _proposer_key = &self.proposer_key;
-                        let ret = self.scc.set_last_proposed(block.block_number);
-                        match ret {
-                            Ok(_) => {
-                                tracing::info!(
-                                    "[ProposeBlock] done. block_number: {}",
-                                    block.block_number,
-                                );
-                            }
-                            Err(e) => {
-                                tracing::error!("[ProposeBlock] set last proposed error: {:?}", e);
-                            }
-                        }
-
-                        // TODO make new metric for matching real data submit to the chain
-                        self.metrics
-                            .proposer_propose_block_batch_size
-                            .set(block.batch_size as i64);
-                    }
-                    None => {
-                        tracing::debug!("[ProposeBlock] no transaction to propose block");
-                    }
-                };
-            }
-            Err(e) => {
-                tracing::error!("[ProposeBlock] error: {:?}", e);
-            }
+        if let Err(e) = self.propose_and_commit_block().await {
+            tracing::error!("[ProposeBlock] error: {:?}", e);
         }
     }
 }
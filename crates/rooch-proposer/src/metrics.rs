@@ -3,14 +3,16 @@
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
 use prometheus::{
-    register_histogram_vec_with_registry, register_int_gauge_with_registry, HistogramVec, IntGauge,
-    Registry,
+    register_histogram_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, HistogramVec, IntCounter, IntGauge, Registry,
 };
 
 #[derive(Debug)]
 pub struct ProposerMetrics {
     pub proposer_propose_block_latency_seconds: HistogramVec,
     pub proposer_propose_block_batch_size: IntGauge,
+    pub proposer_committed_block_number: IntGauge,
+    pub proposer_submit_block_failures: IntCounter,
 }
 
 impl ProposerMetrics {
@@ -30,6 +32,18 @@ impl ProposerMetrics {
                 registry,
             )
             .unwrap(),
+            proposer_committed_block_number: register_int_gauge_with_registry!(
+                "proposer_committed_block_number",
+                "The last block number committed to the on-chain SCC by the proposer",
+                registry,
+            )
+            .unwrap(),
+            proposer_submit_block_failures: register_int_counter_with_registry!(
+                "proposer_submit_block_failures",
+                "How many times the proposer failed to submit a block to the on-chain SCC",
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
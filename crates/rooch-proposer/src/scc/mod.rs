@@ -98,6 +98,7 @@ impl StateCommitmentChain {
                 Ok(Block::new(
                     block_number,
                     batch_size,
+                    block_range.tx_order_end,
                     block_da_submit_state.batch_hash,
                     prev_tx_accumulator_root,
                     tx_accumulator_root,
@@ -160,6 +161,7 @@ impl StateCommitmentChain {
         let block = Block::new(
             block_number,
             batch_size,
+            tx_order_end,
             block_da_submit_state.batch_hash,
             prev_tx_accumulator_root,
             tx_accumulator_root,
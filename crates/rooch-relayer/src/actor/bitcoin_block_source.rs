@@ -0,0 +1,552 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use bitcoin::block::Header;
+use bitcoin::hashes::Hash;
+use bitcoin::pow::Work;
+use bitcoin::{Block, BlockHash, VarInt};
+use bitcoin_client::proxy::BitcoinClientProxy;
+use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
+use rooch_types::bitcoin::types::BlockHeightHash;
+use rooch_types::into_address::FromAddress;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// The source of the Bitcoin blocks for the `BitcoinRelayer`.
+#[async_trait]
+pub trait BitcoinBlockSource: Send + Sync {
+    async fn get_best_block_hash(&self) -> Result<BlockHash>;
+
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult>;
+
+    async fn get_block(&self, hash: BlockHash) -> Result<Block>;
+
+    /// Get the previous block hash of the given block, return None if the previous block is unknown.
+    async fn get_previous_block_hash(&self, hash: BlockHash) -> Result<Option<BlockHash>> {
+        let header_info = self.get_block_header_info(hash).await?;
+        Ok(header_info.previous_block_hash)
+    }
+}
+
+#[async_trait]
+impl BitcoinBlockSource for BitcoinClientProxy {
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        BitcoinClientProxy::get_best_block_hash(self).await
+    }
+
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        BitcoinClientProxy::get_block_header_info(self, hash).await
+    }
+
+    async fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        BitcoinClientProxy::get_block(self, hash).await
+    }
+}
+
+const BLK_FILE_PREFIX: &str = "blk";
+const BLK_FILE_EXTENSION: &str = "dat";
+const XOR_KEY_FILE_NAME: &str = "xor.dat";
+const XOR_KEY_LEN: usize = 8;
+const BLOCK_HEADER_SIZE: usize = 80;
+// the block header and the max size of the tx count varint
+const BLOCK_PREFIX_SIZE: usize = BLOCK_HEADER_SIZE + 9;
+
+#[derive(Debug, Clone)]
+enum BlockLocation {
+    /// A block record in Bitcoin Core `blk*.dat` file, the offset points to the serialized block.
+    BlkFile {
+        path: PathBuf,
+        offset: u64,
+        size: u32,
+    },
+    /// A file only contains one serialized block, in binary or hex format.
+    RawFile { path: PathBuf },
+}
+
+#[derive(Debug, Clone)]
+struct IndexedBlock {
+    header: Header,
+    n_tx: usize,
+    location: BlockLocation,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ChainPosition {
+    height: u64,
+    chainwork: Work,
+}
+
+/// A block source which reads blocks from the local files, supports two layouts:
+/// 1. Bitcoin Core `blocks` directory, which contains `blk*.dat` files(and optional `xor.dat`).
+/// 2. A directory of raw serialized blocks, one block per file, in binary or hex format.
+///
+/// The blocks are indexed on load, the heights are anchored at the genesis block of Rooch,
+/// and the main chain is the chain with the most work.
+pub struct LocalBlockFileSource {
+    blocks: HashMap<BlockHash, IndexedBlock>,
+    positions: HashMap<BlockHash, ChainPosition>,
+    main_chain: Vec<BlockHash>,
+    main_chain_start_height: u64,
+    xor_key: Option<[u8; XOR_KEY_LEN]>,
+}
+
+impl LocalBlockFileSource {
+    pub fn load(block_dir: &Path, genesis_block: BlockHeightHash) -> Result<Self> {
+        if !block_dir.is_dir() {
+            bail!(
+                "Bitcoin block source dir {:?} is not a directory",
+                block_dir
+            );
+        }
+        let xor_key = Self::load_xor_key(block_dir)?;
+        let mut file_paths = std::fs::read_dir(block_dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        file_paths.retain(|path| path.is_file());
+        file_paths.sort();
+
+        let blk_files = file_paths
+            .iter()
+            .filter(|path| is_blk_file(path))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut blocks = HashMap::new();
+        if !blk_files.is_empty() {
+            for path in blk_files {
+                Self::index_blk_file(&path, xor_key, &mut blocks)?;
+            }
+        } else {
+            for path in file_paths {
+                if is_hidden_file(&path) || path.file_name() == Some(XOR_KEY_FILE_NAME.as_ref()) {
+                    continue;
+                }
+                Self::index_raw_file(&path, &mut blocks)?;
+            }
+        }
+
+        let genesis_hash = BlockHash::from_address(genesis_block.block_hash);
+        let mut source = Self {
+            blocks,
+            positions: HashMap::new(),
+            main_chain: vec![],
+            main_chain_start_height: 0,
+            xor_key,
+        };
+        source.build_chain(genesis_hash, genesis_block.block_height)?;
+        info!(
+            "LocalBlockFileSource loaded {} blocks from {:?}, main chain height range: [{}, {}]",
+            source.blocks.len(),
+            block_dir,
+            source.main_chain_start_height,
+            source.best_block_height()
+        );
+        Ok(source)
+    }
+
+    pub fn best_block_height(&self) -> u64 {
+        self.main_chain_start_height + self.main_chain.len() as u64 - 1
+    }
+
+    fn load_xor_key(block_dir: &Path) -> Result<Option<[u8; XOR_KEY_LEN]>> {
+        let xor_key_path = block_dir.join(XOR_KEY_FILE_NAME);
+        if !xor_key_path.exists() {
+            return Ok(None);
+        }
+        let key = std::fs::read(&xor_key_path)?;
+        let key: [u8; XOR_KEY_LEN] = key
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid xor key file {:?}", xor_key_path))?;
+        // All zero key means the block files are not obfuscated
+        Ok(key.iter().any(|b| *b != 0).then_some(key))
+    }
+
+    fn index_blk_file(
+        path: &Path,
+        xor_key: Option<[u8; XOR_KEY_LEN]>,
+        blocks: &mut HashMap<BlockHash, IndexedBlock>,
+    ) -> Result<()> {
+        let mut file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let mut offset = 0u64;
+        let mut record_prefix = [0u8; 8];
+        while offset + record_prefix.len() as u64 <= file_len {
+            read_at(&mut file, offset, &mut record_prefix, xor_key)?;
+            // Bitcoin Core preallocates the block files with zero bytes
+            if record_prefix[..4] == [0u8; 4] {
+                break;
+            }
+            let size = u32::from_le_bytes(record_prefix[4..].try_into().expect("4 bytes"));
+            let block_offset = offset + record_prefix.len() as u64;
+            if block_offset + size as u64 > file_len {
+                warn!(
+                    "Truncated block record in {:?} at offset {}, ignore the rest of the file",
+                    path, offset
+                );
+                break;
+            }
+            let mut prefix = vec![0u8; BLOCK_PREFIX_SIZE.min(size as usize)];
+            read_at(&mut file, block_offset, &mut prefix, xor_key)?;
+            let (header, n_tx) = decode_block_prefix(&prefix)?;
+            blocks.insert(
+                header.block_hash(),
+                IndexedBlock {
+                    header,
+                    n_tx,
+                    location: BlockLocation::BlkFile {
+                        path: path.to_path_buf(),
+                        offset: block_offset,
+                        size,
+                    },
+                },
+            );
+            offset = block_offset + size as u64;
+        }
+        Ok(())
+    }
+
+    fn index_raw_file(path: &Path, blocks: &mut HashMap<BlockHash, IndexedBlock>) -> Result<()> {
+        let bytes = read_raw_block_file(path)?;
+        let (header, n_tx) = match decode_block_prefix(&bytes) {
+            Ok(prefix) => prefix,
+            Err(e) => {
+                warn!("Ignore invalid raw block file {:?}: {:?}", path, e);
+                return Ok(());
+            }
+        };
+        blocks.insert(
+            header.block_hash(),
+            IndexedBlock {
+                header,
+                n_tx,
+                location: BlockLocation::RawFile {
+                    path: path.to_path_buf(),
+                },
+            },
+        );
+        Ok(())
+    }
+
+    /// Assign heights to the blocks connected to the genesis block and select the main chain.
+    fn build_chain(&mut self, genesis_hash: BlockHash, genesis_height: u64) -> Result<()> {
+        if !self.blocks.contains_key(&genesis_hash) {
+            bail!(
+                "The genesis block {:?} at height {} is not found in the block source",
+                genesis_hash,
+                genesis_height
+            );
+        }
+        // Walk back to the earliest known ancestor of the genesis block
+        let mut root_hash = genesis_hash;
+        let mut root_height = genesis_height;
+        while let Some(prev_block) = self
+            .blocks
+            .get(&self.blocks[&root_hash].header.prev_blockhash)
+        {
+            if root_height == 0 {
+                break;
+            }
+            root_hash = prev_block.header.block_hash();
+            root_height -= 1;
+        }
+
+        let mut children: HashMap<BlockHash, Vec<BlockHash>> = HashMap::new();
+        for (hash, block) in self.blocks.iter() {
+            children
+                .entry(block.header.prev_blockhash)
+                .or_default()
+                .push(*hash);
+        }
+
+        let mut best = (root_hash, self.blocks[&root_hash].header.work());
+        let mut queue = VecDeque::new();
+        self.positions.insert(
+            root_hash,
+            ChainPosition {
+                height: root_height,
+                chainwork: best.1,
+            },
+        );
+        queue.push_back(root_hash);
+        while let Some(hash) = queue.pop_front() {
+            let position = self.positions[&hash];
+            if position.chainwork > best.1 {
+                best = (hash, position.chainwork);
+            }
+            for child in children.get(&hash).into_iter().flatten() {
+                let child_position = ChainPosition {
+                    height: position.height + 1,
+                    chainwork: position.chainwork + self.blocks[child].header.work(),
+                };
+                self.positions.insert(*child, child_position);
+                queue.push_back(*child);
+            }
+        }
+
+        let mut main_chain = vec![];
+        let mut hash = best.0;
+        loop {
+            main_chain.push(hash);
+            if hash == root_hash {
+                break;
+            }
+            hash = self.blocks[&hash].header.prev_blockhash;
+        }
+        main_chain.reverse();
+        self.main_chain = main_chain;
+        self.main_chain_start_height = root_height;
+        Ok(())
+    }
+
+    fn main_chain_hash(&self, height: u64) -> Option<BlockHash> {
+        height
+            .checked_sub(self.main_chain_start_height)
+            .and_then(|index| self.main_chain.get(index as usize))
+            .copied()
+    }
+
+    fn indexed_block(&self, hash: BlockHash) -> Result<(&IndexedBlock, ChainPosition)> {
+        match (self.blocks.get(&hash), self.positions.get(&hash)) {
+            (Some(block), Some(position)) => Ok((block, *position)),
+            (Some(_), None) => bail!(
+                "The block {:?} is not connected to the genesis block in the block source",
+                hash
+            ),
+            _ => bail!("The block {:?} is not found in the block source", hash),
+        }
+    }
+
+    fn read_block(&self, block: &IndexedBlock) -> Result<Block> {
+        let bytes = match &block.location {
+            BlockLocation::BlkFile { path, offset, size } => {
+                let mut file = File::open(path)?;
+                let mut bytes = vec![0u8; *size as usize];
+                read_at(&mut file, *offset, &mut bytes, self.xor_key)?;
+                bytes
+            }
+            BlockLocation::RawFile { path } => read_raw_block_file(path)?,
+        };
+        Ok(bitcoin::consensus::deserialize(&bytes)?)
+    }
+}
+
+#[async_trait]
+impl BitcoinBlockSource for LocalBlockFileSource {
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        self.main_chain
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("The block source is empty"))
+    }
+
+    async fn get_block_header_info(&self, hash: BlockHash) -> Result<GetBlockHeaderResult> {
+        let (block, position) = self.indexed_block(hash)?;
+        let header = block.header;
+        let in_main_chain = self.main_chain_hash(position.height) == Some(hash);
+        let (confirmations, next_block_hash) = if in_main_chain {
+            (
+                (self.best_block_height() - position.height + 1) as i32,
+                self.main_chain_hash(position.height + 1),
+            )
+        } else {
+            (-1, None)
+        };
+        let previous_block_hash = if header.prev_blockhash == BlockHash::all_zeros() {
+            None
+        } else {
+            Some(header.prev_blockhash)
+        };
+        Ok(GetBlockHeaderResult {
+            hash,
+            confirmations,
+            height: position.height as usize,
+            version: header.version,
+            version_hex: Some(header.version.to_consensus().to_be_bytes().to_vec()),
+            merkle_root: header.merkle_root,
+            time: header.time as usize,
+            median_time: None,
+            nonce: header.nonce,
+            bits: format!("{:08x}", header.bits.to_consensus()),
+            difficulty: header.target().difficulty_float(),
+            // the chainwork is accumulated from the earliest block in the block source
+            chainwork: position.chainwork.to_be_bytes().to_vec(),
+            n_tx: block.n_tx,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+
+    async fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        let block = self.blocks.get(&hash).ok_or_else(|| {
+            anyhow::anyhow!("The block {:?} is not found in the block source", hash)
+        })?;
+        self.read_block(block)
+    }
+
+    async fn get_previous_block_hash(&self, hash: BlockHash) -> Result<Option<BlockHash>> {
+        Ok(self
+            .blocks
+            .get(&hash)
+            .map(|block| block.header.prev_blockhash)
+            .filter(|prev_hash| self.positions.contains_key(prev_hash)))
+    }
+}
+
+fn is_blk_file(path: &Path) -> bool {
+    let file_name = path.file_name().and_then(|name| name.to_str());
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    matches!(
+        (file_name, extension),
+        (Some(name), Some(BLK_FILE_EXTENSION)) if name.starts_with(BLK_FILE_PREFIX)
+    )
+}
+
+fn is_hidden_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('.'))
+        .unwrap_or(false)
+}
+
+fn read_at(
+    file: &mut File,
+    offset: u64,
+    buf: &mut [u8],
+    xor_key: Option<[u8; XOR_KEY_LEN]>,
+) -> Result<()> {
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(buf)?;
+    if let Some(key) = xor_key {
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b ^= key[((offset + i as u64) % XOR_KEY_LEN as u64) as usize];
+        }
+    }
+    Ok(())
+}
+
+/// Read a raw block file, the file content is the serialized block in binary or hex format.
+fn read_raw_block_file(path: &Path) -> Result<Vec<u8>> {
+    let bytes = std::fs::read(path)?;
+    let trimmed = bytes.trim_ascii();
+    if !trimmed.is_empty() && trimmed.iter().all(|b| b.is_ascii_hexdigit()) {
+        Ok(hex::decode(trimmed)?)
+    } else {
+        Ok(bytes)
+    }
+}
+
+fn decode_block_prefix(bytes: &[u8]) -> Result<(Header, usize)> {
+    let (header, header_len) = bitcoin::consensus::deserialize_partial::<Header>(bytes)?;
+    let (n_tx, _) = bitcoin::consensus::deserialize_partial::<VarInt>(&bytes[header_len..])?;
+    Ok((header, n_tx.0 as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::blockdata::constants::genesis_block;
+    use bitcoin::Network;
+    use rooch_types::into_address::IntoAddress;
+
+    fn regtest_blocks(count: usize) -> Vec<Block> {
+        let mut blocks = vec![genesis_block(Network::Regtest)];
+        for i in 1..count {
+            let mut block = blocks[i - 1].clone();
+            block.header.prev_blockhash = blocks[i - 1].block_hash();
+            block.header.time += 600;
+            blocks.push(block);
+        }
+        blocks
+    }
+
+    fn genesis_of(block: &Block) -> BlockHeightHash {
+        BlockHeightHash {
+            block_height: 0,
+            block_hash: block.block_hash().into_address(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blk_files_with_xor_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let blocks = regtest_blocks(5);
+        let xor_key = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let mut content = vec![];
+        // write the blocks out of order, as Bitcoin Core does
+        for block in blocks.iter().rev() {
+            let bytes = bitcoin::consensus::serialize(block);
+            content.extend_from_slice(&Network::Regtest.magic().to_bytes());
+            content.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            content.extend_from_slice(&bytes);
+        }
+        // preallocated zero bytes
+        content.extend_from_slice(&[0u8; 16]);
+        for (i, b) in content.iter_mut().enumerate() {
+            *b ^= xor_key[i % XOR_KEY_LEN];
+        }
+        std::fs::write(dir.path().join("blk00000.dat"), content).unwrap();
+        std::fs::write(dir.path().join(XOR_KEY_FILE_NAME), xor_key).unwrap();
+
+        let source = LocalBlockFileSource::load(dir.path(), genesis_of(&blocks[0])).unwrap();
+        assert_eq!(source.best_block_height(), 4);
+        assert_eq!(
+            source.get_best_block_hash().await.unwrap(),
+            blocks[4].block_hash()
+        );
+        let header_info = source
+            .get_block_header_info(blocks[2].block_hash())
+            .await
+            .unwrap();
+        assert_eq!(header_info.height, 2);
+        assert_eq!(header_info.confirmations, 3);
+        assert_eq!(header_info.next_block_hash, Some(blocks[3].block_hash()));
+        assert_eq!(
+            source.get_block(blocks[3].block_hash()).await.unwrap(),
+            blocks[3]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_raw_block_dir_with_fork() {
+        let dir = tempfile::tempdir().unwrap();
+        let blocks = regtest_blocks(4);
+        let mut fork_block = blocks[2].clone();
+        fork_block.header.time += 1;
+        for (i, block) in blocks.iter().enumerate() {
+            std::fs::write(
+                dir.path().join(format!("{}.hex", i)),
+                bitcoin::consensus::encode::serialize_hex(block),
+            )
+            .unwrap();
+        }
+        std::fs::write(
+            dir.path().join("fork.bin"),
+            bitcoin::consensus::serialize(&fork_block),
+        )
+        .unwrap();
+
+        let source = LocalBlockFileSource::load(dir.path(), genesis_of(&blocks[0])).unwrap();
+        assert_eq!(source.best_block_height(), 3);
+        let fork_info = source
+            .get_block_header_info(fork_block.block_hash())
+            .await
+            .unwrap();
+        assert_eq!(fork_info.height, 2);
+        assert!(fork_info.confirmations < 0);
+        assert_eq!(
+            source.get_block(fork_block.block_hash()).await.unwrap(),
+            fork_block
+        );
+        assert_eq!(
+            source
+                .get_previous_block_hash(blocks[0].block_hash())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
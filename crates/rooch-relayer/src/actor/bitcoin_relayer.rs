@@ -1,11 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::actor::bitcoin_block_source::BitcoinBlockSource;
 use crate::actor::messages::{GetReadyL1BlockMessage, GetReadyL1TxsMessage, SyncTick};
 use anyhow::Result;
 use async_trait::async_trait;
 use bitcoin::{Block, BlockHash};
-use bitcoin_client::proxy::BitcoinClientProxy;
 use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use indexmap::IndexMap;
@@ -21,13 +21,14 @@ use rooch_types::{
 };
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tracing::{debug, error, info};
 
 pub struct BitcoinRelayer {
     genesis_block: BlockHeightHash,
     // only for data import
     end_block_height: Option<u64>,
-    rpc_client: BitcoinClientProxy,
+    block_source: Arc<dyn BitcoinBlockSource>,
     move_caller: ExecutorProxy,
     buffer: Vec<BlockResult>,
     sync_block_interval: u64,
@@ -46,7 +47,7 @@ pub struct BlockResult {
 impl BitcoinRelayer {
     pub fn new(
         config: BitcoinRelayerConfig,
-        rpc_client: BitcoinClientProxy,
+        block_source: Arc<dyn BitcoinBlockSource>,
         executor: ExecutorProxy,
     ) -> Result<Self> {
         let bitcoin_module = executor.as_module_binding::<BitcoinModule>();
@@ -56,7 +57,7 @@ impl BitcoinRelayer {
         Ok(Self {
             genesis_block,
             end_block_height: config.btc_end_block_height,
-            rpc_client: rpc_client.clone(),
+            block_source: block_source.clone(),
             move_caller: executor,
             buffer: vec![],
             sync_block_interval,
@@ -66,7 +67,7 @@ impl BitcoinRelayer {
             reorg_aware_store: BitcoinReorgAwareStore::new(
                 config.btc_reorg_aware_block_store_dir,
                 config.btc_reorg_aware_height,
-                rpc_client,
+                block_source,
             ),
         })
     }
@@ -96,7 +97,7 @@ impl BitcoinRelayer {
                 block_height: last_block_height as u64,
             })
         };
-        let best_block_hash_in_bitcoin = self.rpc_client.get_best_block_hash().await?;
+        let best_block_hash_in_bitcoin = self.block_source.get_best_block_hash().await?;
 
         //The start block is included
         let start_block_hash = match best_block_in_rooch {
@@ -107,7 +108,7 @@ impl BitcoinRelayer {
                 }
                 //We need to find the next block of the best block in rooch
                 let mut best_block_header_info = self
-                    .rpc_client
+                    .block_source
                     .get_block_header_info(BlockHash::from_address(best_block_in_rooch.block_hash))
                     .await?;
 
@@ -121,7 +122,7 @@ impl BitcoinRelayer {
                             )
                         })?;
                     best_block_header_info = self
-                        .rpc_client
+                        .block_source
                         .get_block_header_info(previous_block_hash)
                         .await?;
                 }
@@ -139,8 +140,8 @@ impl BitcoinRelayer {
 
         let mut batch_count = 0;
         while let Some(next_hash) = next_block_hash {
-            let header_info = self.rpc_client.get_block_header_info(next_hash).await?;
-            let block = self.rpc_client.get_block(next_hash).await?;
+            let header_info = self.block_source.get_block_header_info(next_hash).await?;
+            let block = self.block_source.get_block(next_hash).await?;
             next_block_hash = header_info.next_block_hash;
             let next_block_height = header_info.height as u64;
 
@@ -269,20 +270,20 @@ pub struct BitcoinReorgAwareStore {
     block_store_dir: PathBuf,
     recent_blocks_map: IndexMap<u64, BlockHash>,
     aware_height: usize,
-    rpc_client: BitcoinClientProxy,
+    block_source: Arc<dyn BitcoinBlockSource>,
 }
 
 impl BitcoinReorgAwareStore {
     pub fn new(
         block_store_dir: PathBuf,
         aware_height: usize,
-        rpc_client: BitcoinClientProxy,
+        block_source: Arc<dyn BitcoinBlockSource>,
     ) -> Self {
         Self {
             block_store_dir,
             recent_blocks_map: IndexMap::with_capacity(aware_height),
             aware_height,
-            rpc_client,
+            block_source,
         }
     }
 
@@ -299,7 +300,7 @@ impl BitcoinReorgAwareStore {
 
         // Handle replacement if block height already exists in the map
         if let Some(original_hash) = self.recent_blocks_map.insert(block_height, block_hash) {
-            let original_block = self.rpc_client.get_block(original_hash).await?;
+            let original_block = self.block_source.get_block(original_hash).await?;
             self.write_block_to_store(original_hash, &original_block)
                 .await?;
         }
@@ -331,7 +332,10 @@ impl BitcoinReorgAwareStore {
         for _ in 1..self.aware_height {
             if let Some(previous_block_hash) = previous_block_hash_opt {
                 init_recent_blocks.push((block_height - 1, previous_block_hash));
-                previous_block_hash_opt = self.get_previous_block_hash(previous_block_hash).await?;
+                previous_block_hash_opt = self
+                    .block_source
+                    .get_previous_block_hash(previous_block_hash)
+                    .await?;
                 block_height -= 1;
             } else {
                 break;
@@ -344,9 +348,4 @@ impl BitcoinReorgAwareStore {
         }
         Ok(())
     }
-
-    async fn get_previous_block_hash(&self, block_hash: BlockHash) -> Result<Option<BlockHash>> {
-        let header_info = self.rpc_client.get_block_header_info(block_hash).await?;
-        Ok(header_info.previous_block_hash)
-    }
 }
@@ -14,6 +14,16 @@ impl Message for RelayTick {
 
 impl TimerTick for RelayTick {}
 
+/// Pause or resume relaying the L1 blocks and transactions at runtime
+#[derive(Clone)]
+pub struct PauseRelayerMessage {
+    pub paused: bool,
+}
+
+impl Message for PauseRelayerMessage {
+    type Result = ();
+}
+
 #[derive(Clone)]
 pub struct SyncTick {}
 
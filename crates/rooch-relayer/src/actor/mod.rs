@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod bitcoin_block_source;
 pub mod bitcoin_relayer;
 pub mod ethereum_relayer;
 pub mod messages;
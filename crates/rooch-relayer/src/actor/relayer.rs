@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::bitcoin_block_source::{BitcoinBlockSource, LocalBlockFileSource};
 use super::bitcoin_relayer::BitcoinRelayer;
 use super::ethereum_relayer::EthereumRelayer;
-use crate::actor::messages::RelayTick;
+use crate::actor::messages::{PauseRelayerMessage, RelayTick};
 use crate::actor::relayer_proxy::RelayerProxy;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -20,11 +21,13 @@ use rooch_notify::event::ServiceStatusEvent;
 use rooch_notify::messages::NotifyActorSubscribeMessage;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_types::bitcoin::pending_block::PendingBlockModule;
+use rooch_types::bitcoin::BitcoinModule;
 use rooch_types::error::RoochError;
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::service_status::ServiceStatus;
 use rooch_types::transaction::{L1BlockWithBody, L1Transaction};
 use std::ops::Deref;
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 pub struct RelayerActor {
@@ -80,20 +83,37 @@ impl RelayerActor {
         }
 
         if let Some(bitcoin_config) = &self.bitcoin_config {
-            let bitcoin_client = BitcoinClientActor::new(
-                &bitcoin_config.btc_rpc_url,
-                &bitcoin_config.btc_rpc_user_name,
-                &bitcoin_config.btc_rpc_password,
-                None, // for relayer, only store, no need to get from local
-            )?;
-            let bitcoin_client_actor_ref =
-                ctx.spawn("bitcoin_client".into(), bitcoin_client).await?;
-            let bitcoin_client_proxy = BitcoinClientProxy::new(bitcoin_client_actor_ref.into());
-            let bitcoin_relayer = BitcoinRelayer::new(
-                bitcoin_config.clone(),
-                bitcoin_client_proxy,
-                self.executor.clone(),
-            )?;
+            let block_source: Arc<dyn BitcoinBlockSource> =
+                match &bitcoin_config.btc_block_source_dir {
+                    Some(block_source_dir) => {
+                        let genesis_block = self
+                            .executor
+                            .as_module_binding::<BitcoinModule>()
+                            .get_genesis_block()?;
+                        Arc::new(LocalBlockFileSource::load(block_source_dir, genesis_block)?)
+                    }
+                    None => {
+                        let bitcoin_client = BitcoinClientActor::new(
+                            bitcoin_config.btc_rpc_url.as_deref().ok_or_else(|| {
+                                anyhow::anyhow!("Bitcoin RPC url is required for BitcoinRelayer")
+                            })?,
+                            bitcoin_config
+                                .btc_rpc_user_name
+                                .as_deref()
+                                .unwrap_or_default(),
+                            bitcoin_config
+                                .btc_rpc_password
+                                .as_deref()
+                                .unwrap_or_default(),
+                            None, // for relayer, only store, no need to get from local
+                        )?;
+                        let bitcoin_client_actor_ref =
+                            ctx.spawn("bitcoin_client".into(), bitcoin_client).await?;
+                        Arc::new(BitcoinClientProxy::new(bitcoin_client_actor_ref.into()))
+                    }
+                };
+            let bitcoin_relayer =
+                BitcoinRelayer::new(bitcoin_config.clone(), block_source, self.executor.clone())?;
             let bitcoin_relayer_actor_ref =
                 ctx.spawn("bitcoin_relayer".into(), bitcoin_relayer).await?;
             self.relayers
@@ -276,6 +296,14 @@ impl Handler<RelayTick> for RelayerActor {
     }
 }
 
+#[async_trait]
+impl Handler<PauseRelayerMessage> for RelayerActor {
+    async fn handle(&mut self, message: PauseRelayerMessage, _ctx: &mut ActorContext) {
+        info!("RelayerActor: set paused to {}", message.paused);
+        self.paused = message.paused;
+    }
+}
+
 #[async_trait]
 impl Handler<EventData> for RelayerActor {
     async fn handle(&mut self, message: EventData, _ctx: &mut ActorContext) -> Result<()> {
@@ -0,0 +1,51 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use rooch_open_rpc_macros::open_rpc;
+use rooch_types::service_status::ServiceStatus;
+use std::collections::BTreeMap;
+
+/// The operator API, it is only served by the admin server which requires the admin token.
+#[open_rpc(namespace = "admin")]
+#[rpc(server, client, namespace = "admin")]
+#[async_trait]
+pub trait AdminAPI {
+    /// Switch the service status at runtime, return the previous status
+    #[method(name = "setServiceStatus")]
+    async fn set_service_status(&self, status: ServiceStatus) -> RpcResult<ServiceStatus>;
+
+    /// Get the current log filter, return `None` if the log filter can not be reloaded
+    #[method(name = "getLogFilter")]
+    async fn get_log_filter(&self) -> RpcResult<Option<String>>;
+
+    /// Replace the log filter, the syntax is the same as `RUST_LOG`, e.g. `info,rooch_da=debug`.
+    /// Return the previous log filter
+    #[method(name = "setLogFilter")]
+    async fn set_log_filter(&self, filter: String) -> RpcResult<Option<String>>;
+
+    /// Pause relaying the L1 blocks and transactions
+    #[method(name = "pauseRelayer")]
+    async fn pause_relayer(&self) -> RpcResult<()>;
+
+    /// Resume relaying the L1 blocks and transactions
+    #[method(name = "resumeRelayer")]
+    async fn resume_relayer(&self) -> RpcResult<()>;
+
+    /// Pause submitting the blocks to the DA backends, the blocks are still made
+    #[method(name = "pauseDASubmission")]
+    async fn pause_da_submission(&self) -> RpcResult<()>;
+
+    /// Resume submitting the blocks to the DA backends
+    #[method(name = "resumeDASubmission")]
+    async fn resume_da_submission(&self) -> RpcResult<()>;
+
+    /// Trigger a proposer round without waiting for the proposer interval
+    #[method(name = "proposeBlock")]
+    async fn propose_block(&self) -> RpcResult<()>;
+
+    /// Get the subscribers of every event in the event bus
+    #[method(name = "getEventBusStatus")]
+    async fn get_event_bus_status(&self) -> RpcResult<BTreeMap<String, Vec<String>>>;
+}
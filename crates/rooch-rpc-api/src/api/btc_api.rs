@@ -1,10 +1,15 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::jsonrpc_types::btc::brc20::{
+    BRC20BalanceHistoryFilterView, BRC20BalanceView, BRC20TickInfoView,
+};
 use crate::jsonrpc_types::btc::ord::InscriptionFilterView;
-use crate::jsonrpc_types::btc::utxo::UTXOFilterView;
+use crate::jsonrpc_types::btc::utxo::{OutPointView, SatPointView, SatRangeView, UTXOFilterView};
+use crate::jsonrpc_types::event_view::IndexerEventIDView;
 use crate::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BRC20BalanceHistoryPageView, BytesView, IndexerStateIDView, InscriptionPageView, StrView,
+    UTXOPageView, UnitedAddressView,
 };
 use crate::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -36,6 +41,38 @@ pub trait BtcAPI {
         descending_order: Option<bool>,
     ) -> RpcResult<InscriptionPageView>;
 
+    /// Get the BRC20 tick info, return `None` if the tick is not deployed
+    #[method(name = "getBRC20Tick")]
+    async fn get_brc20_tick(&self, tick: String) -> RpcResult<Option<BRC20TickInfoView>>;
+
+    /// Get the available and transferable BRC20 balances of the owner for the given ticks
+    #[method(name = "getBRC20Balances")]
+    async fn get_brc20_balances(
+        &self,
+        owner: UnitedAddressView,
+        ticks: Vec<String>,
+    ) -> RpcResult<Vec<BRC20BalanceView>>;
+
+    /// Query the BRC20 balance history via global index by filter
+    #[method(name = "queryBRC20BalanceHistory")]
+    async fn query_brc20_balance_history(
+        &self,
+        filter: BRC20BalanceHistoryFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventIDView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<BRC20BalanceHistoryPageView>;
+
+    /// Get the sat ranges of the UTXO, return `None` if the UTXO does not exist or its sat ranges are unknown.
+    /// The sat ranges are only tracked when the `SAT_RANGE_TRACKING` feature is enabled.
+    #[method(name = "getSatRanges")]
+    async fn get_sat_ranges(&self, outpoint: OutPointView) -> RpcResult<Option<Vec<SatRangeView>>>;
+
+    /// Find the current location of the sat, return `None` if the sat is not tracked
+    #[method(name = "findSat")]
+    async fn find_sat(&self, sat: StrView<u64>) -> RpcResult<Option<SatPointView>>;
+
     /// Broadcast a Bitcoin transaction
     #[method(name = "broadcastTX")]
     async fn broadcast_tx(
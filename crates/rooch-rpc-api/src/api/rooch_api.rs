@@ -3,10 +3,15 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::address::UnitedAddressView;
+use crate::jsonrpc_types::block_view::BlockView;
 use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView, IndexerEventView};
 use crate::jsonrpc_types::field_view::FieldFilterView;
+use crate::jsonrpc_types::oracle_view::PriceDataView;
 use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::transaction_view::{
+    PendingTransactionView, TransactionFilterView, TransactionProofView, TransactionWithInfoView,
+    TxHashOrOrderView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FieldKeyView, FieldPageView, FunctionCallView,
@@ -129,6 +134,14 @@ pub trait RoochAPI {
         tx_hashes: Vec<H256View>,
     ) -> RpcResult<Vec<Option<TransactionWithInfoView>>>;
 
+    /// Get the transactions waiting in the mempool for the previous sequence numbers of the sender
+    /// If the sender is None, return the pending transactions of all senders.
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<UnitedAddressView>,
+    ) -> RpcResult<Vec<PendingTransactionView>>;
+
     #[method(name = "getTransactionsByOrder")]
     async fn get_transactions_by_order(
         &self,
@@ -228,6 +241,32 @@ pub trait RoochAPI {
     #[method(name = "status")]
     async fn status(&self) -> RpcResult<Status>;
 
+    /// Get the block committed to the on-chain State Commitment Chain by the block number
+    #[method(name = "getCommittedBlock")]
+    async fn get_committed_block(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockView>>;
+
+    /// Get the inclusion proof of the transaction in the transaction accumulator by the tx hash or the tx order.
+    /// The proof root is the tx accumulator root after the transaction of `anchor_order` is appended,
+    /// default is the latest root.
+    #[method(name = "getTransactionProof")]
+    async fn get_transaction_proof(
+        &self,
+        tx: TxHashOrOrderView,
+        anchor_order: Option<StrView<u64>>,
+    ) -> RpcResult<Option<TransactionProofView>>;
+
+    /// Get the median price of the ticker aggregated from the oracles registered to the on-chain price feed.
+    /// The data older than `max_age` milliseconds is rejected, default is the max age of the price feed.
+    #[method(name = "getOraclePrice")]
+    async fn get_oracle_price(
+        &self,
+        ticker: String,
+        max_age: Option<StrView<u64>>,
+    ) -> RpcResult<Option<PriceDataView>>;
+
     /// Check change sets from sync states
     #[method(name = "checkChangeSets")]
     async fn check_change_set(
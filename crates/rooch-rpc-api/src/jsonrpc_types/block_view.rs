@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use rooch_types::block::Block;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The block committed to the on-chain State Commitment Chain by the proposer
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BlockView {
+    pub block_number: StrView<u128>,
+    pub batch_size: StrView<u64>,
+    pub tx_order_end: StrView<u64>,
+    pub batch_hash: H256View,
+    pub prev_tx_accumulator_root: H256View,
+    pub tx_accumulator_root: H256View,
+    pub state_root: H256View,
+}
+
+impl From<Block> for BlockView {
+    fn from(block: Block) -> Self {
+        Self {
+            block_number: block.block_number.into(),
+            batch_size: block.batch_size.into(),
+            tx_order_end: block.tx_order_end.into(),
+            batch_hash: block.batch_hash.into(),
+            prev_tx_accumulator_root: block.prev_tx_accumulator_root.into(),
+            tx_accumulator_root: block.tx_accumulator_root.into(),
+            state_root: block.state_root.into(),
+        }
+    }
+}
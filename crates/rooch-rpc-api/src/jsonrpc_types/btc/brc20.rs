@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::event_view::IndexerEventIDView;
+use crate::jsonrpc_types::{H256View, RoochAddressView, StrView, UnitedAddressView};
+use move_core_types::u256::U256;
+use rooch_types::bitcoin::brc20::{BRC20Balance, BRC20CoinInfo};
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
+use rooch_types::indexer::event::IndexerEventID;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20TickInfoView {
+    pub tick: String,
+    pub max: StrView<U256>,
+    pub lim: StrView<U256>,
+    pub dec: u8,
+    pub supply: StrView<U256>,
+}
+
+impl From<BRC20CoinInfo> for BRC20TickInfoView {
+    fn from(info: BRC20CoinInfo) -> Self {
+        Self {
+            tick: info.tick.to_string(),
+            max: StrView(info.max),
+            lim: StrView(info.lim),
+            dec: info.dec,
+            supply: StrView(info.supply),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20BalanceView {
+    pub tick: String,
+    /// The balance which can be used to inscribe transfer inscriptions
+    pub available: StrView<U256>,
+    /// The balance locked by transfer inscriptions that have not been sent yet
+    pub transferable: StrView<U256>,
+    /// available + transferable
+    pub overall: StrView<U256>,
+}
+
+impl From<BRC20Balance> for BRC20BalanceView {
+    fn from(balance: BRC20Balance) -> Self {
+        Self {
+            overall: StrView(balance.overall()),
+            tick: balance.tick,
+            available: StrView(balance.available),
+            transferable: StrView(balance.transferable),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BRC20BalanceChangeView {
+    pub tick: String,
+    pub owner: RoochAddressView,
+    pub available: StrView<U256>,
+    pub transferable: StrView<U256>,
+    pub tx_hash: H256View,
+    pub created_at: StrView<u64>,
+    #[serde(flatten)]
+    pub indexer_id: IndexerEventIDView,
+}
+
+impl From<IndexerBRC20BalanceChange> for BRC20BalanceChangeView {
+    fn from(balance_change: IndexerBRC20BalanceChange) -> Self {
+        Self {
+            tick: balance_change.tick,
+            owner: balance_change.owner.into(),
+            available: StrView(balance_change.available),
+            transferable: StrView(balance_change.transferable),
+            tx_hash: balance_change.tx_hash.into(),
+            created_at: StrView(balance_change.created_at),
+            indexer_id: IndexerEventID::new(balance_change.tx_order, balance_change.event_index)
+                .into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BRC20BalanceHistoryFilterView {
+    /// Query the balance history of the owner for all ticks, support rooch address and bitcoin address
+    Owner(UnitedAddressView),
+    /// Query the balance history of all owners for the tick
+    Tick(String),
+    /// Query the balance history of the owner for the tick
+    TickWithOwner {
+        tick: String,
+        owner: UnitedAddressView,
+    },
+}
+
+impl From<BRC20BalanceHistoryFilterView> for BRC20BalanceHistoryFilter {
+    fn from(filter: BRC20BalanceHistoryFilterView) -> Self {
+        match filter {
+            BRC20BalanceHistoryFilterView::Owner(owner) => {
+                BRC20BalanceHistoryFilter::Owner(owner.into())
+            }
+            BRC20BalanceHistoryFilterView::Tick(tick) => BRC20BalanceHistoryFilter::Tick(tick),
+            BRC20BalanceHistoryFilterView::TickWithOwner { tick, owner } => {
+                BRC20BalanceHistoryFilter::TickWithOwner {
+                    tick,
+                    owner: owner.into(),
+                }
+            }
+        }
+    }
+}
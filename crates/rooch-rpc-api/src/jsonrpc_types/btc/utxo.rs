@@ -13,6 +13,7 @@ use bitcoin::{Amount, TxOut, Txid};
 use moveos_types::move_std::string::MoveString;
 use moveos_types::state::{MoveState, MoveStructType};
 use rooch_types::address::BitcoinAddress;
+use rooch_types::bitcoin::sat_range::{SatPoint, SatRange};
 use rooch_types::bitcoin::types::OutPoint;
 use rooch_types::bitcoin::utxo::{self, UTXO};
 use rooch_types::indexer::state::ObjectStateFilter;
@@ -52,6 +53,38 @@ impl From<OutPoint> for OutPointView {
     }
 }
 
+/// A range of sats, the start is inclusive and the end is exclusive
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, JsonSchema)]
+pub struct SatRangeView {
+    pub start: StrView<u64>,
+    pub end: StrView<u64>,
+}
+
+impl From<SatRange> for SatRangeView {
+    fn from(range: SatRange) -> Self {
+        SatRangeView {
+            start: range.start.into(),
+            end: range.end.into(),
+        }
+    }
+}
+
+/// The location of a sat, the offset is the position of the sat in the UTXO
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, JsonSchema)]
+pub struct SatPointView {
+    pub outpoint: OutPointView,
+    pub offset: StrView<u64>,
+}
+
+impl From<SatPoint> for SatPointView {
+    fn from(sat_point: SatPoint) -> Self {
+        SatPointView {
+            outpoint: sat_point.outpoint.into(),
+            offset: sat_point.offset.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UTXOFilterView {
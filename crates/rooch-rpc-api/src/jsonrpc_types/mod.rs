@@ -18,11 +18,13 @@ mod tests;
 mod transaction_argument_view;
 
 pub mod account_view;
+pub mod block_view;
 pub mod decimal_value_view;
 pub mod event_view;
 pub mod export_view;
 pub mod json_to_table_display;
 pub mod move_option_view;
+pub mod oracle_view;
 pub mod transaction_view;
 
 pub mod address;
@@ -18,7 +18,7 @@ use moveos_types::transaction::MoveAction;
 use moveos_types::{
     access_path::AccessPath,
     move_types::FunctionId,
-    transaction::{FunctionCall, ScriptCall},
+    transaction::{FunctionCall, MultiCall, MultiCallArgument, MultiCallFunction, ScriptCall},
 };
 use moveos_types::{
     move_std::{ascii::MoveAsciiString, string::MoveString},
@@ -417,6 +417,91 @@ impl From<FunctionCallView> for FunctionCall {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiCallArgumentView {
+    Pure(BytesView),
+    Result { call_index: u16, result_index: u16 },
+}
+
+impl From<MultiCallArgument> for MultiCallArgumentView {
+    fn from(origin: MultiCallArgument) -> Self {
+        match origin {
+            MultiCallArgument::Pure(arg) => Self::Pure(StrView(arg)),
+            MultiCallArgument::Result {
+                call_index,
+                result_index,
+            } => Self::Result {
+                call_index,
+                result_index,
+            },
+        }
+    }
+}
+
+impl From<MultiCallArgumentView> for MultiCallArgument {
+    fn from(value: MultiCallArgumentView) -> Self {
+        match value {
+            MultiCallArgumentView::Pure(arg) => Self::Pure(arg.into()),
+            MultiCallArgumentView::Result {
+                call_index,
+                result_index,
+            } => Self::Result {
+                call_index,
+                result_index,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct MultiCallFunctionView {
+    pub function_id: FunctionIdView,
+    pub ty_args: Vec<TypeTagView>,
+    pub args: Vec<MultiCallArgumentView>,
+}
+
+impl From<MultiCallFunction> for MultiCallFunctionView {
+    fn from(origin: MultiCallFunction) -> Self {
+        Self {
+            function_id: StrView(origin.function_id),
+            ty_args: origin.ty_args.into_iter().map(StrView).collect(),
+            args: origin.args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MultiCallFunctionView> for MultiCallFunction {
+    fn from(value: MultiCallFunctionView) -> Self {
+        Self {
+            function_id: value.function_id.into(),
+            ty_args: value.ty_args.into_iter().map(Into::into).collect(),
+            args: value.args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct MultiCallView {
+    pub calls: Vec<MultiCallFunctionView>,
+}
+
+impl From<MultiCall> for MultiCallView {
+    fn from(origin: MultiCall) -> Self {
+        Self {
+            calls: origin.calls.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MultiCallView> for MultiCall {
+    fn from(value: MultiCallView) -> Self {
+        Self {
+            calls: value.calls.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MoveActionView {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -425,6 +510,8 @@ pub struct MoveActionView {
     pub script_call: Option<ScriptCallView>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub module_bundle: Option<Vec<BytesView>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_call: Option<MultiCallView>,
 }
 
 impl From<MoveAction> for MoveActionView {
@@ -434,16 +521,25 @@ impl From<MoveAction> for MoveActionView {
                 script_call: Some(script.into()),
                 function_call: None,
                 module_bundle: None,
+                multi_call: None,
             },
             MoveAction::Function(fun) => Self {
                 script_call: None,
                 function_call: Some(fun.into()),
                 module_bundle: None,
+                multi_call: None,
             },
             MoveAction::ModuleBundle(module) => Self {
                 script_call: None,
                 function_call: None,
                 module_bundle: Some(module.into_iter().map(StrView).collect()),
+                multi_call: None,
+            },
+            MoveAction::MultiCall(multi_call) => Self {
+                script_call: None,
+                function_call: None,
+                module_bundle: None,
+                multi_call: Some(multi_call.into()),
             },
         }
     }
@@ -457,6 +553,8 @@ impl From<MoveActionView> for MoveAction {
             MoveAction::Function(function_call.into())
         } else if let Some(module_bundle) = action.module_bundle {
             MoveAction::ModuleBundle(module_bundle.into_iter().map(StrView::into).collect())
+        } else if let Some(multi_call) = action.multi_call {
+            MoveAction::MultiCall(multi_call.into())
         } else {
             panic!("Invalid MoveActionView")
         }
@@ -469,6 +567,7 @@ pub enum MoveActionTypeView {
     ScriptCall,
     FunctionCall,
     ModuleBundle,
+    MultiCall,
 }
 
 impl From<MoveAction> for MoveActionTypeView {
@@ -477,6 +576,7 @@ impl From<MoveAction> for MoveActionTypeView {
             MoveAction::Script(_) => Self::ScriptCall,
             MoveAction::Function(_) => Self::FunctionCall,
             MoveAction::ModuleBundle(_) => Self::ModuleBundle,
+            MoveAction::MultiCall(_) => Self::MultiCall,
         }
     }
 }
@@ -0,0 +1,30 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::decimal_value_view::DecimalValueView;
+use crate::jsonrpc_types::StrView;
+use rooch_types::framework::oracle::PriceData;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The median price of a ticker aggregated from the oracles registered to the price feed
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PriceDataView {
+    pub ticker: String,
+    pub value: DecimalValueView,
+    /// The oldest timestamp of the data used in the aggregation, in milliseconds
+    pub timestamp: StrView<u64>,
+    /// How many oracles are used in the aggregation
+    pub oracle_count: StrView<u64>,
+}
+
+impl From<PriceData> for PriceDataView {
+    fn from(price: PriceData) -> Self {
+        Self {
+            ticker: price.ticker.to_string(),
+            value: price.value.into(),
+            timestamp: price.timestamp.into(),
+            oracle_count: price.oracle_count.into(),
+        }
+    }
+}
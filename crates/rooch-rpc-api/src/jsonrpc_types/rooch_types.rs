@@ -6,6 +6,7 @@ use super::{
     HumanReadableDisplay, IndexerStateIDView, ObjectIDView, StateChangeSetWithTxOrderView,
 };
 use crate::jsonrpc_types::account_view::BalanceInfoView;
+use crate::jsonrpc_types::btc::brc20::BRC20BalanceChangeView;
 use crate::jsonrpc_types::btc::ord::InscriptionStateView;
 use crate::jsonrpc_types::btc::utxo::UTXOStateView;
 use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
@@ -36,6 +37,7 @@ pub type IndexerObjectStatePageView = PageView<IndexerObjectStateView, IndexerSt
 
 pub type UTXOPageView = PageView<UTXOStateView, IndexerStateIDView>;
 pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateIDView>;
+pub type BRC20BalanceHistoryPageView = PageView<BRC20BalanceChangeView, IndexerEventIDView>;
 pub type StateChangeSetPageView = PageView<StateChangeSetWithTxOrderView, StrView<u64>>;
 
 pub type FieldPageView = PageView<IndexerFieldView, StrView<u64>>;
@@ -85,6 +87,9 @@ pub struct TransactionView {
     pub raw: BytesView,
     pub chain_id: StrView<u64>,
     pub max_gas_amount: StrView<u64>,
+    /// The fee payer of the sponsored transaction, who pays the gas instead of the sender.
+    /// The fee payer must co-sign the transaction with the Bitcoin auth validator, the session key is not supported.
+    pub fee_payer: Option<String>,
 }
 
 impl TransactionView {
@@ -101,6 +106,9 @@ impl TransactionView {
             raw: transaction.encode().into(),
             chain_id: transaction.chain_id().into(),
             max_gas_amount: transaction.max_gas_amount().into(),
+            fee_payer: transaction
+                .fee_payer()
+                .map(|fee_payer| fee_payer.to_string()),
         }
     }
 }
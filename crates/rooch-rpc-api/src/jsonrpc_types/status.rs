@@ -6,7 +6,7 @@ use accumulator::accumulator_info::AccumulatorInfo;
 use bitcoin::BlockHash;
 use moveos_types::h256::H256;
 use moveos_types::{startup_info::StartupInfo, state::ObjectState};
-use rooch_types::da::status::DAServerStatus;
+use rooch_types::da::status::{DABackendReconcileState, DAServerStatus};
 use rooch_types::into_address::FromAddress;
 use rooch_types::{
     bitcoin::types::BlockHeightHash, sequencer::SequencerInfo, service_status::ServiceStatus,
@@ -82,6 +82,34 @@ pub struct DAInfoView {
     pub last_avail_tx_order: Option<StrView<u64>>,
     pub last_avail_block_update_time: Option<StrView<u64>>,
     pub avail_backends: Vec<(String, StrView<u128>)>,
+    pub backend_reconcile_states: Vec<DABackendReconcileStateView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DABackendReconcileStateView {
+    pub identifier: String,
+    pub verifiable: bool,
+    pub verified_block_number: Option<StrView<u128>>,
+    pub lag: StrView<u128>,
+    pub missing_segments: StrView<u64>,
+    pub resubmitted_segments: StrView<u64>,
+    pub last_reconcile_time: Option<StrView<u64>>,
+    pub last_error: Option<String>,
+}
+
+impl From<DABackendReconcileState> for DABackendReconcileStateView {
+    fn from(state: DABackendReconcileState) -> Self {
+        DABackendReconcileStateView {
+            identifier: state.identifier,
+            verifiable: state.verifiable,
+            verified_block_number: state.verified_block_number.map(Into::into),
+            lag: state.lag.into(),
+            missing_segments: state.missing_segments.into(),
+            resubmitted_segments: state.resubmitted_segments.into(),
+            last_reconcile_time: state.last_reconcile_time.map(Into::into),
+            last_error: state.last_error,
+        }
+    }
 }
 
 impl From<DAServerStatus> for DAInfoView {
@@ -98,6 +126,11 @@ impl From<DAServerStatus> for DAInfoView {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            backend_reconcile_states: info
+                .backend_reconcile_states
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
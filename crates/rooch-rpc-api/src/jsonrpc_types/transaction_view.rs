@@ -6,12 +6,15 @@ use crate::jsonrpc_types::{
     H256View, TransactionExecutionInfoView, TransactionSequenceInfoView, TransactionView,
     UnitedAddressView,
 };
+use accumulator::proof::AccumulatorProof;
 use bitcoin::hashes::Hash;
+use moveos_types::h256::H256;
 use rooch_types::address::RoochAddress;
 use rooch_types::indexer::transaction::TransactionFilter;
 use rooch_types::indexer::Filter;
 use rooch_types::transaction::{
-    L1Block, L1Transaction, LedgerTransaction, LedgerTxData, TransactionWithInfo,
+    L1Block, L1Transaction, LedgerTransaction, LedgerTxData, RoochTransaction, TransactionProof,
+    TransactionWithInfo,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -156,6 +159,93 @@ impl From<TransactionWithInfo> for TransactionWithInfoView {
     }
 }
 
+/// A transaction waiting in the mempool for the sequence number gap to be filled
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionView {
+    pub tx_hash: H256View,
+    pub transaction: TransactionView,
+    /// The time when the transaction is received by the mempool, in milliseconds
+    pub received_at: StrView<u64>,
+}
+
+impl PendingTransactionView {
+    pub fn new(tx_hash: H256, transaction: RoochTransaction, received_at: u64) -> Self {
+        Self {
+            tx_hash: tx_hash.into(),
+            transaction: TransactionView::new_from_rooch_transaction(transaction, None),
+            received_at: received_at.into(),
+        }
+    }
+}
+
+/// A transaction is identified by the tx hash or the tx order
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TxHashOrOrder {
+    TxHash(H256),
+    TxOrder(u64),
+}
+
+/// The hex string with `0x` prefix is parsed as the tx hash, otherwise it is parsed as the tx order
+pub type TxHashOrOrderView = StrView<TxHashOrOrder>;
+
+impl FromStr for TxHashOrOrderView {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            Ok(StrView(TxHashOrOrder::TxHash(H256View::from_str(s)?.0)))
+        } else {
+            Ok(StrView(TxHashOrOrder::TxOrder(u64::from_str(s)?)))
+        }
+    }
+}
+
+impl std::fmt::Display for TxHashOrOrderView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            TxHashOrOrder::TxHash(tx_hash) => write!(f, "{:#x}", tx_hash),
+            TxHashOrOrder::TxOrder(tx_order) => write!(f, "{}", tx_order),
+        }
+    }
+}
+
+/// The inclusion proof of a transaction in the transaction accumulator,
+/// the leaf is the tx hash and the leaf index is the tx order.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionProofView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    /// The tx order of the transaction whose tx accumulator root is the proof root
+    pub anchor_order: StrView<u64>,
+    pub tx_accumulator_root: H256View,
+    /// The siblings are ordered from the bottom level to the root level
+    pub siblings: Vec<H256View>,
+}
+
+impl From<TransactionProof> for TransactionProofView {
+    fn from(proof: TransactionProof) -> Self {
+        Self {
+            tx_order: proof.tx_order.into(),
+            tx_hash: proof.tx_hash.into(),
+            anchor_order: proof.anchor_order.into(),
+            tx_accumulator_root: proof.tx_accumulator_root.into(),
+            siblings: proof.proof.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TransactionProofView> for TransactionProof {
+    fn from(view: TransactionProofView) -> Self {
+        TransactionProof::new(
+            view.tx_order.0,
+            view.tx_hash.0,
+            view.anchor_order.0,
+            view.tx_accumulator_root.0,
+            AccumulatorProof::new(view.siblings.into_iter().map(|s| s.0).collect()),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TransactionFilterView {
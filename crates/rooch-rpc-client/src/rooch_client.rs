@@ -13,9 +13,12 @@ use moveos_types::state::{FieldKey, MoveStructState};
 use moveos_types::{access_path::AccessPath, state::ObjectState, transaction::FunctionCall};
 use rooch_rpc_api::api::btc_api::BtcAPIClient;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::jsonrpc_types::block_view::BlockView;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionObjectView};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOObjectView};
-use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionProofView, TxHashOrOrder,
+};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView, InscriptionPageView,
     Status, StructTagOrObjectIDView, UTXOPageView,
@@ -430,4 +433,19 @@ impl RoochRpcClient {
     pub async fn status(&self) -> Result<Status> {
         Ok(self.http.status().await?)
     }
+
+    pub async fn get_transaction_proof(
+        &self,
+        tx: TxHashOrOrder,
+        anchor_order: Option<u64>,
+    ) -> Result<Option<TransactionProofView>> {
+        Ok(self
+            .http
+            .get_transaction_proof(tx.into(), anchor_order.map(Into::into))
+            .await?)
+    }
+
+    pub async fn get_committed_block(&self, block_number: u128) -> Result<Option<BlockView>> {
+        Ok(self.http.get_committed_block(block_number.into()).await?)
+    }
 }
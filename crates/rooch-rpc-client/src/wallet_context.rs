@@ -256,6 +256,24 @@ impl WalletContext {
         Ok(tx)
     }
 
+    /// Add the fee payer's signature to a sponsored transaction which has been signed by the sender
+    pub fn sign_transaction_as_fee_payer(
+        &self,
+        fee_payer: RoochAddress,
+        mut tx: RoochTransaction,
+    ) -> RoochResult<RoochTransaction> {
+        if tx.fee_payer() != Some(fee_payer) {
+            return Err(RoochError::SignMessageError(format!(
+                "The fee payer of the transaction is {:?}, not {}",
+                tx.fee_payer(),
+                fee_payer
+            )));
+        }
+        let kp = self.get_key_pair(&fee_payer)?;
+        tx.sign_as_fee_payer(&kp)?;
+        Ok(tx)
+    }
+
     pub async fn execute(
         &self,
         tx: RoochTransaction,
@@ -283,6 +301,20 @@ impl WalletContext {
         self.execute(tx).await
     }
 
+    /// Sign the transaction with the sender and the fee payer, the gas is paid by the fee payer.
+    /// Both keys must be in the keystore, use `sign_transaction_as_fee_payer` for the two-party signing flow.
+    pub async fn sign_and_execute_sponsored(
+        &self,
+        sender: RoochAddress,
+        fee_payer: RoochAddress,
+        tx_data: RoochTransactionData,
+    ) -> RoochResult<ExecuteTransactionResponseView> {
+        let tx_data = tx_data.with_fee_payer(fee_payer);
+        let tx = self.sign_transaction(sender, tx_data)?;
+        let tx = self.sign_transaction_as_fee_payer(fee_payer, tx)?;
+        self.execute(tx).await
+    }
+
     pub fn get_key_pair(&self, address: &RoochAddress) -> Result<RoochKeyPair> {
         self.keystore.get_key_pair(address, self.password.clone())
     }
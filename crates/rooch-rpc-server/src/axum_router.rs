@@ -16,6 +16,7 @@ use serde_json::value::RawValue;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::time::Instant;
+use tracing::Instrument;
 
 pub const MAX_RESPONSE_SIZE: u32 = 2 << 30;
 
@@ -141,7 +142,17 @@ async fn process_raw_request(
     }
 }
 
+// The request span is the root of the trace of a transaction, the actors inherit it via the messages
 async fn process_request(req: Request<'_>, call: CallData<'_>) -> MethodResponse {
+    let span = tracing::info_span!(
+        "rpc.request",
+        rpc.system = "jsonrpc",
+        rpc.method = req.method.as_ref(),
+    );
+    process_request_in_span(req, call).instrument(span).await
+}
+
+async fn process_request_in_span(req: Request<'_>, call: CallData<'_>) -> MethodResponse {
     let CallData {
         methods,
         max_response_body_size,
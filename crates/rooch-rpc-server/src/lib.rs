@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::metrics_server::{init_metrics, start_basic_prometheus_server};
+use crate::server::admin_server::{
+    admin_auth_middleware, AdminAuditLog, AdminServer, ADMIN_AUDIT_LOG_FILE,
+};
 use crate::server::btc_server::BtcServer;
 use crate::server::rooch_server::RoochServer;
 use crate::service::aggregate_service::AggregateService;
 use crate::service::blocklist::{BlockListLayer, BlocklistConfig};
 use crate::service::error::ErrorHandler;
+use crate::service::health::{self, HealthService, HEALTH_LIVE_ROUTE, HEALTH_READY_ROUTE};
 use crate::service::metrics::ServiceMetrics;
 use crate::service::rpc_service::RpcService;
 use anyhow::{ensure, Error, Result};
@@ -16,11 +20,17 @@ use bitcoin_client::proxy::BitcoinClientProxy;
 use coerce::actor::scheduler::timer::Timer;
 use coerce::actor::{system::ActorSystem, IntoActor};
 use jsonrpsee::RpcModule;
+use metrics::otel::{
+    init_tracing_subscriber, install_trace_exporter, shutdown_trace_exporter, TraceExporter,
+};
+use move_core_types::vm_status::KeptVMStatus;
 use moveos_eventbus::bus::EventBus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use raw_store::errors::RawStoreError;
 use rooch_config::da_config::derive_namespace_from_genesis;
 use rooch_config::server_config::ServerConfig;
-use rooch_config::settings::PROPOSER_CHECK_INTERVAL;
+use rooch_config::settings::{MEMPOOL_TICK_INTERVAL, PROPOSER_CHECK_INTERVAL};
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_da::actor::server::DAServerActor;
 use rooch_da::proxy::DAServerProxy;
@@ -32,6 +42,9 @@ use rooch_genesis::RoochGenesisV2;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_mempool::actor::mempool::MempoolActor;
+use rooch_mempool::actor::messages::MempoolTick;
+use rooch_mempool::proxy::MempoolProxy;
 use rooch_notify::actor::NotifyActor;
 use rooch_notify::subscription_handler::SubscriptionHandler;
 use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
@@ -47,8 +60,10 @@ use rooch_sequencer::proxy::SequencerProxy;
 use rooch_store::da_store::DAMetaStore;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{GenesisError, RoochError};
+use rooch_types::framework::randomness::RandomnessModule;
 use rooch_types::rooch_network::BuiltinChainID;
 use rooch_types::service_type::ServiceType;
+use rooch_types::transaction::RoochTransactionData;
 use serde_json::json;
 use std::fmt::Debug;
 use std::net::SocketAddr;
@@ -85,6 +100,7 @@ impl ServerHandle {
             timer.stop();
         }
         let _ = self.shutdown_tx.send(());
+        shutdown_trace_exporter();
         Ok(())
     }
 }
@@ -178,7 +194,27 @@ pub async fn start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Server
 pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<ServerHandle> {
     // We may call `start_server` multiple times in testing scenarios
     // tracing_subscriber can only be inited once.
-    let _ = tracing_subscriber::fmt::try_init();
+    init_tracing_subscriber();
+    if opt.trace.is_enabled() {
+        let exporter = match (&opt.trace.otlp_endpoint, &opt.trace.file) {
+            (Some(endpoint), _) => TraceExporter::Otlp(endpoint.clone()),
+            (None, Some(file)) => TraceExporter::File(file.clone()),
+            (None, None) => unreachable!("the trace config is enabled"),
+        };
+        info!("Export the traces to {:?}", exporter);
+        install_trace_exporter(
+            exporter,
+            &opt.trace.service_name(),
+            opt.trace.sample_ratio(),
+        )?;
+    }
+
+    // Fail fast if the admin server is enabled without a token
+    let admin_token = if opt.admin.is_enabled() {
+        Some(opt.admin.admin_token()?)
+    } else {
+        None
+    };
 
     // Exit the process when some thread panic
     // take_hook() returns the default hook in case when a custom one is not set
@@ -290,6 +326,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
 
     // Init sequencer
     info!("RPC Server sequencer address: {:?}", sequencer_account);
+    let randomness_public_key = server_opt
+        .randomness_keypair
+        .as_ref()
+        .map(|keypair| keypair.public_key_bytes());
     let sequencer = SequencerActor::new(
         sequencer_keypair.copy(),
         rooch_store.clone(),
@@ -297,6 +337,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         &prometheus_registry,
         Some(notify_actor_ref.clone()),
     )?
+    .with_randomness_keypair(server_opt.randomness_keypair)
     .into_actor(Some("Sequencer"), &actor_system)
     .await?;
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
@@ -328,34 +369,11 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .into(),
     );
 
-    // Init proposer
-    let proposer_keypair = server_opt.proposer_keypair.unwrap();
-    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
-    info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(
-        proposer_keypair,
-        moveos_store.clone(),
-        rooch_store,
-        &prometheus_registry,
-        opt.proposer.clone(),
-    )?
-    .into_actor(Some("Proposer"), &actor_system)
-    .await?;
-    let block_propose_duration_in_seconds: u64 =
-        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
-    let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
-
     // Init indexer
     let indexer_executor = IndexerActor::new(
         root,
         indexer_store,
-        moveos_store,
+        moveos_store.clone(),
         Some(notify_actor_ref.clone()),
     )?
     .into_actor(Some("Indexer"), &actor_system)
@@ -364,15 +382,17 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .into_actor(Some("IndexerReader"), &actor_system)
         .await?;
     let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
-    let bitcoin_relayer_config = opt.bitcoin_relayer_config();
-    let bitcoin_client_config = bitcoin_relayer_config
-        .as_ref()
-        .map(|config| BitcoinClientConfig {
-            btc_rpc_url: config.btc_rpc_url.clone(),
-            btc_rpc_user_name: config.btc_rpc_user_name.clone(),
-            btc_rpc_password: config.btc_rpc_password.clone(),
+    let bitcoin_relayer_config = opt.bitcoin_relayer_config()?;
+    // the relayer with local block source has no Bitcoin RPC,
+    // the credentials are checked by `bitcoin_relayer_config` when the RPC url is set
+    let bitcoin_client_config = bitcoin_relayer_config.as_ref().and_then(|config| {
+        Some(BitcoinClientConfig {
+            btc_rpc_url: config.btc_rpc_url.clone()?,
+            btc_rpc_user_name: config.btc_rpc_user_name.clone().unwrap_or_default(),
+            btc_rpc_password: config.btc_rpc_password.clone().unwrap_or_default(),
             local_block_store_dir: Some(config.btc_reorg_aware_block_store_dir.clone()), // this client will be used for startup processing, may need reorg blocks
-        });
+        })
+    });
     let bitcoin_client_proxy = if service_status.is_active() && bitcoin_client_config.is_some() {
         let bitcoin_client = bitcoin_client_config.unwrap().build()?;
         let bitcoin_client_actor_ref = bitcoin_client
@@ -405,7 +425,81 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .await?;
     let processor_proxy = PipelineProcessorProxy::new(processor_actor.into());
 
+    // Register the VRF public key of the randomness beacon, so the committed randomness can be verified on chain
+    if service_status.is_active() {
+        if let Some(public_key) = randomness_public_key {
+            let onchain_public_key = executor_proxy
+                .as_module_binding::<RandomnessModule>()
+                .public_key()?;
+            if onchain_public_key != public_key {
+                info!("Register the randomness beacon VRF public key on chain");
+                let sequence_number = executor_proxy
+                    .get_sequence_number(sequencer_account.into())
+                    .await?;
+                let chain_id = executor_proxy.chain_id().await?.id;
+                let tx = RoochTransactionData::new(
+                    sequencer_account,
+                    sequence_number,
+                    chain_id,
+                    GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+                    RandomnessModule::create_set_public_key_action(public_key),
+                )
+                .sign(&sequencer_keypair);
+                let response = processor_proxy.execute_l2_tx(tx, None).await?;
+                ensure!(
+                    response.execution_info.status == KeptVMStatus::Executed,
+                    "Register the randomness beacon VRF public key failed, status: {:?}",
+                    response.execution_info.status
+                );
+            }
+        }
+    }
+
+    // Init mempool
+    let mempool = MempoolActor::new(
+        processor_proxy.clone(),
+        executor_proxy.clone(),
+        opt.mempool.clone(),
+        &prometheus_registry,
+    )
+    .into_actor(Some("Mempool"), &actor_system)
+    .await?;
+    let mempool_proxy = MempoolProxy::new(mempool.clone().into(), executor_proxy.clone());
+    let mempool_timer = Timer::start(
+        mempool,
+        Duration::from_secs(MEMPOOL_TICK_INTERVAL),
+        MempoolTick {},
+    );
+    let mut timers = vec![mempool_timer];
+
+    // Init proposer, it submits the proposed blocks to the on-chain SCC via the mempool
+    let proposer_keypair = server_opt.proposer_keypair.unwrap();
+    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
+    info!("RPC Server proposer address: {:?}", proposer_account);
+    let proposer = ProposerActor::new(
+        proposer_keypair,
+        moveos_store,
+        rooch_store.clone(),
+        executor_proxy.clone(),
+        processor_proxy.clone(),
+        mempool_proxy.clone(),
+        &prometheus_registry,
+        opt.proposer.clone(),
+    )?
+    .into_actor(Some("Proposer"), &actor_system)
+    .await?;
+    let block_propose_duration_in_seconds: u64 =
+        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
+    let proposer_ref = proposer.clone();
+    let proposer_timer = Timer::start(
+        proposer,
+        Duration::from_secs(block_propose_duration_in_seconds),
+        ProposeBlock {},
+    );
+    timers.push(proposer_timer);
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
+    let mut relayer_ref = None;
 
     if service_status.is_active()
         && (ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some())
@@ -420,6 +514,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .await?
         .into_actor(Some("Relayer"), &actor_system)
         .await?;
+        relayer_ref = Some(relayer.clone());
         let relay_tick_in_seconds: u64 = 1;
         let relayer_timer = Timer::start(
             relayer,
@@ -436,12 +531,15 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         sequencer_proxy,
         indexer_proxy,
         processor_proxy,
+        mempool_proxy,
         bitcoin_client_proxy,
         da_proxy,
+        rooch_store,
         subscription_handle,
         None,
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
+    let health_service = Arc::new(HealthService::new(rpc_service.clone(), opt.health.clone()));
 
     let acl = match env::var("ACCESS_CONTROL_ALLOW_ORIGIN") {
         Ok(value) => {
@@ -576,7 +674,21 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         }
     }
 
-    let app = router.with_state(ser).layer(middleware);
+    // The health probes are not limited by the middleware
+    let health_router = axum::Router::new()
+        .route(
+            HEALTH_LIVE_ROUTE,
+            axum::routing::get(health::health_live_handler),
+        )
+        .route(
+            HEALTH_READY_ROUTE,
+            axum::routing::get(health::health_ready_handler),
+        )
+        .with_state(health_service);
+    let app = router
+        .with_state(ser)
+        .layer(middleware)
+        .merge(health_router);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     let addr = listener.local_addr()?;
@@ -602,6 +714,59 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     info!("JSON-RPC HTTP Server start listening {:?}", addr);
     info!("Available JSON-RPC methods : {:?}", methods_names);
 
+    if let (Some(admin_port), Some(admin_token)) = (opt.admin.admin_port, admin_token) {
+        let audit_log = Arc::new(AdminAuditLog::new(
+            &opt.base().data_dir().join(ADMIN_AUDIT_LOG_FILE),
+        )?);
+        let admin_server = AdminServer::new(
+            rpc_service.pipeline_processor.clone(),
+            rpc_service.da_server.clone(),
+            relayer_ref,
+            proposer_ref,
+            event_bus,
+            audit_log,
+        );
+        let mut admin_module_builder = RpcModuleBuilder::new();
+        admin_module_builder.register_module(admin_server)?;
+        let admin_methods_names = admin_module_builder
+            .module
+            .method_names()
+            .collect::<Vec<_>>();
+        // The admin metrics are kept out of the main registry, the rpc metrics are already registered there
+        let admin_ser = axum_router::JsonRpcService::new(
+            admin_module_builder.module.into(),
+            ServiceMetrics::new(&prometheus::Registry::new(), &admin_methods_names),
+        );
+        let admin_app = axum::Router::new()
+            .route("/", axum::routing::post(axum_router::json_rpc_handler))
+            .with_state(admin_ser)
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(admin_token),
+                admin_auth_middleware,
+            ));
+        let admin_addr: SocketAddr =
+            format!("{}:{}", opt.admin.admin_host(), admin_port).parse()?;
+        let admin_listener = tokio::net::TcpListener::bind(&admin_addr).await?;
+        let admin_addr = admin_listener.local_addr()?;
+        let mut admin_rx = shutdown_tx.subscribe();
+        tokio::spawn(async move {
+            axum::serve(
+                admin_listener,
+                admin_app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(async move {
+                let _ = admin_rx.recv().await;
+            })
+            .await
+            .unwrap();
+        });
+        info!("Admin JSON-RPC Server start listening {:?}", admin_addr);
+        info!(
+            "Available admin JSON-RPC methods : {:?}",
+            admin_methods_names
+        );
+    }
+
     Ok(ServerHandle {
         shutdown_tx,
         timers,
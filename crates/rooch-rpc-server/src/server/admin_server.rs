@@ -0,0 +1,284 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use coerce::actor::LocalActorRef;
+use jsonrpsee::{core::async_trait, RpcModule};
+use moveos_eventbus::bus::EventBus;
+use rooch_da::proxy::DAServerProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_proposer::actor::messages::ProposeBlock;
+use rooch_proposer::actor::proposer::ProposerActor;
+use rooch_relayer::actor::messages::PauseRelayerMessage;
+use rooch_relayer::actor::relayer::RelayerActor;
+use rooch_rpc_api::api::admin_api::AdminAPIServer;
+use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_api::{RpcError, RpcResult};
+use rooch_types::service_status::ServiceStatus;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+pub const ADMIN_AUDIT_LOG_FILE: &str = "admin_audit.log";
+
+/// Record every admin action to the `admin_audit` log target and append it to the audit file as a JSON line.
+pub struct AdminAuditLog {
+    file: Mutex<File>,
+}
+
+impl AdminAuditLog {
+    pub fn new(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    fn record<T: Debug>(&self, action: &str, params: serde_json::Value, result: &RpcResult<T>) {
+        let (ok, output) = match result {
+            Ok(v) => (true, format!("{:?}", v)),
+            Err(e) => (false, e.to_string()),
+        };
+        info!(target: "admin_audit", action, %params, ok, %output, "admin action");
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        let line = json!({
+            "timestamp": timestamp,
+            "action": action,
+            "params": params,
+            "ok": ok,
+            "output": output,
+        });
+        let mut file = match self.file.lock() {
+            Ok(file) => file,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Err(e) = writeln!(file, "{}", line) {
+            warn!("Failed to write the admin audit log: {:?}", e);
+        }
+    }
+}
+
+pub struct AdminServer {
+    pipeline_processor: PipelineProcessorProxy,
+    da_server: DAServerProxy,
+    // the relayer is not started if the service is not active or no relayer is configured
+    relayer: Option<LocalActorRef<RelayerActor>>,
+    proposer: LocalActorRef<ProposerActor>,
+    event_bus: EventBus,
+    audit_log: Arc<AdminAuditLog>,
+}
+
+impl AdminServer {
+    pub fn new(
+        pipeline_processor: PipelineProcessorProxy,
+        da_server: DAServerProxy,
+        relayer: Option<LocalActorRef<RelayerActor>>,
+        proposer: LocalActorRef<ProposerActor>,
+        event_bus: EventBus,
+        audit_log: Arc<AdminAuditLog>,
+    ) -> Self {
+        Self {
+            pipeline_processor,
+            da_server,
+            relayer,
+            proposer,
+            event_bus,
+            audit_log,
+        }
+    }
+
+    async fn pause_relayer_inner(&self, paused: bool) -> RpcResult<()> {
+        let relayer = self
+            .relayer
+            .as_ref()
+            .ok_or_else(|| RpcError::UnexpectedError("The relayer is not running".to_string()))?;
+        relayer
+            .send(PauseRelayerMessage { paused })
+            .await
+            .map_err(|e| RpcError::InternalError(e.into()))
+    }
+}
+
+#[async_trait]
+impl AdminAPIServer for AdminServer {
+    async fn set_service_status(&self, status: ServiceStatus) -> RpcResult<ServiceStatus> {
+        let result = self
+            .pipeline_processor
+            .set_service_status(status)
+            .await
+            .map_err(RpcError::from);
+        self.audit_log
+            .record("set_service_status", json!({ "status": status }), &result);
+        result
+    }
+
+    async fn get_log_filter(&self) -> RpcResult<Option<String>> {
+        Ok(metrics::otel::log_filter())
+    }
+
+    async fn set_log_filter(&self, filter: String) -> RpcResult<Option<String>> {
+        let previous = metrics::otel::log_filter();
+        let result = metrics::otel::set_log_filter(&filter)
+            .map(|_| previous)
+            .map_err(RpcError::from);
+        self.audit_log
+            .record("set_log_filter", json!({ "filter": filter }), &result);
+        result
+    }
+
+    async fn pause_relayer(&self) -> RpcResult<()> {
+        let result = self.pause_relayer_inner(true).await;
+        self.audit_log.record("pause_relayer", json!({}), &result);
+        result
+    }
+
+    async fn resume_relayer(&self) -> RpcResult<()> {
+        let result = self.pause_relayer_inner(false).await;
+        self.audit_log.record("resume_relayer", json!({}), &result);
+        result
+    }
+
+    async fn pause_da_submission(&self) -> RpcResult<()> {
+        let result = self
+            .da_server
+            .pause_submit(true)
+            .await
+            .map_err(RpcError::from);
+        self.audit_log
+            .record("pause_da_submission", json!({}), &result);
+        result
+    }
+
+    async fn resume_da_submission(&self) -> RpcResult<()> {
+        let result = self
+            .da_server
+            .pause_submit(false)
+            .await
+            .map_err(RpcError::from);
+        self.audit_log
+            .record("resume_da_submission", json!({}), &result);
+        result
+    }
+
+    async fn propose_block(&self) -> RpcResult<()> {
+        let result = self
+            .proposer
+            .send(ProposeBlock {})
+            .await
+            .map_err(|e| RpcError::InternalError(e.into()));
+        self.audit_log.record("propose_block", json!({}), &result);
+        result
+    }
+
+    async fn get_event_bus_status(&self) -> RpcResult<BTreeMap<String, Vec<String>>> {
+        Ok(self.event_bus.status()?)
+    }
+}
+
+impl RoochRpcModule for AdminServer {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+}
+
+/// Reject the requests without the `Authorization: Bearer <admin token>` header.
+pub async fn admin_auth_middleware(
+    State(token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|provided| constant_time_eq(provided.as_bytes(), token.as_bytes()))
+        .unwrap_or(false);
+    if !authorized {
+        warn!(target: "admin_audit", "Rejected an unauthorized admin request");
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    next.run(request).await
+}
+
+// do not leak the token by the comparing time
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn request_with_auth(authorization: Option<&str>) -> StatusCode {
+        let app = Router::new().route("/", post(|| async { "ok" })).layer(
+            axum::middleware::from_fn_with_state(
+                Arc::new("secret".to_string()),
+                admin_auth_middleware,
+            ),
+        );
+        let mut request = axum::http::Request::builder().method("POST").uri("/");
+        if let Some(authorization) = authorization {
+            request = request.header(header::AUTHORIZATION, authorization);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_admin_auth_middleware() {
+        assert_eq!(
+            request_with_auth(Some("Bearer secret")).await,
+            StatusCode::OK
+        );
+        assert_eq!(request_with_auth(None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            request_with_auth(Some("Bearer wrong")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            request_with_auth(Some("Bearer secret2")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            request_with_auth(Some("Basic secret")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            request_with_auth(Some("secret")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secre"));
+    }
+}
@@ -7,12 +7,19 @@ use bitcoincore_rpc::bitcoin::Txid;
 use jsonrpsee::{core::async_trait, RpcModule};
 use rooch_rpc_api::api::btc_api::BtcAPIServer;
 use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_rpc_api::jsonrpc_types::btc::brc20::{
+    BRC20BalanceChangeView, BRC20BalanceHistoryFilterView, BRC20BalanceView, BRC20TickInfoView,
+};
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
-use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
+use rooch_rpc_api::jsonrpc_types::btc::utxo::{
+    OutPointView, SatPointView, SatRangeView, UTXOFilterView, UTXOStateView,
+};
+use rooch_rpc_api::jsonrpc_types::event_view::IndexerEventIDView;
 use rooch_rpc_api::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BRC20BalanceHistoryPageView, BytesView, IndexerStateIDView, InscriptionPageView, StrView,
+    UTXOPageView, UnitedAddressView,
 };
-use rooch_rpc_api::RpcResult;
+use rooch_rpc_api::{RpcError, RpcResult};
 use rooch_types::indexer::state::ObjectStateType;
 use std::cmp::min;
 
@@ -116,6 +123,79 @@ impl BtcAPIServer for BtcServer {
         })
     }
 
+    async fn get_brc20_tick(&self, tick: String) -> RpcResult<Option<BRC20TickInfoView>> {
+        let tick_info = self.rpc_service.get_brc20_tick(tick).await?;
+        Ok(tick_info.map(Into::into))
+    }
+
+    async fn get_brc20_balances(
+        &self,
+        owner: UnitedAddressView,
+        ticks: Vec<String>,
+    ) -> RpcResult<Vec<BRC20BalanceView>> {
+        if ticks.len() > MAX_RESULT_LIMIT_USIZE {
+            return Err(RpcError::UnexpectedError(format!(
+                "Too many ticks requested. Maximum allowed: {}",
+                MAX_RESULT_LIMIT_USIZE
+            )));
+        }
+        let balances = self
+            .rpc_service
+            .get_brc20_balances(owner.into(), ticks)
+            .await?;
+        Ok(balances.into_iter().map(Into::into).collect())
+    }
+
+    async fn query_brc20_balance_history(
+        &self,
+        filter: BRC20BalanceHistoryFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventIDView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<BRC20BalanceHistoryPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            MAX_RESULT_LIMIT_USIZE,
+        );
+        let descending_order = descending_order.unwrap_or(true);
+
+        let balance_changes = self
+            .rpc_service
+            .query_brc20_balance_history(
+                filter.into(),
+                cursor.map(Into::into),
+                limit_of + 1,
+                descending_order,
+            )
+            .await?;
+
+        let mut data = balance_changes
+            .into_iter()
+            .map(BRC20BalanceChangeView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.indexer_id));
+
+        Ok(BRC20BalanceHistoryPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_sat_ranges(&self, outpoint: OutPointView) -> RpcResult<Option<Vec<SatRangeView>>> {
+        let sat_ranges = self.rpc_service.get_sat_ranges(outpoint.into()).await?;
+        Ok(sat_ranges.map(|ranges| ranges.into_iter().map(Into::into).collect()))
+    }
+
+    async fn find_sat(&self, sat: StrView<u64>) -> RpcResult<Option<SatPointView>> {
+        let sat_point = self.rpc_service.find_sat(sat.0).await?;
+        Ok(sat_point.map(Into::into))
+    }
+
     async fn broadcast_tx(
         &self,
         hex: BytesView,
@@ -1,5 +1,6 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod admin_server;
 pub mod btc_server;
 pub mod rooch_server;
@@ -20,8 +20,13 @@ use rooch_rpc_api::api::MAX_INTERNAL_LIMIT_USIZE;
 use rooch_rpc_api::jsonrpc_types::field_view::FieldFilterView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView,
+    block_view::BlockView,
     event_view::{EventFilterView, EventView, IndexerEventIDView, IndexerEventView},
-    transaction_view::{TransactionFilterView, TransactionWithInfoView},
+    oracle_view::PriceDataView,
+    transaction_view::{
+        PendingTransactionView, TransactionFilterView, TransactionProofView,
+        TransactionWithInfoView, TxHashOrOrder, TxHashOrOrderView,
+    },
     AccessPathView, BalanceInfoPageView, DryRunTransactionResponseView,
     EnumStructTagOrObjectIDView, EventOptions, EventPageView, ExecuteTransactionResponseView,
     FieldPageView, FunctionCallView, H256View, IndexerEventPageView, IndexerObjectStatePageView,
@@ -171,7 +176,7 @@ impl RoochAPIServer for RoochServer {
     }
 
     async fn dry_run(&self, payload: BytesView) -> RpcResult<DryRunTransactionResponseView> {
-        let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
+        let tx = RoochTransactionData::decode(&payload.0)?;
         let tx_hash = tx.tx_hash();
         let tx_result = self.rpc_service.dry_run_tx(tx).await?;
         let raw_output = tx_result.raw_output;
@@ -500,6 +505,18 @@ impl RoochAPIServer for RoochServer {
         Ok(data)
     }
 
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<UnitedAddressView>,
+    ) -> RpcResult<Vec<PendingTransactionView>> {
+        let sender = sender.map(|sender| AccountAddress::from(sender.0.rooch_address));
+        let pending_txs = self.rpc_service.get_pending_transactions(sender).await?;
+        Ok(pending_txs
+            .into_iter()
+            .map(|tx| PendingTransactionView::new(tx.tx_hash, tx.tx, tx.received_at))
+            .collect())
+    }
+
     async fn get_transactions_by_order(
         &self,
         cursor: Option<StrView<u64>>,
@@ -914,6 +931,47 @@ impl RoochAPIServer for RoochServer {
         Ok(status)
     }
 
+    async fn get_committed_block(
+        &self,
+        block_number: StrView<u128>,
+    ) -> RpcResult<Option<BlockView>> {
+        let block = self.rpc_service.get_committed_block(block_number.0).await?;
+        Ok(block.map(Into::into))
+    }
+
+    async fn get_transaction_proof(
+        &self,
+        tx: TxHashOrOrderView,
+        anchor_order: Option<StrView<u64>>,
+    ) -> RpcResult<Option<TransactionProofView>> {
+        let tx_order = match tx.0 {
+            TxHashOrOrder::TxHash(tx_hash) => {
+                match self.rpc_service.get_transaction_by_hash(tx_hash).await? {
+                    Some(tx) => tx.sequence_info.tx_order,
+                    None => return Ok(None),
+                }
+            }
+            TxHashOrOrder::TxOrder(tx_order) => tx_order,
+        };
+        let proof = self
+            .rpc_service
+            .get_transaction_proof(tx_order, anchor_order.map(|v| v.0))
+            .await?;
+        Ok(proof.map(Into::into))
+    }
+
+    async fn get_oracle_price(
+        &self,
+        ticker: String,
+        max_age: Option<StrView<u64>>,
+    ) -> RpcResult<Option<PriceDataView>> {
+        let price = self
+            .rpc_service
+            .get_oracle_price(ticker, max_age.map(|v| v.0))
+            .await?;
+        Ok(price.map(Into::into))
+    }
+
     async fn check_change_set(
         &self,
         cursor: Option<StrView<u64>>,
@@ -0,0 +1,357 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_config::health_config::HealthConfig;
+use rooch_types::bitcoin::pending_block::PendingBlockModule;
+use rooch_types::indexer::transaction::TransactionFilter;
+use rooch_types::service_status::ServiceStatus;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const HEALTH_LIVE_ROUTE: &str = "/health/live";
+pub const HEALTH_READY_ROUTE: &str = "/health/ready";
+
+// the probes should fail fast if an actor is stuck
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthState {
+    Ok,
+    Unavailable,
+}
+
+/// A readiness condition, `current` is behind `target` by `lag`, which should not exceed `max_lag`.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheck {
+    pub name: &'static str,
+    pub ready: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lag: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl HealthCheck {
+    fn ready(name: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            ready: true,
+            current: None,
+            target: None,
+            lag: None,
+            max_lag: None,
+            message: Some(message.into()),
+        }
+    }
+
+    fn failed(name: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            ready: false,
+            ..Self::ready(name, message)
+        }
+    }
+
+    fn lag(name: &'static str, current: Option<u64>, target: u64, max_lag: u64) -> Self {
+        // nothing is processed yet, the whole range is lagged behind
+        let lag = match current {
+            Some(current) => target.saturating_sub(current),
+            None => target.saturating_add(1),
+        };
+        Self {
+            name,
+            ready: lag <= max_lag,
+            current,
+            target: Some(target),
+            lag: Some(lag),
+            max_lag: Some(max_lag),
+            message: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub status: HealthState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_status: Option<ServiceStatus>,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    fn new(service_status: Option<ServiceStatus>, checks: Vec<HealthCheck>) -> Self {
+        let status = if checks.iter().all(|check| check.ready) {
+            HealthState::Ok
+        } else {
+            HealthState::Unavailable
+        };
+        Self {
+            status,
+            service_status,
+            checks,
+        }
+    }
+
+    fn status_code(&self) -> StatusCode {
+        match self.status {
+            HealthState::Ok => StatusCode::OK,
+            HealthState::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+/// HealthService reports the liveness and the readiness of the node for the orchestration probes.
+#[derive(Clone)]
+pub struct HealthService {
+    rpc_service: RpcService,
+    config: HealthConfig,
+}
+
+impl HealthService {
+    pub fn new(rpc_service: RpcService, config: HealthConfig) -> Self {
+        Self {
+            rpc_service,
+            config,
+        }
+    }
+
+    /// The node is live if the pipeline processor still handles the messages.
+    pub async fn liveness(&self) -> HealthReport {
+        match self.service_status().await {
+            Ok(service_status) => HealthReport::new(
+                Some(service_status),
+                vec![HealthCheck::ready("pipeline_processor", "responsive")],
+            ),
+            Err(e) => HealthReport::new(
+                None,
+                vec![HealthCheck::failed("pipeline_processor", e.to_string())],
+            ),
+        }
+    }
+
+    /// The node is ready if it is not in maintenance, and the relayer, the DA submitter and the indexer
+    /// are within the configured lags.
+    pub async fn readiness(&self) -> HealthReport {
+        let service_status = match self.service_status().await {
+            Ok(service_status) => service_status,
+            Err(e) => {
+                return HealthReport::new(
+                    None,
+                    vec![HealthCheck::failed("service_status", e.to_string())],
+                )
+            }
+        };
+        let mut checks = vec![check_service_status(service_status)];
+
+        let last_order = match with_timeout(self.rpc_service.sequencer.get_sequencer_order()).await
+        {
+            Ok(last_order) => {
+                checks.push(HealthCheck {
+                    current: Some(last_order),
+                    ..HealthCheck::ready("sequencer", "the last tx order")
+                });
+                Some(last_order)
+            }
+            Err(e) => {
+                checks.push(HealthCheck::failed("sequencer", e.to_string()));
+                None
+            }
+        };
+
+        if let Some(last_order) = last_order {
+            // the indexer is not updated in the date import mode
+            if !service_status.is_date_import_mode() {
+                checks.push(self.check_indexer(last_order).await);
+            }
+        }
+        checks.push(self.check_da().await);
+        if self.rpc_service.bitcoin_client.is_some() {
+            checks.push(self.check_bitcoin_relayer().await);
+        }
+
+        HealthReport::new(Some(service_status), checks)
+    }
+
+    async fn service_status(&self) -> Result<ServiceStatus> {
+        with_timeout(self.rpc_service.pipeline_processor.get_service_status()).await
+    }
+
+    async fn check_indexer(&self, last_order: u64) -> HealthCheck {
+        let ret = with_timeout(self.rpc_service.indexer.query_transactions(
+            TransactionFilter::All,
+            None,
+            1,
+            true,
+        ))
+        .await;
+        match ret {
+            Ok(txs) => HealthCheck::lag(
+                "indexer",
+                txs.first().map(|tx| tx.tx_order),
+                last_order,
+                self.config.max_indexer_lag(),
+            ),
+            Err(e) => HealthCheck::failed("indexer", e.to_string()),
+        }
+    }
+
+    async fn check_da(&self) -> HealthCheck {
+        let status = match with_timeout(self.rpc_service.da_server.get_status()).await {
+            Ok(status) => status,
+            Err(e) => return HealthCheck::failed("da", e.to_string()),
+        };
+        if status.avail_backends.is_empty() {
+            return HealthCheck::ready("da", "no DA backend to submit to");
+        }
+        match status.last_block_number {
+            Some(last_block_number) => HealthCheck::lag(
+                "da",
+                status.last_avail_block_number.map(|n| n as u64),
+                last_block_number as u64,
+                self.config.max_da_lag(),
+            ),
+            None => HealthCheck::ready("da", "no block to submit"),
+        }
+    }
+
+    async fn check_bitcoin_relayer(&self) -> HealthCheck {
+        match self.bitcoin_relayer_progress().await {
+            Ok((relayed_height, tip_height)) => HealthCheck::lag(
+                "bitcoin_relayer",
+                relayed_height,
+                tip_height,
+                self.config.max_btc_lag(),
+            ),
+            Err(e) => HealthCheck::failed("bitcoin_relayer", e.to_string()),
+        }
+    }
+
+    // the height of the best block relayed to Rooch and the height of the Bitcoin tip
+    async fn bitcoin_relayer_progress(&self) -> Result<(Option<u64>, u64)> {
+        let bitcoin_client = self
+            .rpc_service
+            .bitcoin_client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("the bitcoin client is not configured"))?;
+        let tip_hash = with_timeout(bitcoin_client.get_best_block_hash()).await?;
+        let tip_header = with_timeout(bitcoin_client.get_block_header_info(tip_hash)).await?;
+        let relayed_block = {
+            let pending_block_module = self
+                .rpc_service
+                .executor
+                .as_module_binding::<PendingBlockModule>();
+            pending_block_module.get_best_block()?
+        };
+        Ok((
+            relayed_block.map(|block| block.block_height),
+            tip_header.height as u64,
+        ))
+    }
+}
+
+fn check_service_status(service_status: ServiceStatus) -> HealthCheck {
+    if service_status.is_maintenance() {
+        HealthCheck::failed("service_status", "the service is in maintenance")
+    } else {
+        HealthCheck::ready("service_status", "the service is not in maintenance")
+    }
+}
+
+async fn with_timeout<T>(fut: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    tokio::time::timeout(HEALTH_CHECK_TIMEOUT, fut)
+        .await
+        .map_err(|_| anyhow::anyhow!("timeout after {:?}", HEALTH_CHECK_TIMEOUT))?
+}
+
+pub async fn health_live_handler(
+    State(service): State<Arc<HealthService>>,
+) -> (StatusCode, Json<HealthReport>) {
+    let report = service.liveness().await;
+    (report.status_code(), Json(report))
+}
+
+pub async fn health_ready_handler(
+    State(service): State<Arc<HealthService>>,
+) -> (StatusCode, Json<HealthReport>) {
+    let report = service.readiness().await;
+    (report.status_code(), Json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_check_lag() {
+        let check = HealthCheck::lag("indexer", Some(90), 100, 10);
+        assert_eq!(check.lag, Some(10));
+        assert!(check.ready);
+
+        let check = HealthCheck::lag("indexer", Some(89), 100, 10);
+        assert_eq!(check.lag, Some(11));
+        assert!(!check.ready);
+
+        // the current is ahead of the target
+        let check = HealthCheck::lag("indexer", Some(101), 100, 0);
+        assert_eq!(check.lag, Some(0));
+        assert!(check.ready);
+
+        // nothing is processed yet
+        let check = HealthCheck::lag("da", None, 0, 0);
+        assert_eq!(check.lag, Some(1));
+        assert!(!check.ready);
+        let check = HealthCheck::lag("da", None, 9, 10);
+        assert_eq!(check.lag, Some(10));
+        assert!(check.ready);
+        let check = HealthCheck::lag("da", None, u64::MAX, 10);
+        assert_eq!(check.lag, Some(u64::MAX));
+        assert!(!check.ready);
+    }
+
+    #[test]
+    fn test_check_service_status() {
+        assert!(!check_service_status(ServiceStatus::Maintenance).ready);
+        assert!(check_service_status(ServiceStatus::Active).ready);
+        assert!(check_service_status(ServiceStatus::ReadOnlyMode).ready);
+        assert!(check_service_status(ServiceStatus::DateImportMode).ready);
+    }
+
+    #[test]
+    fn test_health_report_status_code() {
+        let report = HealthReport::new(
+            Some(ServiceStatus::Active),
+            vec![
+                HealthCheck::ready("service_status", "ok"),
+                HealthCheck::lag("da", Some(1), 1, 0),
+            ],
+        );
+        assert_eq!(report.status, HealthState::Ok);
+        assert_eq!(report.status_code(), StatusCode::OK);
+
+        let report = HealthReport::new(
+            Some(ServiceStatus::Maintenance),
+            vec![
+                HealthCheck::failed("service_status", "the service is in maintenance"),
+                HealthCheck::lag("da", Some(1), 1, 0),
+            ],
+        );
+        assert_eq!(report.status, HealthState::Unavailable);
+        assert_eq!(report.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let report = HealthReport::new(None, vec![HealthCheck::lag("indexer", None, 0, 0)]);
+        assert_eq!(report.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use accumulator::{Accumulator, MerkleAccumulator};
 use anyhow::{bail, format_err, Result};
 use bitcoin_client::proxy::BitcoinClientProxy;
 use bitcoincore_rpc::bitcoin::Txid;
@@ -25,6 +26,8 @@ use rooch_da::proxy::DAServerProxy;
 use rooch_executor::actor::messages::DryRunTransactionResult;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_mempool::pool::PendingTransaction;
+use rooch_mempool::proxy::MempoolProxy;
 use rooch_notify::subscription_handler::SubscriptionHandler;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_rpc_api::jsonrpc_types::event_view::EventFilterView;
@@ -34,10 +37,19 @@ use rooch_rpc_api::jsonrpc_types::{
     BitcoinStatus, DisplayFieldsView, IndexerObjectStateView, ObjectMetaView, RoochStatus, Status,
 };
 use rooch_sequencer::proxy::SequencerProxy;
+use rooch_store::RoochStore;
 use rooch_types::address::{BitcoinAddress, RoochAddress};
+use rooch_types::bitcoin::brc20::{BRC20Balance, BRC20CoinInfo, BRC20Module};
 use rooch_types::bitcoin::pending_block::PendingBlockModule;
+use rooch_types::bitcoin::sat_range::{SatPoint, SatRange, SatRangeModule};
+use rooch_types::bitcoin::types::OutPoint;
+use rooch_types::bitcoin::utxo::UTXOModule;
 use rooch_types::bitcoin::BitcoinModule;
+use rooch_types::block::Block;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
+use rooch_types::framework::oracle::{PriceData, PriceFeedModule};
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::indexer::brc20::{BRC20BalanceHistoryFilter, IndexerBRC20BalanceChange};
 use rooch_types::indexer::event::{
     AnnotatedIndexerEvent, EventFilter, IndexerEvent, IndexerEventID,
 };
@@ -51,6 +63,7 @@ use rooch_types::repair::{RepairIndexerParams, RepairIndexerType};
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use rooch_types::transaction::{
     ExecuteTransactionResponse, LedgerTransaction, RoochTransaction, RoochTransactionData,
+    TransactionProof,
 };
 use serde::Serialize;
 use std::collections::{BTreeMap, HashMap};
@@ -109,8 +122,10 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) indexer: IndexerProxy,
     pub(crate) pipeline_processor: PipelineProcessorProxy,
+    pub(crate) mempool: MempoolProxy,
     pub(crate) bitcoin_client: Option<BitcoinClientProxy>,
     pub(crate) da_server: DAServerProxy,
+    pub(crate) rooch_store: RoochStore,
     // pub(crate) notify: NotifyProxy,
     pub(crate) subscription_handler: Arc<SubscriptionHandler>,
     pub(crate) subscription_semaphore: Arc<Semaphore>,
@@ -124,8 +139,10 @@ impl RpcService {
         sequencer: SequencerProxy,
         indexer: IndexerProxy,
         pipeline_processor: PipelineProcessorProxy,
+        mempool: MempoolProxy,
         bitcoin_client: Option<BitcoinClientProxy>,
         da_server: DAServerProxy,
+        rooch_store: RoochStore,
         subscription_handler: Arc<SubscriptionHandler>,
         max_subscriptions: Option<usize>,
     ) -> Self {
@@ -137,8 +154,10 @@ impl RpcService {
             sequencer,
             indexer,
             pipeline_processor,
+            mempool,
             bitcoin_client,
             da_server,
+            rooch_store,
             subscription_handler,
             subscription_semaphore: Arc::new(Semaphore::new(max_subscriptions)),
         }
@@ -154,14 +173,42 @@ impl RpcService {
         self.bitcoin_network
     }
 
+    /// Submit the transaction to the mempool and do not wait for the transaction to be executed.
+    /// If the transaction fails the validation, the validation error is returned.
     pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<()> {
-        //TODO implement queue tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
+        // The transaction is executing or waiting in the mempool
+        let _receiver = self.mempool.submit_tx(tx).await?;
         Ok(())
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
-        self.pipeline_processor.execute_l2_tx(tx).await
+        let receiver = self.mempool.submit_tx(tx).await?;
+        receiver
+            .await
+            .map_err(|_| format_err!("The transaction is dropped by the mempool"))?
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<AccountAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.mempool.get_pending_transactions(sender).await
+    }
+
+    pub async fn get_committed_block(&self, block_number: u128) -> Result<Option<Block>> {
+        let scc_module = self
+            .executor
+            .as_module_binding::<StateCommitmentChainModule>();
+        scc_module.get_block(block_number)
+    }
+
+    pub async fn get_oracle_price(
+        &self,
+        ticker: String,
+        max_age: Option<u64>,
+    ) -> Result<Option<PriceData>> {
+        let price_feed_module = self.executor.as_module_binding::<PriceFeedModule>();
+        price_feed_module.get_price(ticker, max_age)
     }
 
     pub async fn dry_run_tx(&self, tx: RoochTransactionData) -> Result<DryRunTransactionResult> {
@@ -296,6 +343,59 @@ impl RpcService {
         Ok(resp)
     }
 
+    /// Build the inclusion proof of the transaction at `tx_order` from the accumulator info of the anchor transaction,
+    /// so the proof can be verified against the root committed in a historic block.
+    /// The proof is read from the accumulator nodes in the store, the sequencer is not involved.
+    pub async fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+        anchor_order: Option<u64>,
+    ) -> Result<Option<TransactionProof>> {
+        let anchor_order = match anchor_order {
+            Some(anchor_order) => anchor_order,
+            None => {
+                self.rooch_store
+                    .get_meta_store()
+                    .get_sequencer_info()?
+                    .ok_or_else(|| format_err!("Load sequencer info failed"))?
+                    .last_order
+            }
+        };
+        if tx_order > anchor_order {
+            bail!(
+                "The tx order {} is greater than the anchor order {}",
+                tx_order,
+                anchor_order
+            );
+        }
+        let transaction_store = self.rooch_store.get_transaction_store();
+        let mut tx = match transaction_store.get_tx_by_order(tx_order)? {
+            Some(tx) => tx,
+            None => return Ok(None),
+        };
+        let anchor_tx = match transaction_store.get_tx_by_order(anchor_order)? {
+            Some(anchor_tx) => anchor_tx,
+            None => return Ok(None),
+        };
+        let anchor_info = anchor_tx.sequence_info.tx_accumulator_info();
+        let tx_accumulator_root = anchor_info.accumulator_root;
+        // The accumulator is only read, nothing is appended to it
+        let accumulator = MerkleAccumulator::new_with_info(
+            anchor_info,
+            self.rooch_store.get_transaction_accumulator_store(),
+        );
+        let proof = accumulator.get_proof(tx_order)?.ok_or_else(|| {
+            format_err!("Get the accumulator proof of tx order {} failed", tx_order)
+        })?;
+        Ok(Some(TransactionProof::new(
+            tx_order,
+            tx.tx_hash(),
+            anchor_order,
+            tx_accumulator_root,
+            proof,
+        )))
+    }
+
     pub async fn get_transaction_execution_infos_by_hash(
         &self,
         tx_hashes: Vec<H256>,
@@ -411,6 +511,46 @@ impl RpcService {
         Ok(result)
     }
 
+    pub async fn get_brc20_tick(&self, tick: String) -> Result<Option<BRC20CoinInfo>> {
+        let brc20_module = self.executor.as_module_binding::<BRC20Module>();
+        brc20_module.get_tick_info(tick)
+    }
+
+    pub async fn get_brc20_balances(
+        &self,
+        owner: AccountAddress,
+        ticks: Vec<String>,
+    ) -> Result<Vec<BRC20Balance>> {
+        let brc20_module = self.executor.as_module_binding::<BRC20Module>();
+        ticks
+            .into_iter()
+            .map(|tick| brc20_module.get_balances(tick.to_lowercase(), owner))
+            .collect()
+    }
+
+    pub async fn query_brc20_balance_history(
+        &self,
+        filter: BRC20BalanceHistoryFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerBRC20BalanceChange>> {
+        self.indexer
+            .query_brc20_balance_history(filter, cursor, limit, descending_order)
+            .await
+    }
+
+    pub async fn get_sat_ranges(&self, outpoint: OutPoint) -> Result<Option<Vec<SatRange>>> {
+        let utxo_module = self.executor.as_module_binding::<UTXOModule>();
+        utxo_module.get_sat_ranges(&outpoint)
+    }
+
+    pub async fn find_sat(&self, sat: u64) -> Result<Option<SatPoint>> {
+        let sat_range_module = self.executor.as_module_binding::<SatRangeModule>();
+        sat_range_module.find_sat(sat)
+    }
+
     pub async fn query_object_states(
         &self,
         filter: ObjectStateFilter,
@@ -25,6 +25,9 @@ use rooch_notify::messages::NotifyActorSubscribeMessage;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::randomness::{
+    randomness_block_number, RandomnessKeyPair, TransactionRandomness,
+};
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::service_status::ServiceStatus;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
@@ -38,6 +41,9 @@ pub struct SequencerActor {
     service_status: ServiceStatus,
     metrics: Arc<SequencerMetrics>,
     notify_actor: Option<LocalActorRef<NotifyActor>>,
+    randomness_keypair: Option<RandomnessKeyPair>,
+    /// The randomness of the latest randomness block, all txs in the block share it.
+    block_randomness: Option<(u64, TransactionRandomness)>,
 }
 
 impl SequencerActor {
@@ -75,9 +81,42 @@ impl SequencerActor {
             service_status,
             metrics: Arc::new(SequencerMetrics::new(registry)),
             notify_actor,
+            randomness_keypair: None,
+            block_randomness: None,
         })
     }
 
+    /// Commit the VRF output of the randomness block to the sequence info of every sequenced transaction.
+    pub fn with_randomness_keypair(
+        mut self,
+        randomness_keypair: Option<RandomnessKeyPair>,
+    ) -> Self {
+        if let Some(keypair) = &randomness_keypair {
+            info!(
+                "Randomness beacon enabled, vrf public key: {}",
+                hex::encode(keypair.public_key_bytes())
+            );
+        }
+        self.randomness_keypair = randomness_keypair;
+        self
+    }
+
+    /// Get the randomness of the block the tx order belongs to, the VRF output is only computed once per block.
+    fn block_randomness(&mut self, tx_order: u64) -> Option<TransactionRandomness> {
+        let keypair = self.randomness_keypair.as_ref()?;
+        let block_number = randomness_block_number(tx_order);
+        match &self.block_randomness {
+            Some((cached_block_number, randomness)) if *cached_block_number == block_number => {
+                Some(randomness.clone())
+            }
+            _ => {
+                let randomness = keypair.generate_randomness(block_number);
+                self.block_randomness = Some((block_number, randomness.clone()));
+                Some(randomness)
+            }
+        }
+    }
+
     pub async fn subscribe_event(
         &self,
         notify_actor_ref: LocalActorRef<NotifyActor>,
@@ -154,6 +193,17 @@ impl SequencerActor {
         let now = SystemTime::now();
         let tx_timestamp = now.duration_since(SystemTime::UNIX_EPOCH)?.as_millis() as u64;
 
+        // Reject the expired transaction before it is sequenced
+        if let LedgerTxData::L2Tx(tx) = &tx_data {
+            if tx.data.is_expired(tx_timestamp / 1000) {
+                return Err(anyhow::anyhow!(
+                    "Transaction expired, expiration timestamp: {:?}, now: {}",
+                    tx.data.expiration_timestamp_secs(),
+                    tx_timestamp / 1000
+                ));
+            }
+        }
+
         let tx_hash = tx_data.tx_hash();
         if !self
             .rooch_store
@@ -164,8 +214,13 @@ impl SequencerActor {
         }
 
         let tx_order = self.get_next_tx_order()?;
-        let tx_order_signature =
-            LedgerTransaction::sign_tx_order(tx_order, tx_hash, &self.sequencer_key);
+        let tx_randomness = self.block_randomness(tx_order);
+        let tx_order_signature = LedgerTransaction::sign_tx_order(
+            tx_order,
+            tx_hash,
+            tx_randomness.as_ref(),
+            &self.sequencer_key,
+        );
         let _tx_accumulator_root = self.tx_accumulator.append(vec![tx_hash].as_slice())?;
         let tx_accumulator_unsaved_nodes = self.tx_accumulator.pop_unsaved_nodes();
         let tx_accumulator_info = self.tx_accumulator.get_info();
@@ -176,6 +231,7 @@ impl SequencerActor {
             tx_order,
             tx_order_signature,
             tx_accumulator_info.clone(),
+            tx_randomness,
         );
         let sequencer_info = SequencerInfo::new(tx_order, tx_accumulator_info);
         let save_ret = self.rooch_store.save_sequenced_tx(
@@ -231,7 +287,14 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         msg: TransactionSequenceMessage,
         _ctx: &mut ActorContext,
     ) -> Result<LedgerTransaction> {
-        self.sequence(msg.tx)
+        let span = tracing::info_span!(
+            parent: &msg.span,
+            "sequencer.sequence",
+            tx_order = tracing::field::Empty
+        );
+        let tx = span.in_scope(|| self.sequence(msg.tx))?;
+        span.record("tx_order", tx.sequence_info.tx_order);
+        Ok(tx)
     }
 }
 
@@ -14,6 +14,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TransactionSequenceMessage {
     pub tx: LedgerTxData,
+    /// The span of the caller, the parent of the sequence span
+    #[serde(skip, default = "tracing::Span::none")]
+    pub span: tracing::Span,
 }
 
 impl Message for TransactionSequenceMessage {
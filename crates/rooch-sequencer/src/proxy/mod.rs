@@ -23,7 +23,12 @@ impl SequencerProxy {
     }
 
     pub async fn sequence_transaction(&self, tx: LedgerTxData) -> Result<LedgerTransaction> {
-        self.actor.send(TransactionSequenceMessage { tx }).await?
+        self.actor
+            .send(TransactionSequenceMessage {
+                tx,
+                span: tracing::Span::current(),
+            })
+            .await?
     }
 
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<LedgerTransaction>> {
@@ -87,6 +87,41 @@ async fn test_sequencer() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_sequencer_reject_expired_tx() -> Result<()> {
+    let opt = RoochOpt::new_with_temp_store()?;
+    let registry_service = RegistryService::default();
+    let rooch_db = init_rooch_db(&opt, &registry_service.default_registry())?;
+    let sequencer_key = RoochKeyPair::generate_secp256k1();
+    let mut sequencer = SequencerActor::new(
+        sequencer_key,
+        rooch_db.rooch_store,
+        ServiceStatus::Active,
+        &registry_service.default_registry(),
+        None,
+    )?;
+    let last_tx_order = sequencer.last_order();
+
+    let mock_tx = RoochTransaction::mock();
+    let expired_tx = RoochTransaction::new(
+        mock_tx.data.clone().with_expiration_timestamp_secs(1),
+        mock_tx.authenticator.clone(),
+    );
+    assert!(sequencer.sequence(LedgerTxData::L2Tx(expired_tx)).is_err());
+    assert_eq!(sequencer.last_order(), last_tx_order);
+
+    let unexpired_tx = RoochTransaction::new(
+        mock_tx
+            .data
+            .clone()
+            .with_expiration_timestamp_secs(u64::MAX),
+        mock_tx.authenticator,
+    );
+    let ledger_tx = sequencer.sequence(LedgerTxData::L2Tx(unexpired_tx))?;
+    assert_eq!(ledger_tx.sequence_info.tx_order, last_tx_order + 1);
+    Ok(())
+}
+
 // test concurrent
 // Build a sequencer actor and sequence transactions concurrently
 #[tokio::test(flavor = "multi_thread", worker_threads = 5)]
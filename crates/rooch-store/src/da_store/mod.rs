@@ -18,6 +18,8 @@ pub const MAX_TXS_PER_BLOCK_IN_FIX: usize = 8192; // avoid OOM when fix submitti
 pub const BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY: &str = "background_submit_block_cursor";
 // for fast access to last block number, must be updated with submitting block state updates atomically
 pub const LAST_BLOCK_NUMBER_KEY: &str = "last_block_number";
+// the next block to reconcile of a backend, the key is suffixed by the backend identifier
+pub const RECONCILE_BLOCK_CURSOR_KEY_PREFIX: &str = "reconcile_block_cursor_";
 
 derive_store!(
     DABlockSubmitStateStore,
@@ -81,6 +83,10 @@ pub trait DAMetaStore {
     fn set_background_submit_block_cursor(&self, block_cursor: u128) -> anyhow::Result<()>;
     fn get_background_submit_block_cursor(&self) -> anyhow::Result<Option<u128>>;
 
+    // the next block to reconcile of the backend, blocks before it are verified in the backend
+    fn set_reconcile_block_cursor(&self, backend: &str, block_cursor: u128) -> anyhow::Result<()>;
+    fn get_reconcile_block_cursor(&self, backend: &str) -> anyhow::Result<Option<u128>>;
+
     fn get_last_block_number(&self) -> anyhow::Result<Option<u128>>;
     // get block state by block_number, must exist for the block_number, otherwise return error
     fn get_block_state(&self, block_number: u128) -> anyhow::Result<BlockSubmitState>;
@@ -656,6 +662,18 @@ impl DAMetaStore for DAMetaDBStore {
             .kv_get(BACKGROUND_SUBMIT_BLOCK_CURSOR_KEY.to_string())
     }
 
+    fn set_reconcile_block_cursor(&self, backend: &str, cursor: u128) -> anyhow::Result<()> {
+        self.block_cursor_store.kv_put(
+            format!("{}{}", RECONCILE_BLOCK_CURSOR_KEY_PREFIX, backend),
+            cursor,
+        )
+    }
+
+    fn get_reconcile_block_cursor(&self, backend: &str) -> anyhow::Result<Option<u128>> {
+        self.block_cursor_store
+            .kv_get(format!("{}{}", RECONCILE_BLOCK_CURSOR_KEY_PREFIX, backend))
+    }
+
     fn get_last_block_number(&self) -> anyhow::Result<Option<u128>> {
         self.block_cursor_store
             .kv_get(LAST_BLOCK_NUMBER_KEY.to_string())
@@ -423,6 +423,15 @@ impl DAMetaStore for RoochStore {
             .get_background_submit_block_cursor()
     }
 
+    fn set_reconcile_block_cursor(&self, backend: &str, cursor: u128) -> Result<()> {
+        self.get_da_meta_store()
+            .set_reconcile_block_cursor(backend, cursor)
+    }
+
+    fn get_reconcile_block_cursor(&self, backend: &str) -> Result<Option<u128>> {
+        self.get_da_meta_store().get_reconcile_block_cursor(backend)
+    }
+
     fn get_last_block_number(&self) -> Result<Option<u128>> {
         self.get_da_meta_store().get_last_block_number()
     }
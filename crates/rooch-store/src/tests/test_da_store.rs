@@ -149,6 +149,34 @@ async fn catch_up_last_tx_order() {
     }
 }
 
+#[tokio::test]
+async fn reconcile_block_cursor() {
+    let (rooch_store, _) = RoochStore::mock_rooch_store().unwrap();
+
+    assert_eq!(
+        rooch_store.get_reconcile_block_cursor("celestia").unwrap(),
+        None
+    );
+    rooch_store
+        .set_reconcile_block_cursor("celestia", 3)
+        .unwrap();
+    rooch_store.set_reconcile_block_cursor("fs", 5).unwrap();
+    assert_eq!(
+        rooch_store.get_reconcile_block_cursor("celestia").unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        rooch_store.get_reconcile_block_cursor("fs").unwrap(),
+        Some(5)
+    );
+    // the cursors of the backends do not overwrite the other cursors
+    assert_eq!(
+        rooch_store.get_background_submit_block_cursor().unwrap(),
+        None
+    );
+    assert_eq!(rooch_store.get_last_block_number().unwrap(), None);
+}
+
 fn run_catch_up_last_tx_order_case(
     case: u64,
     last_block_number: Option<u128>,
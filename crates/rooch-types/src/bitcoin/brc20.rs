@@ -1,13 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::addresses::BITCOIN_MOVE_ADDRESS;
+use crate::addresses::ROOCH_NURSERY_ADDRESS;
 use anyhow::Result;
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
 };
 use moveos_types::moveos_std::object;
 use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::transaction::MoveAction;
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::{option::MoveOption, string::MoveString},
@@ -23,10 +24,70 @@ pub struct BRC20CoinInfo {
     pub tick: MoveString,
     pub max: U256,
     pub lim: U256,
-    pub dec: u64,
+    pub dec: u8,
     pub supply: U256,
 }
 
+impl MoveStructType for BRC20CoinInfo {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("BRC20CoinInfo");
+    const ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
+}
+
+impl MoveStructState for BRC20CoinInfo {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            U256::type_layout(),
+            U256::type_layout(),
+            u8::type_layout(),
+            U256::type_layout(),
+        ])
+    }
+}
+
+/// The BRC20 balance of an owner.
+/// The `available` balance can be used to inscribe transfer inscriptions,
+/// the `transferable` balance is locked by transfer inscriptions that have not been sent yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BRC20Balance {
+    pub tick: String,
+    pub available: U256,
+    pub transferable: U256,
+}
+
+impl BRC20Balance {
+    pub fn overall(&self) -> U256 {
+        self.available.wrapping_add(self.transferable)
+    }
+}
+
+/// The event emitted when the available or transferable balance of an owner changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BRC20BalanceChangeEvent {
+    pub tick: MoveString,
+    pub owner: AccountAddress,
+    pub available: U256,
+    pub transferable: U256,
+}
+
+impl MoveStructType for BRC20BalanceChangeEvent {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("BRC20BalanceChangeEvent");
+    const ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
+}
+
+impl MoveStructState for BRC20BalanceChangeEvent {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            AccountAddress::type_layout(),
+            U256::type_layout(),
+            U256::type_layout(),
+        ])
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BRC20Store {
     pub coins: ObjectID,
@@ -41,7 +102,7 @@ impl BRC20Store {
 impl MoveStructType for BRC20Store {
     const MODULE_NAME: &'static IdentStr = MODULE_NAME;
     const STRUCT_NAME: &'static IdentStr = ident_str!("BRC20Store");
-    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
 }
 
 impl MoveStructState for BRC20Store {
@@ -52,18 +113,22 @@ impl MoveStructState for BRC20Store {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Op {
+    pub from: AccountAddress,
+    pub to: AccountAddress,
     pub json_map: SimpleMap<MoveString, MoveString>,
 }
 
 impl MoveStructType for Op {
     const MODULE_NAME: &'static IdentStr = MODULE_NAME;
     const STRUCT_NAME: &'static IdentStr = ident_str!("Op");
-    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
 }
 
 impl MoveStructState for Op {
     fn struct_layout() -> move_core_types::value::MoveStructLayout {
         move_core_types::value::MoveStructLayout::new(vec![
+            AccountAddress::type_layout(),
+            AccountAddress::type_layout(),
             SimpleMap::<MoveString, MoveString>::type_layout(),
         ])
     }
@@ -77,6 +142,19 @@ pub struct BRC20Module<'a> {
 impl<'a> BRC20Module<'a> {
     pub const GET_TICK_INFO_FUNCTION_NAME: &'static IdentStr = ident_str!("get_tick_info");
     pub const GET_BALANCE_FUNCTION_NAME: &'static IdentStr = ident_str!("get_balance");
+    pub const GET_TRANSFERABLE_BALANCE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_transferable_balance");
+    pub const PROCESS_BRC20_EVENT_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("process_brc20_event");
+
+    /// Process at most `batch_size` BRC20 inscription events emitted by the ord module
+    pub fn create_process_brc20_event_action(batch_size: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::PROCESS_BRC20_EVENT_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(batch_size)],
+        )
+    }
 
     pub fn get_tick_info(&self, tick: String) -> Result<Option<BRC20CoinInfo>> {
         let call = Self::create_function_call(
@@ -100,9 +178,35 @@ impl<'a> BRC20Module<'a> {
         Ok(result.into())
     }
 
+    /// Get the available balance of the `addr`
     pub fn get_balance(&self, tick: String, addr: AccountAddress) -> Result<U256> {
+        self.call_balance_function(Self::GET_BALANCE_FUNCTION_NAME, tick, addr)
+    }
+
+    /// Get the transferable balance of the `addr`
+    pub fn get_transferable_balance(&self, tick: String, addr: AccountAddress) -> Result<U256> {
+        self.call_balance_function(Self::GET_TRANSFERABLE_BALANCE_FUNCTION_NAME, tick, addr)
+    }
+
+    /// Get both the available and the transferable balance of the `addr`
+    pub fn get_balances(&self, tick: String, addr: AccountAddress) -> Result<BRC20Balance> {
+        let available = self.get_balance(tick.clone(), addr)?;
+        let transferable = self.get_transferable_balance(tick.clone(), addr)?;
+        Ok(BRC20Balance {
+            tick,
+            available,
+            transferable,
+        })
+    }
+
+    fn call_balance_function(
+        &self,
+        function_name: &IdentStr,
+        tick: String,
+        addr: AccountAddress,
+    ) -> Result<U256> {
         let call = Self::create_function_call(
-            Self::GET_BALANCE_FUNCTION_NAME,
+            function_name,
             vec![],
             vec![
                 BRC20Store::object_id().to_move_value(),
@@ -125,7 +229,7 @@ impl<'a> BRC20Module<'a> {
 
 impl<'a> ModuleBinding<'a> for BRC20Module<'a> {
     const MODULE_NAME: &'static IdentStr = MODULE_NAME;
-    const MODULE_ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_NURSERY_ADDRESS;
 
     fn new(caller: &'a impl MoveFunctionCaller) -> Self
     where
@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::types::{BlockHeightHash, Header};
+use crate::addresses::BITCOIN_MOVE_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
+    moveos_std::tx_context::TxContext,
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("light_client");
+
+pub const ERROR_BLOCK_HASH_MISMATCH: u64 = 1;
+pub const ERROR_INVALID_TARGET: u64 = 2;
+pub const ERROR_INSUFFICIENT_PROOF_OF_WORK: u64 = 3;
+pub const ERROR_INVALID_DIFFICULTY: u64 = 4;
+pub const ERROR_BLOCK_TIME_TOO_OLD: u64 = 5;
+pub const ERROR_INVALID_BLOCK_HEIGHT: u64 = 6;
+pub const ERROR_PREV_BLOCK_NOT_FOUND: u64 = 7;
+
+/// Describe the error code returned by `light_client::validate_header`
+pub fn header_error_message(code: u64) -> &'static str {
+    match code {
+        ERROR_BLOCK_HASH_MISMATCH => "block hash does not match the header",
+        ERROR_INVALID_TARGET => "target is zero or above the proof-of-work limit",
+        ERROR_INSUFFICIENT_PROOF_OF_WORK => "block hash is above the target",
+        ERROR_INVALID_DIFFICULTY => "bits does not match the difficulty retarget rule",
+        ERROR_BLOCK_TIME_TOO_OLD => "block time is not greater than the median time past",
+        ERROR_INVALID_BLOCK_HEIGHT => "block height is not the previous block height plus one",
+        ERROR_PREV_BLOCK_NOT_FOUND => "previous block is unknown",
+        _ => "unknown error",
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct HeaderInfo {
+    pub height: u64,
+    pub header: Header,
+    pub chain_work: U256,
+    pub period_start_time: MoveOption<u32>,
+    pub recent_times: Vec<u32>,
+}
+
+impl MoveStructType for HeaderInfo {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("HeaderInfo");
+}
+
+impl MoveStructState for HeaderInfo {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            u64::type_layout(),
+            Header::type_layout(),
+            move_core_types::value::MoveTypeLayout::U256,
+            MoveOption::<u32>::type_layout(),
+            Vec::<u32>::type_layout(),
+        ])
+    }
+}
+
+/// Rust bindings for BitcoinMove light_client module
+pub struct LightClientModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl LightClientModule<'_> {
+    pub const VALIDATE_HEADER_FUNCTION_NAME: &'static IdentStr = ident_str!("validate_header");
+    pub const GET_HEADER_INFO_FUNCTION_NAME: &'static IdentStr = ident_str!("get_header_info");
+    pub const GET_BEST_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_best_block");
+
+    /// Validate the block header, return 0 if the header is valid, otherwise return the error code
+    pub fn validate_header(
+        &self,
+        block_height: u64,
+        block_hash: AccountAddress,
+        header: &Header,
+    ) -> Result<u64> {
+        let call = Self::create_function_call(
+            Self::VALIDATE_HEADER_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U64(block_height),
+                MoveValue::Address(block_hash),
+                header.to_move_value(),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let error_code =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<u64>(&value.value).expect("should be a valid u64")
+                })?;
+        Ok(error_code)
+    }
+
+    pub fn get_header_info(&self, block_hash: AccountAddress) -> Result<Option<HeaderInfo>> {
+        let call = Self::create_function_call(
+            Self::GET_HEADER_INFO_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(block_hash)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let header_info =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<HeaderInfo>>(&value.value)
+                        .expect("should be a valid MoveOption<HeaderInfo>")
+                })?;
+        Ok(header_info.into())
+    }
+
+    pub fn get_best_block(&self) -> Result<Option<BlockHeightHash>> {
+        let call = Self::create_function_call(Self::GET_BEST_BLOCK_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let best_block =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<BlockHeightHash>>(&value.value)
+                        .expect("should be a valid MoveOption<BlockHeightHash>")
+                })?;
+        Ok(best_block.into())
+    }
+}
+
+impl<'a> ModuleBinding<'a> for LightClientModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
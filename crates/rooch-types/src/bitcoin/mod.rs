@@ -32,10 +32,12 @@ pub mod bitcoin_multisign_validator;
 pub mod brc20;
 pub mod genesis;
 pub mod inscription_updater;
+pub mod light_client;
 pub mod multisign_account;
 pub mod network;
 pub mod ord;
 pub mod pending_block;
+pub mod sat_range;
 pub mod types;
 pub mod utxo;
 
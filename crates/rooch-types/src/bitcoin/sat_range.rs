@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::types::OutPoint;
+use crate::addresses::BITCOIN_MOVE_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
+    moveos_std::tx_context::TxContext,
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("sat_range");
+
+/// A range of sats, the start is inclusive and the end is exclusive
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SatRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl SatRange {
+    pub fn size(&self) -> u64 {
+        self.end - self.start
+    }
+}
+
+impl MoveStructType for SatRange {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SatRange");
+}
+
+impl MoveStructState for SatRange {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![u64::type_layout(), u64::type_layout()])
+    }
+}
+
+/// The location of a sat, the offset is the position of the sat in the UTXO
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SatPoint {
+    pub outpoint: OutPoint,
+    pub offset: u64,
+}
+
+impl MoveStructType for SatPoint {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("SatPoint");
+}
+
+impl MoveStructState for SatPoint {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            OutPoint::type_layout(),
+            u64::type_layout(),
+        ])
+    }
+}
+
+/// Rust bindings for BitcoinMove sat_range module
+pub struct SatRangeModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl SatRangeModule<'_> {
+    pub const FIND_SAT_FUNCTION_NAME: &'static IdentStr = ident_str!("find_sat");
+
+    pub fn find_sat(&self, sat: u64) -> Result<Option<SatPoint>> {
+        let call = Self::create_function_call(
+            Self::FIND_SAT_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(sat)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let sat_point =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<SatPoint>>(&value.value)
+                        .expect("should be a valid MoveOption<SatPoint>")
+                })?;
+        Ok(sat_point.into())
+    }
+}
+
+impl<'a> ModuleBinding<'a> for SatRangeModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -29,6 +29,10 @@ impl Block {
     pub fn encode(&self) -> Vec<u8> {
         bcs::to_bytes(self).expect("encode block should success")
     }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
 }
 
 impl From<bitcoin::Block> for Block {
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::sat_range::SatRange;
 use super::types;
 use crate::addresses::BITCOIN_MOVE_ADDRESS;
 use crate::into_address::FromAddress;
@@ -12,7 +13,7 @@ use moveos_types::moveos_std::object::{self, ObjectMeta};
 use moveos_types::state::{MoveStructState, MoveType, ObjectState};
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
-    move_std::string::MoveString,
+    move_std::{option::MoveOption, string::MoveString},
     moveos_std::{object::ObjectID, simple_multimap::SimpleMultiMap, tx_context::TxContext},
     state::{MoveState, MoveStructType},
 };
@@ -134,6 +135,7 @@ pub struct UTXOModule<'a> {
 
 impl UTXOModule<'_> {
     pub const EXISTS_UTXO_FUNCTION_NAME: &'static IdentStr = ident_str!("exists_utxo");
+    pub const GET_SAT_RANGES_FUNCTION_NAME: &'static IdentStr = ident_str!("get_sat_ranges");
 
     pub fn exists_utxo(&self, outpoint: &types::OutPoint) -> Result<bool> {
         let call = Self::create_function_call(
@@ -152,6 +154,25 @@ impl UTXOModule<'_> {
             })?;
         Ok(exists)
     }
+
+    pub fn get_sat_ranges(&self, outpoint: &types::OutPoint) -> Result<Option<Vec<SatRange>>> {
+        let call = Self::create_function_call(
+            Self::GET_SAT_RANGES_FUNCTION_NAME,
+            vec![],
+            vec![outpoint.to_move_value()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ONE);
+        let sat_ranges =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<Vec<SatRange>>>(&value.value)
+                        .expect("should be a valid MoveOption<Vec<SatRange>>")
+                })?;
+        Ok(sat_ranges.into())
+    }
 }
 
 impl<'a> ModuleBinding<'a> for UTXOModule<'a> {
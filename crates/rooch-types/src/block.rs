@@ -1,7 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::framework::state_commitment_chain::MODULE_NAME;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
 use moveos_types::h256::H256;
+use moveos_types::state::{MoveStructState, MoveStructType};
 use serde::{Deserialize, Serialize};
 
 /// The block in Rooch is constructed by the proposer, representing a batch of transactions
@@ -11,6 +18,8 @@ pub struct Block {
     pub block_number: u128,
     /// How many transactions in the block
     pub batch_size: u64,
+    /// The tx order of the last transaction in the block
+    pub tx_order_end: u64,
     /// The hash of the batch, made by DA
     pub batch_hash: H256,
     /// The previous tx accumulator root of the block
@@ -25,6 +34,7 @@ impl Block {
     pub fn new(
         block_number: u128,
         batch_size: u64,
+        tx_order_end: u64,
         batch_hash: H256,
         prev_tx_accumulator_root: H256,
         tx_accumulator_root: H256,
@@ -33,6 +43,7 @@ impl Block {
         Self {
             block_number,
             batch_size,
+            tx_order_end,
             batch_hash,
             prev_tx_accumulator_root,
             tx_accumulator_root,
@@ -40,3 +51,24 @@ impl Block {
         }
     }
 }
+
+/// The `Block` is committed to the on-chain State Commitment Chain by the proposer.
+impl MoveStructType for Block {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Block");
+}
+
+impl MoveStructState for Block {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U128,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+        ])
+    }
+}
@@ -4,7 +4,6 @@
 use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
 use crate::transaction::LedgerTransaction;
 use fastcrypto::traits::ToFromBytes;
-use moveos_types::h256;
 use moveos_types::h256::{sha2_256_of, H256};
 use serde::{Deserialize, Serialize};
 
@@ -174,9 +173,11 @@ impl DABatch {
         for mut tx in tx_list {
             let tx_order = tx.sequence_info.tx_order;
             let tx_hash = tx.data.tx_hash();
-            let mut witness_data = tx_hash.as_ref().to_vec();
-            witness_data.extend(tx_order.to_le_bytes().iter());
-            let witness_hash = h256::sha3_256_of(&witness_data);
+            let witness_hash = LedgerTransaction::tx_order_witness_hash(
+                tx_order,
+                tx_hash,
+                tx.sequence_info.tx_randomness.as_ref(),
+            );
             let tx_order_signature = Signature::from_bytes(&tx.sequence_info.tx_order_signature)?;
             tx_order_signature.verify(witness_hash.as_bytes())?;
         }
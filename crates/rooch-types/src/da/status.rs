@@ -31,4 +31,30 @@ pub struct DAServerStatus {
     /// The available backend_identifiers and their latest block numbers, e.g., [("openda-gcs", 100), ("openda-fs", 200)]
     /// Block numbers only update in runtime, not in startup. In the beginning, it should be 0.
     pub avail_backends: Vec<(String, u128)>,
+    /// The reconciliation states of the backends, checked by the background reconciler.
+    #[serde(default)]
+    pub backend_reconcile_states: Vec<DABackendReconcileState>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The reconciliation state of a DA backend
+pub struct DABackendReconcileState {
+    /// The backend identifier, e.g., "openda-gcs"
+    pub identifier: String,
+    /// Whether the segments could be read back from the backend.
+    /// The backends which are not verifiable are not reconciled, e.g., Celestia and Avail.
+    pub verifiable: bool,
+    /// All segments of the blocks in [min_avail_block_number, verified_block_number]
+    /// are present in the backend with the correct checksums.
+    pub verified_block_number: Option<u128>,
+    /// How many available blocks are not verified in the backend yet.
+    pub lag: u128,
+    /// The missing or corrupted segments found since the server started.
+    pub missing_segments: u64,
+    /// The segments resubmitted to the backend since the server started.
+    pub resubmitted_segments: u64,
+    /// The last reconciliation time (Unix timestamp in seconds)
+    pub last_reconcile_time: Option<u64>,
+    /// The error of the last reconciliation, None if it succeeded.
+    pub last_error: Option<String>,
 }
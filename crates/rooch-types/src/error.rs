@@ -138,6 +138,9 @@ pub enum RoochError {
     #[error("The l1 tx has been executed.")]
     L1TxAlreadyExecuted,
 
+    #[error("The l1 block validation failed: {0}")]
+    L1BlockValidationError(String),
+
     #[error("VM error: {0}")]
     VMError(VMError),
 
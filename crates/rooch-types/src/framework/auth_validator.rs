@@ -28,6 +28,9 @@ use strum_macros::{Display, EnumString};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("auth_validator");
 
+/// The abort code of the transaction validation when the sequence number is greater than the account's.
+pub const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW: u64 = 1002;
+
 /// The Authenticator auth validator which has builtin Rooch and Ethereum
 #[derive(
     Copy,
@@ -25,8 +25,10 @@ pub mod genesis;
 pub mod indexer;
 pub mod onchain_config;
 pub mod oracle;
+pub mod randomness;
 pub mod session_key;
 pub mod session_validator;
+pub mod state_commitment_chain;
 pub mod timestamp;
 pub mod transaction_validator;
 pub mod transfer;
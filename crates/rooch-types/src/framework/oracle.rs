@@ -2,19 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
-    move_std::string::MoveString,
-    moveos_std::object::ObjectID,
+    move_std::{option::MoveOption, string::MoveString},
+    moveos_std::{decimal_value::DecimalValue, object::ObjectID, tx_context::TxContext},
     state::{MoveState, MoveStructState, MoveStructType},
-    transaction::MoveAction,
+    transaction::{FunctionCall, MoveAction},
 };
 use serde::{Deserialize, Serialize};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("oracle");
+pub const PRICE_FEED_MODULE_NAME: &IdentStr = ident_str!("price_feed");
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewOracleEvent {
@@ -46,6 +48,8 @@ impl OracleModule {
     pub const CREATE_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("create_entry");
     pub const SUBMIT_DECIMAL_DATA_FUNCTION_NAME: &'static IdentStr =
         ident_str!("submit_decimal_data");
+    pub const SUBMIT_DECIMAL_DATA_BATCH_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("submit_decimal_data_batch");
 
     pub fn create_oracle_action(name: String, url: String, description: String) -> MoveAction {
         Self::create_move_action(
@@ -82,6 +86,40 @@ impl OracleModule {
             ],
         )
     }
+
+    /// Submit the decimal data of many tickers in one transaction,
+    /// every item of `data` is (ticker, value, decimal, identifier, timestamp).
+    pub fn submit_decimal_data_batch_action(
+        oracle_id: ObjectID,
+        data: Vec<(String, U256, u8, String, u64)>,
+        admin_obj: ObjectID,
+    ) -> MoveAction {
+        let mut tickers = vec![];
+        let mut values = vec![];
+        let mut decimals = vec![];
+        let mut identifiers = vec![];
+        let mut timestamps = vec![];
+        for (ticker, value, decimal, identifier, timestamp) in data {
+            tickers.push(MoveString::from(ticker).to_move_value());
+            values.push(MoveValue::U256(value));
+            decimals.push(MoveValue::U8(decimal));
+            identifiers.push(MoveString::from(identifier).to_move_value());
+            timestamps.push(MoveValue::U64(timestamp));
+        }
+        Self::create_move_action(
+            Self::SUBMIT_DECIMAL_DATA_BATCH_FUNCTION_NAME,
+            vec![],
+            vec![
+                oracle_id.to_move_value(),
+                MoveValue::Vector(tickers),
+                MoveValue::Vector(values),
+                MoveValue::Vector(decimals),
+                MoveValue::Vector(identifiers),
+                MoveValue::Vector(timestamps),
+                admin_obj.to_move_value(),
+            ],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for OracleModule {
@@ -95,3 +133,163 @@ impl<'a> ModuleBinding<'a> for OracleModule {
         Self
     }
 }
+
+/// The aggregated price of a ticker from the oracles registered to the price feed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PriceData {
+    pub ticker: MoveString,
+    pub value: DecimalValue,
+    /// The oldest timestamp of the data used in the aggregation, in milliseconds
+    pub timestamp: u64,
+    /// How many oracles are used in the aggregation
+    pub oracle_count: u64,
+}
+
+impl MoveStructType for PriceData {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = PRICE_FEED_MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PriceData");
+}
+
+impl MoveStructState for PriceData {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            DecimalValue::type_layout(),
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// Rust bindings for RoochFramework price_feed module
+pub struct PriceFeedModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> PriceFeedModule<'a> {
+    pub const REGISTER_ORACLE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("register_oracle_entry");
+    pub const UNREGISTER_ORACLE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("unregister_oracle_entry");
+    pub const SET_FEED_CONFIG_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_feed_config_entry");
+    pub const GET_PRICE_FUNCTION_NAME: &'static IdentStr = ident_str!("get_price");
+    pub const GET_PRICE_WITH_MAX_AGE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("get_price_with_max_age");
+    pub const GET_TWAP_FUNCTION_NAME: &'static IdentStr = ident_str!("get_twap");
+
+    pub fn register_oracle_action(ticker: String, oracle_id: ObjectID) -> MoveAction {
+        Self::create_move_action(
+            Self::REGISTER_ORACLE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveString::from(ticker).to_move_value(),
+                oracle_id.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn unregister_oracle_action(ticker: String, oracle_id: ObjectID) -> MoveAction {
+        Self::create_move_action(
+            Self::UNREGISTER_ORACLE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveString::from(ticker).to_move_value(),
+                oracle_id.to_move_value(),
+            ],
+        )
+    }
+
+    /// Set the max age in milliseconds and the min oracles of the price feed of the ticker
+    pub fn set_feed_config_action(ticker: String, max_age: u64, min_oracles: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::SET_FEED_CONFIG_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveString::from(ticker).to_move_value(),
+                MoveValue::U64(max_age),
+                MoveValue::U64(min_oracles),
+            ],
+        )
+    }
+
+    /// Get the median price of the fresh data of the oracles of the ticker,
+    /// the data older than `max_age` milliseconds is rejected, default is the max age of the feed.
+    /// Returns None if the ticker is not registered or the fresh data is not enough.
+    pub fn get_price(&self, ticker: String, max_age: Option<u64>) -> Result<Option<PriceData>> {
+        let ticker = MoveString::from(ticker).to_move_value();
+        let call = match max_age {
+            Some(max_age) => FunctionCall::new(
+                Self::function_id(Self::GET_PRICE_WITH_MAX_AGE_FUNCTION_NAME),
+                vec![],
+                vec![
+                    ticker.simple_serialize().unwrap(),
+                    MoveValue::U64(max_age).simple_serialize().unwrap(),
+                ],
+            ),
+            None => FunctionCall::new(
+                Self::function_id(Self::GET_PRICE_FUNCTION_NAME),
+                vec![],
+                vec![ticker.simple_serialize().unwrap()],
+            ),
+        };
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let price = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<PriceData>>(&value.value)
+                    .expect("should be a valid MoveOption<PriceData>")
+                    .into()
+            })?;
+        Ok(price)
+    }
+
+    /// Get the TWAP of the ticker over the last `window` milliseconds from the data archived by `period` milliseconds.
+    pub fn get_twap(
+        &self,
+        ticker: String,
+        window: u64,
+        period: u64,
+    ) -> Result<Option<DecimalValue>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_TWAP_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveString::from(ticker)
+                    .to_move_value()
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::U64(window).simple_serialize().unwrap(),
+                MoveValue::U64(period).simple_serialize().unwrap(),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let twap = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<DecimalValue>>(&value.value)
+                    .expect("should be a valid MoveOption<DecimalValue>")
+                    .into()
+            })?;
+        Ok(twap)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for PriceFeedModule<'a> {
+    const MODULE_NAME: &'static IdentStr = PRICE_FEED_MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use fastcrypto::vrf::ecvrf::{ECVRFKeyPair, ECVRFProof, ECVRFPublicKey};
+use fastcrypto::vrf::{VRFKeyPair, VRFProof};
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::h256::{self, H256};
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::{MoveStructState, MoveStructType};
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("randomness");
+
+/// The size of the ECVRF output in bytes.
+pub const VRF_OUTPUT_SIZE: usize = 64;
+
+/// The number of the transactions sharing the randomness of a block,
+/// the block number of a transaction is `tx_order / RANDOMNESS_BLOCK_SIZE`.
+pub const RANDOMNESS_BLOCK_SIZE: u64 = 100;
+
+/// The randomness block number of the transaction.
+pub fn randomness_block_number(tx_order: u64) -> u64 {
+    tx_order / RANDOMNESS_BLOCK_SIZE
+}
+
+/// The randomness committed by the sequencer for a block,
+/// it is recorded in the sequence info of every transaction in the block, and written to the tx context.
+/// The `randomness` Move module verifies it and derives the per-transaction randomness with the tx hash.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct TransactionRandomness {
+    /// The ECVRF output of the `vrf_input` of the block.
+    pub output: Vec<u8>,
+    /// The BCS serialized ECVRF proof.
+    pub proof: Vec<u8>,
+}
+
+impl TransactionRandomness {
+    pub fn new(output: Vec<u8>, proof: Vec<u8>) -> Self {
+        Self { output, proof }
+    }
+
+    /// Evaluate the VRF over the block with the sequencer VRF key.
+    pub fn generate(keypair: &ECVRFKeyPair, block_number: u64) -> Self {
+        let (output, proof) = keypair.output(vrf_input(block_number).as_bytes());
+        Self {
+            output: output.to_vec(),
+            proof: bcs::to_bytes(&proof).expect("serialize the vrf proof should success"),
+        }
+    }
+
+    /// Verify the proof and the output with the sequencer VRF public key.
+    pub fn verify(&self, public_key: &ECVRFPublicKey, block_number: u64) -> Result<()> {
+        let output: [u8; VRF_OUTPUT_SIZE] = self
+            .output
+            .as_slice()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid vrf output length: {}", self.output.len()))?;
+        let proof = bcs::from_bytes::<ECVRFProof>(&self.proof)?;
+        proof
+            .verify_output(vrf_input(block_number).as_bytes(), public_key, &output)
+            .map_err(|e| anyhow::anyhow!("Invalid vrf proof: {:?}", e))
+    }
+}
+
+impl MoveStructType for TransactionRandomness {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TransactionRandomness");
+}
+
+impl MoveStructState for TransactionRandomness {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+        ])
+    }
+}
+
+/// The VRF input of a block, it only depends on the block number,
+/// so the sequencer can not choose the input, and the output is unique for the VRF key.
+pub fn vrf_input(block_number: u64) -> H256 {
+    h256::sha3_256_of(&block_number.to_le_bytes())
+}
+
+/// The VRF keypair of the randomness beacon, it is stored in the sequencer keystore
+/// and commits a VRF output to every randomness block.
+#[derive(Serialize, Deserialize)]
+pub struct RandomnessKeyPair(ECVRFKeyPair);
+
+impl RandomnessKeyPair {
+    pub fn generate() -> Self {
+        Self(ECVRFKeyPair::generate(&mut rand::thread_rng()))
+    }
+
+    pub fn public_key(&self) -> &ECVRFPublicKey {
+        &self.0.pk
+    }
+
+    /// The BCS serialized VRF public key, it should be set to the `randomness` Move module.
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.0.pk).expect("serialize the vrf public key should success")
+    }
+
+    pub fn generate_randomness(&self, block_number: u64) -> TransactionRandomness {
+        TransactionRandomness::generate(&self.0, block_number)
+    }
+}
+
+impl std::fmt::Debug for RandomnessKeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RandomnessKeyPair")
+            .field(&hex::encode(self.public_key_bytes()))
+            .finish()
+    }
+}
+
+/// Rust bindings for RoochFramework randomness module
+pub struct RandomnessModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> RandomnessModule<'a> {
+    pub const SET_PUBLIC_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_public_key_entry");
+    pub const PUBLIC_KEY_FUNCTION_NAME: &'static IdentStr = ident_str!("public_key");
+
+    /// The `public_key` is the BCS serialized VRF public key, see `RandomnessKeyPair::public_key_bytes`.
+    pub fn create_set_public_key_action(public_key: Vec<u8>) -> MoveAction {
+        MoveAction::Function(Self::create_function_call(
+            Self::SET_PUBLIC_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(public_key)],
+        ))
+    }
+
+    /// Get the VRF public key of the randomness beacon, returns empty bytes if it is not set.
+    pub fn public_key(&self) -> Result<Vec<u8>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::PUBLIC_KEY_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let public_key =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<Vec<u8>>(&value.value)
+                        .expect("should be a valid vector<u8> value")
+                })?;
+        Ok(public_key)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for RandomnessModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_randomness() {
+        let keypair = RandomnessKeyPair::generate();
+        // The keypair is stored in the keystore with BCS
+        let decoded: RandomnessKeyPair =
+            bcs::from_bytes(&bcs::to_bytes(&keypair).unwrap()).unwrap();
+        assert_eq!(decoded.public_key_bytes(), keypair.public_key_bytes());
+
+        let randomness = keypair.generate_randomness(1);
+        assert_eq!(randomness.output.len(), VRF_OUTPUT_SIZE);
+        // The VRF output is deterministic
+        assert_eq!(randomness, decoded.generate_randomness(1));
+        randomness.verify(keypair.public_key(), 1).unwrap();
+        assert!(randomness.verify(keypair.public_key(), 2).is_err());
+        assert_eq!(randomness_block_number(RANDOMNESS_BLOCK_SIZE - 1), 0);
+        assert_eq!(randomness_block_number(RANDOMNESS_BLOCK_SIZE), 1);
+
+        let other_keypair = RandomnessKeyPair::generate();
+        assert!(randomness.verify(other_keypair.public_key(), 1).is_err());
+    }
+}
@@ -158,6 +158,8 @@ impl SessionKey {
         match action {
             MoveAction::Script(_) => false,
             MoveAction::ModuleBundle(_) => false,
+            // Keep consistent with `session_key::in_session_scope`, the MultiCall is not supported
+            MoveAction::MultiCall(_) => false,
             MoveAction::Function(function) => self.is_scope_match(function),
         }
     }
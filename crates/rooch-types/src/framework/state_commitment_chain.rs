@@ -0,0 +1,116 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::block::Block;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::move_std::option::MoveOption;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::{FunctionCall, MoveAction};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("state_commitment_chain");
+
+/// Rust bindings for RoochFramework state_commitment_chain module
+pub struct StateCommitmentChainModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> StateCommitmentChainModule<'a> {
+    pub const SUBMIT_BLOCK_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("submit_block_entry");
+    pub const SET_PROPOSER_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("set_proposer_entry");
+    pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
+    pub const LAST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr = ident_str!("last_block_number");
+
+    /// Returns true if the action submits a block to the SCC, it is the transaction of the proposer.
+    pub fn is_submit_block_action(action: &MoveAction) -> bool {
+        matches!(
+            action,
+            MoveAction::Function(call)
+                if call.function_id == Self::function_id(Self::SUBMIT_BLOCK_ENTRY_FUNCTION_NAME)
+        )
+    }
+
+    pub fn create_submit_block_action(block: &Block) -> MoveAction {
+        MoveAction::Function(Self::create_function_call(
+            Self::SUBMIT_BLOCK_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U128(block.block_number),
+                MoveValue::U64(block.batch_size),
+                MoveValue::U64(block.tx_order_end),
+                MoveValue::vector_u8(block.batch_hash.as_bytes().to_vec()),
+                MoveValue::vector_u8(block.prev_tx_accumulator_root.as_bytes().to_vec()),
+                MoveValue::vector_u8(block.tx_accumulator_root.as_bytes().to_vec()),
+                MoveValue::vector_u8(block.state_root.as_bytes().to_vec()),
+            ],
+        ))
+    }
+
+    pub fn create_set_proposer_action(proposer: AccountAddress) -> MoveAction {
+        MoveAction::Function(Self::create_function_call(
+            Self::SET_PROPOSER_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(proposer)],
+        ))
+    }
+
+    /// Get the committed block by the block number, returns None if the block is not committed.
+    pub fn get_block(&self, block_number: u128) -> Result<Option<Block>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_BLOCK_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::U128(block_number).simple_serialize().unwrap()],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<Block>>(&value.value)
+                    .expect("should be a valid MoveOption<Block>")
+                    .into()
+            })?;
+        Ok(block)
+    }
+
+    /// Get the number of the last committed block, returns None if no block is committed.
+    pub fn last_block_number(&self) -> Result<Option<u128>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::LAST_BLOCK_NUMBER_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let block_number =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<u128>>(&value.value)
+                        .expect("should be a valid MoveOption<u128>")
+                        .into()
+                })?;
+        Ok(block_number)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for StateCommitmentChainModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -25,6 +25,8 @@ pub struct TransactionValidator<'a> {
 
 impl<'a> TransactionValidator<'a> {
     pub const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    pub const VALIDATE_FEE_PAYER_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("validate_fee_payer");
     pub const PRE_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("pre_execute");
     pub const POST_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("post_execute");
 
@@ -57,6 +59,34 @@ impl<'a> TransactionValidator<'a> {
         Ok(function_result)
     }
 
+    /// Validate the fee payer's authenticator of a sponsored transaction
+    pub fn validate_fee_payer(
+        &self,
+        ctx: &TxContext,
+        fee_payer: AccountAddress,
+        auth: AuthenticatorInfo,
+    ) -> Result<DecodedFunctionResult<()>> {
+        let tx_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FEE_PAYER_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::U64(auth.chain_id).simple_serialize().unwrap(),
+                MoveValue::Address(fee_payer).simple_serialize().unwrap(),
+                MoveValue::U64(auth.authenticator.auth_validator_id)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::vector_u8(auth.authenticator.payload)
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        );
+        let function_result = self
+            .caller
+            .call_function(ctx, tx_validator_call)?
+            .decode(|_values| Ok(()))?;
+        Ok(function_result)
+    }
+
     pub fn pre_execute_function_id() -> FunctionId {
         Self::function_id(Self::PRE_EXECUTE_FUNCTION_NAME)
     }
@@ -0,0 +1,80 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::bitcoin::brc20::BRC20BalanceChangeEvent;
+use crate::indexer::Filter;
+use crate::transaction::LedgerTransaction;
+use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::Event;
+use moveos_types::state::MoveStructType;
+use serde::{Deserialize, Serialize};
+
+/// A BRC20 balance snapshot of an owner, recorded every time the balance changes.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerBRC20BalanceChange {
+    pub tick: String,
+    pub owner: AccountAddress,
+    /// The available balance after the change
+    pub available: U256,
+    /// The transferable balance after the change
+    pub transferable: U256,
+
+    /// The tx order of the transaction which changed the balance
+    pub tx_order: u64,
+    /// The index of the balance change event in the transaction events
+    pub event_index: u64,
+    /// The hash of the transaction which changed the balance
+    pub tx_hash: H256,
+    /// The balance change timestamp on chain
+    pub created_at: u64,
+}
+
+impl IndexerBRC20BalanceChange {
+    /// Decode the balance change from a `BRC20BalanceChangeEvent`,
+    /// return `None` if the event is not a BRC20 balance change event.
+    pub fn try_from_event(
+        event: &Event,
+        ledger_transaction: &mut LedgerTransaction,
+    ) -> Result<Option<Self>> {
+        if event.event_type != BRC20BalanceChangeEvent::struct_tag() {
+            return Ok(None);
+        }
+        let balance_change = bcs::from_bytes::<BRC20BalanceChangeEvent>(&event.event_data)?;
+        Ok(Some(IndexerBRC20BalanceChange {
+            tick: balance_change.tick.to_string(),
+            owner: balance_change.owner,
+            available: balance_change.available,
+            transferable: balance_change.transferable,
+            tx_order: ledger_transaction.sequence_info.tx_order,
+            event_index: event.event_index,
+            tx_hash: ledger_transaction.tx_hash(),
+            created_at: ledger_transaction.sequence_info.tx_timestamp,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BRC20BalanceHistoryFilter {
+    /// Query the balance history of the owner for all ticks
+    Owner(AccountAddress),
+    /// Query the balance history of all owners for the tick
+    Tick(String),
+    /// Query the balance history of the owner for the tick
+    TickWithOwner { tick: String, owner: AccountAddress },
+}
+
+impl Filter<IndexerBRC20BalanceChange> for BRC20BalanceHistoryFilter {
+    fn matches(&self, item: &IndexerBRC20BalanceChange) -> bool {
+        match self {
+            BRC20BalanceHistoryFilter::Owner(owner) => owner == &item.owner,
+            BRC20BalanceHistoryFilter::Tick(tick) => tick.to_lowercase() == item.tick,
+            BRC20BalanceHistoryFilter::TickWithOwner { tick, owner } => {
+                tick.to_lowercase() == item.tick && owner == &item.owner
+            }
+        }
+    }
+}
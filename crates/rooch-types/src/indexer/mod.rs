@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod brc20;
 pub mod event;
 pub mod field;
 pub mod state;
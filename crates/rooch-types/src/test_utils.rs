@@ -38,7 +38,7 @@ pub fn random_ledger_transaction_with_order(
 ) -> LedgerTransaction {
     let mut rooch_transaction = random_rooch_transaction();
     let tx_hash = rooch_transaction.tx_hash();
-    let tx_order_signature = LedgerTransaction::sign_tx_order(tx_order, tx_hash, keypair);
+    let tx_order_signature = LedgerTransaction::sign_tx_order(tx_order, tx_hash, None, keypair);
     let accumulator_info = random_accumulator_info();
     let random_sequence_info =
         TransactionSequenceInfo::new(tx_order, tx_order_signature, accumulator_info, 0);
@@ -3,6 +3,7 @@
 
 use super::{RoochTransaction, TransactionSequenceInfo};
 use crate::crypto::{RoochKeyPair, Signature};
+use crate::framework::randomness::TransactionRandomness;
 use crate::{
     address::RoochAddress,
     multichain_id::{MultiChainID, RoochMultiChainID},
@@ -13,7 +14,8 @@ use bitcoin::hashes::Hash;
 use core::fmt;
 use moveos_types::h256;
 use moveos_types::h256::H256;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::fmt::Display;
 
 #[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -155,12 +157,115 @@ impl LedgerTxData {
 }
 
 /// The transaction which is recorded in the L2 DA ledger.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LedgerTransaction {
     pub data: LedgerTxData,
     pub sequence_info: TransactionSequenceInfo,
 }
 
+/// The binary layout of the `LedgerTransaction`.
+/// The first three variants keep the original layout `LedgerTransaction { data, sequence_info }`,
+/// the variant index is the same as the `LedgerTxData` variant index.
+/// The `V2` variant is used when the transaction has the fields added later,
+/// the `tx_randomness` is the randomness of the sequence info.
+#[derive(Serialize, Deserialize)]
+enum RawLedgerTransaction<'a> {
+    L1Block(Cow<'a, L1Block>, Cow<'a, TransactionSequenceInfo>),
+    L1Tx(Cow<'a, L1Transaction>, Cow<'a, TransactionSequenceInfo>),
+    L2Tx(Cow<'a, RoochTransaction>, Cow<'a, TransactionSequenceInfo>),
+    V2 {
+        data: Cow<'a, LedgerTxData>,
+        sequence_info: Cow<'a, TransactionSequenceInfo>,
+        tx_randomness: Option<Cow<'a, TransactionRandomness>>,
+    },
+}
+
+/// The human readable layout of the `LedgerTransaction`.
+#[derive(Serialize, Deserialize)]
+struct ReadableLedgerTransaction<'a> {
+    data: Cow<'a, LedgerTxData>,
+    sequence_info: Cow<'a, TransactionSequenceInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tx_randomness: Option<Cow<'a, TransactionRandomness>>,
+}
+
+impl Serialize for LedgerTransaction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sequence_info = Cow::Borrowed(&self.sequence_info);
+        if serializer.is_human_readable() {
+            return ReadableLedgerTransaction {
+                data: Cow::Borrowed(&self.data),
+                sequence_info,
+                tx_randomness: self.sequence_info.tx_randomness.as_ref().map(Cow::Borrowed),
+            }
+            .serialize(serializer);
+        }
+        let raw = match (&self.data, &self.sequence_info.tx_randomness) {
+            (LedgerTxData::L1Block(block), None) => {
+                RawLedgerTransaction::L1Block(Cow::Borrowed(block), sequence_info)
+            }
+            (LedgerTxData::L1Tx(tx), None) => {
+                RawLedgerTransaction::L1Tx(Cow::Borrowed(tx), sequence_info)
+            }
+            (LedgerTxData::L2Tx(tx), None) => {
+                RawLedgerTransaction::L2Tx(Cow::Borrowed(tx), sequence_info)
+            }
+            (data, Some(tx_randomness)) => RawLedgerTransaction::V2 {
+                data: Cow::Borrowed(data),
+                sequence_info,
+                tx_randomness: Some(Cow::Borrowed(tx_randomness)),
+            },
+        };
+        raw.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LedgerTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let readable = ReadableLedgerTransaction::deserialize(deserializer)?;
+            return Ok(Self {
+                data: readable.data.into_owned(),
+                sequence_info: readable
+                    .sequence_info
+                    .into_owned()
+                    .with_randomness(readable.tx_randomness.map(Cow::into_owned)),
+            });
+        }
+        let (data, sequence_info, tx_randomness) =
+            match RawLedgerTransaction::deserialize(deserializer)? {
+                RawLedgerTransaction::L1Block(block, sequence_info) => (
+                    LedgerTxData::L1Block(block.into_owned()),
+                    sequence_info,
+                    None,
+                ),
+                RawLedgerTransaction::L1Tx(tx, sequence_info) => {
+                    (LedgerTxData::L1Tx(tx.into_owned()), sequence_info, None)
+                }
+                RawLedgerTransaction::L2Tx(tx, sequence_info) => {
+                    (LedgerTxData::L2Tx(tx.into_owned()), sequence_info, None)
+                }
+                RawLedgerTransaction::V2 {
+                    data,
+                    sequence_info,
+                    tx_randomness,
+                } => (data.into_owned(), sequence_info, tx_randomness),
+            };
+        Ok(Self {
+            data,
+            sequence_info: sequence_info
+                .into_owned()
+                .with_randomness(tx_randomness.map(Cow::into_owned)),
+        })
+    }
+}
+
 impl LedgerTransaction {
     pub fn new(data: LedgerTxData, sequence_info: TransactionSequenceInfo) -> Self {
         Self {
@@ -214,22 +319,41 @@ impl LedgerTransaction {
         tx_order: u64,
         tx_order_signature: Vec<u8>,
         tx_accumulator_info: AccumulatorInfo,
+        tx_randomness: Option<TransactionRandomness>,
     ) -> LedgerTransaction {
         let tx_sequence_info = TransactionSequenceInfo::new(
             tx_order,
             tx_order_signature,
             tx_accumulator_info,
             tx_timestamp,
-        );
+        )
+        .with_randomness(tx_randomness);
 
         LedgerTransaction::new(tx_data, tx_sequence_info)
     }
 
-    /// Sign the tx order with the sequencer key.
-    pub fn sign_tx_order(tx_order: u64, tx_hash: H256, sequencer_key: &RoochKeyPair) -> Vec<u8> {
+    /// The witness hash signed by the tx order signature, it covers the randomness if it is committed.
+    pub fn tx_order_witness_hash(
+        tx_order: u64,
+        tx_hash: H256,
+        tx_randomness: Option<&TransactionRandomness>,
+    ) -> H256 {
         let mut witness_data = tx_hash.as_ref().to_vec();
         witness_data.extend(tx_order.to_le_bytes().iter());
-        let witness_hash = h256::sha3_256_of(&witness_data);
+        if let Some(tx_randomness) = tx_randomness {
+            witness_data.extend(tx_randomness.output.iter());
+        }
+        h256::sha3_256_of(&witness_data)
+    }
+
+    /// Sign the tx order and the randomness with the sequencer key.
+    pub fn sign_tx_order(
+        tx_order: u64,
+        tx_hash: H256,
+        tx_randomness: Option<&TransactionRandomness>,
+        sequencer_key: &RoochKeyPair,
+    ) -> Vec<u8> {
+        let witness_hash = Self::tx_order_witness_hash(tx_order, tx_hash, tx_randomness);
         Signature::sign(&witness_hash.0, sequencer_key)
             .as_ref()
             .to_vec()
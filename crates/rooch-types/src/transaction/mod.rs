@@ -13,15 +13,20 @@ use serde::{Deserialize, Serialize};
 
 pub mod authenticator;
 mod ledger_transaction;
+mod proof;
 pub mod rooch;
+mod witness;
 
+use crate::framework::randomness::TransactionRandomness;
 use crate::test_utils::random_accumulator_info;
 pub use authenticator::Authenticator;
 pub use ledger_transaction::{
     L1Block, L1BlockWithBody, L1Transaction, LedgerTransaction, LedgerTxData,
 };
 use moveos_types::test_utils::random_bytes;
+pub use proof::TransactionProof;
 pub use rooch::{RoochTransaction, RoochTransactionData};
+pub use witness::TransactionWitness;
 
 pub const TRANSACTION_SEQUENCE_INFO_STR: &str = "TransactionSequenceInfo";
 
@@ -85,6 +90,11 @@ pub struct TransactionSequenceInfo {
     pub tx_accumulator_num_leaves: u64,
     /// The total number of nodes in the accumulator.
     pub tx_accumulator_num_nodes: u64,
+
+    /// The randomness of the block committed by the sequencer, it is covered by the tx order signature.
+    /// It is encoded with the `LedgerTransaction`, and is not a field of the Move struct.
+    #[serde(skip)]
+    pub tx_randomness: Option<TransactionRandomness>,
 }
 
 impl TransactionSequenceInfo {
@@ -102,9 +112,15 @@ impl TransactionSequenceInfo {
             tx_accumulator_frozen_subtree_roots: tx_accumulator_info.frozen_subtree_roots,
             tx_accumulator_num_leaves: tx_accumulator_info.num_leaves,
             tx_accumulator_num_nodes: tx_accumulator_info.num_nodes,
+            tx_randomness: None,
         }
     }
 
+    pub fn with_randomness(mut self, tx_randomness: Option<TransactionRandomness>) -> Self {
+        self.tx_randomness = tx_randomness;
+        self
+    }
+
     pub fn tx_accumulator_info(&self) -> AccumulatorInfo {
         AccumulatorInfo::new(
             self.tx_accumulator_root,
@@ -143,6 +159,27 @@ impl MoveStructState for TransactionSequenceInfo {
     }
 }
 
+/// `TransactionExpiration` is written to the tx context when the transaction has an expiration timestamp.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionExpiration {
+    /// The transaction is invalid once the on-chain timestamp reaches it, in second.
+    pub expiration_timestamp_secs: u64,
+}
+
+impl MoveStructType for TransactionExpiration {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("transaction");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TransactionExpiration");
+}
+
+impl MoveStructState for TransactionExpiration {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
 /// Transaction with sequence info and execution info.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionWithInfo {
@@ -187,6 +224,207 @@ mod tests {
         test_serialize_deserialize_roundtrip(tx)
     }
 
+    #[test]
+    fn test_serialize_deserialize_sponsored_transaction() {
+        use super::authenticator::Authenticator;
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+        use crate::crypto::RoochKeyPair;
+
+        let tx = RoochTransaction::mock();
+        let legacy_bytes = bcs::to_bytes(&(&tx.data, &tx.authenticator)).unwrap();
+        // The transaction without fee payer keeps the original encoding
+        assert_eq!(tx.encode(), legacy_bytes);
+        assert_eq!(
+            tx.data.tx_hash(),
+            moveos_types::h256::sha3_256_of(&bcs::to_bytes(&tx.data).unwrap())
+        );
+
+        let fee_payer_kp = RoochKeyPair::generate_secp256k1();
+        let data = tx
+            .data
+            .clone()
+            .with_fee_payer(RoochAddress::random())
+            .with_expiration_timestamp_secs(100);
+        assert_ne!(data.tx_hash(), tx.data.tx_hash());
+        assert!(!data.is_expired(99));
+        assert!(data.is_expired(100));
+        assert_eq!(
+            super::RoochTransactionData::decode(&data.encode()).unwrap(),
+            data
+        );
+
+        let mut sponsored_tx = RoochTransaction::new(data.clone(), tx.authenticator.clone());
+        assert!(!sponsored_tx.is_fully_signed());
+        test_serialize_deserialize_roundtrip(sponsored_tx.clone());
+        sponsored_tx.fee_payer_authenticator = Some(Authenticator::bitcoin(&fee_payer_kp, &data));
+        assert!(sponsored_tx.is_fully_signed());
+        test_serialize_deserialize_roundtrip(sponsored_tx);
+    }
+
+    #[test]
+    fn test_transaction_data_extension_versions() {
+        use super::rooch::{PartiallySignedRoochTransaction, RoochTransactionData};
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+
+        let tx = RoochTransaction::mock();
+        let fee_payer = RoochAddress::random();
+        // The extension with the fee payer only keeps the V1 layout
+        let data = tx.data.clone().with_fee_payer(fee_payer);
+        assert_eq!(
+            data.encode(),
+            [tx.data.encode(), bcs::to_bytes(&Some(fee_payer)).unwrap()].concat()
+        );
+        assert_eq!(RoochTransactionData::decode(&data.encode()).unwrap(), data);
+        test_serialize_deserialize_roundtrip(RoochTransaction::new(
+            data.clone(),
+            tx.authenticator.clone(),
+        ));
+
+        // The expiration timestamp needs the V2 layout
+        let data = data.with_expiration_timestamp_secs(100);
+        assert_eq!(
+            data.encode(),
+            [
+                tx.data.encode(),
+                bcs::to_bytes(&(Some(fee_payer), Some(100u64))).unwrap()
+            ]
+            .concat()
+        );
+        assert_eq!(RoochTransactionData::decode(&data.encode()).unwrap(), data);
+        test_serialize_deserialize_roundtrip(RoochTransaction::new(
+            data.clone(),
+            tx.authenticator.clone(),
+        ));
+        let psrt = PartiallySignedRoochTransaction::new(data, 1);
+        assert_eq!(
+            PartiallySignedRoochTransaction::decode(&psrt.encode()).unwrap(),
+            psrt
+        );
+    }
+
+    #[test]
+    fn test_decode_non_canonical_transaction_data() {
+        use super::rooch::{PartiallySignedRoochTransaction, RoochTransactionData};
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+
+        let data = RoochTransaction::mock().data;
+        let fee_payer = RoochAddress::random();
+        let non_canonical = vec![
+            // The empty V1 extension
+            [data.encode(), bcs::to_bytes(&None::<RoochAddress>).unwrap()].concat(),
+            // The V2 extension without the expiration timestamp
+            [
+                data.encode(),
+                bcs::to_bytes(&(Some(fee_payer), None::<u64>)).unwrap(),
+            ]
+            .concat(),
+        ];
+        for bytes in non_canonical {
+            assert!(RoochTransactionData::decode(&bytes).is_err());
+        }
+        assert_eq!(
+            RoochTransactionData::decode(
+                &[data.encode(), bcs::to_bytes(&Some(fee_payer)).unwrap()].concat()
+            )
+            .unwrap(),
+            data.clone().with_fee_payer(fee_payer)
+        );
+
+        // The PSRT with the empty extension and without the fee payer's signature
+        let psrt = PartiallySignedRoochTransaction::new(data, 1);
+        let bytes = [
+            psrt.encode(),
+            bcs::to_bytes(&(None::<RoochAddress>, None::<u8>)).unwrap(),
+        ]
+        .concat();
+        assert!(PartiallySignedRoochTransaction::decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_non_canonical_transaction_envelope() {
+        use super::authenticator::Authenticator;
+        use super::rooch::TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID;
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+
+        let tx = RoochTransaction::mock();
+        let auth_bytes = bcs::to_bytes(&tx.authenticator).unwrap();
+        let encode_with_envelope = |payload: Vec<u8>| {
+            let envelope = Authenticator::new(TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID, payload);
+            bcs::to_bytes(&(&tx.data, envelope)).unwrap()
+        };
+        let fee_payer = RoochAddress::random();
+        let non_canonical = vec![
+            // The V1 envelope with the empty extension and without the fee payer's signature
+            [vec![0u8, 0u8], auth_bytes.clone(), vec![0u8]].concat(),
+            // The V2 envelope without the expiration timestamp
+            [
+                vec![1u8],
+                bcs::to_bytes(&(Some(fee_payer), None::<u64>)).unwrap(),
+                auth_bytes.clone(),
+                vec![0u8],
+            ]
+            .concat(),
+            // The envelope wraps another envelope
+            [
+                vec![0u8, 0u8],
+                bcs::to_bytes(&Authenticator::new(
+                    TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID,
+                    [vec![0u8, 0u8], auth_bytes.clone(), vec![0u8]].concat(),
+                ))
+                .unwrap(),
+                vec![0u8],
+            ]
+            .concat(),
+        ];
+        for payload in non_canonical {
+            assert!(RoochTransaction::decode(&encode_with_envelope(payload)).is_err());
+        }
+
+        // The canonical V1 envelope with the fee payer
+        let bytes = encode_with_envelope(
+            [
+                vec![0u8],
+                bcs::to_bytes(&Some(fee_payer)).unwrap(),
+                auth_bytes,
+                vec![0u8],
+            ]
+            .concat(),
+        );
+        let decoded = RoochTransaction::decode(&bytes).unwrap();
+        assert_eq!(decoded.fee_payer(), Some(fee_payer));
+        assert_eq!(decoded.encode(), bytes);
+    }
+
+    #[test]
+    fn test_serde_transaction_data_extension() {
+        use super::rooch::{PartiallySignedRoochTransaction, RoochTransactionData};
+        use crate::address::{RoochAddress, RoochSupportedAddress};
+
+        let data = RoochTransaction::mock()
+            .data
+            .with_fee_payer(RoochAddress::random());
+        // The human-readable format keeps the extension
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RoochTransactionData>(&json).unwrap(),
+            data
+        );
+        // The binary format appends the extension to the original fields
+        assert_eq!(bcs::to_bytes(&data).unwrap(), data.encode());
+
+        let psrt = PartiallySignedRoochTransaction::new(data, 1);
+        let json = serde_json::to_string(&psrt).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PartiallySignedRoochTransaction>(&json).unwrap(),
+            psrt
+        );
+        assert_eq!(bcs::to_bytes(&psrt).unwrap(), psrt.encode());
+        assert_eq!(
+            PartiallySignedRoochTransaction::decode(&psrt.encode()).unwrap(),
+            psrt
+        );
+    }
+
     #[test]
     fn test_serialize_deserialize_transaction_sequence_info() {
         let tx_order_signature = random_bytes();
@@ -203,4 +441,60 @@ mod tests {
         //     _h256_bcs_bytes.len()
         // );
     }
+
+    #[test]
+    fn test_serialize_deserialize_ledger_transaction_randomness() {
+        use super::LedgerTransaction;
+        use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
+        use crate::framework::randomness::{RandomnessKeyPair, TransactionRandomness};
+        use crate::test_utils::random_ledger_transaction;
+        use fastcrypto::traits::ToFromBytes;
+
+        let tx = random_ledger_transaction();
+        // The transaction without randomness keeps the original encoding
+        let bytes = bcs::to_bytes(&tx).unwrap();
+        assert_eq!(
+            bytes,
+            bcs::to_bytes(&(&tx.data, &tx.sequence_info)).unwrap()
+        );
+        assert_eq!(bcs::from_bytes::<LedgerTransaction>(&bytes).unwrap(), tx);
+
+        let mut tx_with_randomness = tx.clone();
+        tx_with_randomness.sequence_info.tx_randomness =
+            Some(TransactionRandomness::new(random_bytes(), random_bytes()));
+        let bytes = bcs::to_bytes(&tx_with_randomness).unwrap();
+        assert_eq!(
+            bcs::from_bytes::<LedgerTransaction>(&bytes).unwrap(),
+            tx_with_randomness
+        );
+        // The randomness is not a field of the Move struct
+        assert_eq!(
+            bcs::to_bytes(&tx_with_randomness.sequence_info).unwrap(),
+            bcs::to_bytes(&tx.sequence_info).unwrap()
+        );
+
+        for tx in [tx, tx_with_randomness] {
+            let json = serde_json::to_string(&tx).unwrap();
+            assert_eq!(
+                serde_json::from_str::<LedgerTransaction>(&json).unwrap(),
+                tx
+            );
+        }
+
+        // The tx order signature covers the randomness
+        let sequencer_key = RoochKeyPair::generate_secp256k1();
+        let randomness = RandomnessKeyPair::generate().generate_randomness(0);
+        let tx_hash = moveos_types::h256::H256::random();
+        let witness_hash = LedgerTransaction::tx_order_witness_hash(1, tx_hash, Some(&randomness));
+        assert_ne!(
+            witness_hash,
+            LedgerTransaction::tx_order_witness_hash(1, tx_hash, None)
+        );
+        let signature =
+            LedgerTransaction::sign_tx_order(1, tx_hash, Some(&randomness), &sequencer_key);
+        Signature::from_bytes(&signature)
+            .unwrap()
+            .verify(witness_hash.as_bytes())
+            .unwrap();
+    }
 }
@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use accumulator::proof::AccumulatorProof;
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+
+/// The inclusion proof of a transaction in the transaction accumulator.
+/// The leaf of the accumulator is the tx hash and the leaf index is the tx order,
+/// the root is the tx accumulator root after the transaction of the `anchor_order` is appended.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionProof {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    /// The tx order of the transaction whose tx accumulator root is used as the proof root.
+    pub anchor_order: u64,
+    pub tx_accumulator_root: H256,
+    pub proof: AccumulatorProof,
+}
+
+impl TransactionProof {
+    pub fn new(
+        tx_order: u64,
+        tx_hash: H256,
+        anchor_order: u64,
+        tx_accumulator_root: H256,
+        proof: AccumulatorProof,
+    ) -> Self {
+        Self {
+            tx_order,
+            tx_hash,
+            anchor_order,
+            tx_accumulator_root,
+            proof,
+        }
+    }
+
+    /// Verify the transaction is included in the accumulator with the root of this proof.
+    pub fn verify(&self) -> Result<()> {
+        self.verify_root(self.tx_accumulator_root)
+    }
+
+    /// Verify the transaction is included in the accumulator with the `expected_root`,
+    /// the `expected_root` should come from a trusted source, such as a block committed by the proposer.
+    pub fn verify_root(&self, expected_root: H256) -> Result<()> {
+        ensure!(
+            self.tx_order <= self.anchor_order,
+            "The tx order {} is greater than the anchor order {}",
+            self.tx_order,
+            self.anchor_order
+        );
+        // The leaf index should be in the range of the tree, otherwise the proof can be reused for other orders
+        let depth = self.proof.siblings.len();
+        ensure!(
+            depth >= u64::BITS as usize || self.tx_order >> depth == 0,
+            "The tx order {} is out of the range of the proof with {} siblings",
+            self.tx_order,
+            depth
+        );
+        self.proof
+            .verify(expected_root, self.tx_hash, self.tx_order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::tree_store::mock::MockAccumulatorStore;
+    use accumulator::{Accumulator, MerkleAccumulator};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_transaction_proof() {
+        let store = Arc::new(MockAccumulatorStore::new());
+        let accumulator = MerkleAccumulator::new_empty(store.clone());
+        let tx_hashes = (0..5).map(|_| H256::random()).collect::<Vec<_>>();
+        accumulator.append(&tx_hashes[..3]).unwrap();
+        accumulator.flush().unwrap();
+        let anchor_info = accumulator.get_info();
+        accumulator.append(&tx_hashes[3..]).unwrap();
+        accumulator.flush().unwrap();
+
+        // Prove against the latest root
+        let proof = accumulator.get_proof(1).unwrap().unwrap();
+        let tx_proof =
+            TransactionProof::new(1, tx_hashes[1], 4, accumulator.root_hash(), proof.clone());
+        tx_proof.verify().unwrap();
+        assert!(tx_proof.verify_root(anchor_info.accumulator_root).is_err());
+
+        // Prove against a historic root
+        let anchor_root = anchor_info.accumulator_root;
+        let anchor_accumulator = MerkleAccumulator::new_with_info(anchor_info, store);
+        let proof = anchor_accumulator.get_proof(2).unwrap().unwrap();
+        let tx_proof = TransactionProof::new(2, tx_hashes[2], 2, anchor_root, proof);
+        tx_proof.verify().unwrap();
+
+        let invalid_proof =
+            TransactionProof::new(2, tx_hashes[1], 2, anchor_root, tx_proof.proof.clone());
+        assert!(invalid_proof.verify().is_err());
+        let out_of_range_proof =
+            TransactionProof::new(6, tx_hashes[2], 6, anchor_root, tx_proof.proof);
+        assert!(out_of_range_proof.verify().is_err());
+    }
+}
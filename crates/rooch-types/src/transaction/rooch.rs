@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::authenticator::{BitcoinAuthenticator, BitcoinMultisignAuthenticator};
-use super::RawTransaction;
 use super::{authenticator::Authenticator, AuthenticatorInfo};
+use super::{RawTransaction, TransactionExpiration};
 use crate::address::RoochAddress;
 use crate::crypto::RoochKeyPair;
 use crate::rooch_network::BuiltinChainID;
@@ -11,14 +11,90 @@ use anyhow::Result;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::tx_context::GasPaymentAccount;
 use moveos_types::{
     moveos_std::tx_context::TxContext,
     transaction::{MoveAction, MoveOSTransaction},
 };
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+/// The reserved auth validator id of the transaction envelope.
+/// When a transaction carries a data extension or a fee payer authenticator, the authenticator slot of the
+/// original transaction layout is replaced with a `RoochTransactionEnvelope` under this id,
+/// so the transactions without these fields keep the same BCS encoding.
+pub const TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID: u64 = u64::MAX;
+
+/// The optional fields of the transaction data which are not in the original transaction data layout.
+/// The extension is appended to the encoded transaction data when it is not empty,
+/// so it is covered by the transaction hash and the signatures.
+/// It is the V2 layout of the extension, the V1 layout is used when the V2 fields are not set.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RoochTransactionDataExtension {
+    /// The account which pays the gas of the transaction, it must co-sign the transaction
+    /// with the Bitcoin auth validator, the session key can not sign for the fee payer.
+    pub fee_payer: Option<RoochAddress>,
+    /// The transaction is invalid once the on-chain timestamp reaches it, in second.
+    pub expiration_timestamp_secs: Option<u64>,
+}
+
+/// The V1 layout of the transaction data extension, it only has the fee payer.
+#[derive(Serialize, Deserialize)]
+struct RoochTransactionDataExtensionV1 {
+    fee_payer: Option<RoochAddress>,
+}
+
+impl From<RoochTransactionDataExtensionV1> for RoochTransactionDataExtension {
+    fn from(extension: RoochTransactionDataExtensionV1) -> Self {
+        Self {
+            fee_payer: extension.fee_payer,
+            expiration_timestamp_secs: None,
+        }
+    }
+}
+
+impl RoochTransactionDataExtension {
+    pub fn is_empty(&self) -> bool {
+        self.fee_payer.is_none() && self.expiration_timestamp_secs.is_none()
+    }
+
+    /// Whether the extension can be encoded in the V1 layout
+    fn is_v1(&self) -> bool {
+        self.expiration_timestamp_secs.is_none()
+    }
+
+    fn to_v1(&self) -> RoochTransactionDataExtensionV1 {
+        RoochTransactionDataExtensionV1 {
+            fee_payer: self.fee_payer,
+        }
+    }
+}
+
+/// Decode the bytes of `T` followed by the extension in the V1 or V2 layout.
+fn decode_with_extension<T, V1, V2>(bytes: &[u8]) -> Result<(T, V2)>
+where
+    T: DeserializeOwned,
+    V1: DeserializeOwned + Into<V2>,
+    V2: DeserializeOwned,
+{
+    match bcs::from_bytes::<(T, V1)>(bytes) {
+        Ok((value, extension)) => Ok((value, extension.into())),
+        Err(_) => Ok(bcs::from_bytes::<(T, V2)>(bytes)?),
+    }
+}
+
+/// The empty extension or the V2 layout without the V2 fields can also be decoded,
+/// reject them by comparing the bytes with the re-encoded value.
+fn ensure_canonical(encoded: &[u8], bytes: &[u8], name: &str) -> Result<()> {
+    if encoded != bytes {
+        return Err(anyhow::anyhow!("Non-canonical {} encoding", name));
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransactionData {
     /// Sender's address.
     pub sender: RoochAddress,
@@ -30,6 +106,85 @@ pub struct RoochTransactionData {
     pub max_gas_amount: u64,
     // The MoveAction to execute.
     pub action: MoveAction,
+    // The optional fields, they are encoded after the original fields by `encode`.
+    pub extension: RoochTransactionDataExtension,
+}
+
+/// The original transaction data layout, without the extension.
+#[derive(Serialize, Deserialize)]
+struct RawRoochTransactionData<'a> {
+    sender: RoochAddress,
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    action: Cow<'a, MoveAction>,
+}
+
+/// The human-readable transaction data layout, the extension is a field of it.
+#[derive(Serialize, Deserialize)]
+struct HumanReadableRoochTransactionData<'a> {
+    sender: RoochAddress,
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    action: Cow<'a, MoveAction>,
+    #[serde(default, skip_serializing_if = "is_empty_extension")]
+    extension: Cow<'a, RoochTransactionDataExtension>,
+}
+
+fn is_empty_extension(extension: &Cow<'_, RoochTransactionDataExtension>) -> bool {
+    extension.is_empty()
+}
+
+impl Serialize for RoochTransactionData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            HumanReadableRoochTransactionData {
+                sender: self.sender,
+                sequence_number: self.sequence_number,
+                chain_id: self.chain_id,
+                max_gas_amount: self.max_gas_amount,
+                action: Cow::Borrowed(&self.action),
+                extension: Cow::Borrowed(&self.extension),
+            }
+            .serialize(serializer)
+        } else {
+            // The extension is appended to the original fields, the same bytes as `encode`.
+            if self.extension.is_empty() {
+                self.raw().serialize(serializer)
+            } else if self.extension.is_v1() {
+                (self.raw(), self.extension.to_v1()).serialize(serializer)
+            } else {
+                (self.raw(), &self.extension).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransactionData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let data = HumanReadableRoochTransactionData::deserialize(deserializer)?;
+            Ok(Self {
+                sender: data.sender,
+                sequence_number: data.sequence_number,
+                chain_id: data.chain_id,
+                max_gas_amount: data.max_gas_amount,
+                action: data.action.into_owned(),
+                extension: data.extension.into_owned(),
+            })
+        } else {
+            // The binary layout can not tell whether the extension follows the original fields,
+            // the data with extension is decoded by `decode`.
+            RawRoochTransactionData::deserialize(deserializer).map(Self::from_raw)
+        }
+    }
 }
 
 impl RoochTransactionData {
@@ -46,6 +201,7 @@ impl RoochTransactionData {
             chain_id,
             max_gas_amount,
             action,
+            extension: RoochTransactionDataExtension::default(),
         }
     }
 
@@ -56,18 +212,90 @@ impl RoochTransactionData {
             chain_id: BuiltinChainID::Local.chain_id().id(),
             max_gas_amount: GasScheduleConfig::INITIAL_MAX_GAS_AMOUNT,
             action,
+            extension: RoochTransactionDataExtension::default(),
+        }
+    }
+
+    fn raw(&self) -> RawRoochTransactionData<'_> {
+        RawRoochTransactionData {
+            sender: self.sender,
+            sequence_number: self.sequence_number,
+            chain_id: self.chain_id,
+            max_gas_amount: self.max_gas_amount,
+            action: Cow::Borrowed(&self.action),
+        }
+    }
+
+    fn from_raw(raw: RawRoochTransactionData<'_>) -> Self {
+        Self {
+            sender: raw.sender,
+            sequence_number: raw.sequence_number,
+            chain_id: raw.chain_id,
+            max_gas_amount: raw.max_gas_amount,
+            action: raw.action.into_owned(),
+            extension: RoochTransactionDataExtension::default(),
         }
     }
 
+    /// Set the fee payer of the transaction, the fee payer pays the gas and must co-sign the transaction
+    /// with its Bitcoin key, see `RoochTransaction::sign_as_fee_payer`.
+    pub fn with_fee_payer(mut self, fee_payer: RoochAddress) -> Self {
+        self.extension.fee_payer = Some(fee_payer);
+        self
+    }
+
+    pub fn fee_payer(&self) -> Option<RoochAddress> {
+        self.extension.fee_payer
+    }
+
+    /// Set the expiration timestamp of the transaction, in second.
+    pub fn with_expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.extension.expiration_timestamp_secs = Some(expiration_timestamp_secs);
+        self
+    }
+
+    pub fn expiration_timestamp_secs(&self) -> Option<u64> {
+        self.extension.expiration_timestamp_secs
+    }
+
+    /// Check if the transaction is expired at the given timestamp, in second.
+    pub fn is_expired(&self, now_secs: u64) -> bool {
+        self.expiration_timestamp_secs()
+            .map(|expiration| now_secs >= expiration)
+            .unwrap_or(false)
+    }
+
+    /// The gas payment account of the transaction, the fee payer if present, otherwise the sender.
+    pub fn gas_payment_account(&self) -> RoochAddress {
+        self.fee_payer().unwrap_or(self.sender)
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         bcs::to_bytes(self).expect("encode transaction should success")
     }
 
+    /// Decode the transaction data, the extension is decoded if it follows the original fields.
+    /// Only the canonical encoding is accepted, so a transaction data has exactly one encoding and hash.
     pub fn decode(bytes: &[u8]) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
-        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+        match bcs::from_bytes::<RawRoochTransactionData>(bytes) {
+            Ok(raw) => Ok(Self::from_raw(raw)),
+            Err(e) => {
+                // The transaction data with extension
+                let (raw, extension) = decode_with_extension::<
+                    RawRoochTransactionData,
+                    RoochTransactionDataExtensionV1,
+                    RoochTransactionDataExtension,
+                >(bytes)
+                .map_err(|_| e)?;
+                let mut data = Self::from_raw(raw);
+                data.extension = extension;
+                ensure_canonical(&data.encode(), bytes, "transaction data")?;
+                Ok(data)
+            }
+        }
     }
 
     pub fn tx_hash(&self) -> H256 {
@@ -75,7 +303,7 @@ impl RoochTransactionData {
     }
 
     pub fn tx_size(&self) -> u64 {
-        bcs::serialized_size(self).expect("serialize transaction size should success") as u64
+        self.encode().len() as u64
     }
 
     pub fn sign(&self, kp: &RoochKeyPair) -> RoochTransaction {
@@ -88,21 +316,127 @@ impl Display for RoochTransactionData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {} }}",
+            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {}",
             self.sender, self.sequence_number, self.chain_id, self.max_gas_amount, self.action
-        )
+        )?;
+        if let Some(fee_payer) = self.fee_payer() {
+            write!(f, ", fee_payer: {}", fee_payer)?;
+        }
+        if let Some(expiration_timestamp_secs) = self.expiration_timestamp_secs() {
+            write!(
+                f,
+                ", expiration_timestamp_secs: {}",
+                expiration_timestamp_secs
+            )?;
+        }
+        write!(f, " }}")
     }
 }
 
 /// PartiallySignedRoochTransaction(PSRT) is a transaction that has been signed by partial signers.
-/// It can be used for multi-signatures.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// It can be used for multi-signatures, and for the sponsored transaction which needs the fee payer's signature.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PartiallySignedRoochTransaction {
     pub data: RoochTransactionData,
     /// The threshold of the signatures.
     pub threshold: u64,
     /// The signatures of the partial signers.
     pub authenticators: Vec<BitcoinAuthenticator>,
+    /// The signature of the fee payer, it is encoded after the original fields by `encode`.
+    pub fee_payer_authenticator: Option<BitcoinAuthenticator>,
+}
+
+/// The original PSRT layout, without the extension.
+#[derive(Serialize, Deserialize)]
+struct RawPartiallySignedRoochTransaction<'a> {
+    data: RawRoochTransactionData<'a>,
+    threshold: u64,
+    authenticators: Cow<'a, Vec<BitcoinAuthenticator>>,
+}
+
+/// The human-readable PSRT layout, the fee payer's signature is a field of it.
+#[derive(Serialize, Deserialize)]
+struct HumanReadablePartiallySignedRoochTransaction<'a> {
+    data: Cow<'a, RoochTransactionData>,
+    threshold: u64,
+    authenticators: Cow<'a, Vec<BitcoinAuthenticator>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fee_payer_authenticator: Option<Cow<'a, BitcoinAuthenticator>>,
+}
+
+impl Serialize for PartiallySignedRoochTransaction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            HumanReadablePartiallySignedRoochTransaction {
+                data: Cow::Borrowed(&self.data),
+                threshold: self.threshold,
+                authenticators: Cow::Borrowed(&self.authenticators),
+                fee_payer_authenticator: self.fee_payer_authenticator.as_ref().map(Cow::Borrowed),
+            }
+            .serialize(serializer)
+        } else {
+            // Same as the transaction data, the extension is appended to the original fields.
+            if self.data.extension.is_empty() && self.fee_payer_authenticator.is_none() {
+                self.raw().serialize(serializer)
+            } else if self.data.extension.is_v1() {
+                let extension = PartiallySignedRoochTransactionExtension {
+                    data_extension: self.data.extension.to_v1(),
+                    fee_payer_authenticator: self.fee_payer_authenticator.clone(),
+                };
+                (self.raw(), extension).serialize(serializer)
+            } else {
+                let extension = PartiallySignedRoochTransactionExtension {
+                    data_extension: self.data.extension.clone(),
+                    fee_payer_authenticator: self.fee_payer_authenticator.clone(),
+                };
+                (self.raw(), extension).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PartiallySignedRoochTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let psrt = HumanReadablePartiallySignedRoochTransaction::deserialize(deserializer)?;
+            Ok(Self {
+                data: psrt.data.into_owned(),
+                threshold: psrt.threshold,
+                authenticators: psrt.authenticators.into_owned(),
+                fee_payer_authenticator: psrt.fee_payer_authenticator.map(Cow::into_owned),
+            })
+        } else {
+            // Same as the transaction data, the PSRT with extension is decoded by `decode`.
+            RawPartiallySignedRoochTransaction::deserialize(deserializer).map(Self::from_raw)
+        }
+    }
+}
+
+/// The fields of the PSRT which are appended to the original PSRT encoding,
+/// the data extension is in the V1 or V2 layout.
+#[derive(Serialize, Deserialize)]
+struct PartiallySignedRoochTransactionExtension<E> {
+    data_extension: E,
+    fee_payer_authenticator: Option<BitcoinAuthenticator>,
+}
+
+impl From<PartiallySignedRoochTransactionExtension<RoochTransactionDataExtensionV1>>
+    for PartiallySignedRoochTransactionExtension<RoochTransactionDataExtension>
+{
+    fn from(
+        extension: PartiallySignedRoochTransactionExtension<RoochTransactionDataExtensionV1>,
+    ) -> Self {
+        Self {
+            data_extension: extension.data_extension.into(),
+            fee_payer_authenticator: extension.fee_payer_authenticator,
+        }
+    }
 }
 
 impl PartiallySignedRoochTransaction {
@@ -111,6 +445,24 @@ impl PartiallySignedRoochTransaction {
             data,
             threshold,
             authenticators: vec![],
+            fee_payer_authenticator: None,
+        }
+    }
+
+    fn raw(&self) -> RawPartiallySignedRoochTransaction<'_> {
+        RawPartiallySignedRoochTransaction {
+            data: self.data.raw(),
+            threshold: self.threshold,
+            authenticators: Cow::Borrowed(&self.authenticators),
+        }
+    }
+
+    fn from_raw(raw: RawPartiallySignedRoochTransaction<'_>) -> Self {
+        Self {
+            data: RoochTransactionData::from_raw(raw.data),
+            threshold: raw.threshold,
+            authenticators: raw.authenticators.into_owned(),
+            fee_payer_authenticator: None,
         }
     }
 
@@ -118,8 +470,12 @@ impl PartiallySignedRoochTransaction {
         self.data.sender
     }
 
+    pub fn fee_payer(&self) -> Option<RoochAddress> {
+        self.data.fee_payer()
+    }
+
     pub fn signatories(&self) -> usize {
-        self.authenticators.len()
+        self.authenticators.len() + self.fee_payer_authenticator.iter().count()
     }
 
     pub fn contains_authenticator(&self, authenticator: &BitcoinAuthenticator) -> bool {
@@ -139,12 +495,32 @@ impl PartiallySignedRoochTransaction {
         Ok(())
     }
 
+    pub fn set_fee_payer_authenticator(
+        &mut self,
+        authenticator: BitcoinAuthenticator,
+    ) -> Result<()> {
+        if self.fee_payer().is_none() {
+            return Err(anyhow::anyhow!("The transaction does not have a fee payer"));
+        }
+        if self.fee_payer_authenticator.is_some() {
+            return Err(anyhow::anyhow!(
+                "The fee payer has already signed the transaction"
+            ));
+        }
+        self.fee_payer_authenticator = Some(authenticator);
+        Ok(())
+    }
+
     pub fn threshold(&self) -> u64 {
         self.threshold
     }
 
+    pub fn is_fee_payer_signed(&self) -> bool {
+        self.fee_payer().is_none() || self.fee_payer_authenticator.is_some()
+    }
+
     pub fn is_fully_signed(&self) -> bool {
-        self.authenticators.len() as u64 >= self.threshold
+        self.authenticators.len() as u64 >= self.threshold && self.is_fee_payer_signed()
     }
 
     pub fn try_into_rooch_transaction(self) -> Result<RoochTransaction> {
@@ -157,28 +533,167 @@ impl PartiallySignedRoochTransaction {
         let authenticator =
             BitcoinMultisignAuthenticator::build_multisig_authenticator(self.authenticators)?
                 .into();
-        Ok(RoochTransaction::new(self.data, authenticator))
+        let mut tx = RoochTransaction::new(self.data, authenticator);
+        tx.fee_payer_authenticator = self.fee_payer_authenticator.map(Into::into);
+        Ok(tx)
     }
 
     pub fn encode(&self) -> Vec<u8> {
         bcs::to_bytes(self).expect("encode transaction should success")
     }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        match bcs::from_bytes::<RawPartiallySignedRoochTransaction>(bytes) {
+            Ok(raw) => Ok(Self::from_raw(raw)),
+            Err(e) => {
+                let (raw, extension) = decode_with_extension::<
+                    RawPartiallySignedRoochTransaction,
+                    PartiallySignedRoochTransactionExtension<RoochTransactionDataExtensionV1>,
+                    PartiallySignedRoochTransactionExtension<RoochTransactionDataExtension>,
+                >(bytes)
+                .map_err(|_| e)?;
+                let mut psrt = Self::from_raw(raw);
+                psrt.data.extension = extension.data_extension;
+                psrt.fee_payer_authenticator = extension.fee_payer_authenticator;
+                ensure_canonical(&psrt.encode(), bytes, "PSRT")?;
+                Ok(psrt)
+            }
+        }
+    }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransaction {
     pub data: RoochTransactionData,
     pub authenticator: Authenticator,
+    /// The authenticator of the fee payer, only for the sponsored transaction.
+    /// It must be a Bitcoin authenticator, the other auth validators are rejected by the transaction validator.
+    pub fee_payer_authenticator: Option<Authenticator>,
 
-    #[serde(skip_serializing, skip_deserializing)]
     data_hash: Option<H256>,
 }
 
+/// The versioned envelope which carries the fields that are not in the original transaction layout.
+#[derive(Serialize, Deserialize)]
+enum RoochTransactionEnvelope {
+    V1 {
+        data_extension: RoochTransactionDataExtensionV1,
+        authenticator: Authenticator,
+        fee_payer_authenticator: Option<Authenticator>,
+    },
+    /// The data extension with the expiration timestamp.
+    V2 {
+        data_extension: RoochTransactionDataExtension,
+        authenticator: Authenticator,
+        fee_payer_authenticator: Option<Authenticator>,
+    },
+}
+
+/// The original transaction layout
+#[derive(Serialize, Deserialize)]
+struct RawRoochTransaction<'a> {
+    data: RawRoochTransactionData<'a>,
+    authenticator: Cow<'a, Authenticator>,
+}
+
+impl RoochTransaction {
+    /// The authenticator in the original transaction layout,
+    /// it is the envelope if the transaction has the data extension or the fee payer's authenticator.
+    fn encoded_authenticator(&self) -> Result<Cow<'_, Authenticator>, bcs::Error> {
+        if self.data.extension.is_empty() && self.fee_payer_authenticator.is_none() {
+            return Ok(Cow::Borrowed(&self.authenticator));
+        }
+        let authenticator = self.authenticator.clone();
+        let fee_payer_authenticator = self.fee_payer_authenticator.clone();
+        let envelope = if self.data.extension.is_v1() {
+            RoochTransactionEnvelope::V1 {
+                data_extension: self.data.extension.to_v1(),
+                authenticator,
+                fee_payer_authenticator,
+            }
+        } else {
+            RoochTransactionEnvelope::V2 {
+                data_extension: self.data.extension.clone(),
+                authenticator,
+                fee_payer_authenticator,
+            }
+        };
+        let payload = bcs::to_bytes(&envelope)?;
+        Ok(Cow::Owned(Authenticator::new(
+            TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID,
+            payload,
+        )))
+    }
+}
+
+impl Serialize for RoochTransaction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let authenticator = self
+            .encoded_authenticator()
+            .map_err(serde::ser::Error::custom)?;
+        RawRoochTransaction {
+            data: self.data.raw(),
+            authenticator,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawRoochTransaction::deserialize(deserializer)?;
+        let mut data = RoochTransactionData::from_raw(raw.data);
+        let encoded_authenticator = raw.authenticator.into_owned();
+        if encoded_authenticator.auth_validator_id != TRANSACTION_ENVELOPE_AUTH_VALIDATOR_ID {
+            return Ok(RoochTransaction::new(data, encoded_authenticator));
+        }
+        let envelope = bcs::from_bytes::<RoochTransactionEnvelope>(&encoded_authenticator.payload)
+            .map_err(serde::de::Error::custom)?;
+        let (data_extension, authenticator, fee_payer_authenticator) = match envelope {
+            RoochTransactionEnvelope::V1 {
+                data_extension,
+                authenticator,
+                fee_payer_authenticator,
+            } => (
+                data_extension.into(),
+                authenticator,
+                fee_payer_authenticator,
+            ),
+            RoochTransactionEnvelope::V2 {
+                data_extension,
+                authenticator,
+                fee_payer_authenticator,
+            } => (data_extension, authenticator, fee_payer_authenticator),
+        };
+        data.extension = data_extension;
+        let mut tx = RoochTransaction::new(data, authenticator);
+        tx.fee_payer_authenticator = fee_payer_authenticator;
+        // The envelope of the empty extension, the V2 envelope without the V2 fields,
+        // or the nested envelope can also be decoded, only the canonical envelope is accepted.
+        let canonical = tx
+            .encoded_authenticator()
+            .map_err(serde::de::Error::custom)?;
+        if *canonical != encoded_authenticator {
+            return Err(serde::de::Error::custom(
+                "Non-canonical transaction envelope encoding",
+            ));
+        }
+        Ok(tx)
+    }
+}
+
 impl RoochTransaction {
     pub fn new(data: RoochTransactionData, authenticator: Authenticator) -> Self {
         Self {
             data,
             authenticator,
+            fee_payer_authenticator: None,
             data_hash: None,
         }
     }
@@ -191,6 +706,7 @@ impl RoochTransaction {
         Self {
             data: RoochTransactionData::new(genesis_address, 0, chain_id, u64::MAX, action),
             authenticator: Authenticator::genesis(),
+            fee_payer_authenticator: None,
             data_hash: None,
         }
     }
@@ -199,6 +715,10 @@ impl RoochTransaction {
         self.data.sender
     }
 
+    pub fn fee_payer(&self) -> Option<RoochAddress> {
+        self.data.fee_payer()
+    }
+
     pub fn sequence_number(&self) -> u64 {
         self.data.sequence_number
     }
@@ -244,6 +764,28 @@ impl RoochTransaction {
         &self.authenticator
     }
 
+    /// The fee payer's authenticator info, return `None` if the transaction is not sponsored
+    /// or the fee payer has not signed the transaction.
+    pub fn fee_payer_authenticator_info(&self) -> Option<AuthenticatorInfo> {
+        self.fee_payer_authenticator
+            .as_ref()
+            .map(|auth| AuthenticatorInfo::new(self.chain_id(), auth.clone()))
+    }
+
+    /// Add the fee payer's signature to the sponsored transaction, the fee payer signs with the Bitcoin auth validator
+    pub fn sign_as_fee_payer(&mut self, kp: &RoochKeyPair) -> Result<()> {
+        if self.fee_payer().is_none() {
+            return Err(anyhow::anyhow!("The transaction does not have a fee payer"));
+        }
+        self.fee_payer_authenticator = Some(Authenticator::bitcoin(kp, &self.data));
+        Ok(())
+    }
+
+    /// Whether the transaction is signed by all the required signers
+    pub fn is_fully_signed(&self) -> bool {
+        self.fee_payer().is_none() || self.fee_payer_authenticator.is_some()
+    }
+
     pub fn tx_size(&self) -> u64 {
         bcs::serialized_size(self).expect("serialize transaction size should success") as u64
     }
@@ -278,13 +820,28 @@ impl RoochTransaction {
     pub fn into_moveos_transaction(mut self, root: ObjectMeta) -> MoveOSTransaction {
         let tx_hash = self.tx_hash();
         let tx_size = self.tx_size();
-        let tx_ctx = TxContext::new(
+        let mut tx_ctx = TxContext::new(
             self.data.sender.into(),
             self.data.sequence_number,
             self.data.max_gas_amount,
             tx_hash,
             tx_size,
         );
+        if let Some(fee_payer) = self.data.fee_payer() {
+            tx_ctx
+                .add(GasPaymentAccount {
+                    account: fee_payer.into(),
+                    pay_gas_by_module_account: false,
+                })
+                .expect("add gas payment account to the tx context should success");
+        }
+        if let Some(expiration_timestamp_secs) = self.data.expiration_timestamp_secs() {
+            tx_ctx
+                .add(TransactionExpiration {
+                    expiration_timestamp_secs,
+                })
+                .expect("add transaction expiration to the tx context should success");
+        }
         MoveOSTransaction::new(root, tx_ctx, self.data.action)
     }
 }
@@ -302,8 +859,12 @@ impl Display for RoochTransaction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "RoochTransaction {{ data: {}, authenticator: {}, data_hash {:?} }}",
-            self.data, self.authenticator, self.data_hash
-        )
+            "RoochTransaction {{ data: {}, authenticator: {}, ",
+            self.data, self.authenticator
+        )?;
+        if let Some(fee_payer_authenticator) = &self.fee_payer_authenticator {
+            write!(f, "fee_payer_authenticator: {}, ", fee_payer_authenticator)?;
+        }
+        write!(f, "data_hash {:?} }}", self.data_hash)
     }
 }
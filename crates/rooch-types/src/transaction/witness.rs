@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::LedgerTransaction;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectMeta;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The witness to re-execute a single transaction without the full state.
+/// It contains the state nodes read during the execution, so a verifier can replay the transaction
+/// from the `pre_state_root` and check the execution result is the `post_state_root`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionWitness {
+    pub transaction: LedgerTransaction,
+    pub pre_state_root: H256,
+    pub pre_size: u64,
+    pub post_state_root: H256,
+    pub post_size: u64,
+    /// The state nodes read during the execution, the key is the node hash.
+    pub nodes: BTreeMap<H256, Vec<u8>>,
+}
+
+impl TransactionWitness {
+    pub fn new(
+        transaction: LedgerTransaction,
+        pre_state_root: H256,
+        pre_size: u64,
+        post_state_root: H256,
+        post_size: u64,
+        nodes: BTreeMap<H256, Vec<u8>>,
+    ) -> Self {
+        Self {
+            transaction,
+            pre_state_root,
+            pre_size,
+            post_state_root,
+            post_size,
+            nodes,
+        }
+    }
+
+    pub fn tx_order(&self) -> u64 {
+        self.transaction.sequence_info.tx_order
+    }
+
+    pub fn pre_root(&self) -> ObjectMeta {
+        ObjectMeta::root_metadata(self.pre_state_root, self.pre_size)
+    }
+
+    pub fn post_root(&self) -> ObjectMeta {
+        ObjectMeta::root_metadata(self.post_state_root, self.post_size)
+    }
+
+    /// The total bytes of the witness nodes
+    pub fn nodes_size(&self) -> usize {
+        self.nodes.values().map(|node| 32 + node.len()).sum()
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+}
@@ -828,6 +828,10 @@ impl ExecInner {
             }
         };
 
+        // Replay the randomness committed by the sequencer, so the execution is reproducible
+        if let Some(tx_randomness) = &ledger_tx.sequence_info.tx_randomness {
+            moveos_tx.ctx.add(tx_randomness.clone())?;
+        }
         moveos_tx.ctx.add(ledger_tx.sequence_info)?;
         Ok(moveos_tx)
     }
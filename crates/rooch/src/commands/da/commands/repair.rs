@@ -12,6 +12,7 @@ use moveos_types::h256::H256;
 use rooch_config::R_OPT_NET_HELP;
 use rooch_store::{RoochStore, TX_ACCUMULATOR_NODE_COLUMN_FAMILY_NAME};
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::randomness::{randomness_block_number, TransactionRandomness};
 use rooch_types::rooch_network::RoochChainID;
 use rooch_types::transaction::{LedgerTransaction, TransactionSequenceInfo};
 use std::path::PathBuf;
@@ -192,9 +193,14 @@ impl InnerRepair {
         tx_order: u64,
         tx_hash: H256,
         timestamp: u64,
+        tx_randomness: Option<TransactionRandomness>,
     ) -> anyhow::Result<TransactionSequenceInfo> {
-        let tx_order_signature =
-            LedgerTransaction::sign_tx_order(tx_order, tx_hash, &self.sequencer_keypair);
+        let tx_order_signature = LedgerTransaction::sign_tx_order(
+            tx_order,
+            tx_hash,
+            tx_randomness.as_ref(),
+            &self.sequencer_keypair,
+        );
         let _tx_accumulator_root = self.tx_accumulator.append(vec![tx_hash].as_slice())?;
 
         let tx_accumulator_info = self.tx_accumulator.get_info();
@@ -203,7 +209,8 @@ impl InnerRepair {
             tx_order_signature,
             tx_accumulator_info,
             timestamp,
-        );
+        )
+        .with_randomness(tx_randomness);
         Ok(sequence_info)
     }
 
@@ -227,8 +234,12 @@ impl InnerRepair {
             let tx_order = tx.sequence_info.tx_order;
             let tx_hash = tx.tx_hash();
             if tx_order == expected_tx_order {
-                let new_tx_sequence_info =
-                    self.append_to(tx_order, tx_hash, tx.sequence_info.tx_timestamp)?;
+                let new_tx_sequence_info = self.append_to(
+                    tx_order,
+                    tx_hash,
+                    tx.sequence_info.tx_timestamp,
+                    tx.sequence_info.tx_randomness.clone(),
+                )?;
                 assert_eq!(new_tx_sequence_info, tx.sequence_info);
                 new_tx_list.push(LedgerTransaction {
                     data: tx.data.clone(),
@@ -245,7 +256,16 @@ impl InnerRepair {
             );
 
             let timestamp = self.derive_tx_timestamp(expected_tx_order);
-            let new_tx_sequence_info = self.append_to(expected_tx_order, tx_hash, timestamp)?;
+            // The VRF input is the randomness block number, the randomness is invalid if the tx moves to another block
+            let tx_randomness = if randomness_block_number(expected_tx_order)
+                == randomness_block_number(tx_order)
+            {
+                tx.sequence_info.tx_randomness.clone()
+            } else {
+                None
+            };
+            let new_tx_sequence_info =
+                self.append_to(expected_tx_order, tx_hash, timestamp, tx_randomness)?;
             new_tx_list.push(LedgerTransaction {
                 data: tx.data.clone(),
                 sequence_info: new_tx_sequence_info,
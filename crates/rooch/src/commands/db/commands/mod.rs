@@ -25,6 +25,7 @@ pub mod revert;
 pub mod rollback;
 pub mod stat_changeset;
 pub mod verify_order;
+pub mod witness;
 
 fn open_rocks(
     base_data_dir: Option<PathBuf>,
@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::open_rooch_db;
+use anyhow::Error;
+use clap::Parser;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::RoochDB;
+use rooch_executor::witness::{generate_witness, verify_witness};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+use rooch_types::transaction::TransactionWitness;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WitnessOutput {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub pre_state_root: H256,
+    pub post_state_root: H256,
+    pub nodes: usize,
+    pub nodes_size: usize,
+    pub witness_size: usize,
+    pub output: Option<PathBuf>,
+}
+
+/// Generate the re-execution witness of a transaction, and check the transaction can be replayed
+/// from the witness alone to the executed state root.
+/// Check an existing witness file without the db via `--input`.
+#[derive(Debug, Parser)]
+pub struct WitnessCommand {
+    /// The tx order of the transaction to generate the witness
+    #[clap(required_unless_present = "input")]
+    pub tx_order: Option<u64>,
+
+    /// Write the BCS encoded witness to the file
+    #[clap(long, short = 'o', conflicts_with = "input")]
+    pub output: Option<PathBuf>,
+
+    /// Check the BCS encoded witness file by stateless replay
+    #[clap(long, short = 'i', conflicts_with = "tx_order")]
+    pub input: Option<PathBuf>,
+
+    #[clap(long = "data-dir", short = 'd')]
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl WitnessCommand {
+    pub fn execute(self) -> RoochResult<WitnessOutput> {
+        let (witness, witness_bytes) = match (self.tx_order, self.input.as_ref()) {
+            (_, Some(input)) => {
+                let witness_bytes = std::fs::read(input)?;
+                (TransactionWitness::decode(&witness_bytes)?, witness_bytes)
+            }
+            (Some(tx_order), None) => {
+                let (_root, rooch_db, _start_time) =
+                    open_rooch_db(self.base_data_dir, self.chain_id);
+                let witness = Self::generate(&rooch_db, tx_order)?;
+                let witness_bytes = witness.encode()?;
+                (witness, witness_bytes)
+            }
+            (None, None) => {
+                return Err(RoochError::CommandArgumentError(
+                    "tx_order or --input is required".to_string(),
+                ))
+            }
+        };
+
+        verify_witness(&witness).map_err(|e| {
+            RoochError::from(Error::msg(format!(
+                "witness check failed: tx_order {}: {:?}",
+                witness.tx_order(),
+                e
+            )))
+        })?;
+
+        if let Some(output) = &self.output {
+            std::fs::write(output, &witness_bytes)?;
+        }
+
+        let mut transaction = witness.transaction.clone();
+        Ok(WitnessOutput {
+            tx_order: witness.tx_order(),
+            tx_hash: transaction.tx_hash(),
+            pre_state_root: witness.pre_state_root,
+            post_state_root: witness.post_state_root,
+            nodes: witness.nodes.len(),
+            nodes_size: witness.nodes_size(),
+            witness_size: witness_bytes.len(),
+            output: self.output,
+        })
+    }
+
+    fn generate(rooch_db: &RoochDB, tx_order: u64) -> anyhow::Result<TransactionWitness> {
+        if tx_order == 0 {
+            return Err(Error::msg("tx order should be greater than 0"));
+        }
+        let transaction = rooch_db
+            .rooch_store
+            .transaction_store
+            .get_tx_by_order(tx_order)?
+            .ok_or_else(|| Error::msg(format!("tx not found for tx_order {}", tx_order)))?;
+        let pre_execution_info = Self::get_execution_info(rooch_db, tx_order - 1)?;
+        let execution_info = Self::get_execution_info(rooch_db, tx_order)?;
+
+        let witness = generate_witness(
+            &rooch_db.moveos_store,
+            rooch_db.rooch_store.clone(),
+            pre_execution_info.root_metadata(),
+            transaction,
+        )?;
+        // The witness is generated by re-execution, it should be the same as the executed result
+        if witness.post_state_root != execution_info.state_root
+            || witness.post_size != execution_info.size
+        {
+            return Err(Error::msg(format!(
+                "Re-execution state root is not equal to the executed: tx_order: {}, exp: {:?}, act: {:?}",
+                tx_order, execution_info.state_root, witness.post_state_root
+            )));
+        }
+        Ok(witness)
+    }
+
+    fn get_execution_info(
+        rooch_db: &RoochDB,
+        tx_order: u64,
+    ) -> anyhow::Result<TransactionExecutionInfo> {
+        let tx_hash = rooch_db
+            .rooch_store
+            .transaction_store
+            .get_tx_hashes(vec![tx_order])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| Error::msg(format!("tx_hash not found for tx_order {}", tx_order)))?;
+        rooch_db
+            .moveos_store
+            .transaction_store
+            .get_tx_execution_info(tx_hash)?
+            .ok_or_else(|| {
+                Error::msg(format!(
+                    "tx not executed via tx_hash {} of tx_order {}",
+                    tx_hash, tx_order
+                ))
+            })
+    }
+}
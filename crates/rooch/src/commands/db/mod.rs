@@ -16,6 +16,7 @@ use crate::commands::db::commands::repair::RepairCommand;
 use crate::commands::db::commands::revert::RevertCommand;
 use crate::commands::db::commands::stat_changeset::StatChangesetCommand;
 use crate::commands::db::commands::verify_order::VerifyOrderCommand;
+use crate::commands::db::commands::witness::WitnessCommand;
 use async_trait::async_trait;
 use clap::Parser;
 use commands::rollback::RollbackCommand;
@@ -88,6 +89,9 @@ impl CommandAction<String> for DB {
                     serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
                 })
             }
+            DBCommand::Witness(witness) => witness.execute().map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -110,4 +114,5 @@ pub enum DBCommand {
     VerifyOrder(VerifyOrderCommand),
     GetSequencerInfo(GetSequencerInfoCommand),
     GetAccumulatorLeafByIndex(GetAccumulatorLeafByIndexCommand),
+    Witness(WitnessCommand),
 }
@@ -4,7 +4,7 @@
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
-use rooch_faucet::{DiscordConfig, FaucetConfig, WebConfig};
+use rooch_faucet::{AntiAbuseConfig, DiscordConfig, FaucetConfig, WebConfig};
 use rooch_types::error::RoochResult;
 
 #[derive(Parser)]
@@ -23,6 +23,9 @@ pub struct ServerCommand {
     #[clap(flatten)]
     pub discord_config: DiscordConfig,
 
+    #[clap(flatten)]
+    pub anti_abuse_config: AntiAbuseConfig,
+
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
 }
@@ -34,12 +37,17 @@ impl CommandAction<String> for ServerCommand {
             web_config,
             faucet_config,
             discord_config,
+            anti_abuse_config,
             context_options,
         } = self;
         let wallet_context = context_options.build_require_password()?;
-        Ok(
-            rooch_faucet::server::start(wallet_context, web_config, faucet_config, discord_config)
-                .await?,
+        Ok(rooch_faucet::server::start(
+            wallet_context,
+            web_config,
+            faucet_config,
+            discord_config,
+            anti_abuse_config,
         )
+        .await?)
     }
 }
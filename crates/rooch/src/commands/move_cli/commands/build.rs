@@ -13,13 +13,15 @@ use clap::Parser;
 use move_cli::{base::reroot_path, Move};
 use moveos_types::move_std::string::MoveString;
 use moveos_types::moveos_std::module_store::PackageData;
-use moveos_verifier::build::run_verifier;
+use moveos_verifier::build::{run_verifier, run_verifier_with_lints};
+use moveos_verifier::lint::{render_lints, LintConfig, LintFormat};
 use rooch_types::error::RoochError;
 use rooch_types::error::RoochResult;
 use serde_json::Value;
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::path::PathBuf;
 
 /// Build the package at `path`. If no path is provided defaults to current directory.
 #[derive(Parser)]
@@ -39,6 +41,20 @@ pub struct BuildCommand {
     #[clap(flatten)]
     move_args: Move,
 
+    /// Skip the lints, only run the extended checks
+    #[clap(long)]
+    no_lint: bool,
+
+    /// The format of the lint report: text, json or sarif.
+    /// The text format reports the lints together with the compiler diagnostics,
+    /// the json and sarif formats return the lint report as the command output.
+    #[clap(long, default_value = "text")]
+    lint_format: LintFormat,
+
+    /// Also write the json or sarif lint report to the file
+    #[clap(long)]
+    lint_output: Option<PathBuf>,
+
     /// Return command outputs in json format
     #[clap(long, default_value = "false")]
     json: bool,
@@ -72,7 +88,23 @@ impl CommandAction<Option<Value>> for BuildCommand {
 
         let mut package = config.compile_package_no_exit(&rerooted_path, &mut std::io::stdout())?;
 
-        run_verifier(rerooted_path.clone(), config_cloned.clone(), &mut package)?;
+        let mut lint_report = None;
+        if self.no_lint {
+            run_verifier(rerooted_path.clone(), config_cloned.clone(), &mut package)?;
+        } else {
+            let lint_config = LintConfig::from_package(&rerooted_path)
+                .map_err(|e| RoochError::ConfigLoadError("lints".to_owned(), e.to_string()))?;
+            let lints = run_verifier_with_lints(
+                rerooted_path.clone(),
+                config_cloned.clone(),
+                &mut package,
+                &lint_config,
+            )?;
+            lint_report = render_lints(&lints, self.lint_format)?;
+            if let (Some(report), Some(lint_output)) = (&lint_report, &self.lint_output) {
+                std::fs::write(lint_output, serde_json::to_string_pretty(report)?)?;
+            }
+        }
 
         // export bcs serialized package data to `package.rpd`(rpd for Rooch Package Data).
         let export_path = match &config_cloned.install_dir {
@@ -115,6 +147,9 @@ impl CommandAction<Option<Value>> for BuildCommand {
 
         println!("Exported package to {}", export_path.display());
 
-        print_serialized_success(self.json)
+        match lint_report {
+            Some(lint_report) => Ok(Some(lint_report)),
+            None => print_serialized_success(self.json),
+        }
     }
 }
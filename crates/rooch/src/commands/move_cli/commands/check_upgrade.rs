@@ -0,0 +1,154 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::errors::Location;
+use move_binary_format::CompiledModule;
+use move_cli::Move;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::module_store::ModuleStore;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::ObjectState;
+use moveos_verifier::build::run_verifier;
+use moveos_verifier::upgrade::{diff_packages, ChangeLevel, UpgradeReport};
+use rooch_rpc_client::Client;
+use rooch_types::address::ParsedAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+use std::io::stderr;
+
+/// Compare the local package with the package published on chain,
+/// and report the changes classified as breaking, risky or compatible.
+#[derive(Parser)]
+#[clap(name = "check-upgrade")]
+pub struct CheckUpgradeCommand {
+    /// The address of the published package, defaults to the address of the local package.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    address: Option<ParsedAddress>,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser=crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    /// Fail the command if any change reaches this level: compatible, risky or breaking.
+    #[clap(long)]
+    fail_on: Option<ChangeLevel>,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[async_trait]
+impl CommandAction<UpgradeReport> for CheckUpgradeCommand {
+    async fn execute(self) -> RoochResult<UpgradeReport> {
+        let context = self.context_options.build()?;
+
+        let package_path = self
+            .move_args
+            .package_path
+            .clone()
+            .unwrap_or_else(|| std::env::current_dir().unwrap());
+        let mut config = self.move_args.build_config.clone();
+        config.additional_named_addresses =
+            context.parse_and_resolve_addresses(self.named_addresses.clone())?;
+        let config_cloned = config.clone();
+
+        let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
+        run_verifier(package_path, config_cloned, &mut package)?;
+
+        let new_modules = package
+            .root_modules_map()
+            .iter_modules_owned()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let pkg_address = match self.address {
+            Some(address) => address.into_account_address(&context.address_mapping())?,
+            None => match new_modules.first() {
+                Some(module) => *module.self_id().address(),
+                None => {
+                    return Err(RoochError::MoveCompilationError(
+                        "compiling move modules error! The package is empty".to_owned(),
+                    ))
+                }
+            },
+        };
+
+        let client = context.get_client().await?;
+        let old_modules = get_published_modules(&client, pkg_address).await?;
+        if old_modules.is_empty() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "No package is published at address {}",
+                pkg_address.to_hex_literal()
+            )));
+        }
+
+        Ok(diff_packages(&old_modules, &new_modules))
+    }
+
+    /// Executes the command, and serializes it to the common JSON output type
+    async fn execute_serialized(self) -> RoochResult<String> {
+        let json = self.json;
+        let fail_on = self.fail_on;
+        let report = self.execute().await?;
+
+        let output = if json {
+            serde_json::to_string_pretty(&report)?
+        } else {
+            report.to_string()
+        };
+        match fail_on {
+            Some(level) if report.level() >= level => {
+                println!("{}", output);
+                Err(RoochError::UnexpectedError(format!(
+                    "The upgrade level is {}, fail on {}",
+                    report.level(),
+                    level
+                )))
+            }
+            _ => Ok(output),
+        }
+    }
+}
+
+/// List all modules of the package published at `package_address`.
+async fn get_published_modules(
+    client: &Client,
+    package_address: AccountAddress,
+) -> RoochResult<Vec<CompiledModule>> {
+    let package_id = ModuleStore::package_id(&package_address);
+    let mut modules = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .rooch
+            .list_field_states(package_id.clone().into(), cursor, None, None)
+            .await?;
+        for state_kv in page.data {
+            let state = ObjectState::from(state_kv.state);
+            let module = state.value_as_df::<MoveString, MoveModule>()?;
+            modules.push(
+                CompiledModule::deserialize(&module.value.byte_codes)
+                    .map_err(|e| e.finish(Location::Undefined))?,
+            );
+        }
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    Ok(modules)
+}
@@ -0,0 +1,386 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, FunctionArg, TransactionOptions, WalletContextOptions};
+use crate::commands::move_cli::commands::run_function::parse_type_args;
+use crate::commands::move_cli::serialized_success;
+use crate::tx_runner::{execute_tx_locally_with_trace, get_latest_state_root};
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::types::ParsedStructType;
+use moveos_gas_profiling::tracer::{ExecutionTrace, TraceEvent, TraceFrame, TraceStep};
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use rooch_rpc_api::jsonrpc_types::transaction_view::LedgerTxDataView;
+use rooch_rpc_client::Client;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::function_arg::{parse_function_arg, ParsedFunctionId};
+use rooch_types::transaction::{RoochTransaction, RoochTransactionData};
+use serde_json::Value;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Replay a transaction locally with the execution tracer, and inspect the call frames, the bytecode steps,
+/// the arguments, the locals and the objects read and written in an interactive debugger.
+#[derive(Parser)]
+pub struct DebugCommand {
+    /// The hash of the executed transaction, it is replayed on the state root before the transaction.
+    #[clap(required_unless_present = "function", conflicts_with = "function")]
+    pub tx_hash: Option<H256>,
+
+    /// Debug a function call instead of an executed transaction, it is executed on the latest state.
+    /// Example: `0x42::message::set_message`
+    #[clap(long)]
+    pub function: Option<ParsedFunctionId>,
+
+    /// TypeTag arguments separated by spaces.
+    #[clap(
+        long = "type-args",
+        value_parser=ParsedStructType::parse,
+    )]
+    pub type_args: Vec<ParsedStructType>,
+
+    /// Function arguments, the format is the same as `rooch move run --args`
+    #[clap(long = "args", value_parser=parse_function_arg)]
+    pub args: Vec<FunctionArg>,
+
+    /// Replay the transaction on the given state root instead of the default one
+    #[clap(long)]
+    pub state_root: Option<H256>,
+
+    /// Write the execution trace as JSON to the file and exit, without the interactive debugger
+    #[clap(long)]
+    pub output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[async_trait]
+impl CommandAction<Option<Value>> for DebugCommand {
+    async fn execute(self) -> RoochResult<Option<Value>> {
+        let context = self.context.build()?;
+        let client = context.get_client().await?;
+
+        let (tx_data, default_state_root) = match (self.tx_hash, self.function) {
+            (Some(tx_hash), _) => load_executed_tx(&client, tx_hash).await?,
+            (None, Some(function)) => {
+                let address_mapping = context.address_mapping();
+                let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+                let function_id = function.into_function_id(&address_mapping)?;
+                let args = self
+                    .args
+                    .into_iter()
+                    .map(|arg| arg.into_bytes(&address_mapping))
+                    .collect::<Result<Vec<_>>>()?;
+                let type_args = parse_type_args(self.type_args, &address_mapping)?;
+                let action = MoveAction::new_function_call(function_id, type_args, args);
+                let tx_data = context
+                    .build_tx_data_with_sequence_number(
+                        sender,
+                        action,
+                        self.tx_options.max_gas_amount,
+                        self.tx_options.sequence_number,
+                    )
+                    .await?;
+                let state_root = H256::from_slice(&get_latest_state_root(&client).await?);
+                (tx_data, state_root)
+            }
+            (None, None) => {
+                return Err(RoochError::CommandArgumentError(
+                    "Either tx hash or --function is required".to_owned(),
+                ))
+            }
+        };
+        let state_root = self.state_root.unwrap_or(default_state_root);
+
+        let trace = execute_tx_locally_with_trace(state_root.as_bytes().to_vec(), client, tx_data)?;
+
+        match self.output {
+            Some(output) => export_trace(&trace, &output)?,
+            None => TraceDebugger::new(&trace).run()?,
+        }
+        serialized_success(self.json)
+    }
+}
+
+/// Load the executed transaction and the state root before the transaction
+async fn load_executed_tx(client: &Client, tx_hash: H256) -> Result<(RoochTransactionData, H256)> {
+    let tx = client
+        .rooch
+        .get_transactions_by_hash(vec![tx_hash])
+        .await?
+        .pop()
+        .flatten()
+        .ok_or_else(|| anyhow!("Transaction {:?} not found", tx_hash))?;
+    let tx_data = match tx.transaction.data {
+        LedgerTxDataView::L2Tx(tx_view) => RoochTransaction::decode(&tx_view.raw.0)?.data,
+        _ => bail!("Only the L2 transaction can be debugged"),
+    };
+    let tx_order = tx.transaction.sequence_info.tx_order.0;
+    if tx_order == 0 {
+        bail!("The genesis transaction can not be debugged");
+    }
+    let previous_tx = client
+        .rooch
+        .get_transactions_by_order(Some(tx_order), Some(1), Some(true))
+        .await?
+        .data
+        .pop()
+        .ok_or_else(|| anyhow!("The previous transaction of {:?} not found", tx_hash))?;
+    let state_root = previous_tx
+        .execution_info
+        .ok_or_else(|| anyhow!("The execution info of the previous transaction not found"))?
+        .state_root
+        .0;
+    Ok((tx_data, state_root))
+}
+
+fn export_trace(trace: &ExecutionTrace, path: &Path) -> Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(trace)?)?;
+    println!("Execution trace is written to {}", path.display());
+    Ok(())
+}
+
+const DEBUGGER_HELP: &str = "Commands:
+  s, step            step to the next bytecode, step into the callee
+  n, next            step to the next bytecode of the current frame, step over the callee
+  f, finish          run until the current frame returns
+  g, goto <STEP>     jump to the step
+  bt, backtrace      print the call stack of the current step
+  tree               print the call tree
+  frame [INDEX]      print the arguments, locals and return values of the frame
+  reads              print the object fields read from the state
+  writes             print the objects written by the transaction
+  export <PATH>      write the execution trace as JSON
+  h, help            print this help
+  q, quit            exit the debugger";
+
+/// An interactive debugger which steps through the recorded execution trace
+struct TraceDebugger<'a> {
+    trace: &'a ExecutionTrace,
+    /// The frames in the call order, with the depth and the parent index
+    frames: Vec<(usize, Option<usize>, &'a TraceFrame)>,
+    /// The bytecode steps with the index of the frame
+    steps: Vec<(usize, &'a TraceStep)>,
+    cursor: usize,
+}
+
+impl<'a> TraceDebugger<'a> {
+    fn new(trace: &'a ExecutionTrace) -> Self {
+        let mut debugger = Self {
+            trace,
+            frames: vec![],
+            steps: vec![],
+            cursor: 0,
+        };
+        for frame in trace.frames.iter() {
+            debugger.index_frame(frame, 0, None);
+        }
+        debugger
+    }
+
+    fn index_frame(&mut self, frame: &'a TraceFrame, depth: usize, parent: Option<usize>) {
+        let frame_index = self.frames.len();
+        self.frames.push((depth, parent, frame));
+        for event in frame.events.iter() {
+            match event {
+                TraceEvent::Step(step) => self.steps.push((frame_index, step)),
+                TraceEvent::Call(callee) => self.index_frame(callee, depth + 1, Some(frame_index)),
+            }
+        }
+    }
+
+    fn run(&mut self) -> Result<()> {
+        println!(
+            "Transaction {:?} status: {}, gas used: {}, steps: {}",
+            self.trace.tx_hash, self.trace.status, self.trace.gas_used, self.trace.total_steps
+        );
+        if let Some(error) = &self.trace.error {
+            println!("Error: {}", error);
+        }
+        println!("{}", DEBUGGER_HELP);
+        self.print_current();
+
+        let stdin = io::stdin();
+        loop {
+            print!("(debug) ");
+            io::stdout().flush()?;
+            let mut line = String::new();
+            if stdin.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let mut parts = line.split_whitespace();
+            let Some(command) = parts.next() else {
+                continue;
+            };
+            let arg = parts.next();
+            match command {
+                "s" | "step" => self.move_to(self.cursor + 1),
+                "n" | "next" => self.next(),
+                "f" | "finish" => self.finish(),
+                "g" | "goto" => match arg.map(str::parse::<u64>) {
+                    Some(Ok(step)) => self.goto(step),
+                    _ => println!("Usage: goto <STEP>"),
+                },
+                "bt" | "backtrace" => self.print_backtrace(),
+                "tree" => self.print_tree(),
+                "frame" => match arg.map(str::parse::<usize>) {
+                    Some(Ok(index)) if index < self.frames.len() => self.print_frame(index),
+                    Some(_) => println!("Invalid frame index"),
+                    None => match self.current_frame() {
+                        Some(index) => self.print_frame(index),
+                        None => println!("No frame"),
+                    },
+                },
+                "reads" => {
+                    for read in self.trace.object_reads.iter() {
+                        println!(
+                            "{:?} {} exists: {}",
+                            read.state_root, read.field_key, read.exists
+                        );
+                    }
+                }
+                "writes" => {
+                    for write in self.trace.object_writes.iter() {
+                        println!("{} {}", write.op, write.object_id);
+                    }
+                }
+                "export" => match arg {
+                    Some(path) => export_trace(self.trace, Path::new(path))?,
+                    None => println!("Usage: export <PATH>"),
+                },
+                "h" | "help" => println!("{}", DEBUGGER_HELP),
+                "q" | "quit" => return Ok(()),
+                _ => println!("Unknown command: {}, type `help` for the commands", command),
+            }
+        }
+    }
+
+    fn current_frame(&self) -> Option<usize> {
+        self.steps
+            .get(self.cursor)
+            .map(|(frame_index, _)| *frame_index)
+    }
+
+    fn move_to(&mut self, cursor: usize) {
+        if cursor >= self.steps.len() {
+            println!("End of the trace");
+            return;
+        }
+        self.cursor = cursor;
+        self.print_current();
+    }
+
+    fn next(&mut self) {
+        let Some(frame_index) = self.current_frame() else {
+            return;
+        };
+        let target = (self.cursor + 1..self.steps.len()).find(|cursor| {
+            self.steps[*cursor].0 == frame_index
+                || !self.is_descendant(self.steps[*cursor].0, frame_index)
+        });
+        self.move_to(target.unwrap_or(self.steps.len()));
+    }
+
+    fn finish(&mut self) {
+        let Some(frame_index) = self.current_frame() else {
+            return;
+        };
+        let target = (self.cursor + 1..self.steps.len())
+            .find(|cursor| !self.is_descendant(self.steps[*cursor].0, frame_index));
+        self.move_to(target.unwrap_or(self.steps.len()));
+    }
+
+    fn goto(&mut self, step: u64) {
+        match self.steps.iter().position(|(_, s)| s.step == step) {
+            Some(cursor) => self.move_to(cursor),
+            None => println!("Step {} not found", step),
+        }
+    }
+
+    /// Whether the frame is the ancestor frame itself or one of its callees
+    fn is_descendant(&self, mut frame_index: usize, ancestor: usize) -> bool {
+        loop {
+            if frame_index == ancestor {
+                return true;
+            }
+            match self.frames[frame_index].1 {
+                Some(parent) => frame_index = parent,
+                None => return false,
+            }
+        }
+    }
+
+    fn print_current(&self) {
+        match self.steps.get(self.cursor) {
+            Some((frame_index, step)) => {
+                let (_, _, frame) = self.frames[*frame_index];
+                let target = step
+                    .branch_target
+                    .map(|offset| format!(" -> {}", offset))
+                    .unwrap_or_default();
+                println!("[{}] {} {}{}", step.step, frame.name, step.op, target);
+            }
+            None => println!("No bytecode step is recorded"),
+        }
+    }
+
+    fn print_backtrace(&self) {
+        let mut frame_index = self.current_frame();
+        let mut level = 0;
+        while let Some(index) = frame_index {
+            let (_, parent, frame) = self.frames[index];
+            println!(
+                "#{} [frame {}] {}({})",
+                level,
+                index,
+                frame.name,
+                frame.args.join(", ")
+            );
+            frame_index = parent;
+            level += 1;
+        }
+    }
+
+    fn print_tree(&self) {
+        for (index, (depth, _, frame)) in self.frames.iter().enumerate() {
+            println!(
+                "{}[{}] {}{} steps: {}..{} gas: {}",
+                "  ".repeat(*depth),
+                index,
+                frame.name,
+                if frame.is_native { " (native)" } else { "" },
+                frame.step_start,
+                frame.step_end,
+                frame.gas_used
+            );
+        }
+    }
+
+    fn print_frame(&self, index: usize) {
+        let (_, _, frame) = self.frames[index];
+        println!("Frame {}: {}", index, frame.name);
+        if !frame.ty_args.is_empty() {
+            println!("  type args: <{}>", frame.ty_args.join(", "));
+        }
+        println!("  args: ({})", frame.args.join(", "));
+        println!("  locals on return: ({})", frame.locals.join(", "));
+        if let Some(return_values) = &frame.return_values {
+            println!("  return values: ({})", return_values.join(", "));
+        }
+        println!(
+            "  steps: {}..{}, gas used: {}",
+            frame.step_start, frame.step_end, frame.gas_used
+        );
+    }
+}
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod build;
+pub mod check_upgrade;
 pub mod coverage;
+pub mod debug;
 pub mod disassemble;
 pub mod docgen;
 pub mod errmap;
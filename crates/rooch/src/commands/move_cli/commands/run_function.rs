@@ -3,12 +3,13 @@
 
 use crate::cli_types::{CommandAction, FunctionArg, TransactionOptions, WalletContextOptions};
 use crate::tx_runner::{dry_run_tx_locally, execute_tx_locally_with_gas_profile};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use clap::Parser;
 use move_command_line_common::types::ParsedStructType;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{MoveAction, MultiCallArgument, MultiCallFunction};
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, HumanReadableDisplay};
 use rooch_types::function_arg::parse_function_arg;
 use rooch_types::{
@@ -17,14 +18,16 @@ use rooch_types::{
     function_arg::ParsedFunctionId,
     transaction::rooch::RoochTransaction,
 };
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
 
 /// Run a Move function
 #[derive(Parser)]
 pub struct RunFunction {
     /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
     /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
-    #[clap(long)]
-    pub function: ParsedFunctionId,
+    #[clap(long, required_unless_present = "multi")]
+    pub function: Option<ParsedFunctionId>,
 
     /// TypeTag arguments separated by spaces.
     ///
@@ -46,6 +49,13 @@ pub struct RunFunction {
     #[clap(long = "args", value_parser=parse_function_arg)]
     pub args: Vec<FunctionArg>,
 
+    /// Run multiple functions in one transaction, the value is the path of a JSON file.
+    /// The return values of the previous functions can be passed to the later functions via `result:<CALL_INDEX>:<RESULT_INDEX>`.
+    ///
+    /// Example: `[{"function": "0x42::m::create", "args": ["u64:1"]}, {"function": "0x42::m::transfer", "args": ["result:0:0", "@0x42"]}]`
+    #[clap(long, conflicts_with_all = ["function", "type_args", "args", "gas_profile"])]
+    pub multi: Option<PathBuf>,
+
     /// RPC client options.
     #[clap(flatten)]
     context: WalletContextOptions,
@@ -74,22 +84,27 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
         let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
         let max_gas_amount: Option<u64> = self.tx_options.max_gas_amount;
         let sequence_number: Option<u64> = self.tx_options.sequence_number;
-        let function_id = self.function.into_function_id(&address_mapping)?;
-        let args = self
-            .args
-            .into_iter()
-            .map(|arg| arg.into_bytes(&address_mapping))
-            .collect::<Result<Vec<_>>>()?;
-        let type_args = self
-            .type_args
-            .into_iter()
-            .map(|tag| {
-                Ok(TypeTag::Struct(Box::new(
-                    tag.into_struct_tag(&address_mapping)?,
-                )))
-            })
-            .collect::<Result<Vec<_>>>()?;
-        let action = MoveAction::new_function_call(function_id, type_args, args);
+        let action = match (self.function, self.multi) {
+            (_, Some(multi)) => {
+                let calls = parse_multi_call_file(&multi, &address_mapping)?;
+                MoveAction::new_multi_call(calls)
+            }
+            (Some(function), None) => {
+                let function_id = function.into_function_id(&address_mapping)?;
+                let args = self
+                    .args
+                    .into_iter()
+                    .map(|arg| arg.into_bytes(&address_mapping))
+                    .collect::<Result<Vec<_>>>()?;
+                let type_args = parse_type_args(self.type_args, &address_mapping)?;
+                MoveAction::new_function_call(function_id, type_args, args)
+            }
+            (None, None) => {
+                return Err(RoochError::CommandArgumentError(
+                    "Either --function or --multi is required".to_owned(),
+                ))
+            }
+        };
 
         if self.dry_run {
             let rooch_tx_data = context
@@ -216,3 +231,79 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
         }
     }
 }
+
+/// A function call in the `--multi` JSON file
+#[derive(Debug, Deserialize)]
+struct MultiCallFunctionArg {
+    function: String,
+    #[serde(default)]
+    type_args: Vec<String>,
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+const MULTI_CALL_RESULT_ARG_PREFIX: &str = "result:";
+
+pub(crate) fn parse_type_args(
+    type_args: Vec<ParsedStructType>,
+    address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<Vec<TypeTag>> {
+    type_args
+        .into_iter()
+        .map(|tag| {
+            Ok(TypeTag::Struct(Box::new(
+                tag.into_struct_tag(address_mapping)?,
+            )))
+        })
+        .collect()
+}
+
+fn parse_multi_call_argument(
+    arg: &str,
+    address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<MultiCallArgument> {
+    match arg.strip_prefix(MULTI_CALL_RESULT_ARG_PREFIX) {
+        Some(result) => {
+            let (call_index, result_index) = result.split_once(':').ok_or_else(|| {
+                anyhow!(
+                    "Invalid result argument: {}, the format is `result:<CALL_INDEX>:<RESULT_INDEX>`",
+                    arg
+                )
+            })?;
+            Ok(MultiCallArgument::new_result(
+                call_index.parse()?,
+                result_index.parse()?,
+            ))
+        }
+        None => Ok(MultiCallArgument::Pure(
+            parse_function_arg(arg)?.into_bytes(address_mapping)?,
+        )),
+    }
+}
+
+fn parse_multi_call_file(
+    path: &Path,
+    address_mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<Vec<MultiCallFunction>> {
+    let content = std::fs::read_to_string(path)?;
+    let calls: Vec<MultiCallFunctionArg> = serde_json::from_str(&content)?;
+    calls
+        .into_iter()
+        .map(|call| {
+            let function_id =
+                ParsedFunctionId::parse(&call.function)?.into_function_id(address_mapping)?;
+            let type_args = call
+                .type_args
+                .iter()
+                .map(|tag| ParsedStructType::parse(tag.as_str()))
+                .collect::<Result<Vec<_>>>()?;
+            let type_args = parse_type_args(type_args, address_mapping)?;
+            let args = call
+                .args
+                .iter()
+                .map(|arg| parse_multi_call_argument(arg, address_mapping))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(MultiCallFunction::new(function_id, type_args, args))
+        })
+        .collect()
+}
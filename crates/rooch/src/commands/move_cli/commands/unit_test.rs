@@ -21,6 +21,7 @@ use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::{moveos_std::tx_context::TxContext, state_resolver::RootObjectResolver};
 use moveos_verifier::build::build_model_with_test_attr;
 use moveos_verifier::metadata::run_extended_checks;
+use moveos_wasm::context::NativeWASMContext;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use rooch_genesis::FrameworksGasParameters;
@@ -148,7 +149,9 @@ fn new_moveos_natives_runtime(ext: &mut NativeContextExtensions) {
     let table_ext = ObjectRuntimeContext::new(object_runtime, feature_store);
     let module_ext = NativeModuleContext::new(resolver);
     let event_ext = NativeEventContext::default();
+    let wasm_ext = NativeWASMContext::default();
     ext.add(table_ext);
     ext.add(module_ext);
     ext.add(event_ext);
+    ext.add(wasm_ext);
 }
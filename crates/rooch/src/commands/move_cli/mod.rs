@@ -4,11 +4,11 @@
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
 use commands::{
-    build::BuildCommand, coverage::CoverageCommand, disassemble::DisassembleCommand,
-    docgen::DocgenCommand, errmap::ErrmapCommand, info::InfoCommand,
-    integration_test::IntegrationTestCommand, new::NewCommand, prove::ProveCommand,
-    publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::TestCommand,
+    build::BuildCommand, check_upgrade::CheckUpgradeCommand, coverage::CoverageCommand,
+    debug::DebugCommand, disassemble::DisassembleCommand, docgen::DocgenCommand,
+    errmap::ErrmapCommand, info::InfoCommand, integration_test::IntegrationTestCommand,
+    new::NewCommand, prove::ProveCommand, publish::Publish, run_function::RunFunction,
+    run_view_function::RunViewFunction, unit_test::TestCommand,
 };
 use rooch_types::error::RoochResult;
 use serde_json::{json, Value};
@@ -28,7 +28,9 @@ pub struct MoveCli {
 #[clap(name = "move")]
 pub enum MoveCommand {
     Build(BuildCommand),
+    CheckUpgrade(CheckUpgradeCommand),
     Coverage(CoverageCommand),
+    Debug(DebugCommand),
     Disassemble(DisassembleCommand),
     Docgen(DocgenCommand),
     Errmap(ErrmapCommand),
@@ -48,7 +50,9 @@ impl CommandAction<String> for MoveCli {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
             MoveCommand::Build(c) => c.execute_serialized().await,
+            MoveCommand::CheckUpgrade(c) => c.execute_serialized().await,
             MoveCommand::Coverage(c) => c.execute_serialized().await,
+            MoveCommand::Debug(c) => c.execute_serialized().await,
             MoveCommand::Disassemble(c) => c.execute_serialized().await,
             MoveCommand::Docgen(c) => c.execute_serialized().await,
             MoveCommand::Errmap(c) => c.execute_serialized().await,
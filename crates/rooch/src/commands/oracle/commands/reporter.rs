@@ -6,9 +6,11 @@ use async_trait::async_trait;
 use clap::Parser;
 use moveos_types::moveos_std::object::ObjectID;
 use rooch_oracle::aggregator_stream::AggregateStrategy;
+use rooch_oracle::config::ReporterConfig;
 use rooch_oracle::datasource::{DataSourceType, Ticker};
 use rooch_oracle::reporter::Reporter;
 use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
 
 /// Start a Oracle data reporter
 #[derive(Debug, Parser)]
@@ -25,13 +27,18 @@ pub struct ReporterCommand {
     #[clap(long, env = "ROOCH_ORACLE_ADMIN_ID")]
     pub oracle_admin_id: ObjectID,
 
-    /// The DataSource Type
+    /// The reporter config file in YAML or JSON, it defines the sources and the symbols of the tickers per source.
+    /// The `--data-source`, `--ticker` and `--aggregate-strategy` are ignored if the config is provided.
+    #[clap(long, required_unless_present = "data_source")]
+    pub config: Option<PathBuf>,
+
+    /// The DataSource Type, it can be repeated to report the data aggregated from multiple sources
     #[clap(long)]
-    pub data_source: DataSourceType,
+    pub data_source: Vec<DataSourceType>,
 
-    /// The ticker of the price data source
-    #[clap(long, default_value_t)]
-    pub ticker: Ticker,
+    /// The ticker of the price data, it can be repeated to report multiple tickers in one transaction
+    #[clap(long, default_values_t = vec![Ticker::default()])]
+    pub ticker: Vec<Ticker>,
 
     #[clap(long, default_value_t)]
     pub aggregate_strategy: AggregateStrategy,
@@ -57,15 +64,21 @@ impl CommandAction<String> for ReporterCommand {
         let sender = wallet_context
             .resolve_address(self.tx_options.sender)?
             .into();
+        let config = match &self.config {
+            Some(config) => ReporterConfig::load(config)?,
+            None => ReporterConfig::new_with_default_symbols(
+                self.data_source,
+                self.ticker,
+                self.aggregate_strategy,
+            )?,
+        };
         let reporter = Reporter::new(
             wallet_context,
             sender,
             self.oracle_id,
             self.oracle_admin_id,
             self.report_interval,
-            self.ticker,
-            self.data_source,
-            self.aggregate_strategy,
+            config,
             self.stop_on_error,
             self.stop_after_report_times,
         );
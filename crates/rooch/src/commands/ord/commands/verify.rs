@@ -0,0 +1,143 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_ord::ord_client::OrdClient;
+use rooch_ord::verify::{
+    InscriptionMismatch, OrdEventFileSource, OrdServerSource, OrdVerifier, RoochInscriptionReader,
+    VerifyCheckpoint,
+};
+use rooch_rpc_api::jsonrpc_types::btc::ord::InscriptionFilterView;
+use rooch_rpc_api::jsonrpc_types::StrView;
+use rooch_rpc_client::Client;
+use rooch_types::bitcoin::ord::{Inscription, InscriptionID};
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Verify the inscriptions on Rooch against ord.
+/// The expected inscriptions come from the ord events file or an ord server,
+/// the mismatches are written to `<output-dir>/mismatches.jsonl`.
+#[derive(Debug, Parser)]
+pub struct VerifyCommand {
+    /// The ord events file, every line is a JSON serialized ord event
+    #[clap(long, conflicts_with = "ord_url", required_unless_present = "ord_url")]
+    pub events_file: Option<PathBuf>,
+
+    /// The ord server url, or a local stand-in serving the same API
+    #[clap(long, requires = "end_block_height")]
+    pub ord_url: Option<String>,
+
+    /// The first block height to fetch from the ord server
+    #[clap(long, default_value_t = 0)]
+    pub start_block_height: u64,
+
+    /// The last block height to fetch from the ord server, inclusive
+    #[clap(long)]
+    pub end_block_height: Option<u64>,
+
+    /// The directory of the mismatch report and checkpoint
+    #[clap(long)]
+    pub output_dir: PathBuf,
+
+    /// Resume from the checkpoint in the output dir
+    #[clap(long)]
+    pub resume: bool,
+
+    /// The number of inscriptions to verify in one batch
+    #[clap(long, default_value_t = 100)]
+    pub batch_size: usize,
+
+    /// Only output the first N mismatches, all the mismatches are in the report file
+    #[clap(long, default_value_t = 100)]
+    pub max_output_mismatches: usize,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyOutput {
+    pub checkpoint: VerifyCheckpoint,
+    pub report_path: PathBuf,
+    pub mismatches: Vec<InscriptionMismatch>,
+}
+
+struct RpcInscriptionReader {
+    client: Client,
+}
+
+#[async_trait]
+impl RoochInscriptionReader for RpcInscriptionReader {
+    async fn get_inscriptions(&self, ids: &[InscriptionID]) -> Result<Vec<Option<Inscription>>> {
+        let object_ids = ids.iter().map(|id| id.object_id()).collect::<Vec<_>>();
+        let page = self
+            .client
+            .rooch
+            .query_inscriptions(
+                InscriptionFilterView::ObjectId(StrView(object_ids)),
+                None,
+                Some(ids.len() as u64),
+                None,
+            )
+            .await?;
+        let mut inscriptions = page
+            .data
+            .into_iter()
+            .map(|state| {
+                let inscription = Inscription::from(state.value);
+                (inscription.id, inscription)
+            })
+            .collect::<HashMap<_, _>>();
+        Ok(ids.iter().map(|id| inscriptions.remove(id)).collect())
+    }
+}
+
+#[async_trait]
+impl CommandAction<VerifyOutput> for VerifyCommand {
+    async fn execute(self) -> RoochResult<VerifyOutput> {
+        if self.batch_size == 0 {
+            return Err(RoochError::CommandArgumentError(
+                "batch size should be greater than 0".to_string(),
+            ));
+        }
+        let context = self.context_options.build()?;
+        let client = context.get_client().await?;
+        let verifier = OrdVerifier::new(RpcInscriptionReader { client }, self.output_dir);
+
+        let mut report = match (self.events_file, self.ord_url) {
+            (Some(events_file), _) => {
+                let mut source = OrdEventFileSource::new(events_file, self.batch_size);
+                verifier.verify(&mut source, self.resume).await?
+            }
+            (None, Some(ord_url)) => {
+                let end_block_height = self.end_block_height.ok_or_else(|| {
+                    RoochError::CommandArgumentError(
+                        "end block height is required for ord server".to_string(),
+                    )
+                })?;
+                let mut source = OrdServerSource::new(
+                    OrdClient::new(ord_url),
+                    self.start_block_height,
+                    end_block_height,
+                );
+                verifier.verify(&mut source, self.resume).await?
+            }
+            (None, None) => {
+                return Err(RoochError::CommandArgumentError(
+                    "either events file or ord url is required".to_string(),
+                ))
+            }
+        };
+        report.mismatches.truncate(self.max_output_mismatches);
+        Ok(VerifyOutput {
+            checkpoint: report.checkpoint,
+            report_path: verifier.report_path(),
+            mismatches: report.mismatches,
+        })
+    }
+}
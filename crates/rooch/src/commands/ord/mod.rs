@@ -0,0 +1,32 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::verify::VerifyCommand;
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Ord commands
+#[derive(Parser)]
+pub struct Ord {
+    #[clap(subcommand)]
+    cmd: OrdCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Ord {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            OrdCommand::Verify(verify) => verify.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "ord")]
+pub enum OrdCommand {
+    Verify(VerifyCommand),
+}
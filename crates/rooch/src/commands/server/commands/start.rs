@@ -68,18 +68,8 @@ impl CommandAction<()> for StartCommand {
             )?
         };
 
-        let (sequencer_keypair, proposer_keypair) = if context.keystore.get_if_password_is_empty() {
-            let sequencer_keypair = context
-                .keystore
-                .get_key_pair(&sequencer_account, None)
-                .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
-
-            let proposer_keypair = context
-                .keystore
-                .get_key_pair(&proposer_account, None)
-                .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
-
-            (sequencer_keypair, proposer_keypair)
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
         } else {
             let password = prompt_password("Enter the password:").unwrap_or_default();
             let is_verified =
@@ -90,23 +80,46 @@ impl CommandAction<()> for StartCommand {
                     "Password is invalid".to_owned(),
                 ));
             }
+            Some(password)
+        };
 
-            let sequencer_keypair = context
-                .keystore
-                .get_key_pair(&sequencer_account, Some(password.clone()))
-                .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
+        let sequencer_keypair = context
+            .keystore
+            .get_key_pair(&sequencer_account, password.clone())
+            .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
 
-            let proposer_keypair = context
-                .keystore
-                .get_key_pair(&proposer_account, Some(password.clone()))
-                .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
+        let proposer_keypair = context
+            .keystore
+            .get_key_pair(&proposer_account, password.clone())
+            .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
 
-            (sequencer_keypair, proposer_keypair)
+        // The randomness beacon VRF keypair is stored along with the sequencer key
+        let randomness_keypair = if self.opt.randomness_beacon {
+            let keypair = match context
+                .keystore
+                .get_vrf_key_pair(&sequencer_account, password.clone())?
+            {
+                Some(keypair) => keypair,
+                None => {
+                    info!(
+                        "Generate the randomness beacon VRF keypair for the sequencer account: {}",
+                        sequencer_account
+                    );
+                    context
+                        .keystore
+                        .generate_vrf_key_pair(&sequencer_account, password.clone())?
+                }
+            };
+            Some(keypair)
+        } else {
+            None
         };
+
         // Construct sequencer, proposer and relayer keypair
         let mut server_opt = ServerOpt::new();
         server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
         server_opt.proposer_keypair = Some(proposer_keypair.copy());
+        server_opt.randomness_keypair = randomness_keypair;
 
         let active_env = context.client_config.get_active_env()?;
         server_opt.active_env = Some(active_env.clone().alias);
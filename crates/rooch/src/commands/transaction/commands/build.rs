@@ -8,11 +8,12 @@ use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::MoveAction;
 use rooch_types::{
+    address::ParsedAddress,
     error::RoochResult,
     function_arg::{parse_function_arg, FunctionArg, ParsedFunctionId},
 };
 
-use super::{FileOutput, FileOutputData};
+use super::{expiration_timestamp_secs, FileOutput, FileOutputData};
 
 /// Get transactions by order
 #[derive(Debug, clap::Parser)]
@@ -43,6 +44,14 @@ pub struct BuildCommand {
     #[clap(flatten)]
     tx_options: TransactionOptions,
 
+    /// The fee payer of the sponsored transaction, the fee payer pays the gas and must co-sign the transaction.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    fee_payer: Option<ParsedAddress>,
+
+    /// The transaction expires after the given seconds, the expired transaction is rejected by the sequencer and the validator.
+    #[clap(long)]
+    expires_in: Option<u64>,
+
     #[clap(flatten)]
     context: WalletContextOptions,
 
@@ -82,9 +91,16 @@ impl CommandAction<Option<FileOutput>> for BuildCommand {
             .collect::<Result<Vec<_>>>()?;
         let action = MoveAction::new_function_call(function_id, type_args, args);
 
-        let tx_data = context
+        let mut tx_data = context
             .build_tx_data_with_sequence_number(sender, action, max_gas_amount, sequenc_number)
             .await?;
+        if let Some(fee_payer) = self.fee_payer {
+            tx_data = tx_data.with_fee_payer(context.resolve_rooch_address(fee_payer)?);
+        }
+        if let Some(expires_in) = self.expires_in {
+            tx_data =
+                tx_data.with_expiration_timestamp_secs(expiration_timestamp_secs(expires_in)?);
+        }
 
         let output =
             FileOutput::write_to_file(FileOutputData::RoochTransactionData(tx_data), self.output)?;
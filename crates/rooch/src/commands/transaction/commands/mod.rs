@@ -7,16 +7,30 @@ use rooch_types::transaction::{
     rooch::PartiallySignedRoochTransaction, RoochTransaction, RoochTransactionData,
 };
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File, io::Write, path::PathBuf};
+use std::{
+    env,
+    fs::File,
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub mod build;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
+pub mod prove;
 pub mod query;
 pub mod sign;
 pub mod sign_order;
 pub mod submit;
 
+/// The expiration timestamp in second of the transaction which expires after `expires_in` seconds from now
+pub(crate) fn expiration_timestamp_secs(expires_in: u64) -> Result<u64> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    now.checked_add(expires_in)
+        .ok_or_else(|| anyhow::anyhow!("Invalid expires in: {}", expires_in))
+}
+
 pub(crate) enum FileOutputData {
     RoochTransactionData(RoochTransactionData),
     SignedRoochTransaction(RoochTransaction),
@@ -0,0 +1,88 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use rooch_rpc_api::jsonrpc_types::block_view::BlockView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    TransactionProofView, TxHashOrOrder, TxHashOrOrderView,
+};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::TransactionProof;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProveTransactionOutput {
+    pub block: BlockView,
+    pub proof: TransactionProofView,
+}
+
+/// Prove a transaction is included in a block committed by the proposer.
+/// The proof is fetched from the node and verified offline against the tx accumulator root of the committed block.
+#[derive(Debug, clap::Parser)]
+pub struct ProveCommand {
+    /// The tx hash with `0x` prefix or the tx order
+    #[clap(long)]
+    pub tx: TxHashOrOrderView,
+
+    /// The number of the block committed to the State Commitment Chain
+    #[clap(long)]
+    pub block_number: u128,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ProveTransactionOutput> for ProveCommand {
+    async fn execute(self) -> RoochResult<ProveTransactionOutput> {
+        let client = self.context_options.build()?.get_client().await?;
+
+        let block = client
+            .rooch
+            .get_committed_block(self.block_number)
+            .await?
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!(
+                    "Block {} is not committed",
+                    self.block_number
+                ))
+            })?;
+        // Anchor the proof at the last transaction of the block, so the proof root is the root committed in the block
+        let tx_order_end = block.tx_order_end.0;
+        let proof_view = client
+            .rooch
+            .get_transaction_proof(self.tx.0, Some(tx_order_end))
+            .await?
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!("Transaction {} not found", self.tx))
+            })?;
+
+        let proof: TransactionProof = proof_view.clone().into();
+        let is_requested_tx = match self.tx.0 {
+            TxHashOrOrder::TxHash(tx_hash) => proof.tx_hash == tx_hash,
+            TxHashOrOrder::TxOrder(tx_order) => proof.tx_order == tx_order,
+        };
+        if !is_requested_tx {
+            return Err(RoochError::TransactionError(format!(
+                "The proof of tx order {} is not for the transaction {}",
+                proof.tx_order, self.tx
+            )));
+        }
+        if proof.tx_order > tx_order_end {
+            return Err(RoochError::TransactionError(format!(
+                "Transaction at order {} is not included in block {}, the last tx order of the block is {}",
+                proof.tx_order, self.block_number, tx_order_end
+            )));
+        }
+        // Do not trust the root returned with the proof, verify against the committed block
+        proof
+            .verify_root(block.tx_accumulator_root.0)
+            .map_err(|e| RoochError::TransactionError(format!("Invalid proof: {}", e)))?;
+
+        Ok(ProveTransactionOutput {
+            block,
+            proof: proof_view,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{FileOutput, FileOutputData};
+use super::{expiration_timestamp_secs, FileOutput, FileOutputData};
 use crate::cli_types::{CommandAction, FileOrHexInput, WalletContextOptions};
 use crate::utils::prompt_yes_no;
 use async_trait::async_trait;
@@ -21,33 +21,51 @@ use rooch_types::{
 pub enum SignInput {
     RoochTransactionData(RoochTransactionData),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    /// The sponsored transaction signed by the sender, waiting for the fee payer's signature
+    SignedRoochTransaction(RoochTransaction),
 }
 
 impl TryFrom<FileOrHexInput> for SignInput {
     type Error = anyhow::Error;
 
     fn try_from(value: FileOrHexInput) -> Result<Self, Self::Error> {
-        let input = match bcs::from_bytes::<RoochTransactionData>(&value.data) {
-            Ok(tx_data) => SignInput::RoochTransactionData(tx_data),
-            Err(_) => {
-                let psrt: PartiallySignedRoochTransaction = match bcs::from_bytes(&value.data) {
-                    Ok(psrt) => psrt,
-                    Err(_) => {
-                        return Err(anyhow::anyhow!("Invalid tx data or psrt data"));
-                    }
-                };
-                SignInput::PartiallySignedRoochTransaction(psrt)
-            }
-        };
-        Ok(input)
+        if let Ok(tx_data) = RoochTransactionData::decode(&value.data) {
+            return Ok(SignInput::RoochTransactionData(tx_data));
+        }
+        if let Ok(psrt) = PartiallySignedRoochTransaction::decode(&value.data) {
+            return Ok(SignInput::PartiallySignedRoochTransaction(psrt));
+        }
+        match RoochTransaction::decode(&value.data) {
+            Ok(tx) => Ok(SignInput::SignedRoochTransaction(tx)),
+            Err(_) => Err(anyhow::anyhow!(
+                "Invalid tx data, psrt data or signed tx data"
+            )),
+        }
     }
 }
 
 impl SignInput {
+    /// Set the expiration of the transaction data before signing,
+    /// the signed or partially signed transaction can not be changed.
+    pub fn with_expires_in(self, expires_in: Option<u64>) -> anyhow::Result<Self> {
+        match (self, expires_in) {
+            (input, None) => Ok(input),
+            (SignInput::RoochTransactionData(tx_data), Some(expires_in)) => {
+                Ok(SignInput::RoochTransactionData(
+                    tx_data.with_expiration_timestamp_secs(expiration_timestamp_secs(expires_in)?),
+                ))
+            }
+            (_, Some(_)) => Err(anyhow::anyhow!(
+                "Cannot set the expiration of a transaction which has been signed"
+            )),
+        }
+    }
+
     pub fn sender(&self) -> RoochAddress {
         match self {
             SignInput::RoochTransactionData(tx_data) => tx_data.sender,
             SignInput::PartiallySignedRoochTransaction(psrt) => psrt.sender(),
+            SignInput::SignedRoochTransaction(tx) => tx.sender(),
         }
     }
 }
@@ -58,7 +76,10 @@ pub enum SignOutput {
 
 impl SignOutput {
     pub fn is_finished(&self) -> bool {
-        matches!(self, SignOutput::SignedRoochTransaction(_))
+        match self {
+            SignOutput::SignedRoochTransaction(tx) => tx.is_fully_signed(),
+            SignOutput::PartiallySignedRoochTransaction(_) => false,
+        }
     }
 }
 
@@ -86,6 +107,10 @@ pub struct SignCommand {
     #[clap(short = 's', long, value_parser=ParsedAddress::parse)]
     signer: Option<ParsedAddress>,
 
+    /// The transaction expires after the given seconds, only for the unsigned transaction data
+    #[clap(long)]
+    expires_in: Option<u64>,
+
     /// The output file path for the signed transaction
     /// If not specified, the signed output will write to temp directory.
     #[clap(long, short = 'o')]
@@ -104,12 +129,36 @@ pub struct SignCommand {
 }
 
 impl SignCommand {
-    async fn sign(self) -> anyhow::Result<SignOutput> {
+    async fn sign(self, sign_input: SignInput) -> anyhow::Result<SignOutput> {
         let context = self.context.build_require_password()?;
         let client = context.get_client().await?;
         let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
-        let sign_input = SignInput::try_from(self.input)?;
         let sender = sign_input.sender();
+        let signer = self
+            .signer
+            .map(|signer| context.resolve_rooch_address(signer))
+            .transpose()?;
+
+        // The fee payer co-signs the sponsored transaction which has been signed by the sender
+        if let SignInput::SignedRoochTransaction(tx) = sign_input {
+            let fee_payer = tx.fee_payer().ok_or_else(|| {
+                anyhow::anyhow!("The transaction is already signed and does not have a fee payer")
+            })?;
+            if tx.is_fully_signed() {
+                return Err(anyhow::anyhow!(
+                    "The fee payer has already signed the transaction"
+                ));
+            }
+            if signer.is_some() && signer != Some(fee_payer) {
+                return Err(anyhow::anyhow!(
+                    "The signer address is not the fee payer {} of the transaction",
+                    fee_payer
+                ));
+            }
+            let tx = context.sign_transaction_as_fee_payer(fee_payer, tx)?;
+            return Ok(SignOutput::SignedRoochTransaction(tx));
+        }
+
         let output = if multisign_account_module.is_multisign_account(sender.into())? {
             let threshold = multisign_account_module.threshold(sender.into())?;
 
@@ -118,17 +167,22 @@ impl SignCommand {
                     PartiallySignedRoochTransaction::new(tx_data, threshold)
                 }
                 SignInput::PartiallySignedRoochTransaction(psrt) => psrt,
+                SignInput::SignedRoochTransaction(_) => unreachable!(),
             };
-            match self.signer {
+            let fee_payer = psrt.fee_payer();
+            match signer {
+                Some(signer) if Some(signer) == fee_payer => {
+                    let kp = context.get_key_pair(&signer)?;
+                    psrt.set_fee_payer_authenticator(BitcoinAuthenticator::sign(&kp, &psrt.data))?;
+                }
                 Some(signer) => {
-                    let signer = context.resolve_address(signer)?;
-                    if !multisign_account_module.is_participant(sender.into(), signer)? {
+                    if !multisign_account_module.is_participant(sender.into(), signer.into())? {
                         return Err(anyhow::anyhow!(
                             "The signer address {} is not a participant in the multisign account",
                             signer
                         ));
                     }
-                    let kp = context.get_key_pair(&signer.into())?;
+                    let kp = context.get_key_pair(&signer)?;
                     let authenticator = BitcoinAuthenticator::sign(&kp, &psrt.data);
                     if psrt.contains_authenticator(&authenticator) {
                         return Err(anyhow::anyhow!(
@@ -155,7 +209,20 @@ impl SignCommand {
                             psrt.add_authenticator(authenticator)?;
                         }
                     }
-                    if !has_participant {
+                    // The fee payer can sign at the same time if its key is in the keystore
+                    let has_fee_payer = match fee_payer {
+                        Some(fee_payer) if context.keystore.contains_address(&fee_payer) => {
+                            if !psrt.is_fee_payer_signed() {
+                                let kp = context.get_key_pair(&fee_payer)?;
+                                psrt.set_fee_payer_authenticator(BitcoinAuthenticator::sign(
+                                    &kp, &psrt.data,
+                                ))?;
+                            }
+                            true
+                        }
+                        _ => false,
+                    };
+                    if !has_participant && !has_fee_payer {
                         return Err(anyhow::anyhow!("No participant found in the multisign account from the keystore, participants: {:?}", participants));
                     }
                 }
@@ -174,21 +241,43 @@ impl SignCommand {
                         "Cannot sign a partially signed transaction with a single signer"
                     ))
                 }
+                SignInput::SignedRoochTransaction(_) => unreachable!(),
             };
-            SignOutput::SignedRoochTransaction(context.sign_transaction(sender, tx_data)?)
+            let fee_payer = tx_data.fee_payer();
+            let tx = context.sign_transaction(sender, tx_data)?;
+            // The fee payer can sign at the same time if its key is in the keystore,
+            // otherwise the signed transaction is sent to the fee payer to co-sign.
+            match fee_payer {
+                Some(fee_payer) if context.keystore.contains_address(&fee_payer) => {
+                    SignOutput::SignedRoochTransaction(
+                        context.sign_transaction_as_fee_payer(fee_payer, tx)?,
+                    )
+                }
+                _ => SignOutput::SignedRoochTransaction(tx),
+            }
         };
         Ok(output)
     }
 
     fn print_tx_details(input: &SignInput) {
         let tx_data = |tx_data: &RoochTransactionData| -> String {
+            let fee_payer = tx_data
+                .fee_payer()
+                .map(|fee_payer| format!(" Fee payer: {}\n", fee_payer))
+                .unwrap_or_default();
+            let expiration = tx_data
+                .expiration_timestamp_secs()
+                .map(|expiration| format!(" Expiration timestamp: {}\n", expiration))
+                .unwrap_or_default();
             format!(
-                " Sender: {}\n Sequence number: {}\n Chain id: {}\n Max gas amount: {}\n Action: {}\n Transaction hash: {:?}\n",
+                " Sender: {}\n Sequence number: {}\n Chain id: {}\n Max gas amount: {}\n Action: {}\n{}{} Transaction hash: {:?}\n",
                 tx_data.sender,
                 tx_data.sequence_number,
                 tx_data.chain_id,
                 tx_data.max_gas_amount,
                 tx_data.action,
+                fee_payer,
+                expiration,
                 tx_data.tx_hash()
             )
         };
@@ -207,6 +296,15 @@ impl SignCommand {
                     pstx.authenticators.len(),
                     pstx.threshold
                 );
+                if pstx.fee_payer().is_some() {
+                    println!(" Fee payer signed: {}", pstx.is_fee_payer_signed());
+                }
+            }
+            SignInput::SignedRoochTransaction(tx) => {
+                println!(
+                    "Signed transaction waiting for the fee payer:\n{}",
+                    tx_data(&tx.data)
+                );
             }
         }
     }
@@ -215,15 +313,20 @@ impl SignCommand {
 #[async_trait]
 impl CommandAction<Option<FileOutput>> for SignCommand {
     async fn execute(self) -> RoochResult<Option<FileOutput>> {
-        let sign_input = SignInput::try_from(self.input.clone())?;
+        let sign_input =
+            SignInput::try_from(self.input.clone())?.with_expires_in(self.expires_in)?;
         SignCommand::print_tx_details(&sign_input);
         if !self.answer_yes && !prompt_yes_no("Do you want to sign this transaction?") {
             return Ok(None);
         }
         let json = self.json;
         let output = self.output.clone();
-        let sign_output = self.sign().await?;
+        let sign_output = self.sign(sign_input).await?;
         let is_finished = sign_output.is_finished();
+        let fee_payer_to_sign = match &sign_output {
+            SignOutput::SignedRoochTransaction(tx) if !tx.is_fully_signed() => tx.fee_payer(),
+            _ => None,
+        };
 
         let file_output_data = sign_output.into();
         let file_output = FileOutput::write_to_file(file_output_data, output)?;
@@ -235,6 +338,15 @@ impl CommandAction<Option<FileOutput>> for SignCommand {
                     "You can submit the transaction with `rooch tx submit {}`",
                     file_output.path
                 );
+            } else if let Some(fee_payer) = fee_payer_to_sign {
+                println!(
+                    "Signed transaction is written to {:?}, it needs to be signed by the fee payer {}",
+                    file_output.path, fee_payer
+                );
+                println!(
+                    "You can send the signed transaction to the fee payer, and sign it with `rooch tx sign {}`",
+                    file_output.path
+                );
             } else {
                 println!(
                     "Partially signed transaction is written to {:?}",
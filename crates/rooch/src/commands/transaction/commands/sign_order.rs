@@ -26,7 +26,7 @@ impl SignOrderCommand {
         let sequencer_keypair =
             get_sequencer_keypair(self.context_options, self.sequencer_account)?;
         let tx_order_sign =
-            LedgerTransaction::sign_tx_order(self.tx_order, self.tx_hash, &sequencer_keypair);
+            LedgerTransaction::sign_tx_order(self.tx_order, self.tx_hash, None, &sequencer_keypair);
         let tx_order_sign_str = serde_json::to_string(&tx_order_sign)?;
         Ok(tx_order_sign_str)
     }
@@ -35,6 +35,12 @@ impl CommandAction<ExecuteTransactionResponseView> for SubmitCommand {
                 hex::encode(&self.input.data)
             ))
         })?;
+        if !signed_tx.is_fully_signed() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The sponsored transaction is not signed by the fee payer {:?}",
+                signed_tx.fee_payer()
+            )));
+        }
 
         //TODO support no json output
         let response = context.execute(signed_tx).await?;
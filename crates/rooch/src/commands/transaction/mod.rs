@@ -5,8 +5,8 @@ use crate::cli_types::CommandAction;
 use crate::commands::transaction::commands::sign_order::SignOrderCommand;
 use crate::commands::transaction::commands::{
     build::BuildCommand, get_transactions_by_hash::GetTransactionsByHashCommand,
-    get_transactions_by_order::GetTransactionsByOrderCommand, query::QueryCommand,
-    sign::SignCommand, submit::SubmitCommand,
+    get_transactions_by_order::GetTransactionsByOrderCommand, prove::ProveCommand,
+    query::QueryCommand, sign::SignCommand, submit::SubmitCommand,
 };
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -28,6 +28,7 @@ impl CommandAction<String> for Transaction {
             TransactionCommand::GetTransactionsByOrder(cmd) => cmd.execute_serialized().await,
             TransactionCommand::GetTransactionsByHash(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Query(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Prove(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Build(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Sign(cmd) => cmd.execute_serialized().await,
             TransactionCommand::Submit(cmd) => cmd.execute_serialized().await,
@@ -42,6 +43,7 @@ pub enum TransactionCommand {
     GetTransactionsByOrder(GetTransactionsByOrderCommand),
     GetTransactionsByHash(GetTransactionsByHashCommand),
     Query(QueryCommand),
+    Prove(ProveCommand),
     Sign(SignCommand),
     Submit(SubmitCommand),
     SignOrder(SignOrderCommand),
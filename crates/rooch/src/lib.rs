@@ -14,9 +14,9 @@ use cli_types::CommandAction;
 use commands::{
     abi::ABI, account::Account, bitcoin::Bitcoin, bitseed::Bitseed, dynamic_field::DynamicField,
     env::Env, faucet::Faucet, genesis::Genesis, init::Init, move_cli::MoveCli,
-    object::ObjectCommand, oracle::Oracle, resource::ResourceCommand, rpc::Rpc, server::Server,
-    session_key::SessionKey, state::StateCommand, task::Task, transaction::Transaction,
-    upgrade::Upgrade, util::Util, version::Version,
+    object::ObjectCommand, oracle::Oracle, ord::Ord, resource::ResourceCommand, rpc::Rpc,
+    server::Server, session_key::SessionKey, state::StateCommand, task::Task,
+    transaction::Transaction, upgrade::Upgrade, util::Util, version::Version,
 };
 use once_cell::sync::Lazy;
 use rooch_types::error::RoochResult;
@@ -75,6 +75,7 @@ pub enum Command {
     Util(Util),
     Faucet(Faucet),
     Oracle(Oracle),
+    Ord(Ord),
     DA(DA),
 }
 
@@ -106,6 +107,7 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Util(util) => util.execute().await,
         Command::Faucet(faucet) => faucet.execute().await,
         Command::Oracle(oracle) => oracle.execute().await,
+        Command::Ord(ord) => ord.execute().await,
         Command::DA(da) => da.execute().await,
     }
 }
@@ -33,7 +33,7 @@ static GLOBAL: allocator::Allocator = allocator::allocator();
 /// rooch is a command line tools for Rooch Network
 #[tokio::main]
 async fn main() {
-    let _ = tracing_subscriber::fmt::try_init();
+    metrics::otel::init_tracing_subscriber();
 
     let opt = RoochCli::parse();
     let result = rooch::run_cli(opt).await;
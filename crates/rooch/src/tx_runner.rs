@@ -16,6 +16,9 @@ use moveos::vm::data_cache::MoveosDataCache;
 use moveos::vm::moveos_vm::{MoveOSSession, MoveOSVM};
 use moveos_common::types::ClassifiedGasMeter;
 use moveos_gas_profiling::profiler::{new_gas_profiler, ProfileGasMeter};
+use moveos_gas_profiling::tracer::{
+    ExecutionTrace, ExecutionTracer, ObjectReadRecorder, ObjectWrite,
+};
 use moveos_object_runtime::runtime::ObjectRuntime;
 use moveos_types::h256::H256;
 use moveos_types::move_std::option::MoveOption;
@@ -23,6 +26,7 @@ use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_meta::TxMeta;
+use moveos_types::state_resolver::{MoveOSResolver, RootObjectResolver};
 use moveos_types::transaction::{
     MoveAction, RawTransactionOutput, VMErrorInfo, VerifiedMoveAction, VerifiedMoveOSTransaction,
 };
@@ -125,7 +129,7 @@ pub fn execute_tx_locally_with_gas_profile(
     );
     gas_meter.charge_io_write(tx.tx_size()).unwrap();
 
-    let mut gas_profiler = new_gas_profiler(tx.clone().action, gas_meter);
+    let mut gas_profiler = new_gas_profiler(tx.clone().action, gas_meter)?;
 
     let mut moveos_session = MoveOSSession::new(
         move_mv.inner(),
@@ -184,14 +188,81 @@ pub fn execute_tx_locally_with_gas_profile(
     result
 }
 
-pub fn prepare_execute_env(
+/// Replay the transaction on the given state root with the execution tracer,
+/// the trace records the call frames, the bytecode steps and the objects read and written.
+pub fn execute_tx_locally_with_trace(
+    state_root_bytes: Vec<u8>,
+    client: Client,
+    tx: RoochTransactionData,
+) -> anyhow::Result<ExecutionTrace> {
+    let state_root = H256::from_slice(state_root_bytes.as_slice());
+    let root_object_meta = ObjectMeta::root_metadata(state_root, 0);
+    let client_resolver = ClientResolver::new(client, root_object_meta.clone());
+    let read_recorder = ObjectReadRecorder::new(&client_resolver);
+    let resolver = RootObjectResolver::new(root_object_meta.clone(), &read_recorder);
+
+    let (move_mv, object_runtime, resolver, action, cost_table) =
+        prepare_execute_env(root_object_meta, &resolver, tx.clone());
+
+    let mut gas_meter = MoveOSGasMeter::new(cost_table, tx.max_gas_amount, true);
+    gas_meter.charge_io_write(tx.tx_size() + AUTH_PAYLOAD_SIZE)?;
+
+    let tracer = ExecutionTracer::new(gas_meter);
+
+    let mut moveos_session = MoveOSSession::new(
+        move_mv.inner(),
+        resolver,
+        object_runtime,
+        tracer.clone(),
+        false,
+    );
+
+    tracer.enter_section("system_pre_execute");
+    moveos_session.execute_function_call(system_pre_execute_functions(), false)?;
+
+    tracer.enter_section(action_section_name(&action));
+    let (status, error) = match moveos_session.execute_move_action(action) {
+        Ok(_) => (Executed, None),
+        Err(vm_err) => match vm_err.clone().into_vm_status().keep_or_discard() {
+            Ok(kept_status) => (kept_status, Some(vm_err.to_string())),
+            Err(discarded_status) => {
+                anyhow::bail!("Transaction is discarded: {:?}", discarded_status)
+            }
+        },
+    };
+    let (_tx_context, raw_tx_output) = moveos_session.finish_with_extensions(status)?;
+    let (frames, total_steps) = tracer.finish();
+
+    Ok(ExecutionTrace {
+        tx_hash: tx.tx_hash(),
+        state_root,
+        status: format!("{:?}", raw_tx_output.status),
+        error,
+        gas_used: raw_tx_output.gas_used,
+        total_steps,
+        frames,
+        object_reads: read_recorder.reads(),
+        object_writes: ObjectWrite::from_change_set(&raw_tx_output.changeset),
+    })
+}
+
+fn action_section_name(action: &VerifiedMoveAction) -> String {
+    match action {
+        VerifiedMoveAction::Function { call, .. } => call.function_id.to_string(),
+        VerifiedMoveAction::Script { .. } => "script".to_string(),
+        VerifiedMoveAction::ModuleBundle { .. } => "module_bundle".to_string(),
+        VerifiedMoveAction::MultiCall { .. } => "multi_call".to_string(),
+    }
+}
+
+pub fn prepare_execute_env<R: MoveOSResolver>(
     state_root: ObjectMeta,
-    client_resolver: &ClientResolver,
+    client_resolver: &R,
     tx: RoochTransactionData,
 ) -> (
     MoveOSVM,
     Rc<RwLock<ObjectRuntime>>,
-    &ClientResolver,
+    &R,
     VerifiedMoveAction,
     CostTable,
 ) {
@@ -267,6 +338,7 @@ fn convert_to_verified_tx(
             module_bundle,
             init_function_modules: vec![],
         },
+        MoveAction::MultiCall(multi_call) => VerifiedMoveAction::MultiCall { multi_call },
     };
 
     Ok(VerifiedMoveOSTransaction::new(
@@ -310,7 +382,7 @@ pub async fn dry_run_tx_locally(
     }
 }
 
-async fn get_latest_state_root(client: &Client) -> anyhow::Result<Vec<u8>> {
+pub async fn get_latest_state_root(client: &Client) -> anyhow::Result<Vec<u8>> {
     let status = client.rooch.status().await?;
     Ok(status
         .rooch_status
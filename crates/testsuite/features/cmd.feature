@@ -31,6 +31,57 @@ Feature: Rooch CLI integration tests
       Then assert: "{{$.rpc[-1].name}} == 'display'"
       Then stop the server 
     
+    @serial
+    Scenario: state commitment chain
+      Given a server with da for state_commitment_chain
+      Then cmd: "move run --function rooch_framework::empty::empty --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then sleep: "2"
+      Then cmd: "move run --function rooch_framework::empty::empty --json"
+      Then cmd: "move run --function rooch_framework::empty::empty --json"
+      # wait for the block to be submitted to the DA and committed to the on-chain SCC by the proposer
+      Then sleep: "10"
+      Then cmd: "rpc request --method rooch_getCommittedBlock --params '["0"]' --json"
+      Then assert: "{{$.rpc[-1].block_number}} == 0"
+      Then cmd: "rpc request --method rooch_getTransactionProof --params '["1"]' --json"
+      Then assert: "{{$.rpc[-1].tx_order}} == 1"
+      # verify the inclusion proof against the committed block
+      Then cmd: "transaction prove --tx 1 --block-number 0"
+      Then assert: "{{$.transaction[-1].proof.tx_order}} == 1"
+      # the chain is idle, the block submissions of the proposer do not produce new blocks
+      Then sleep: "10"
+      Then cmd: "rpc request --method rooch_status"
+      Then assert: "{{$.rpc[-1].rooch_status.da_info.last_block_number}} == 0"
+      Then stop the server
+
+    @serial
+    Scenario: mempool
+      Given a server with mempool tx ttl 10 for mempool
+      # the transaction with a sequence number gap waits in the mempool
+      Then cmd: "tx build --function rooch_framework::empty::empty --sequence-number 1 --json"
+      Then cmd: "tx sign {{$.tx[-1].path}} --json -y"
+      Then cmd: "rpc request --method rooch_sendRawTransaction --params '["0x{{$.tx[-1].content}}"]' --json"
+      Then cmd: "rpc request --method rooch_getPendingTransactions --params '[null]' --json"
+      Then assert: "{{$.rpc[-1][0].transaction.sequence_number}} == 1"
+      # fill the gap, the pending transaction is promoted and executed
+      Then cmd: "move run --function rooch_framework::empty::empty --sequence-number 0 --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then sleep: "2"
+      Then cmd: "rpc request --method rooch_getPendingTransactions --params '[null]' --json"
+      Then assert: "'{{$.rpc[-1]}}' == '[]'"
+      Then cmd: "move run --function rooch_framework::empty::empty --sequence-number 2 --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      # the pending transaction is evicted after the tx ttl if the gap is not filled
+      Then cmd: "tx build --function rooch_framework::empty::empty --sequence-number 4 --json"
+      Then cmd: "tx sign {{$.tx[-1].path}} --json -y"
+      Then cmd: "rpc request --method rooch_sendRawTransaction --params '["0x{{$.tx[-1].content}}"]' --json"
+      Then cmd: "rpc request --method rooch_getPendingTransactions --params '[null]' --json"
+      Then assert: "{{$.rpc[-1][0].transaction.sequence_number}} == 4"
+      Then sleep: "12"
+      Then cmd: "rpc request --method rooch_getPendingTransactions --params '[null]' --json"
+      Then assert: "'{{$.rpc[-1]}}' == '[]'"
+      Then stop the server
+
     @serial
     Scenario: account
       Given a server for account
@@ -500,6 +551,14 @@ Feature: Rooch CLI integration tests
       Then cmd: "move run --function default::cosmwasm_vm_execution::run_cosmwasm_example --sender default --args 'file:./data/cosmwasm_vm_execution_opt.wasm' --json --max-gas-amount=1000000000"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
 
+      # run cosmwasm contract lifecycle example
+      Then cmd: "move run --function default::cosmwasm_vm_execution::run_cosmwasm_contract_example --sender default --args 'file:./data/cosmwasm_vm_execution_opt.wasm' --json --max-gas-amount=1000000000"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+
+      # run the cw20 example with the standard cw20-base contract, including the sub message reply
+      Then cmd: "move run --function default::cosmwasm_vm_execution::run_cw20_example --sender default --args 'file:./data/cw20_base.wasm' --args 'file:./data/cosmwasm_vm_execution_opt.wasm' --json --max-gas-amount=1000000000"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+
       # release servers
       Then stop the server
 
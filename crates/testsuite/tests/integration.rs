@@ -11,11 +11,13 @@ use images::bitseed::Bitseed;
 use images::ord::Ord;
 use jpst::TemplateContext;
 use rooch::RoochCli;
+use rooch_config::da_config::DAConfig;
 use rooch_config::{RoochOpt, ServerOpt, ROOCH_CONFIR_DIR};
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_rpc_server::Service;
 use rooch_types::crypto::RoochKeyPair;
 use serde_json::Value;
+use std::str::FromStr;
 use std::time::Duration;
 use std::{path::Path, vec};
 use testcontainers::{
@@ -95,6 +97,28 @@ async fn start_server(w: &mut World, _scenario: String) {
     w.service = Some(service);
 }
 
+#[given(expr = "a server with da for {word}")] // Cucumber Expression
+async fn start_server_with_da(w: &mut World, scenario: String) {
+    // Make blocks and submit them to the local fs DA quickly, so the proposer can propose them
+    let openda_fs_dir = w.opt.da.get_openda_fs_dir();
+    std::fs::create_dir_all(&openda_fs_dir).unwrap();
+    let da_config = format!(
+        r#"{{"da-backend": {{"backends": [{{"open-da": {{"scheme": "fs", "config": {{"root": "{}"}}}}}}]}}, "background-submit-interval": 1, "batch-interval": 1000}}"#,
+        openda_fs_dir.display()
+    );
+    w.opt.da = DAConfig::from_str(&da_config).unwrap();
+    w.opt.proposer.interval = Some(1);
+
+    start_server(w, scenario).await;
+}
+
+#[given(expr = "a server with mempool tx ttl {int} for {word}")] // Cucumber Expression
+async fn start_server_with_mempool_tx_ttl(w: &mut World, tx_ttl: u64, scenario: String) {
+    w.opt.mempool.tx_ttl = Some(tx_ttl);
+
+    start_server(w, scenario).await;
+}
+
 #[then(expr = "stop the server")] // Cucumber Expression
 async fn stop_server(w: &mut World) {
     println!("stop server");
@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 module rooch_examples::cosmwasm_vm_execution {
-   use std::debug;
-   use std::string;
+   use std::string::{Self, String};
    use std::vector;
    use std::option;
+   use std::signer;
 
    use moveos_std::result;
+   use moveos_std::json;
    use rooch_nursery::cosmwasm_std;
    use rooch_nursery::cosmwasm_vm;
+   use rooch_nursery::cosmwasm_contract;
 
    #[data_struct]
    struct InstantiateMsg has store, copy, drop {
@@ -49,13 +51,69 @@ module rooch_examples::cosmwasm_vm_execution {
       update_value: UpdateValue
    }
 
-   entry public fun run_cosmwasm_example(_account: &signer, wasm_bytes: vector<u8>) {
-      debug::print(&string::utf8(b"run_cosmwasm_example start"));
+   #[data_struct]
+   struct Cw20Coin has store, copy, drop {
+      address: address,
+      amount: String
+   }
+
+   #[data_struct]
+   struct Cw20InstantiateMsg has store, copy, drop {
+      name: String,
+      symbol: String,
+      decimals: u8,
+      initial_balances: vector<Cw20Coin>
+   }
+
+   #[data_struct]
+   struct Cw20Transfer has store, copy, drop {
+      recipient: address,
+      amount: String
+   }
+
+   #[data_struct]
+   struct Cw20ExecuteMsg has store, copy, drop {
+      transfer: Cw20Transfer
+   }
+
+   #[data_struct]
+   struct Cw20Balance has store, copy, drop {
+      address: address
+   }
+
+   #[data_struct]
+   struct Cw20QueryMsg has store, copy, drop {
+      balance: Cw20Balance
+   }
+
+   #[data_struct]
+   struct Cw20BalanceResponse has store, copy, drop {
+      balance: String
+   }
+
+   #[data_struct]
+   struct TransferCw20 has store, copy, drop {
+      token: address,
+      recipient: address,
+      amount: String
+   }
+
+   #[data_struct]
+   struct CounterExecuteMsg has store, copy, drop {
+      transfer_cw20: TransferCw20
+   }
+
+   #[data_struct]
+   struct GetLastReplyId has store, copy, drop {}
+
+   #[data_struct]
+   struct CounterQueryMsg has store, copy, drop {
+      get_last_reply_id: GetLastReplyId
+   }
 
+   entry public fun run_cosmwasm_example(_account: &signer, wasm_bytes: vector<u8>) {
       // 1. create wasm VM instance (required step)
       let instance_result = cosmwasm_vm::from_code(wasm_bytes);
-      debug::print(&string::utf8(b"instance_result:"));
-      debug::print(&instance_result);
 
       // Verify that the result is successful
       let instance = result::assert_ok(instance_result, 1); // Use assert_ok here
@@ -72,9 +130,7 @@ module rooch_examples::cosmwasm_vm_execution {
          initial_value: 1
       };
       let instantiate_result = cosmwasm_vm::call_instantiate(&mut instance, &env, &info, &msg);
-      let instantiate_resp = result::assert_ok(instantiate_result, 3); // Use assert_ok here
-      debug::print(&string::utf8(b"instantiate_resp:"));
-      debug::print(&instantiate_resp);
+      result::assert_ok(instantiate_result, 3); // Use assert_ok here
 
       // 4. call execute of the instance
       let msg = ExecuteMsg{
@@ -84,9 +140,7 @@ module rooch_examples::cosmwasm_vm_execution {
       };
 
       let execute_result = cosmwasm_vm::call_execute(&mut instance, &env, &info, &msg);
-      let execute_resp = result::assert_ok(execute_result, 4); // Use assert_ok here
-      debug::print(&string::utf8(b"execute_resp:"));
-      debug::print(&execute_resp);
+      result::assert_ok(execute_result, 4); // Use assert_ok here
 
       // 5. call query of the instance
       let msg = QueryMsg{
@@ -94,9 +148,7 @@ module rooch_examples::cosmwasm_vm_execution {
       };
 
       let query_result = cosmwasm_vm::call_query(&instance, &env, &msg);
-      let query_resp = result::assert_ok(query_result, 5); // Use assert_ok here
-      debug::print(&string::utf8(b"query_resp:"));
-      debug::print(&query_resp);
+      result::assert_ok(query_result, 5); // Use assert_ok here
 
       // 6. call migrate of the instance
       let msg = MigreateMsg{
@@ -104,18 +156,14 @@ module rooch_examples::cosmwasm_vm_execution {
       };
 
       let migrate_result = cosmwasm_vm::call_migrate(&mut instance, &env, &msg);
-      let migrate_resp = result::assert_ok(migrate_result, 6); // Use assert_ok here
-      debug::print(&string::utf8(b"migrate_resp:"));
-      debug::print(&migrate_resp);
+      result::assert_ok(migrate_result, 6); // Use assert_ok here
 
       // 7. call reply of the instance
       let resp = cosmwasm_std::new_sub_msg_response();
       let msg = cosmwasm_std::new_reply(1, cosmwasm_std::new_binary(b"hello"), 10, resp);
 
       let reply_result = cosmwasm_vm::call_reply(&mut instance, &env, &msg);
-      let reply_resp = result::assert_ok(reply_result, 7); // Use assert_ok here
-      debug::print(&string::utf8(b"reply_resp:"));
-      debug::print(&reply_resp);
+      result::assert_ok(reply_result, 7); // Use assert_ok here
 
       // 8. call sudo of the instance
       let msg = SudoMsg{
@@ -125,12 +173,100 @@ module rooch_examples::cosmwasm_vm_execution {
       };
 
       let sudo_result = cosmwasm_vm::call_sudo(&mut instance, &env, &msg);
-      let sudo_resp = result::assert_ok(sudo_result, 8); // Use assert_ok here
-      debug::print(&string::utf8(b"sudo_resp:"));
-      debug::print(&sudo_resp);
+      result::assert_ok(sudo_result, 8); // Use assert_ok here
 
       // 9. Destroy the instance
       let destroy_result = cosmwasm_vm::destroy_instance(instance);
       assert!(option::is_none(&destroy_result), 9);
    }
+
+   entry public fun run_cosmwasm_contract_example(account: &signer, wasm_bytes: vector<u8>) {
+      // 1. store the code, the same code is only stored once
+      let code_id = cosmwasm_contract::store_code(account, wasm_bytes);
+      assert!(cosmwasm_contract::store_code(account, wasm_bytes) == code_id, 10);
+
+      // 2. instantiate the contract by the code id
+      let msg = InstantiateMsg{
+         initial_value: 1
+      };
+      let instantiate_result = cosmwasm_contract::instantiate(account, code_id, json::to_json(&msg), vector::empty(), string::utf8(b"counter"), option::none());
+      let contract = result::assert_ok(instantiate_result, 11);
+      assert!(cosmwasm_contract::contract_exists(contract), 12);
+      assert!(cosmwasm_contract::contract_code_id(contract) == code_id, 13);
+
+      // 3. execute the contract, the state is persisted between the calls
+      let msg = ExecuteMsg{
+         add: Add {
+            value: 1
+         }
+      };
+      let execute_result = cosmwasm_contract::execute(account, contract, json::to_json(&msg), vector::empty());
+      result::assert_ok(execute_result, 14);
+
+      // 4. query the contract
+      let msg = QueryMsg{
+         get_value: GetValue {}
+      };
+      let query_result = cosmwasm_contract::query(contract, json::to_json(&msg));
+      let query_resp = result::assert_ok(query_result, 15);
+      assert!(vector::length(&query_resp) > 0, 16);
+   }
+
+   /// Run the standard cw20-base contract: instantiate, transfer and query the balances,
+   /// then transfer the token from the counter contract in a sub message and handle the reply.
+   entry public fun run_cw20_example(account: &signer, cw20_wasm_bytes: vector<u8>, counter_wasm_bytes: vector<u8>) {
+      let sender = signer::address_of(account);
+      let recipient = @0x42;
+
+      // 1. instantiate the counter contract, it holds some tokens and transfers them in a sub message
+      let counter_code_id = cosmwasm_contract::store_code(account, counter_wasm_bytes);
+      let msg = InstantiateMsg{
+         initial_value: 0
+      };
+      let counter = result::assert_ok(cosmwasm_contract::instantiate(account, counter_code_id, json::to_json(&msg), vector::empty(), string::utf8(b"counter"), option::none()), 20);
+
+      // 2. instantiate the cw20 token with the initial balances
+      let cw20_code_id = cosmwasm_contract::store_code(account, cw20_wasm_bytes);
+      let initial_balances = vector::empty();
+      vector::push_back(&mut initial_balances, Cw20Coin{ address: sender, amount: string::utf8(b"1000") });
+      vector::push_back(&mut initial_balances, Cw20Coin{ address: counter, amount: string::utf8(b"100") });
+      let msg = Cw20InstantiateMsg{
+         name: string::utf8(b"Rooch Token"),
+         symbol: string::utf8(b"RTK"),
+         decimals: 6,
+         initial_balances,
+      };
+      let token = result::assert_ok(cosmwasm_contract::instantiate(account, cw20_code_id, json::to_json(&msg), vector::empty(), string::utf8(b"cw20"), option::none()), 21);
+      assert!(cw20_balance(token, sender) == string::utf8(b"1000"), 22);
+
+      // 3. transfer the token
+      let msg = Cw20ExecuteMsg{
+         transfer: Cw20Transfer{ recipient, amount: string::utf8(b"100") }
+      };
+      result::assert_ok(cosmwasm_contract::execute(account, token, json::to_json(&msg), vector::empty()), 23);
+      assert!(cw20_balance(token, sender) == string::utf8(b"900"), 24);
+      assert!(cw20_balance(token, recipient) == string::utf8(b"100"), 25);
+
+      // 4. the counter contract transfers the token in a sub message, and records the reply
+      let msg = CounterExecuteMsg{
+         transfer_cw20: TransferCw20{ token, recipient, amount: string::utf8(b"10") }
+      };
+      result::assert_ok(cosmwasm_contract::execute(account, counter, json::to_json(&msg), vector::empty()), 26);
+      assert!(cw20_balance(token, counter) == string::utf8(b"90"), 27);
+      assert!(cw20_balance(token, recipient) == string::utf8(b"110"), 28);
+      let msg = CounterQueryMsg{
+         get_last_reply_id: GetLastReplyId {}
+      };
+      let last_reply_id = result::assert_ok(cosmwasm_contract::query(counter, json::to_json(&msg)), 29);
+      assert!(last_reply_id == b"1", 30);
+   }
+
+   fun cw20_balance(token: address, owner: address): String {
+      let msg = Cw20QueryMsg{
+         balance: Cw20Balance{ address: owner }
+      };
+      let resp = result::assert_ok(cosmwasm_contract::query(token, json::to_json(&msg)), 31);
+      let balance = json::from_json<Cw20BalanceResponse>(resp);
+      balance.balance
+   }
 }
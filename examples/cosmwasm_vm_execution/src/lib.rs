@@ -3,7 +3,7 @@
 
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdResult, SubMsgResponse, SubMsgResult,
+    StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use serde::{Deserialize, Serialize};
 
@@ -15,13 +15,31 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    Add { value: u64 },
+    Add {
+        value: u64,
+    },
+    /// Transfer the cw20 token owned by the contract in a sub message, the result is sent back in the reply
+    TransferCw20 {
+        token: String,
+        recipient: String,
+        amount: Uint128,
+    },
 }
 
+/// The transfer message of the cw20 token contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20ExecuteMsg {
+    Transfer { recipient: String, amount: Uint128 },
+}
+
+pub const TRANSFER_CW20_REPLY_ID: u64 = 1;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     GetValue {},
+    GetLastReplyId {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -69,6 +87,20 @@ pub fn execute(
             deps.storage.set(b"value", &new_value.to_be_bytes());
             Ok(Response::default())
         }
+        ExecuteMsg::TransferCw20 {
+            token,
+            recipient,
+            amount,
+        } => {
+            let msg = WasmMsg::Execute {
+                contract_addr: token,
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer { recipient, amount })?,
+                funds: vec![],
+            };
+            Ok(Response::new()
+                .add_submessage(SubMsg::reply_on_success(msg, TRANSFER_CW20_REPLY_ID))
+                .add_attribute("action", "transfer_cw20"))
+        }
     }
 }
 
@@ -83,6 +115,14 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 .unwrap_or(0);
             to_json_binary(&value)
         }
+        QueryMsg::GetLastReplyId {} => {
+            let id = deps
+                .storage
+                .get(b"last_reply_id")
+                .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap_or([0; 8])))
+                .unwrap_or(0);
+            to_json_binary(&id)
+        }
     }
 }
 
@@ -95,7 +135,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
 }
 
 #[entry_point]
-pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    deps.storage.set(b"last_reply_id", &msg.id.to_be_bytes());
     Ok(Response::new()
         .add_attribute("action", "reply")
         .add_attribute("id", msg.id.to_string()))
@@ -213,6 +254,55 @@ mod tests {
         assert_eq!(res.attributes[1].value, "1");
     }
 
+    #[test]
+    fn test_execute_transfer_cw20() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let creator = deps.api.addr_make("creator");
+        let info = message_info(&creator, &[]);
+        let init_msg = InstantiateMsg { initial_value: 100 };
+        instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
+
+        let exec_msg = ExecuteMsg::TransferCw20 {
+            token: "token".to_string(),
+            recipient: "recipient".to_string(),
+            amount: Uint128::new(10),
+        };
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, TRANSFER_CW20_REPLY_ID);
+        assert_eq!(
+            res.messages[0].msg,
+            WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "recipient".to_string(),
+                    amount: Uint128::new(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        );
+
+        #[allow(deprecated)]
+        let reply_msg = Reply {
+            id: TRANSFER_CW20_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                data: None,
+                msg_responses: vec![],
+                events: vec![],
+            }),
+        };
+        reply(deps.as_mut(), env, reply_msg).unwrap();
+        let id: u64 =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GetLastReplyId {}).unwrap())
+                .unwrap();
+        assert_eq!(id, TRANSFER_CW20_REPLY_ID);
+    }
+
     #[test]
     fn test_sudo() {
         let mut deps = mock_dependencies();
@@ -25,6 +25,8 @@ module bitcoin_move::bitcoin{
     use bitcoin_move::pending_block::{Self, PendingBlock};
     use bitcoin_move::script_buf;
     use bitcoin_move::bbn;
+    use bitcoin_move::light_client;
+    use bitcoin_move::sat_range::{Self, SatRange, SatFlow, BlockFeeSatRanges};
 
     friend bitcoin_move::genesis;
 
@@ -137,6 +139,10 @@ module bitcoin_move::bitcoin{
         let sender: Option<address> = option::none<address>();
         let find_sender: bool = false;
         let input_len = vector::length(txinput);
+        let track_sat_ranges = sat_range::is_enabled();
+        let input_sat_ranges = vector::empty<SatRange>();
+        //The sats after the first input without sat ranges are unknown
+        let input_sats_known = true;
         while (idx < input_len) {
             let txin = vector::borrow(txinput, idx);
             let outpoint = *types::txin_previous_output(txin);
@@ -147,6 +153,14 @@ module bitcoin_move::bitcoin{
             if (utxo::exists_utxo(outpoint)) {
                 let object_id = utxo::derive_utxo_id(outpoint);
                 let utxo_obj = utxo::take(object_id);
+                if (track_sat_ranges) {
+                    let sat_ranges = utxo::remove_sat_ranges(&mut utxo_obj);
+                    if (input_sats_known && option::is_some(&sat_ranges)) {
+                        vector::append(&mut input_sat_ranges, option::destroy_some(sat_ranges));
+                    } else {
+                        input_sats_known = false;
+                    };
+                };
                 let utxo_owner = object::owner(&utxo_obj);
                 if (!find_sender && utxo_owner != @bitcoin_move) {
                     sender = option::some(utxo_owner);
@@ -165,6 +179,7 @@ module bitcoin_move::bitcoin{
                 };
                 let utxo = utxo::mock_utxo(outpoint, 0);
                 vector::push_back(&mut input_utxos, utxo);
+                input_sats_known = false;
             };
 
             idx = idx + 1;
@@ -185,8 +200,39 @@ module bitcoin_move::bitcoin{
             };
         };
     
+        let sat_flow = if (!track_sat_ranges) {
+            option::none<SatFlow>()
+        } else if (is_coinbase) {
+            //The coinbase is the last tx of the block, it takes the subsidy and all the fees of the block
+            let block_fee = if (pending_block::exists_intermediate<BlockFeeSatRanges>(pending_block)) {
+                pending_block::take_intermediate<BlockFeeSatRanges>(pending_block)
+            } else {
+                sat_range::new_block_fee()
+            };
+            option::some(sat_range::coinbase_flow(block_height, block_fee))
+        } else {
+            option::some(sat_range::new_flow(input_sat_ranges, input_sats_known))
+        };
+
         // create new utxo
-        let repeat_txid = handle_new_utxo(tx, is_coinbase, &mut output_seals, block_height, sender);
+        let repeat_txid = handle_new_utxo(tx, is_coinbase, &mut output_seals, block_height, sender, &mut sat_flow);
+
+        if (option::is_some(&sat_flow) && is_coinbase) {
+            //The sats not claimed by the coinbase outputs are lost
+            let (lost_ranges, lost_known) = sat_range::remaining(option::destroy_some(sat_flow));
+            if (lost_known && !vector::is_empty(&lost_ranges)) {
+                sat_range::add_burned_locations(&lost_ranges);
+            };
+        } else if (option::is_some(&sat_flow)) {
+            let (fee_ranges, fee_known) = sat_range::remaining(option::destroy_some(sat_flow));
+            let block_fee = if (pending_block::exists_intermediate<BlockFeeSatRanges>(pending_block)) {
+                pending_block::take_intermediate<BlockFeeSatRanges>(pending_block)
+            } else {
+                sat_range::new_block_fee()
+            };
+            sat_range::add_block_fee(&mut block_fee, fee_ranges, fee_known);
+            pending_block::add_intermediate(pending_block, block_fee);
+        };
 
         //We do not remove the value from output_seals, for the preformance reason.
         //So, we can not check the output_seals is empty here. just drop it.
@@ -199,7 +245,7 @@ module bitcoin_move::bitcoin{
         repeat_txid
     }
 
-    fun handle_new_utxo(tx: &Transaction, is_coinbase: bool, output_seals: &mut SimpleMultiMap<u32, UTXOSeal>, block_height: u64, sender: Option<address>) :bool {
+    fun handle_new_utxo(tx: &Transaction, is_coinbase: bool, output_seals: &mut SimpleMultiMap<u32, UTXOSeal>, block_height: u64, sender: Option<address>, sat_flow: &mut Option<SatFlow>) :bool {
         let txid = types::tx_id(tx);
         let txoutput = types::tx_output(tx);
         let idx = 0;
@@ -211,6 +257,12 @@ module bitcoin_move::bitcoin{
             let value = types::txout_value(txout);
             let output_script_buf = types::txout_script_pubkey(txout);
             let is_op_return = script_buf::is_op_return(output_script_buf);
+            //The sats of the OP_RETURN output are burned, but we still need to take them from the flow
+            let sat_ranges = if (option::is_some(sat_flow)) {
+                sat_range::take(option::borrow_mut(sat_flow), value)
+            } else {
+                option::none()
+            };
             if (is_coinbase &&  ((block_height < BIP_34_HEIGHT && network::is_mainnet()) || !network::is_mainnet())) {
                 let outpoint = types::new_outpoint(txid, vout);
                 let utxo_id = utxo::derive_utxo_id(outpoint);
@@ -230,10 +282,16 @@ module bitcoin_move::bitcoin{
             };
             //We should not create UTXO object for OP_RETURN output
             if(is_op_return){
+                if (option::is_some(&sat_ranges)) {
+                    sat_range::add_burned_locations(option::borrow(&sat_ranges));
+                };
                 idx = idx + 1;
                 continue
             };
             let utxo_obj = utxo::new(txid, vout, value);
+            if (option::is_some(&sat_ranges)) {
+                utxo::add_sat_ranges(&mut utxo_obj, option::destroy_some(sat_ranges));
+            };
             let utxo = object::borrow_mut(&mut utxo_obj);
             let seal_index = (idx as u32);
             if(simple_multimap::contains_key(output_seals, &seal_index)){
@@ -266,6 +324,8 @@ module bitcoin_move::bitcoin{
         let block = bcs::from_bytes<Block>(block_bytes);
         let block_header = types::header(&block);
         let time = types::time(block_header);
+        //Validate the proof-of-work and the difficulty of the header, abort if the header is invalid
+        light_client::process_header(block_height, block_hash, block_header);
         if(pending_block::add_pending_block(block_height, block_hash, block)){
             //We do not update the timestamp via bitcoin block header in testnet
             //Because the testnet block time is not accurate
@@ -0,0 +1,445 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Bitcoin block header light client, validate the proof-of-work, the difficulty retarget,
+/// the median-time-past and accumulate the chain work of the headers submitted by the relayer.
+/// The first header whose previous header is unknown is accepted as a checkpoint,
+/// so the light client can start from any height, like the Rooch genesis block height.
+/// Only the headers within a difficulty period below the best block are kept.
+module bitcoin_move::light_client{
+    use std::option::{Self, Option};
+    use std::vector;
+    use moveos_std::object;
+    use moveos_std::table::{Self, Table};
+    use moveos_std::bcs;
+    use moveos_std::address;
+    use bitcoin_move::types::{Self, Header, BlockHeightHash};
+    use bitcoin_move::network;
+    use bitcoin_move::pow;
+
+    friend bitcoin_move::bitcoin;
+
+    /// The block hash is not the hash of the block header
+    const ErrorBlockHashMismatch: u64 = 1;
+    /// The target of the block header is zero or above the proof-of-work limit
+    const ErrorInvalidTarget: u64 = 2;
+    /// The block hash is above the target
+    const ErrorInsufficientProofOfWork: u64 = 3;
+    /// The bits of the block header does not match the difficulty retarget rule
+    const ErrorInvalidDifficulty: u64 = 4;
+    /// The block time is not greater than the median time of the previous 11 blocks
+    const ErrorBlockTimeTooOld: u64 = 5;
+    /// The block height is not the previous block height plus one
+    const ErrorInvalidBlockHeight: u64 = 6;
+    /// The previous block is unknown, and the light client already has a checkpoint
+    const ErrorPrevBlockNotFound: u64 = 7;
+
+    /// The number of blocks of a difficulty adjustment period
+    const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+    /// Two weeks, the expected timespan of a difficulty adjustment period
+    const TARGET_TIMESPAN: u64 = 1209600;
+    /// The number of previous blocks to calculate the median time past
+    const MEDIAN_TIME_SPAN: u64 = 11;
+    /// The headers deeper than this below the best block are pruned.
+    /// The header info carries the period start time and the recent times,
+    /// so the older headers are only needed to follow a reorg deeper than a difficulty period.
+    const HEADER_RETAIN_WINDOW: u64 = 2016;
+
+    const POW_LIMIT_BITS_MAINNET: u32 = 0x1d00ffff;
+    const POW_LIMIT_BITS_SIGNET: u32 = 0x1e0377ae;
+    const POW_LIMIT_BITS_REGTEST: u32 = 0x207fffff;
+
+    struct HeaderInfo has store, copy, drop {
+        height: u64,
+        header: Header,
+        /// The accumulated chain work up to this block, start from the checkpoint
+        chain_work: u256,
+        /// The time of the first block of the current difficulty period, none if the period starts before the checkpoint
+        period_start_time: Option<u32>,
+        /// The time of the latest blocks, include this block, at most `MEDIAN_TIME_SPAN` items
+        recent_times: vector<u32>,
+    }
+
+    struct LightClientStore has key {
+        /// block hash -> header info
+        headers: Table<address, HeaderInfo>,
+        /// block height -> block hashes, used to prune the old headers
+        height_to_hashes: Table<u64, vector<address>>,
+        /// The block with the most chain work
+        best_block: Option<BlockHeightHash>,
+    }
+
+    fun exists_store(): bool {
+        object::exists_object(object::named_object_id<LightClientStore>())
+    }
+
+    fun borrow_store(): &LightClientStore {
+        let object_id = object::named_object_id<LightClientStore>();
+        object::borrow(object::borrow_object<LightClientStore>(object_id))
+    }
+
+    fun borrow_mut_store(): &mut LightClientStore {
+        let object_id = object::named_object_id<LightClientStore>();
+        // The store is created when the first header submitted after the light client is enabled
+        if (!object::exists_object(object_id)) {
+            let obj = object::new_named_object(LightClientStore {
+                headers: table::new(),
+                height_to_hashes: table::new(),
+                best_block: option::none(),
+            });
+            object::to_shared(obj);
+        };
+        object::borrow_mut(object::borrow_mut_object_shared<LightClientStore>(object_id))
+    }
+
+    /// Validate the block header, return 0 if the header is valid, otherwise return the error code.
+    /// It does not change the state, so it can be called before the block is submitted.
+    public fun validate_header(block_height: u64, block_hash: address, header: Header): u64 {
+        let (error, _) = check_header(block_height, block_hash, &header);
+        error
+    }
+
+    /// Validate and record the block header, abort if the header is invalid
+    public(friend) fun process_header(block_height: u64, block_hash: address, header: &Header) {
+        let (error, header_info) = check_header(block_height, block_hash, header);
+        assert!(error == 0, error);
+        record_header(block_height, block_hash, option::destroy_some(header_info));
+    }
+
+    fun record_header(block_height: u64, block_hash: address, header_info: HeaderInfo) {
+        let store = borrow_mut_store();
+        let chain_work = header_info.chain_work;
+        if (!table::contains(&store.headers, block_hash)) {
+            if (table::contains(&store.height_to_hashes, block_height)) {
+                vector::push_back(table::borrow_mut(&mut store.height_to_hashes, block_height), block_hash);
+            } else {
+                table::add(&mut store.height_to_hashes, block_height, vector[block_hash]);
+            };
+        };
+        table::upsert(&mut store.headers, block_hash, header_info);
+        let is_best = if (option::is_none(&store.best_block)) {
+            true
+        } else {
+            let (_, best_hash) = types::unpack_block_height_hash(*option::borrow(&store.best_block));
+            chain_work > table::borrow(&store.headers, best_hash).chain_work
+        };
+        if (is_best) {
+            store.best_block = option::some(types::new_block_height_hash(block_height, block_hash));
+            prune_headers(store, block_height);
+        };
+    }
+
+    /// Remove the headers at `HEADER_RETAIN_WINDOW` blocks below the new best block.
+    /// The best block moves forward one block at a time, so every height is pruned in turn.
+    fun prune_headers(store: &mut LightClientStore, best_height: u64) {
+        if (best_height < HEADER_RETAIN_WINDOW) {
+            return
+        };
+        let prune_height = best_height - HEADER_RETAIN_WINDOW;
+        if (!table::contains(&store.height_to_hashes, prune_height)) {
+            return
+        };
+        let hashes = table::remove(&mut store.height_to_hashes, prune_height);
+        while (!vector::is_empty(&hashes)) {
+            table::remove(&mut store.headers, vector::pop_back(&mut hashes));
+        };
+    }
+
+    fun check_header(block_height: u64, block_hash: address, header: &Header): (u64, Option<HeaderInfo>) {
+        if (types::header_to_hash(header) != block_hash) {
+            return (ErrorBlockHashMismatch, option::none())
+        };
+        let bits = types::bits(header);
+        let target = pow::bits_to_target(bits);
+        if (target == 0 || target > pow::bits_to_target(pow_limit_bits())) {
+            return (ErrorInvalidTarget, option::none())
+        };
+        if (hash_to_u256(block_hash) > target) {
+            return (ErrorInsufficientProofOfWork, option::none())
+        };
+        let time = types::time(header);
+        let work = pow::target_to_work(target);
+        let prev_info = get_header_info(types::prev_blockhash(header));
+        if (option::is_none(&prev_info)) {
+            // Accept the first header as checkpoint, the regtest may submit the blocks discontinuously
+            if (exists_store() && option::is_some(&borrow_store().best_block) && !network::is_regtest()) {
+                return (ErrorPrevBlockNotFound, option::none())
+            };
+            let period_start_time = if (block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0) {
+                option::some(time)
+            } else {
+                option::none()
+            };
+            return (0, option::some(HeaderInfo {
+                height: block_height,
+                header: *header,
+                chain_work: work,
+                period_start_time,
+                recent_times: vector[time],
+            }))
+        };
+        check_with_prev(&option::destroy_some(prev_info), block_height, header, work)
+    }
+
+    /// Check the header follows the previous header, the proof-of-work of the header is already checked
+    fun check_with_prev(prev_info: &HeaderInfo, block_height: u64, header: &Header, work: u256): (u64, Option<HeaderInfo>) {
+        let time = types::time(header);
+        if (prev_info.height + 1 != block_height) {
+            return (ErrorInvalidBlockHeight, option::none())
+        };
+        if (vector::length(&prev_info.recent_times) == MEDIAN_TIME_SPAN && time <= median_time(&prev_info.recent_times)) {
+            return (ErrorBlockTimeTooOld, option::none())
+        };
+        if (!check_difficulty(prev_info, block_height, types::bits(header))) {
+            return (ErrorInvalidDifficulty, option::none())
+        };
+        let is_period_start = block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0;
+        let period_start_time = if (is_period_start) {
+            option::some(time)
+        } else {
+            prev_info.period_start_time
+        };
+        let recent_times = prev_info.recent_times;
+        vector::push_back(&mut recent_times, time);
+        if (vector::length(&recent_times) > MEDIAN_TIME_SPAN) {
+            vector::remove(&mut recent_times, 0);
+        };
+        (0, option::some(HeaderInfo {
+            height: block_height,
+            header: *header,
+            chain_work: prev_info.chain_work + work,
+            period_start_time,
+            recent_times,
+        }))
+    }
+
+    /// Check the difficulty transition, the rule is only fully enforced on mainnet and signet.
+    /// The testnet allows minimum difficulty blocks and the regtest does not retarget.
+    fun check_difficulty(prev_info: &HeaderInfo, block_height: u64, bits: u32): bool {
+        if (!network::is_mainnet() && !network::is_signet()) {
+            return true
+        };
+        let prev_bits = types::bits(&prev_info.header);
+        if (block_height % DIFFICULTY_ADJUSTMENT_INTERVAL != 0) {
+            return bits == prev_bits
+        };
+        // The start time of the previous period is before the checkpoint, we can not verify the retarget
+        if (option::is_none(&prev_info.period_start_time)) {
+            return true
+        };
+        let first_time = *option::borrow(&prev_info.period_start_time);
+        let last_time = types::time(&prev_info.header);
+        bits == retarget_bits(prev_bits, first_time, last_time)
+    }
+
+    /// Calculate the bits of the next difficulty period, follow `CalculateNextWorkRequired` of Bitcoin Core
+    public fun retarget_bits(prev_bits: u32, first_time: u32, last_time: u32): u32 {
+        calculate_retarget_bits(prev_bits, first_time, last_time, pow_limit_bits())
+    }
+
+    fun calculate_retarget_bits(prev_bits: u32, first_time: u32, last_time: u32, pow_limit_bits: u32): u32 {
+        let actual_timespan = if (last_time > first_time) {
+            ((last_time - first_time) as u64)
+        } else {
+            0
+        };
+        if (actual_timespan < TARGET_TIMESPAN / 4) {
+            actual_timespan = TARGET_TIMESPAN / 4;
+        };
+        if (actual_timespan > TARGET_TIMESPAN * 4) {
+            actual_timespan = TARGET_TIMESPAN * 4;
+        };
+        let new_target = pow::bits_to_target(prev_bits) * (actual_timespan as u256) / (TARGET_TIMESPAN as u256);
+        let pow_limit = pow::bits_to_target(pow_limit_bits);
+        if (new_target > pow_limit) {
+            new_target = pow_limit;
+        };
+        pow::target_to_bits(new_target)
+    }
+
+    fun median_time(times: &vector<u32>): u32 {
+        let sorted = *times;
+        let len = vector::length(&sorted);
+        // insertion sort, the vector length is at most 11
+        let i = 1;
+        while (i < len) {
+            let j = i;
+            while (j > 0 && *vector::borrow(&sorted, j - 1) > *vector::borrow(&sorted, j)) {
+                vector::swap(&mut sorted, j - 1, j);
+                j = j - 1;
+            };
+            i = i + 1;
+        };
+        *vector::borrow(&sorted, len / 2)
+    }
+
+    fun pow_limit_bits(): u32 {
+        if (network::is_signet()) {
+            POW_LIMIT_BITS_SIGNET
+        } else if (network::is_mainnet() || network::is_testnet()) {
+            POW_LIMIT_BITS_MAINNET
+        } else {
+            POW_LIMIT_BITS_REGTEST
+        }
+    }
+
+    /// The Bitcoin hash is the little-endian 256-bit number
+    fun hash_to_u256(hash: address): u256 {
+        bcs::from_bytes<u256>(address::to_bytes(&hash))
+    }
+
+    public fun get_header_info(block_hash: address): Option<HeaderInfo> {
+        if (!exists_store()) {
+            return option::none()
+        };
+        let store = borrow_store();
+        if (table::contains(&store.headers, block_hash)) {
+            option::some(*table::borrow(&store.headers, block_hash))
+        } else {
+            option::none()
+        }
+    }
+
+    /// Get the block with the most chain work
+    public fun get_best_block(): Option<BlockHeightHash> {
+        if (!exists_store()) {
+            return option::none()
+        };
+        borrow_store().best_block
+    }
+
+    public fun header_info_height(info: &HeaderInfo): u64 {
+        info.height
+    }
+
+    public fun header_info_header(info: &HeaderInfo): &Header {
+        &info.header
+    }
+
+    public fun header_info_chain_work(info: &HeaderInfo): u256 {
+        info.chain_work
+    }
+
+    #[test]
+    fun test_median_time() {
+        assert!(median_time(&vector[5, 1, 4, 2, 3, 11, 9, 7, 8, 10, 6]) == 6, 1);
+        assert!(median_time(&vector[1]) == 1, 2);
+    }
+
+    #[test]
+    fun test_retarget_bits() {
+        let first_time = 1231006505;
+        // The blocks are mined twice as fast as expected, the target is halved
+        assert!(calculate_retarget_bits(0x1d00ffff, first_time, first_time + (TARGET_TIMESPAN as u32) / 2, POW_LIMIT_BITS_MAINNET) == 0x1c7fff80, 1);
+        // The adjustment is limited to a factor of 4
+        assert!(calculate_retarget_bits(0x1d00ffff, first_time, first_time + 1, POW_LIMIT_BITS_MAINNET) == 0x1c3fffc0, 2);
+        // The target can not exceed the proof-of-work limit
+        assert!(calculate_retarget_bits(0x1d00ffff, first_time, first_time + (TARGET_TIMESPAN as u32) * 2, POW_LIMIT_BITS_MAINNET) == 0x1d00ffff, 3);
+        // The mainnet vectors of Bitcoin Core pow_tests.cpp
+        // block 32256
+        assert!(calculate_retarget_bits(0x1d00ffff, 1261130161, 1262152739, POW_LIMIT_BITS_MAINNET) == 0x1d00d86a, 4);
+        // block 2016, the target is limited by the proof-of-work limit
+        assert!(calculate_retarget_bits(0x1d00ffff, 1231006505, 1233061996, POW_LIMIT_BITS_MAINNET) == 0x1d00ffff, 5);
+        // block 68544, the timespan is limited to a quarter of the target timespan
+        assert!(calculate_retarget_bits(0x1c05a3f4, 1279008237, 1279297671, POW_LIMIT_BITS_MAINNET) == 0x1c0168fd, 6);
+        // block 46368, the timespan is limited to four times of the target timespan
+        assert!(calculate_retarget_bits(0x1c387f6f, 1263163443, 1269211443, POW_LIMIT_BITS_MAINNET) == 0x1d00e1fd, 7);
+    }
+
+    // The header info of mainnet block 32255, the last block of the period started at block 30240
+    #[test_only]
+    fun mainnet_header_info_32255(): HeaderInfo {
+        let time = 1262152739;
+        let recent_times = vector::empty();
+        let i = MEDIAN_TIME_SPAN;
+        while (i > 0) {
+            i = i - 1;
+            vector::push_back(&mut recent_times, time - (i as u32) * 600);
+        };
+        HeaderInfo {
+            height: 32255,
+            header: types::new_header_for_test(1, @0x1, @0x2, time, 0x1d00ffff, 0),
+            chain_work: 0,
+            period_start_time: option::some(1261130161),
+            recent_times,
+        }
+    }
+
+    #[test]
+    fun test_check_with_prev_retarget() {
+        network::init_for_test(network::network_bitcoin());
+        let prev_info = mainnet_header_info_32255();
+        let time = 1262152739 + 600;
+        let work = pow::target_to_work(pow::bits_to_target(0x1d00d86a));
+
+        // The first block of the period must carry the retarget bits
+        let header = types::new_header_for_test(1, @0x3, @0x4, time, 0x1d00d86a, 0);
+        let (error, info) = check_with_prev(&prev_info, 32256, &header, work);
+        assert!(error == 0, 1);
+        let info = option::destroy_some(info);
+        assert!(info.period_start_time == option::some(time), 2);
+        assert!(vector::length(&info.recent_times) == MEDIAN_TIME_SPAN, 3);
+        assert!(*vector::borrow(&info.recent_times, MEDIAN_TIME_SPAN - 1) == time, 4);
+
+        let bad_bits = types::new_header_for_test(1, @0x3, @0x4, time, 0x1d00ffff, 0);
+        let (error, _) = check_with_prev(&prev_info, 32256, &bad_bits, work);
+        assert!(error == ErrorInvalidDifficulty, 5);
+
+        // Inside the period the bits must not change
+        let next = types::new_header_for_test(1, @0x5, @0x6, time + 600, 0x1d00d86a, 0);
+        let (error, _) = check_with_prev(&info, 32257, &next, work);
+        assert!(error == 0, 6);
+        let next_bad_bits = types::new_header_for_test(1, @0x5, @0x6, time + 600, 0x1d00ffff, 0);
+        let (error, _) = check_with_prev(&info, 32257, &next_bad_bits, work);
+        assert!(error == ErrorInvalidDifficulty, 7);
+    }
+
+    #[test]
+    fun test_check_with_prev_invalid_time_and_height() {
+        network::init_for_test(network::network_bitcoin());
+        let prev_info = mainnet_header_info_32255();
+        let work = pow::target_to_work(pow::bits_to_target(0x1d00d86a));
+        let median = median_time(&prev_info.recent_times);
+
+        let header = types::new_header_for_test(1, @0x3, @0x4, median, 0x1d00d86a, 0);
+        let (error, _) = check_with_prev(&prev_info, 32256, &header, work);
+        assert!(error == ErrorBlockTimeTooOld, 1);
+
+        let header = types::new_header_for_test(1, @0x3, @0x4, median + 1, 0x1d00d86a, 0);
+        let (error, _) = check_with_prev(&prev_info, 32256, &header, work);
+        assert!(error == 0, 2);
+        let (error, _) = check_with_prev(&prev_info, 32257, &header, work);
+        assert!(error == ErrorInvalidBlockHeight, 3);
+    }
+
+    #[test_only]
+    fun record_header_for_test(height: u64, block_hash: address) {
+        let info = HeaderInfo {
+            height,
+            header: types::new_header_for_test(1, @0x1, @0x2, 0, 0x1d00ffff, 0),
+            chain_work: (height as u256) + 1,
+            period_start_time: option::none(),
+            recent_times: vector::empty(),
+        };
+        record_header(height, block_hash, info);
+    }
+
+    #[test]
+    fun test_prune_headers() {
+        network::init_for_test(network::network_bitcoin());
+        record_header_for_test(100, @0x100);
+        record_header_for_test(101, @0x101);
+        // A fork block at the same height
+        record_header_for_test(101, @0x1011);
+        record_header_for_test(100 + HEADER_RETAIN_WINDOW, @0x200);
+        assert!(option::is_none(&get_header_info(@0x100)), 1);
+        assert!(option::is_some(&get_header_info(@0x101)), 2);
+        record_header_for_test(101 + HEADER_RETAIN_WINDOW, @0x201);
+        assert!(option::is_none(&get_header_info(@0x101)), 3);
+        assert!(option::is_none(&get_header_info(@0x1011)), 4);
+        assert!(option::is_some(&get_header_info(@0x200)), 5);
+        assert!(option::is_some(&get_header_info(@0x201)), 6);
+        let (best_height, best_hash) = types::unpack_block_height_hash(option::destroy_some(get_best_block()));
+        assert!(best_height == 101 + HEADER_RETAIN_WINDOW && best_hash == @0x201, 7);
+    }
+}
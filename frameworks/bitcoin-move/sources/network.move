@@ -70,6 +70,10 @@ module bitcoin_move::network{
         network() == NETWORK_SIGNET
     }
 
+    public fun is_regtest(): bool {
+        network() == NETWORK_REGTEST
+    }
+
     public fun from_str(network: &String): u8 {
         if (string::bytes(network) == &b"bitcoin") {
             NETWORK_BITCOIN
@@ -156,4 +160,8 @@ module bitcoin_move::network{
         }
     }
 
-}
\ No newline at end of file
+    #[test_only]
+    public fun init_for_test(network: u8){
+        genesis_init(network);
+    }
+}
@@ -114,6 +114,10 @@ module bitcoin_move::ord {
 
     const InscriptionEventTypeNew: u8 = 0;
     const InscriptionEventTypeBurn: u8 = 1;
+    const InscriptionEventTypeTransfer: u8 = 2;
+
+    /// The event queue name of the BRC20 inscription event
+    const BRC20_EVENT_QUEUE: vector<u8> = b"brc-20";
 
     /// Inscription event for metaprotocol
     ///
@@ -134,6 +138,23 @@ module bitcoin_move::ord {
         event_type: u8,
     }
 
+    /// BRC20 inscription event
+    ///
+    /// BRC20 inscriptions do not set the metaprotocol field, they are recognized by the `"p":"brc-20"` json body.
+    /// Unlike the InscriptionEvent, this event is also emitted when the inscription is transferred,
+    /// because sending a BRC20 transfer inscription moves the balance.
+    ///
+    /// @param inscription_obj_id: The ID of the inscription object
+    /// @param from: The owner of the inscription before the operation, same as `to` for creation
+    /// @param to: The owner of the inscription after the operation, @bitcoin_move if the inscription is burned
+    /// @param event_type: Event type, 0 for creation, 2 for transfer
+    struct BRC20InscriptionEvent has store, copy, drop {
+        inscription_obj_id: ObjectID,
+        from: address,
+        to: address,
+        event_type: u8,
+    }
+
     /// Event emitted when the temporary state of an Inscription is dropped
     /// The temporary state is dropped when the inscription is transferred
     /// The event is onchain event, and the event_queue name is type_name of the temporary state
@@ -287,6 +308,7 @@ module bitcoin_move::ord {
     ): ObjectID {
         
         let metaprotocol = envelope.payload.metaprotocol;
+        let is_brc20 = is_brc20_body(&envelope.payload.body, &envelope.payload.content_type);
         let inscription = Inscription {
             id,
             location,
@@ -316,6 +338,14 @@ module bitcoin_move::ord {
                 event_type: InscriptionEventTypeNew,
             });
         };
+        if (is_brc20) {
+            event_queue::emit(string::utf8(BRC20_EVENT_QUEUE), BRC20InscriptionEvent {
+                inscription_obj_id,
+                from: owner,
+                to: owner,
+                event_type: InscriptionEventTypeNew,
+            });
+        };
         object::transfer_extend(obj, owner);
         inscription_obj_id
     }
@@ -333,14 +363,23 @@ module bitcoin_move::ord {
     public(friend) fun transfer_object(inscription_obj: Object<Inscription>, to: address, new_location: SatPoint, is_op_return: bool){
         //drop the temp area when inscription is transferred
         drop_temp_area(&mut inscription_obj);
+        let from = object::owner(&inscription_obj);
+        let inscription_obj_id = object::id(&inscription_obj);
         let inscription = object::borrow_mut(&mut inscription_obj);
         inscription.location = new_location;
+        if (is_brc20_body(&inscription.body, &inscription.content_type)) {
+            event_queue::emit(string::utf8(BRC20_EVENT_QUEUE), BRC20InscriptionEvent {
+                inscription_obj_id,
+                from,
+                to: if (is_op_return) { @bitcoin_move } else { to },
+                event_type: InscriptionEventTypeTransfer,
+            });
+        };
         if (is_op_return){
             //if the output is OP_RETURN, set the burn flag and freeze the inscription
             inscription.charms = set_charm(inscription.charms, charm_burned_flag());
             let metaprotocol = inscription.metaprotocol;
             let sequence_number = inscription.sequence_number;
-            let inscription_obj_id = object::id(&inscription_obj);
             if (option::is_some(&metaprotocol)) {
                 let metaprotocol = option::destroy_some(metaprotocol);
                 moveos_std::event_queue::emit(metaprotocol, InscriptionEvent {
@@ -366,17 +405,23 @@ module bitcoin_move::ord {
         object::borrow_object(inscription_obj_id)
     }
 
-    fun parse_json_body(record: &InscriptionRecord): SimpleMap<String, String> {
-        if (vector::is_empty(&record.body) || option::is_none(&record.content_type)) {
+    fun parse_json_body(body: &vector<u8>, content_type: &Option<String>): SimpleMap<String, String> {
+        if (vector::is_empty(body) || option::is_none(content_type)) {
             return simple_map::new()
         };
-        let content_type = option::destroy_some(record.content_type);
-        if (content_type != string::utf8(b"text/plain;charset=utf-8") || content_type != string::utf8(
+        let content_type = option::borrow(content_type);
+        if (content_type != &string::utf8(b"text/plain;charset=utf-8") && content_type != &string::utf8(
             b"text/plain"
-        ) || content_type != string::utf8(b"application/json")) {
+        ) && content_type != &string::utf8(b"application/json")) {
             return simple_map::new()
         };
-        json::to_map(record.body)
+        json::to_map(*body)
+    }
+
+    fun is_brc20_body(body: &vector<u8>, content_type: &Option<String>): bool {
+        let json_map = parse_json_body(body, content_type);
+        let protocol_key = string::utf8(b"p");
+        simple_map::contains_key(&json_map, &protocol_key) && simple_map::borrow(&json_map, &protocol_key) == &string::utf8(BRC20_EVENT_QUEUE)
     }
 
     public fun exists_inscription(id: InscriptionID): bool {
@@ -896,6 +941,19 @@ module bitcoin_move::ord {
         InscriptionEventTypeBurn
     }
 
+    public fun inscription_event_type_transfer(): u8 {
+        InscriptionEventTypeTransfer
+    }
+
+    public fun brc20_event_queue_name(): String {
+        string::utf8(BRC20_EVENT_QUEUE)
+    }
+
+    public fun unpack_brc20_inscription_event(event: BRC20InscriptionEvent): (ObjectID, address, address, u8) {
+        let BRC20InscriptionEvent { inscription_obj_id, from, to, event_type } = event;
+        (inscription_obj_id, from, to, event_type)
+    }
+
     public fun unpack_temp_state_drop_event(event: TempStateDropEvent): (ObjectID, InscriptionID) {
         let TempStateDropEvent { inscription_obj_id, inscription_id } = event;
         (inscription_obj_id, inscription_id)
@@ -0,0 +1,25 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Bitcoin proof-of-work helpers, the target and work calculation follow Bitcoin Core
+module bitcoin_move::pow{
+
+    /// Decode the compact `bits` of the block header to the 256-bit target
+    native public fun bits_to_target(bits: u32): u256;
+
+    /// Encode the 256-bit target to the compact `bits`, the lower bits of the target are lost
+    native public fun target_to_bits(target: u256): u32;
+
+    /// The expected number of hashes to find a block with the target, work = 2**256 / (target + 1)
+    /// Abort if the target is zero
+    native public fun target_to_work(target: u256): u256;
+
+    #[test]
+    fun test_bits_target() {
+        let target = bits_to_target(0x1d00ffff);
+        assert!(target == 0x00000000ffff0000000000000000000000000000000000000000000000000000, 1);
+        assert!(target_to_bits(target) == 0x1d00ffff, 2);
+        // The work of the genesis block
+        assert!(target_to_work(target) == 0x100010001, 3);
+    }
+}
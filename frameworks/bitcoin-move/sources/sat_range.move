@@ -0,0 +1,428 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Track the satoshi ranges of the UTXOs, follow the ordinal theory numbering.
+/// The tracking is gated by the `SAT_RANGE_TRACKING` feature, so the chains which do not need it pay no cost.
+/// The feature changes the state of every Bitcoin block, so it is a chain-wide consensus feature,
+/// it can only be enabled at genesis, see `onchain_config::change_feature_flags`.
+/// The sats of the missing UTXOs mocked by the test networks are unknown, so the ranges of the outputs
+/// spending them are unknown too.
+module bitcoin_move::sat_range{
+    use std::vector;
+    use std::option::{Self, Option};
+    use moveos_std::object;
+    use moveos_std::table::{Self, Table};
+    use moveos_std::features;
+    use bitcoin_move::types::{Self, OutPoint};
+
+    friend bitcoin_move::utxo;
+    friend bitcoin_move::bitcoin;
+
+    const ErrorInvalidSatRange: u64 = 1;
+
+    #[test_only]
+    const COIN_VALUE: u64 = 100_000_000;
+    const INITIAL_SUBSIDY: u64 = 50 * 100_000_000;
+    const SUBSIDY_HALVING_INTERVAL: u64 = 210_000;
+    /// After 33 halvings the subsidy is 0
+    const MAX_HALVINGS: u64 = 33;
+
+    /// A range of sats, the start is inclusive and the end is exclusive
+    struct SatRange has store, copy, drop {
+        start: u64,
+        end: u64,
+    }
+
+    /// The location of a sat range in a UTXO
+    struct SatRangeLocation has store, copy, drop {
+        start: u64,
+        end: u64,
+        /// The null outpoint if the sats are burned by an OP_RETURN output or lost by the coinbase
+        outpoint: OutPoint,
+        /// The offset of the range start in the UTXO
+        offset: u64,
+    }
+
+    /// The location of a sat
+    struct SatPoint has store, copy, drop {
+        outpoint: OutPoint,
+        offset: u64,
+    }
+
+    struct SatRangeStore has key {
+        /// The start of the sat range -> the location of the sat range.
+        /// The tracked ranges do not overlap, so the start is unique.
+        locations: Table<u64, SatRangeLocation>,
+    }
+
+    /// The sats flowing through a transaction, from the inputs to the outputs
+    struct SatFlow has drop {
+        /// The remaining ranges in reverse order, so we can pop the next range from the back
+        ranges: vector<SatRange>,
+        /// Whether all the remaining sats are known
+        known: bool,
+    }
+
+    /// The fee sats of the processed transactions in the block, they are assigned to the coinbase outputs.
+    /// It is stored in the pending block as intermediate.
+    struct BlockFeeSatRanges has store {
+        ranges: vector<SatRange>,
+        known: bool,
+    }
+
+    public fun is_enabled(): bool {
+        features::sat_range_tracking_enabled()
+    }
+
+    fun borrow_store(): &SatRangeStore {
+        let object_id = object::named_object_id<SatRangeStore>();
+        let obj = object::borrow_object<SatRangeStore>(object_id);
+        object::borrow(obj)
+    }
+
+    fun borrow_mut_store(): &mut SatRangeStore {
+        let object_id = object::named_object_id<SatRangeStore>();
+        // The store is created lazily when the tracking is enabled
+        if (!object::exists_object(object_id)) {
+            let obj = object::new_named_object(SatRangeStore {
+                locations: table::new(),
+            });
+            object::to_shared(obj);
+        };
+        let obj = object::borrow_mut_object_shared<SatRangeStore>(object_id);
+        object::borrow_mut(obj)
+    }
+
+    // ======== SatRange ========
+
+    public fun new(start: u64, end: u64): SatRange {
+        assert!(start < end, ErrorInvalidSatRange);
+        SatRange { start, end }
+    }
+
+    public fun start(range: &SatRange): u64 {
+        range.start
+    }
+
+    public fun end(range: &SatRange): u64 {
+        range.end
+    }
+
+    public fun size(range: &SatRange): u64 {
+        range.end - range.start
+    }
+
+    public fun total_size(ranges: &vector<SatRange>): u64 {
+        let total = 0;
+        vector::for_each_ref(ranges, |range| {
+            total = total + size(range);
+        });
+        total
+    }
+
+    /// Append the range to the ranges, merge it with the last range if they are contiguous and mined in the same block.
+    /// Do not merge the ranges of different blocks, so every range belongs to one block, and it can be located by the block height.
+    fun push_merged(ranges: &mut vector<SatRange>, range: SatRange) {
+        let len = vector::length(ranges);
+        if (len > 0) {
+            let last = vector::borrow_mut(ranges, len - 1);
+            if (last.end == range.start && mined_height(last.start) == mined_height(range.start)) {
+                last.end = range.end;
+                return
+            };
+        };
+        vector::push_back(ranges, range);
+    }
+
+    // ======== Ordinal numbering ========
+
+    fun epoch_subsidy(epoch: u64): u64 {
+        if (epoch >= MAX_HALVINGS) {
+            0
+        } else {
+            INITIAL_SUBSIDY >> (epoch as u8)
+        }
+    }
+
+    /// The block subsidy of the height
+    public fun subsidy(height: u64): u64 {
+        epoch_subsidy(height / SUBSIDY_HALVING_INTERVAL)
+    }
+
+    /// The first sat mined at the height
+    public fun first_sat(height: u64): u64 {
+        let epoch = height / SUBSIDY_HALVING_INTERVAL;
+        let sat = 0;
+        let i = 0;
+        while (i < epoch && i < MAX_HALVINGS) {
+            sat = sat + SUBSIDY_HALVING_INTERVAL * epoch_subsidy(i);
+            i = i + 1;
+        };
+        sat + (height % SUBSIDY_HALVING_INTERVAL) * epoch_subsidy(epoch)
+    }
+
+    /// The sat range minted by the coinbase at the height, none if the subsidy is 0
+    public fun subsidy_range(height: u64): Option<SatRange> {
+        let subsidy = subsidy(height);
+        if (subsidy == 0) {
+            option::none()
+        } else {
+            let start = first_sat(height);
+            option::some(SatRange { start, end: start + subsidy })
+        }
+    }
+
+    /// The block height when the sat was mined
+    public fun mined_height(sat: u64): u64 {
+        let epoch = 0;
+        let epoch_start_sat = 0;
+        loop {
+            let subsidy = epoch_subsidy(epoch);
+            if (subsidy == 0) {
+                // all the sats are mined before this epoch
+                break
+            };
+            let epoch_supply = SUBSIDY_HALVING_INTERVAL * subsidy;
+            if (sat < epoch_start_sat + epoch_supply) {
+                return epoch * SUBSIDY_HALVING_INTERVAL + (sat - epoch_start_sat) / subsidy
+            };
+            epoch_start_sat = epoch_start_sat + epoch_supply;
+            epoch = epoch + 1;
+        };
+        epoch * SUBSIDY_HALVING_INTERVAL
+    }
+
+    // ======== SatFlow ========
+
+    /// Create a flow with the input ranges in order, `known` is false if some input ranges are unknown.
+    /// The sats after the first unknown input are unknown, so the caller should stop pushing ranges after it.
+    public(friend) fun new_flow(input_ranges: vector<SatRange>, known: bool): SatFlow {
+        vector::reverse(&mut input_ranges);
+        SatFlow {
+            ranges: input_ranges,
+            known,
+        }
+    }
+
+    /// Take the sats of an output from the flow, return none if the sats are unknown
+    public(friend) fun take(flow: &mut SatFlow, value: u64): Option<vector<SatRange>> {
+        let taken = vector::empty();
+        let remaining = value;
+        while (remaining > 0 && !vector::is_empty(&flow.ranges)) {
+            let range = vector::pop_back(&mut flow.ranges);
+            let range_size = size(&range);
+            if (range_size <= remaining) {
+                push_merged(&mut taken, range);
+                remaining = remaining - range_size;
+            } else {
+                push_merged(&mut taken, SatRange { start: range.start, end: range.start + remaining });
+                vector::push_back(&mut flow.ranges, SatRange { start: range.start + remaining, end: range.end });
+                remaining = 0;
+            };
+        };
+        if (remaining > 0) {
+            // The known sats are exhausted, the rest sats are unknown
+            flow.known = false;
+        };
+        if (flow.known) {
+            option::some(taken)
+        } else {
+            option::none()
+        }
+    }
+
+    /// The remaining sats after all the outputs are taken, they are the fee of the transaction.
+    public(friend) fun remaining(flow: SatFlow): (vector<SatRange>, bool) {
+        let SatFlow { ranges, known } = flow;
+        vector::reverse(&mut ranges);
+        (ranges, known)
+    }
+
+    // ======== BlockFeeSatRanges ========
+
+    public(friend) fun new_block_fee(): BlockFeeSatRanges {
+        BlockFeeSatRanges {
+            ranges: vector::empty(),
+            known: true,
+        }
+    }
+
+    public(friend) fun add_block_fee(block_fee: &mut BlockFeeSatRanges, ranges: vector<SatRange>, known: bool) {
+        if (!block_fee.known) {
+            return
+        };
+        if (!known) {
+            block_fee.known = false;
+            block_fee.ranges = vector::empty();
+            return
+        };
+        vector::for_each(ranges, |range| {
+            push_merged(&mut block_fee.ranges, range);
+        });
+    }
+
+    /// The input ranges of the coinbase transaction: the subsidy range and the fee ranges
+    public(friend) fun coinbase_flow(height: u64, block_fee: BlockFeeSatRanges): SatFlow {
+        let BlockFeeSatRanges { ranges: fee_ranges, known } = block_fee;
+        let input_ranges = vector::empty();
+        let subsidy_range = subsidy_range(height);
+        if (option::is_some(&subsidy_range)) {
+            vector::push_back(&mut input_ranges, option::destroy_some(subsidy_range));
+        };
+        vector::for_each(fee_ranges, |range| {
+            push_merged(&mut input_ranges, range);
+        });
+        new_flow(input_ranges, known)
+    }
+
+    // ======== Locations ========
+
+    /// Record the locations of the ranges of the UTXO
+    public(friend) fun add_locations(outpoint: OutPoint, ranges: &vector<SatRange>) {
+        let store = borrow_mut_store();
+        let offset = 0;
+        vector::for_each_ref(ranges, |range| {
+            let range: &SatRange = range;
+            table::add(&mut store.locations, range.start, SatRangeLocation {
+                start: range.start,
+                end: range.end,
+                outpoint,
+                offset,
+            });
+            offset = offset + size(range);
+        });
+    }
+
+    /// Record the ranges of the sats burned by an OP_RETURN output or lost by the coinbase,
+    /// so the ranges of a block stay contiguous and the sats after them can still be found.
+    public(friend) fun add_burned_locations(ranges: &vector<SatRange>) {
+        add_locations(types::null_outpoint(), ranges);
+    }
+
+    /// Remove the locations of the ranges of the spent UTXO
+    public(friend) fun remove_locations(outpoint: OutPoint, ranges: &vector<SatRange>) {
+        let store = borrow_mut_store();
+        vector::for_each_ref(ranges, |range| {
+            let range: &SatRange = range;
+            if (table::contains(&store.locations, range.start)
+                && table::borrow(&store.locations, range.start).outpoint == outpoint) {
+                let _ = table::remove(&mut store.locations, range.start);
+            };
+        });
+    }
+
+    /// Find the current location of the sat, return none if the sat is not tracked or burned.
+    /// The ranges never cross the block boundary and the tracked ranges of a block are contiguous,
+    /// so it follows the ranges from the first sat of the block, every step is a lookup by the range start.
+    public fun find_sat(sat: u64): Option<SatPoint> {
+        let object_id = object::named_object_id<SatRangeStore>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        let store = borrow_store();
+        let start = if (table::contains(&store.locations, sat)) {
+            sat
+        } else {
+            first_sat(mined_height(sat))
+        };
+        loop {
+            if (!table::contains(&store.locations, start)) {
+                return option::none()
+            };
+            let location = table::borrow(&store.locations, start);
+            if (sat < location.end) {
+                if (location.outpoint == types::null_outpoint()) {
+                    return option::none()
+                };
+                return option::some(SatPoint {
+                    outpoint: location.outpoint,
+                    offset: location.offset + sat - location.start,
+                })
+            };
+            start = location.end;
+        }
+    }
+
+    public fun sat_point_outpoint(sat_point: &SatPoint): OutPoint {
+        sat_point.outpoint
+    }
+
+    public fun sat_point_offset(sat_point: &SatPoint): u64 {
+        sat_point.offset
+    }
+
+    #[test]
+    fun test_ordinal_numbering() {
+        assert!(first_sat(0) == 0, 1);
+        assert!(first_sat(1) == 50 * COIN_VALUE, 2);
+        assert!(first_sat(210_000) == 210_000 * 50 * COIN_VALUE, 3);
+        assert!(first_sat(420_000) == 210_000 * 75 * COIN_VALUE, 4);
+        assert!(subsidy(840_000) == 3 * COIN_VALUE + 12_500_000, 5);
+        assert!(mined_height(0) == 0, 6);
+        assert!(mined_height(50 * COIN_VALUE - 1) == 0, 7);
+        assert!(mined_height(50 * COIN_VALUE) == 1, 8);
+        assert!(mined_height(first_sat(420_001) + 1) == 420_001, 9);
+        assert!(mined_height(first_sat(6_929_999)) == 6_929_999, 10);
+        assert!(option::is_none(&subsidy_range(6_930_000)), 11);
+    }
+
+    #[test]
+    fun test_sat_flow() {
+        // two inputs, the first one is from block 0, the second one is from block 1
+        let flow = new_flow(vector[new(10, 20), new(50 * COIN_VALUE, 50 * COIN_VALUE + 10)], true);
+        let output0 = option::destroy_some(take(&mut flow, 15));
+        assert!(output0 == vector[new(10, 20), new(50 * COIN_VALUE, 50 * COIN_VALUE + 5)], 1);
+        let (fee, known) = remaining(flow);
+        assert!(known, 2);
+        assert!(fee == vector[new(50 * COIN_VALUE + 5, 50 * COIN_VALUE + 10)], 3);
+
+        // the contiguous ranges in the same block are merged
+        let flow = new_flow(vector[new(0, 10), new(10, 20)], true);
+        assert!(option::destroy_some(take(&mut flow, 20)) == vector[new(0, 20)], 4);
+
+        // the outputs after the known sats are unknown
+        let flow = new_flow(vector[new(0, 10)], false);
+        assert!(option::destroy_some(take(&mut flow, 5)) == vector[new(0, 5)], 5);
+        assert!(option::is_none(&take(&mut flow, 10)), 6);
+        assert!(option::is_none(&take(&mut flow, 1)), 7);
+    }
+
+    #[test]
+    fun test_coinbase_flow() {
+        let block_fee = new_block_fee();
+        add_block_fee(&mut block_fee, vector[new(10, 20)], true);
+        let flow = coinbase_flow(1, block_fee);
+        let output0 = option::destroy_some(take(&mut flow, 50 * COIN_VALUE + 10));
+        assert!(output0 == vector[new(50 * COIN_VALUE, 100 * COIN_VALUE), new(10, 20)], 1);
+
+        let block_fee = new_block_fee();
+        add_block_fee(&mut block_fee, vector[new(10, 20)], false);
+        let flow = coinbase_flow(1, block_fee);
+        assert!(option::is_some(&take(&mut flow, 50 * COIN_VALUE)), 2);
+        assert!(option::is_none(&take(&mut flow, 1)), 3);
+    }
+
+    #[test]
+    fun test_locations() {
+        let outpoint = types::new_outpoint(@0x1, 0);
+        let ranges = vector[new(0, 20), new(50 * COIN_VALUE, 50 * COIN_VALUE + 10)];
+        add_locations(outpoint, &ranges);
+        let sat_point = option::destroy_some(find_sat(50 * COIN_VALUE + 1));
+        assert!(sat_point.outpoint == outpoint, 1);
+        assert!(sat_point.offset == 21, 2);
+        assert!(option::is_none(&find_sat(20)), 3);
+
+        // The burned range is skipped, the sats after it are found
+        add_burned_locations(&vector[new(20, 30)]);
+        let outpoint2 = types::new_outpoint(@0x2, 0);
+        add_locations(outpoint2, &vector[new(30, 40)]);
+        assert!(option::is_none(&find_sat(25)), 4);
+        let sat_point = option::destroy_some(find_sat(35));
+        assert!(sat_point.outpoint == outpoint2, 5);
+        assert!(sat_point.offset == 5, 6);
+
+        remove_locations(outpoint, &ranges);
+        assert!(option::is_none(&find_sat(15)), 7);
+        assert!(option::is_none(&find_sat(50 * COIN_VALUE + 1)), 8);
+    }
+}
@@ -12,6 +12,7 @@ module bitcoin_move::utxo{
     use moveos_std::address::to_string;
     use bitcoin_move::types::{Self, OutPoint};
     use bitcoin_move::temp_state;
+    use bitcoin_move::sat_range::{Self, SatRange};
     #[test_only]
     use std::option::none;
 
@@ -22,6 +23,7 @@ module bitcoin_move::utxo{
     friend bitcoin_move::bbn;
 
     const TEMPORARY_AREA: vector<u8> = b"temporary_area";
+    const SAT_RANGES: vector<u8> = b"sat_ranges";
 
     const ErrorDeprecatedFunction: u64 = 1;
 
@@ -243,6 +245,7 @@ module bitcoin_move::utxo{
 
     public(friend) fun remove(utxo_obj: Object<UTXO>): UTXO{
         drop_temp_area(&mut utxo_obj);
+        let _ = remove_sat_ranges(&mut utxo_obj);
         object::remove(utxo_obj)
     }
 
@@ -339,6 +342,40 @@ module bitcoin_move::utxo{
         temp_state::remove_state(temp_state)
     }
 
+    // ==== Sat Ranges ===
+
+    /// Attach the sat ranges to the UTXO and index their locations
+    public(friend) fun add_sat_ranges(utxo_obj: &mut Object<UTXO>, ranges: vector<SatRange>){
+        let utxo = object::borrow(utxo_obj);
+        let outpoint = types::new_outpoint(utxo.txid, utxo.vout);
+        sat_range::add_locations(outpoint, &ranges);
+        object::add_field(utxo_obj, SAT_RANGES, ranges);
+    }
+
+    /// Detach the sat ranges from the UTXO, return none if the sat ranges of the UTXO are unknown
+    public(friend) fun remove_sat_ranges(utxo_obj: &mut Object<UTXO>): Option<vector<SatRange>>{
+        if(!object::contains_field(utxo_obj, SAT_RANGES)){
+            return option::none()
+        };
+        let utxo = object::borrow(utxo_obj);
+        let outpoint = types::new_outpoint(utxo.txid, utxo.vout);
+        let ranges: vector<SatRange> = object::remove_field(utxo_obj, SAT_RANGES);
+        sat_range::remove_locations(outpoint, &ranges);
+        option::some(ranges)
+    }
+
+    /// Get the sat ranges of the UTXO, return none if the UTXO does not exist or the sat ranges are unknown
+    public fun get_sat_ranges(outpoint: OutPoint): Option<vector<SatRange>>{
+        if(!exists_utxo(outpoint)){
+            return option::none()
+        };
+        let utxo_obj = borrow_utxo(outpoint);
+        if(!object::contains_field(utxo_obj, SAT_RANGES)){
+            return option::none()
+        };
+        option::some(*object::borrow_field(utxo_obj, SAT_RANGES))
+    }
+
     // Should we require the input utxo exists
     // Sometimes, we may not sync the Bitcoin block from genesis
     public(friend) fun check_utxo_input(): bool{
@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::pow::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "pow", [
+    [.bits_to_target.base, "bits_to_target.base", 1000 * MUL],
+    [.target_to_bits.base, "target_to_bits.base", 1000 * MUL],
+    [.target_to_work.base, "target_to_work.base", 1000 * MUL],
+]);
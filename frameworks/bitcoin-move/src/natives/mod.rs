@@ -14,23 +14,28 @@ use std::collections::BTreeMap;
 
 mod gas_parameter;
 pub mod ord;
+pub mod pow;
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
 pub struct GasParameters {
     ord: ord::GasParameters,
+    pow: pow::GasParameters,
 }
 
 impl FromOnChainGasSchedule for GasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
             ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            pow: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
         })
     }
 }
 
 impl ToOnChainGasSchedule for GasParameters {
     fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
-        self.ord.to_on_chain_gas_schedule()
+        let mut entries = self.ord.to_on_chain_gas_schedule();
+        entries.extend(self.pow.to_on_chain_gas_schedule());
+        entries
     }
 }
 
@@ -38,6 +43,7 @@ impl InitialGasSchedule for GasParameters {
     fn initial() -> Self {
         Self {
             ord: InitialGasSchedule::initial(),
+            pow: InitialGasSchedule::initial(),
         }
     }
 }
@@ -46,6 +52,7 @@ impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             ord: ord::GasParameters::zeros(),
+            pow: pow::GasParameters::zeros(),
         }
     }
 }
@@ -62,6 +69,7 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
     }
 
     add_natives!("ord", ord::make_all(gas_params.ord));
+    add_natives!("pow", pow::make_all(gas_params.pow));
 
     make_table_from_iter(BITCOIN_MOVE_ADDRESS, natives)
 }
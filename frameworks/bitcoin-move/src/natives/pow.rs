@@ -0,0 +1,149 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::{CompactTarget, Target};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_core_types::u256::U256;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_ZERO_TARGET: u64 = 1;
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct PowGasParameters {
+    pub base: InternalGas,
+}
+
+impl PowGasParameters {
+    pub fn zeros() -> Self {
+        Self { base: 0.into() }
+    }
+}
+
+fn target_to_u256(target: Target) -> U256 {
+    U256::from_le_bytes(&target.to_le_bytes())
+}
+
+fn u256_to_target(value: U256) -> Target {
+    Target::from_le_bytes(value.to_le_bytes())
+}
+
+/// Rust implementation of decode the compact bits of the block header to the target
+#[inline]
+pub(crate) fn native_bits_to_target(
+    gas_params: &PowGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 0);
+    debug_assert_eq!(args.len(), 1);
+
+    let bits = pop_arg!(args, u32);
+    let target = Target::from_compact(CompactTarget::from_consensus(bits));
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u256(target_to_u256(target))],
+    ))
+}
+
+/// Rust implementation of encode the target to the compact bits, the lower bits of the target are lost
+#[inline]
+pub(crate) fn native_target_to_bits(
+    gas_params: &PowGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 0);
+    debug_assert_eq!(args.len(), 1);
+
+    let target = pop_arg!(args, U256);
+    let bits = u256_to_target(target).to_compact_lossy().to_consensus();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u32(bits)],
+    ))
+}
+
+/// Rust implementation of calculate the expected work of the target, work = 2**256 / (target + 1)
+#[inline]
+pub(crate) fn native_target_to_work(
+    gas_params: &PowGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 0);
+    debug_assert_eq!(args.len(), 1);
+
+    let target = pop_arg!(args, U256);
+    if target == U256::zero() {
+        return Ok(NativeResult::err(gas_params.base, E_ZERO_TARGET));
+    }
+    let work = u256_to_target(target).to_work();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u256(U256::from_le_bytes(&work.to_le_bytes()))],
+    ))
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct GasParameters {
+    pub bits_to_target: PowGasParameters,
+    pub target_to_bits: PowGasParameters,
+    pub target_to_work: PowGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            bits_to_target: PowGasParameters::zeros(),
+            target_to_bits: PowGasParameters::zeros(),
+            target_to_work: PowGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "bits_to_target",
+            make_native(gas_params.bits_to_target, native_bits_to_target),
+        ),
+        (
+            "target_to_bits",
+            make_native(gas_params.target_to_bits, native_target_to_bits),
+        ),
+        (
+            "target_to_work",
+            make_native(gas_params.target_to_work, native_target_to_work),
+        ),
+    ]
+    .to_vec();
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_roundtrip() {
+        let bits = 0x1d00ffffu32;
+        let target = target_to_u256(Target::from_compact(CompactTarget::from_consensus(bits)));
+        assert_eq!(
+            u256_to_target(target).to_compact_lossy().to_consensus(),
+            bits
+        );
+        assert_eq!(Target::MAX_ATTAINABLE_MAINNET, u256_to_target(target));
+    }
+}
@@ -155,6 +155,19 @@ module moveos_std::features {
         assert!(is_enabled(COMPATIBILITY_CHECKER_V2), EAPI_DISABLED);
     }
 
+    /// Whether to enable the sat range tracking of the Bitcoin UTXOs.
+    /// It is a chain-wide consensus feature and can only be enabled at genesis,
+    /// enabling it on a running chain leaves the sats of the existing UTXOs unknown.
+    /// It is enabled on localnet.
+    const SAT_RANGE_TRACKING: u64 = 9;
+    public fun get_sat_range_tracking_feature(): u64 { SAT_RANGE_TRACKING }
+    public fun sat_range_tracking_enabled(): bool {
+        is_enabled(SAT_RANGE_TRACKING)
+    }
+    public fun ensure_sat_range_tracking_enabled() {
+        assert!(is_enabled(SAT_RANGE_TRACKING), EAPI_DISABLED);
+    }
+
     /// Helper for getting all features. 
     /// Update this once new feature added.
     public fun get_all_features(): vector<u64> {
@@ -167,6 +180,7 @@ module moveos_std::features {
             WASM,
             VALUE_SIZE_GAS,
             COMPATIBILITY_CHECKER_V2,
+            SAT_RANGE_TRACKING,
         ]
     }
     // --------------------------------------------------------------------------------------------
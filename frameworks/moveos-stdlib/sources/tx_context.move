@@ -52,6 +52,14 @@ module moveos_std::tx_context {
         is_upgrade: bool,
     }
 
+    /// The account that pays the gas of the current transaction.
+    /// It is written to the context by the VM when the gas payer is different from the sender,
+    /// for example, a sponsored transaction co-signed by a fee payer.
+    struct GasPaymentAccount has copy, drop, store {
+        account: address,
+        pay_gas_by_module_account: bool,
+    }
+
     /// Return the address of the user that signed the current transaction
     public fun sender(): address {
         borrow().sender
@@ -147,11 +155,20 @@ module moveos_std::tx_context {
     }
 
     /// Get the gas payment account of the transaction
-    /// Currently, the gas payment account is the sender of the transaction.
-    /// In the future, the gas payment account may be different from the sender.
+    /// If the transaction has a fee payer, return the fee payer, otherwise return the sender.
     public fun tx_gas_payment_account(): address {
         let ctx = borrow();
-        ctx.sender
+        let gas_payment_account = get<GasPaymentAccount>(ctx);
+        if (option::is_some(&gas_payment_account)) {
+            option::destroy_some(gas_payment_account).account
+        } else {
+            ctx.sender
+        }
+    }
+
+    /// Check if the gas of the transaction is paid by an account other than the sender
+    public fun is_gas_sponsored(): bool {
+        tx_gas_payment_account() != sender()
     }
 
     /// The result is only available in the `post_execute` function.
@@ -230,6 +247,13 @@ module moveos_std::tx_context {
         ctx.tx_hash = tx_hash;
     }
 
+    #[test_only]
+    /// set the TxContext gas payment account for unit test
+    public fun set_ctx_gas_payment_account_for_testing(account: address){
+        let ctx = borrow_mut();
+        add(ctx, GasPaymentAccount{account, pay_gas_by_module_account: false});
+    }
+
     #[test_only]
     public fun fresh_address_for_testing(): address {
         fresh_address()
@@ -250,6 +274,16 @@ module moveos_std::tx_context {
         assert!(value == option::extract(&mut value2), 1000);
     }
 
+    #[test(sender=@0x42)]
+    fun test_gas_payment_account() {
+        set_ctx_sender_for_testing(@0x42);
+        assert!(tx_gas_payment_account() == @0x42, 1000);
+        assert!(!is_gas_sponsored(), 1001);
+        set_ctx_gas_payment_account_for_testing(@0x43);
+        assert!(tx_gas_payment_account() == @0x43, 1002);
+        assert!(is_gas_sponsored(), 1003);
+    }
+
     #[test(sender=@0x42)]
     fun test_fresh_address() {
         let addr1 = fresh_address();
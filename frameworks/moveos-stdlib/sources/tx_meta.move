@@ -11,6 +11,8 @@ module moveos_std::tx_meta {
     public fun move_action_function_type(): u8 { MoveActionFunctionType }
     const MoveActionModuleBundleType: u8 = 2;
     public fun move_action_module_bundle_type(): u8 { MoveActionModuleBundleType }
+    const MoveActionMultiCallType: u8 = 3;
+    public fun move_action_multi_call_type(): u8 { MoveActionMultiCallType }
 
     /// The transaction Meta data
     /// We can not define MoveAction in Move, so we define a simple meta data struct to represent it
@@ -57,6 +59,10 @@ module moveos_std::tx_meta {
         self.action_type == MoveActionModuleBundleType
     }
 
+    public fun is_multi_call(self: &TxMeta): bool {
+        self.action_type == MoveActionMultiCallType
+    }
+
     public fun function_meta(self: &TxMeta): Option<FunctionCallMeta> {
         *&self.function_meta
     }
@@ -0,0 +1,65 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Verify the inclusion proof of the transaction accumulator.
+/// The leaf of the accumulator is the tx hash and the leaf index is the tx order,
+/// the internal node hash is the sha3_256 of the left child hash and the right child hash.
+module rooch_framework::accumulator_proof {
+    use std::vector;
+    use std::hash;
+
+    /// The max depth of the accumulator proof, it is the same as the `MAX_ACCUMULATOR_PROOF_DEPTH` of the accumulator.
+    const MAX_ACCUMULATOR_PROOF_DEPTH: u64 = 63;
+
+    /// Verify the `leaf` at `leaf_index` is included in the accumulator with the `root`.
+    /// The `siblings` are ordered from the bottom level to the root level.
+    public fun verify(root: vector<u8>, leaf: vector<u8>, leaf_index: u64, siblings: &vector<vector<u8>>): bool {
+        let depth = vector::length(siblings);
+        if (depth > MAX_ACCUMULATOR_PROOF_DEPTH) {
+            return false
+        };
+        let node_hash = leaf;
+        let index = leaf_index;
+        let i = 0;
+        while (i < depth) {
+            let sibling = *vector::borrow(siblings, i);
+            let node_bytes = if (index % 2 == 0) {
+                // The current node is a left child
+                vector::append(&mut node_hash, sibling);
+                node_hash
+            } else {
+                // The current node is a right child
+                vector::append(&mut sibling, node_hash);
+                sibling
+            };
+            node_hash = hash::sha3_256(node_bytes);
+            index = index / 2;
+            i = i + 1;
+        };
+        // The leaf index should be in the range of the tree
+        index == 0 && node_hash == root
+    }
+
+    #[test]
+    fun test_verify() {
+        // The accumulator with 3 leaves: sha3_256(b"tx0"), sha3_256(b"tx1"), sha3_256(b"tx2")
+        let root = x"336b21c1439788a9ee25e9c2d73885b3319c7511db085c1879f33a2143898c70";
+        let leaf0 = x"02061f0e031cc0cb3a3c7edad514464a904827653ad4749c84b173f3873b2c88";
+        let leaf1 = x"8a74d0de385f8904abb576d386a6fd0e4e973433c8a2763963be8f9c872a86e7";
+        let leaf2 = x"b13e6934ec2298d8f36fd1caa82b03ce98bf5e29daa9604cfd8615edafc4f8de";
+        let node01 = x"f3560f6cebddcd44e65a44b5985a016fcd6fcb4408cf66c71cd81dcb65240f74";
+        let node2 = x"9eb1607fbe6622bac7a0da47ce956e8d39fc73669162e8f9c52fd5a5b27ed397";
+        // ACCUMULATOR_PLACEHOLDER_HASH
+        let placeholder = x"414343554d554c41544f525f504c414345484f4c4445525f4841534800000000";
+
+        assert!(verify(root, leaf0, 0, &vector[leaf1, node2]), 1);
+        assert!(verify(root, leaf1, 1, &vector[leaf0, node2]), 2);
+        assert!(verify(root, leaf2, 2, &vector[placeholder, node01]), 3);
+
+        // Wrong leaf index
+        assert!(!verify(root, leaf1, 0, &vector[leaf0, node2]), 4);
+        assert!(!verify(root, leaf0, 4, &vector[leaf1, node2]), 5);
+        // Wrong leaf
+        assert!(!verify(root, leaf1, 2, &vector[placeholder, node01]), 6);
+    }
+}
@@ -42,8 +42,18 @@ module rooch_framework::bitcoin_validator {
     }
 
     public(friend) fun validate(authenticator_payload: vector<u8>) :BitcoinAddress{
-
         let sender = tx_context::sender();
+        validate_for_account(sender, authenticator_payload)
+    }
+
+    /// Validate the fee payer's authenticator of a sponsored transaction.
+    /// The fee payer signs the same transaction hash as the sender.
+    public(friend) fun validate_fee_payer(fee_payer: address, authenticator_payload: vector<u8>) :BitcoinAddress{
+        validate_for_account(fee_payer, authenticator_payload)
+    }
+
+    fun validate_for_account(account: address, authenticator_payload: vector<u8>) :BitcoinAddress{
+
         let tx_hash = tx_context::tx_hash();
         let payload = auth_payload::from_bytes(authenticator_payload);
 
@@ -60,9 +70,9 @@ module rooch_framework::bitcoin_validator {
 
         let rooch_addr = bitcoin_address::to_rooch_address(&bitcoin_addr);
 
-        // Check if the sender is related to the Rooch address
+        // Check if the account is related to the Rooch address
         assert!(
-            sender == rooch_addr,
+            account == rooch_addr,
             auth_validator::error_validate_invalid_authenticator()
         );
         bitcoin_addr
@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+module rooch_framework::ecvrf {
+    /// constant codes
+    const ECVRF_OUTPUT_LENGTH: u64 = 64;
+
+    /// built-in functions
+    public fun output_length(): u64 {
+        ECVRF_OUTPUT_LENGTH
+    }
+
+    /// @param output: The 64-byte output of the VRF.
+    /// @param alpha_string: The input of the VRF.
+    /// @param public_key: The BCS serialized ECVRF public key over the Ristretto255 group.
+    /// @param proof: The BCS serialized ECVRF proof.
+    ///
+    /// If the proof is a valid ECVRF-RISTRETTO255-SHA512 proof of the output for the input and public key, return true.
+    /// Otherwise, return false.
+    native public fun verify(output: &vector<u8>, alpha_string: &vector<u8>, public_key: &vector<u8>, proof: &vector<u8>): bool;
+
+    #[test]
+    fun test_ecvrf_invalid_output() {
+        let output = x"00";
+        let alpha_string = b"Hello, world!";
+        let public_key = x"";
+        let proof = x"";
+
+        let verify = verify(&output, &alpha_string, &public_key, &proof);
+        assert!(verify == false, 0);
+    }
+
+    #[test]
+    fun test_ecvrf_invalid_public_key_and_proof() {
+        let output = std::vector::empty<u8>();
+        let i = 0;
+        while (i < ECVRF_OUTPUT_LENGTH) {
+            std::vector::push_back(&mut output, (i as u8));
+            i = i + 1;
+        };
+        let alpha_string = b"Hello, world!";
+        let public_key = x"0102";
+        let proof = x"0304";
+
+        let verify = verify(&output, &alpha_string, &public_key, &proof);
+        assert!(verify == false, 0);
+    }
+}
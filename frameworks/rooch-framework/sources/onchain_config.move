@@ -14,6 +14,8 @@ module rooch_framework::onchain_config {
     friend rooch_framework::genesis;
 
     const ErrorNotAdmin: u64 = 1;
+    /// The feature can only be changed at genesis
+    const ErrorGenesisOnlyFeature: u64 = 2;
 
     /// OnchainConfig is framework configurations stored on chain.
     struct OnchainConfig has key {
@@ -96,8 +98,11 @@ module rooch_framework::onchain_config {
     /****** API for changing feature flags *******/
 
     /// Enable or disable features. You can find all feature flags in moveos_std::features.
+    /// The `SAT_RANGE_TRACKING` feature can only be set at genesis, the sats of the UTXOs created before it is enabled would be unknown.
     public entry fun change_feature_flags(account: &signer, enable: vector<u64>, disable: vector<u64>) {
         ensure_admin(account);
+        let sat_range_tracking = features::get_sat_range_tracking_feature();
+        assert!(!vector::contains(&enable, &sat_range_tracking) && !vector::contains(&disable, &sat_range_tracking), ErrorGenesisOnlyFeature);
         let system_account = signer::module_signer<OnchainConfig>();
         features::change_feature_flags(&system_account, enable, disable);
     }
@@ -120,6 +125,7 @@ module rooch_framework::onchain_config {
             vector::push_back(&mut enables, features::get_testnet_feature());
             vector::push_back(&mut enables, features::get_value_size_gas_feature());
             vector::push_back(&mut enables, features::get_compatibility_checker_v2_feature());
+            vector::push_back(&mut enables, features::get_sat_range_tracking_feature());
         } else if (chain_id::is_dev()) {
             vector::push_back(&mut enables, features::get_devnet_feature());
             vector::push_back(&mut enables, features::get_testnet_feature());
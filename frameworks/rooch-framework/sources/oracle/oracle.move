@@ -6,18 +6,21 @@ module rooch_framework::oracle {
     use std::option::Option;
     use std::string;
     use std::string::String;
+    use std::vector;
     use moveos_std::timestamp::now_milliseconds;
     use moveos_std::tx_context::sender;
     use moveos_std::object;
     use moveos_std::object::{Object, ObjectID};
     use moveos_std::table::{Self, Table};
     use moveos_std::event;
-    use moveos_std::decimal_value;
+    use moveos_std::decimal_value::{Self, DecimalValue};
 
     use rooch_framework::oracle_data::{Self, Data};
 
     const ErrorSenderNotOracle: u64 = 0;
     const ErrorTickerNotExists: u64 = 1;
+    const ErrorInvalidBatchLength: u64 = 2;
+    const ErrorInvalidArchivePeriod: u64 = 3;
 
     struct TablePlaceholder has key {
         _placeholder: bool,
@@ -53,14 +56,21 @@ module rooch_framework::oracle {
         admin_id: ObjectID
     }
 
+    /// Get the archived data of the `ticker` by the `archival_key`, returns none if the data is not archived.
     public fun get_historical_data<K: copy + drop + store, V: store + copy>(
         oracle_obj: &Object<SimpleOracle>,
         ticker: String,
         archival_key: K
     ): Option<Data<V>> {
         let oracle = object::borrow(oracle_obj);
-        string::append(&mut string::utf8(b"[historical] "), ticker);
-        let historical_data: &Table<K, StoredData<V>> = object::borrow_field(&oracle.id, ticker);
+        let historical_key = historical_key(ticker);
+        if (!object::contains_field(&oracle.id, historical_key)) {
+            return option::none()
+        };
+        let historical_data: &Table<K, StoredData<V>> = object::borrow_field(&oracle.id, historical_key);
+        if (!table::contains(historical_data, archival_key)) {
+            return option::none()
+        };
         let StoredData { value, sequence_number, timestamp, identifier } = *table::borrow(
             historical_data,
             archival_key
@@ -68,6 +78,14 @@ module rooch_framework::oracle {
         option::some(oracle_data::new(value, ticker, sequence_number, timestamp, oracle.address, identifier))
     }
 
+    /// The historical data of a ticker is stored in a table with the field key `[historical] <ticker>`,
+    /// the latest data is stored with the field key `<ticker>`.
+    fun historical_key(ticker: String): String {
+        let key = string::utf8(b"[historical] ");
+        string::append(&mut key, ticker);
+        key
+    }
+
     public fun get_latest_data<T: store + copy>(oracle_obj: &Object<SimpleOracle>, ticker: String): Option<Data<T>> {
         let oracle = object::borrow(oracle_obj);
         if (!object::contains_field(&oracle.id, ticker)) {
@@ -164,6 +182,40 @@ module rooch_framework::oracle {
         submit_data_with_timestamp(oracle_obj, ticker, decimal_value, identifier, timestamp, admin_obj);
     }
 
+    /// Submit the decimal data of many tickers in one transaction.
+    /// The item at the same index of every vector is the data of a ticker.
+    public entry fun submit_decimal_data_batch(
+        oracle_obj: &mut Object<SimpleOracle>,
+        tickers: vector<String>,
+        values: vector<u256>,
+        decimals: vector<u8>,
+        identifiers: vector<String>,
+        timestamps: vector<u64>,
+        admin_obj: &mut Object<OracleAdminCap>
+    ) {
+        let len = vector::length(&tickers);
+        assert!(
+            vector::length(&values) == len
+                && vector::length(&decimals) == len
+                && vector::length(&identifiers) == len
+                && vector::length(&timestamps) == len,
+            ErrorInvalidBatchLength
+        );
+        let i = 0;
+        while (i < len) {
+            submit_decimal_data(
+                oracle_obj,
+                *vector::borrow(&tickers, i),
+                *vector::borrow(&values, i),
+                *vector::borrow(&decimals, i),
+                *vector::borrow(&identifiers, i),
+                *vector::borrow(&timestamps, i),
+                admin_obj
+            );
+            i = i + 1;
+        };
+    }
+
     public fun archive_data<K: store + copy + drop, V: store + copy + drop>(
         oracle_obj: &mut Object<SimpleOracle>,
         ticker: String,
@@ -178,16 +230,92 @@ module rooch_framework::oracle {
 
         let latest_data: StoredData<V> = *object::borrow_mut_field(&mut oracle.id, ticker);
 
-        string::append(&mut string::utf8(b"[historical] "), ticker);
-        if (!object::contains_field(&oracle.id, ticker)) {
+        let historical_key = historical_key(ticker);
+        if (!object::contains_field(&oracle.id, historical_key)) {
             let data_source = table::new<K, StoredData<V>>();
-            object::add_field(&mut oracle.id, ticker, data_source);
+            object::add_field(&mut oracle.id, historical_key, data_source);
         };
-        let historical_data: &mut Table<K, StoredData<V>> = object::borrow_mut_field(&mut oracle.id, ticker);
+        let historical_data: &mut Table<K, StoredData<V>> = object::borrow_mut_field(&mut oracle.id, historical_key);
         // Replace the old data in historical data if any.
         if (table::contains(historical_data, archival_key)) {
             table::remove(historical_data, archival_key);
         };
         table::add(historical_data, archival_key, latest_data);
     }
-}
\ No newline at end of file
+
+    /// Archive the latest decimal data of the `ticker` with the archival key `timestamp / period`,
+    /// so the data is sampled once per `period` milliseconds, the `price_feed` TWAP reads the archives by this key.
+    public entry fun archive_decimal_data(
+        oracle_obj: &mut Object<SimpleOracle>,
+        ticker: String,
+        period: u64,
+        admin_obj: &mut Object<OracleAdminCap>,
+    ) {
+        assert!(period > 0, ErrorInvalidArchivePeriod);
+        let oracle = object::borrow(oracle_obj);
+        assert!(object::contains_field(&oracle.id, ticker), ErrorTickerNotExists);
+        let latest_data: &StoredData<DecimalValue> = object::borrow_field(&oracle.id, ticker);
+        let archival_key = latest_data.timestamp / period;
+        archive_data<u64, DecimalValue>(oracle_obj, ticker, archival_key, admin_obj);
+    }
+
+    #[test]
+    fun test_submit_decimal_data_batch() {
+        rooch_framework::genesis::init_for_test();
+        let (oracle, admin_cap) = create(string::utf8(b"test"), string::utf8(b""), string::utf8(b""));
+        submit_decimal_data_batch(
+            &mut oracle,
+            vector[string::utf8(b"BTCUSD"), string::utf8(b"ETHUSD")],
+            vector[6000000, 300000],
+            vector[2, 2],
+            vector[string::utf8(b"binance,okx"), string::utf8(b"binance")],
+            vector[1000, 2000],
+            &mut admin_cap
+        );
+        let data = option::destroy_some(get_latest_data<DecimalValue>(&oracle, string::utf8(b"ETHUSD")));
+        assert!(decimal_value::value(oracle_data::value(&data)) == 300000, 1);
+        assert!(oracle_data::timestamp(&data) == 2000, 2);
+        object::to_shared(oracle);
+        object::transfer(admin_cap, @0x42);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidBatchLength, location = Self)]
+    fun test_submit_decimal_data_batch_invalid_length() {
+        rooch_framework::genesis::init_for_test();
+        let (oracle, admin_cap) = create(string::utf8(b"test"), string::utf8(b""), string::utf8(b""));
+        submit_decimal_data_batch(
+            &mut oracle,
+            vector[string::utf8(b"BTCUSD")],
+            vector[],
+            vector[2],
+            vector[string::utf8(b"binance")],
+            vector[1000],
+            &mut admin_cap
+        );
+        object::to_shared(oracle);
+        object::transfer(admin_cap, @0x42);
+    }
+
+    #[test]
+    fun test_archive_decimal_data() {
+        rooch_framework::genesis::init_for_test();
+        let ticker = string::utf8(b"BTCUSD");
+        let (oracle, admin_cap) = create(string::utf8(b"test"), string::utf8(b""), string::utf8(b""));
+        submit_decimal_data(&mut oracle, ticker, 100, 2, string::utf8(b"binance"), 1500, &mut admin_cap);
+        archive_decimal_data(&mut oracle, ticker, 1000, &mut admin_cap);
+        submit_decimal_data(&mut oracle, ticker, 200, 2, string::utf8(b"binance"), 2500, &mut admin_cap);
+        archive_decimal_data(&mut oracle, ticker, 1000, &mut admin_cap);
+
+        let data = option::destroy_some(get_historical_data<u64, DecimalValue>(&oracle, ticker, 1));
+        assert!(decimal_value::value(oracle_data::value(&data)) == 100, 1);
+        let data = option::destroy_some(get_historical_data<u64, DecimalValue>(&oracle, ticker, 2));
+        assert!(decimal_value::value(oracle_data::value(&data)) == 200, 2);
+        assert!(option::is_none(&get_historical_data<u64, DecimalValue>(&oracle, ticker, 3)), 3);
+        // The latest data is kept
+        let data = option::destroy_some(get_latest_data<DecimalValue>(&oracle, ticker));
+        assert!(oracle_data::timestamp(&data) == 2500, 4);
+        object::to_shared(oracle);
+        object::transfer(admin_cap, @0x42);
+    }
+}
@@ -0,0 +1,454 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The consumer API of the oracles.
+/// The admin registers the `SimpleOracle`s of a ticker to the price feed,
+/// the consumer reads the median price of the fresh data of all the registered oracles,
+/// or the TWAP of the archived data via `oracle::archive_decimal_data`.
+module rooch_framework::price_feed {
+    use std::option::{Self, Option};
+    use std::string::String;
+    use std::vector;
+    use moveos_std::decimal_value::{Self, DecimalValue};
+    use moveos_std::event;
+    use moveos_std::object::{Self, Object, ObjectID};
+    use moveos_std::sort;
+    use moveos_std::timestamp::now_milliseconds;
+    use rooch_framework::onchain_config;
+    use rooch_framework::oracle::{Self, SimpleOracle};
+    use rooch_framework::oracle_data;
+
+    const ErrorOracleNotFound: u64 = 1;
+    const ErrorOracleAlreadyRegistered: u64 = 2;
+    const ErrorOracleNotRegistered: u64 = 3;
+    const ErrorTooManyOracles: u64 = 4;
+    const ErrorInvalidMinOracles: u64 = 5;
+    const ErrorInvalidPeriod: u64 = 6;
+
+    /// The default max age of the data, in milliseconds.
+    const DEFAULT_MAX_AGE: u64 = 60000;
+    const DEFAULT_MIN_ORACLES: u64 = 1;
+    /// The max number of the oracles of a ticker, to bound the gas of reading the price.
+    const MAX_ORACLES: u64 = 16;
+    /// The max number of the archived samples of an oracle read by the TWAP.
+    const MAX_TWAP_SAMPLES: u64 = 64;
+
+    /// The price feeds are stored as the fields of this object, the key is the ticker.
+    struct PriceFeedRegistry has key {}
+
+    struct PriceFeed has store, copy, drop {
+        /// The oracles of the ticker
+        oracles: vector<ObjectID>,
+        /// The data older than this is rejected, in milliseconds.
+        max_age: u64,
+        /// The price is not available if the number of the oracles with fresh data is less than this.
+        min_oracles: u64,
+    }
+
+    /// The aggregated price of a ticker.
+    struct PriceData has store, copy, drop {
+        ticker: String,
+        value: DecimalValue,
+        /// The oldest timestamp of the data used in the aggregation, in milliseconds.
+        timestamp: u64,
+        /// How many oracles are used in the aggregation
+        oracle_count: u64,
+    }
+
+    struct OracleRegisteredEvent has copy, drop {
+        ticker: String,
+        oracle_id: ObjectID,
+    }
+
+    struct OracleUnregisteredEvent has copy, drop {
+        ticker: String,
+        oracle_id: ObjectID,
+    }
+
+    /// Register the oracle to the price feed of the ticker, only the admin can call it.
+    public entry fun register_oracle_entry(account: &signer, ticker: String, oracle_id: ObjectID) {
+        onchain_config::ensure_admin(account);
+        register_oracle(ticker, oracle_id);
+    }
+
+    /// Unregister the oracle from the price feed of the ticker, only the admin can call it.
+    public entry fun unregister_oracle_entry(account: &signer, ticker: String, oracle_id: ObjectID) {
+        onchain_config::ensure_admin(account);
+        unregister_oracle(ticker, oracle_id);
+    }
+
+    /// Set the max age in milliseconds and the min oracles of the price feed of the ticker, only the admin can call it.
+    public entry fun set_feed_config_entry(account: &signer, ticker: String, max_age: u64, min_oracles: u64) {
+        onchain_config::ensure_admin(account);
+        set_feed_config(ticker, max_age, min_oracles);
+    }
+
+    fun register_oracle(ticker: String, oracle_id: ObjectID) {
+        assert!(object::exists_object_with_type<SimpleOracle>(oracle_id), ErrorOracleNotFound);
+        let feed = borrow_mut_feed(ticker);
+        assert!(!vector::contains(&feed.oracles, &oracle_id), ErrorOracleAlreadyRegistered);
+        assert!(vector::length(&feed.oracles) < MAX_ORACLES, ErrorTooManyOracles);
+        vector::push_back(&mut feed.oracles, oracle_id);
+        event::emit(OracleRegisteredEvent { ticker, oracle_id });
+    }
+
+    fun unregister_oracle(ticker: String, oracle_id: ObjectID) {
+        let feed = borrow_mut_feed(ticker);
+        let (found, index) = vector::index_of(&feed.oracles, &oracle_id);
+        assert!(found, ErrorOracleNotRegistered);
+        vector::remove(&mut feed.oracles, index);
+        event::emit(OracleUnregisteredEvent { ticker, oracle_id });
+    }
+
+    fun set_feed_config(ticker: String, max_age: u64, min_oracles: u64) {
+        assert!(min_oracles > 0, ErrorInvalidMinOracles);
+        let feed = borrow_mut_feed(ticker);
+        feed.max_age = max_age;
+        feed.min_oracles = min_oracles;
+    }
+
+    fun borrow_mut_feed(ticker: String): &mut PriceFeed {
+        let object_id = object::named_object_id<PriceFeedRegistry>();
+        if (!object::exists_object(object_id)) {
+            object::to_shared(object::new_named_object(PriceFeedRegistry {}));
+        };
+        let registry = object::borrow_mut_object_shared<PriceFeedRegistry>(object_id);
+        if (!object::contains_field(registry, ticker)) {
+            object::add_field(registry, ticker, PriceFeed {
+                oracles: vector::empty(),
+                max_age: DEFAULT_MAX_AGE,
+                min_oracles: DEFAULT_MIN_ORACLES,
+            });
+        };
+        object::borrow_mut_field(registry, ticker)
+    }
+
+    /// Get the price feed of the ticker, returns none if the ticker is not registered.
+    public fun get_feed(ticker: String): Option<PriceFeed> {
+        let object_id = object::named_object_id<PriceFeedRegistry>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        let registry = object::borrow_object<PriceFeedRegistry>(object_id);
+        if (!object::contains_field(registry, ticker)) {
+            return option::none()
+        };
+        option::some(*object::borrow_field<PriceFeedRegistry, String, PriceFeed>(registry, ticker))
+    }
+
+    /// Get the median price of the latest data of the oracles of the ticker, the data older than the max age of the feed is rejected.
+    /// Returns none if the ticker is not registered or the fresh data is not enough.
+    public fun get_price(ticker: String): Option<PriceData> {
+        let feed = get_feed(ticker);
+        if (option::is_none(&feed)) {
+            return option::none()
+        };
+        let max_age = option::borrow(&feed).max_age;
+        get_price_with_max_age(ticker, max_age)
+    }
+
+    /// Same as `get_price`, but reject the data older than `max_age` milliseconds.
+    public fun get_price_with_max_age(ticker: String, max_age: u64): Option<PriceData> {
+        let feed = get_feed(ticker);
+        if (option::is_none(&feed)) {
+            return option::none()
+        };
+        let PriceFeed { oracles, max_age: _, min_oracles } = option::destroy_some(feed);
+        let now = now_milliseconds();
+        let values = vector::empty<DecimalValue>();
+        let oldest_timestamp = now;
+        let i = 0;
+        let len = vector::length(&oracles);
+        while (i < len) {
+            let oracle_id = *vector::borrow(&oracles, i);
+            if (object::exists_object_with_type<SimpleOracle>(oracle_id)) {
+                let oracle_obj = object::borrow_object<SimpleOracle>(oracle_id);
+                let data = oracle::get_latest_data<DecimalValue>(oracle_obj, ticker);
+                if (option::is_some(&data)) {
+                    let data = option::destroy_some(data);
+                    let data_timestamp = oracle_data::timestamp(&data);
+                    if (age(now, data_timestamp) <= max_age) {
+                        vector::push_back(&mut values, *oracle_data::value(&data));
+                        if (data_timestamp < oldest_timestamp) {
+                            oldest_timestamp = data_timestamp;
+                        };
+                    };
+                };
+            };
+            i = i + 1;
+        };
+        let oracle_count = vector::length(&values);
+        if (oracle_count == 0 || oracle_count < min_oracles) {
+            return option::none()
+        };
+        option::some(PriceData {
+            ticker,
+            value: median(values),
+            timestamp: oldest_timestamp,
+            oracle_count,
+        })
+    }
+
+    /// Get the time weighted average price of the ticker over the last `window` milliseconds.
+    /// The TWAP of every oracle is calculated from the data archived with `oracle::archive_decimal_data` by the same `period`,
+    /// every sample is weighted by the time until the next sample, and the median of the TWAPs of the oracles is returned.
+    /// At most the latest `MAX_TWAP_SAMPLES` periods are read.
+    /// Returns none if the ticker is not registered or the oracles with archived data are less than the min oracles of the feed.
+    public fun get_twap(ticker: String, window: u64, period: u64): Option<DecimalValue> {
+        assert!(period > 0, ErrorInvalidPeriod);
+        let feed = get_feed(ticker);
+        if (option::is_none(&feed)) {
+            return option::none()
+        };
+        let PriceFeed { oracles, max_age: _, min_oracles } = option::destroy_some(feed);
+        let now = now_milliseconds();
+        let start = if (now > window) { now - window } else { 0 };
+        let twaps = vector::empty<DecimalValue>();
+        let i = 0;
+        let len = vector::length(&oracles);
+        while (i < len) {
+            let oracle_id = *vector::borrow(&oracles, i);
+            if (object::exists_object_with_type<SimpleOracle>(oracle_id)) {
+                let oracle_obj = object::borrow_object<SimpleOracle>(oracle_id);
+                let twap = oracle_twap(oracle_obj, ticker, start, now, period);
+                if (option::is_some(&twap)) {
+                    vector::push_back(&mut twaps, option::destroy_some(twap));
+                };
+            };
+            i = i + 1;
+        };
+        let oracle_count = vector::length(&twaps);
+        if (oracle_count == 0 || oracle_count < min_oracles) {
+            return option::none()
+        };
+        option::some(median(twaps))
+    }
+
+    fun oracle_twap(oracle_obj: &Object<SimpleOracle>, ticker: String, start: u64, now: u64, period: u64): Option<DecimalValue> {
+        let last_key = now / period;
+        let first_key = start / period;
+        if (last_key - first_key >= MAX_TWAP_SAMPLES) {
+            first_key = last_key - MAX_TWAP_SAMPLES + 1;
+        };
+        let values = vector::empty<DecimalValue>();
+        let timestamps = vector::empty<u64>();
+        let key = first_key;
+        while (key <= last_key) {
+            let data = oracle::get_historical_data<u64, DecimalValue>(oracle_obj, ticker, key);
+            if (option::is_some(&data)) {
+                let data = option::destroy_some(data);
+                let data_timestamp = oracle_data::timestamp(&data);
+                // The samples are read in the order of the archival key, skip the out of order data.
+                let in_order = vector::is_empty(&timestamps) || data_timestamp >= *vector::borrow(&timestamps, vector::length(&timestamps) - 1);
+                if (data_timestamp <= now && in_order) {
+                    vector::push_back(&mut values, *oracle_data::value(&data));
+                    vector::push_back(&mut timestamps, data_timestamp);
+                };
+            };
+            key = key + 1;
+        };
+        let len = vector::length(&values);
+        if (len == 0) {
+            return option::none()
+        };
+        let decimal = max_decimal(&values);
+        let weighted_sum = 0u256;
+        let total_weight = 0u256;
+        let i = 0;
+        while (i < len) {
+            let sample_start = *vector::borrow(&timestamps, i);
+            if (sample_start < start) {
+                sample_start = start;
+            };
+            let sample_end = if (i + 1 < len) { *vector::borrow(&timestamps, i + 1) } else { now };
+            if (sample_end > sample_start) {
+                let weight = ((sample_end - sample_start) as u256);
+                let value = decimal_value::value(&decimal_value::with_precision(vector::borrow(&values, i), decimal));
+                weighted_sum = weighted_sum + value * weight;
+                total_weight = total_weight + weight;
+            };
+            i = i + 1;
+        };
+        // All the samples are at `now`, use the latest sample.
+        if (total_weight == 0) {
+            return option::some(decimal_value::with_precision(vector::borrow(&values, len - 1), decimal))
+        };
+        option::some(decimal_value::new(weighted_sum / total_weight, decimal))
+    }
+
+    /// The median of the values, the values are normalized to the max decimal of the values.
+    /// The median of an even number of values is the average of the two middle values.
+    fun median(values: vector<DecimalValue>): DecimalValue {
+        let decimal = max_decimal(&values);
+        let normalized = vector::empty<u256>();
+        let i = 0;
+        let len = vector::length(&values);
+        while (i < len) {
+            let value = decimal_value::with_precision(vector::borrow(&values, i), decimal);
+            vector::push_back(&mut normalized, decimal_value::value(&value));
+            i = i + 1;
+        };
+        sort::sort(&mut normalized);
+        let middle = len / 2;
+        let value = if (len % 2 == 0) {
+            (*vector::borrow(&normalized, middle - 1) + *vector::borrow(&normalized, middle)) / 2
+        } else {
+            *vector::borrow(&normalized, middle)
+        };
+        decimal_value::new(value, decimal)
+    }
+
+    fun max_decimal(values: &vector<DecimalValue>): u8 {
+        let decimal = 0u8;
+        let i = 0;
+        let len = vector::length(values);
+        while (i < len) {
+            let value_decimal = decimal_value::decimal(vector::borrow(values, i));
+            if (value_decimal > decimal) {
+                decimal = value_decimal;
+            };
+            i = i + 1;
+        };
+        decimal
+    }
+
+    /// The data from the future is treated as fresh, the clock of the oracle may be a little ahead of the chain.
+    fun age(now: u64, data_timestamp: u64): u64 {
+        if (now > data_timestamp) { now - data_timestamp } else { 0 }
+    }
+
+    public fun oracles(self: &PriceFeed): vector<ObjectID> {
+        self.oracles
+    }
+
+    public fun max_age(self: &PriceFeed): u64 {
+        self.max_age
+    }
+
+    public fun min_oracles(self: &PriceFeed): u64 {
+        self.min_oracles
+    }
+
+    public fun ticker(self: &PriceData): String {
+        self.ticker
+    }
+
+    public fun value(self: &PriceData): DecimalValue {
+        self.value
+    }
+
+    public fun timestamp(self: &PriceData): u64 {
+        self.timestamp
+    }
+
+    public fun oracle_count(self: &PriceData): u64 {
+        self.oracle_count
+    }
+
+    #[test_only]
+    use std::string;
+    #[test_only]
+    use rooch_framework::oracle::OracleAdminCap;
+
+    #[test_only]
+    fun create_oracle_for_testing(ticker: String, value: u256, decimal: u8, data_timestamp: u64): (ObjectID, Object<OracleAdminCap>) {
+        let (oracle_obj, admin_cap) = oracle::create(string::utf8(b"test"), string::utf8(b""), string::utf8(b""));
+        oracle::submit_decimal_data(&mut oracle_obj, ticker, value, decimal, string::utf8(b"test"), data_timestamp, &mut admin_cap);
+        let oracle_id = object::id(&oracle_obj);
+        object::to_shared(oracle_obj);
+        (oracle_id, admin_cap)
+    }
+
+    #[test]
+    fun test_get_price() {
+        rooch_framework::genesis::init_for_test();
+        moveos_std::timestamp::update_global_time_for_test(100000);
+        let ticker = string::utf8(b"BTCUSD");
+        assert!(option::is_none(&get_price(ticker)), 1);
+
+        let (oracle1, cap1) = create_oracle_for_testing(ticker, 6000000, 2, 99000);
+        let (oracle2, cap2) = create_oracle_for_testing(ticker, 600100000, 4, 98000);
+        let (oracle3, cap3) = create_oracle_for_testing(ticker, 6500000, 2, 97000);
+        // The stale data
+        let (oracle4, cap4) = create_oracle_for_testing(ticker, 1, 2, 10000);
+        register_oracle(ticker, oracle1);
+        register_oracle(ticker, oracle2);
+        register_oracle(ticker, oracle3);
+        register_oracle(ticker, oracle4);
+
+        let price = option::destroy_some(get_price(ticker));
+        assert!(decimal_value::value(&price.value) == 600100000, 2);
+        assert!(decimal_value::decimal(&price.value) == 4, 3);
+        assert!(price.timestamp == 97000, 4);
+        assert!(price.oracle_count == 3, 5);
+
+        // Only the data of oracle1 and oracle2 is fresh
+        let price = option::destroy_some(get_price_with_max_age(ticker, 2000));
+        assert!(decimal_value::value(&price.value) == 600050000, 6);
+        assert!(price.oracle_count == 2, 7);
+
+        set_feed_config(ticker, 2000, 3);
+        assert!(option::is_none(&get_price(ticker)), 8);
+
+        unregister_oracle(ticker, oracle3);
+        assert!(vector::length(&option::destroy_some(get_feed(ticker)).oracles) == 3, 9);
+
+        object::transfer(cap1, @0x42);
+        object::transfer(cap2, @0x42);
+        object::transfer(cap3, @0x42);
+        object::transfer(cap4, @0x42);
+    }
+
+    #[test]
+    fun test_get_twap() {
+        rooch_framework::genesis::init_for_test();
+        let ticker = string::utf8(b"BTCUSD");
+        let (oracle_obj, admin_cap) = oracle::create(string::utf8(b"test"), string::utf8(b""), string::utf8(b""));
+        let identifier = string::utf8(b"test");
+        oracle::submit_decimal_data(&mut oracle_obj, ticker, 100, 0, identifier, 1000, &mut admin_cap);
+        oracle::archive_decimal_data(&mut oracle_obj, ticker, 1000, &mut admin_cap);
+        oracle::submit_decimal_data(&mut oracle_obj, ticker, 200, 0, identifier, 4000, &mut admin_cap);
+        oracle::archive_decimal_data(&mut oracle_obj, ticker, 1000, &mut admin_cap);
+        let oracle_id = object::id(&oracle_obj);
+        object::to_shared(oracle_obj);
+        register_oracle(ticker, oracle_id);
+
+        moveos_std::timestamp::update_global_time_for_test(5000);
+        // 100 for 3 seconds and 200 for 1 second
+        let twap = option::destroy_some(get_twap(ticker, 5000, 1000));
+        assert!(decimal_value::value(&twap) == 125, 1);
+        // The last 3.5 seconds: 100 for 2.5 seconds and 200 for 1 second
+        let twap = option::destroy_some(get_twap(ticker, 3500, 1000));
+        assert!(decimal_value::value(&twap) == 128, 2);
+        // Only the sample archived in the window is read
+        let twap = option::destroy_some(get_twap(ticker, 2000, 1000));
+        assert!(decimal_value::value(&twap) == 200, 3);
+        // No sample in the window
+        moveos_std::timestamp::update_global_time_for_test(10000);
+        assert!(option::is_none(&get_twap(ticker, 2000, 1000)), 4);
+
+        object::transfer(admin_cap, @0x42);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorOracleAlreadyRegistered, location = Self)]
+    fun test_register_oracle_twice() {
+        rooch_framework::genesis::init_for_test();
+        let ticker = string::utf8(b"BTCUSD");
+        let (oracle_id, admin_cap) = create_oracle_for_testing(ticker, 100, 0, 0);
+        register_oracle(ticker, oracle_id);
+        register_oracle(ticker, oracle_id);
+        object::transfer(admin_cap, @0x42);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::onchain_config)]
+    fun test_register_oracle_not_admin() {
+        rooch_framework::genesis::init_for_test();
+        let ticker = string::utf8(b"BTCUSD");
+        let (oracle_id, admin_cap) = create_oracle_for_testing(ticker, 100, 0, 0);
+        let account = moveos_std::account::create_signer_for_testing(@0x42);
+        register_oracle_entry(&account, ticker, oracle_id);
+        object::transfer(admin_cap, @0x42);
+    }
+}
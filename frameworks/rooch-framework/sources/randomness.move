@@ -0,0 +1,203 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The on-chain randomness beacon.
+/// The sequencer commits an ECVRF output to every randomness block, a block is a fixed range of `RANDOMNESS_BLOCK_SIZE` tx orders,
+/// and the VRF input is the hash of the block number. The output is committed in the sequence info signed by the sequencer,
+/// and verified with the VRF public key of the sequencer, so the sequencer can not choose the value of a given block.
+/// The randomness of a transaction is derived from the block output and the tx hash.
+/// But the sequencer knows the value before the transaction is executed, and it can still delay, reorder or drop transactions,
+/// so do not use it to settle high value outcomes against the sequencer.
+/// The committed randomness is recorded with the ledger transaction in the DA, so the replay reproduces the same values.
+module rooch_framework::randomness {
+    use std::option;
+    use std::vector;
+    use std::hash;
+    use moveos_std::bcs;
+    use moveos_std::event;
+    use moveos_std::object;
+    use moveos_std::signer;
+    use moveos_std::tx_context;
+    use rooch_framework::ecvrf;
+    use rooch_framework::onchain_config;
+    use rooch_framework::simple_rng;
+    use rooch_framework::transaction::{Self, TransactionSequenceInfo};
+
+    const ErrorRandomnessNotAvailable: u64 = 1;
+    const ErrorInvalidRandomness: u64 = 2;
+    const ErrorInvalidRange: u64 = 3;
+    const ErrorInvalidPublicKey: u64 = 4;
+    const ErrorNotSequencerOrAdmin: u64 = 5;
+
+    /// The number of tx orders in a randomness block, it should be same as the `RANDOMNESS_BLOCK_SIZE` of the sequencer.
+    const RANDOMNESS_BLOCK_SIZE: u64 = 100;
+
+    /// The randomness of the randomness block committed by the sequencer, it is written to the tx context.
+    struct TransactionRandomness has copy, drop, store {
+        output: vector<u8>,
+        proof: vector<u8>,
+    }
+
+    /// The verified randomness output of the transaction, it is written to the tx context after the proof is verified.
+    struct VerifiedRandomness has copy, drop, store {
+        output: vector<u8>,
+    }
+
+    /// The configuration of the randomness beacon.
+    struct RandomnessConfig has key {
+        /// The BCS serialized ECVRF public key of the sequencer.
+        public_key: vector<u8>,
+    }
+
+    struct PublicKeyUpdatedEvent has copy, drop {
+        public_key: vector<u8>,
+    }
+
+    /// Set the VRF public key of the sequencer, only the sequencer or the admin can call it.
+    /// The sequencer registers its key when it starts with the randomness beacon enabled.
+    public entry fun set_public_key_entry(account: &signer, public_key: vector<u8>) {
+        let sender = signer::address_of(account);
+        assert!(sender == onchain_config::sequencer() || sender == onchain_config::admin(), ErrorNotSequencerOrAdmin);
+        set_public_key(public_key);
+    }
+
+    fun set_public_key(public_key: vector<u8>) {
+        assert!(!vector::is_empty(&public_key), ErrorInvalidPublicKey);
+        event::emit(PublicKeyUpdatedEvent { public_key: copy public_key });
+        let object_id = object::named_object_id<RandomnessConfig>();
+        if (!object::exists_object(object_id)) {
+            object::to_shared(object::new_named_object(RandomnessConfig { public_key }));
+        } else {
+            let config = object::borrow_mut(object::borrow_mut_object_shared<RandomnessConfig>(object_id));
+            config.public_key = public_key;
+        };
+    }
+
+    /// The VRF public key of the sequencer, returns empty bytes if it is not set.
+    public fun public_key(): vector<u8> {
+        let object_id = object::named_object_id<RandomnessConfig>();
+        if (!object::exists_object(object_id)) {
+            return vector::empty()
+        };
+        object::borrow(object::borrow_object<RandomnessConfig>(object_id)).public_key
+    }
+
+    /// Returns true if the current transaction has the randomness committed by the sequencer.
+    public fun is_available(): bool {
+        tx_context::contains_attribute<VerifiedRandomness>()
+            || (tx_context::contains_attribute<TransactionRandomness>() && !vector::is_empty(&public_key()))
+    }
+
+    /// Returns a fresh 32-byte seed, every call in the same transaction returns a different seed.
+    public fun seed(): vector<u8> {
+        let seed_bytes = verified_output();
+        vector::append(&mut seed_bytes, bcs::to_bytes(&tx_context::fresh_address()));
+        hash::sha3_256(seed_bytes)
+    }
+
+    /// Returns a 32-byte seed derived from the `domain`, the same domain returns the same seed in a transaction.
+    public fun derive_seed(domain: vector<u8>): vector<u8> {
+        let seed_bytes = verified_output();
+        vector::append(&mut seed_bytes, domain);
+        hash::sha3_256(seed_bytes)
+    }
+
+    public fun random_u64(): u64 {
+        simple_rng::bytes_to_u64(seed())
+    }
+
+    public fun random_u128(): u128 {
+        simple_rng::bytes_to_u128(seed())
+    }
+
+    /// Returns a random u64 in the range [low, high).
+    public fun u64_range(low: u64, high: u64): u64 {
+        assert!(high > low, ErrorInvalidRange);
+        let value = random_u64();
+        (value % (high - low)) + low
+    }
+
+    /// The randomness block number of a tx order.
+    public fun block_number(tx_order: u64): u64 {
+        tx_order / RANDOMNESS_BLOCK_SIZE
+    }
+
+    /// The VRF input of a randomness block, it should be same as the `vrf_input` of the sequencer.
+    fun vrf_input(block_number: u64): vector<u8> {
+        hash::sha3_256(bcs::to_bytes(&block_number))
+    }
+
+    fun verified_output(): vector<u8> {
+        let verified = tx_context::get_attribute<VerifiedRandomness>();
+        if (option::is_some(&verified)) {
+            let verified = option::destroy_some(verified);
+            return verified.output
+        };
+
+        let randomness = tx_context::get_attribute<TransactionRandomness>();
+        assert!(option::is_some(&randomness), ErrorRandomnessNotAvailable);
+        let randomness = option::destroy_some(randomness);
+        let sequence_info = tx_context::get_attribute<TransactionSequenceInfo>();
+        assert!(option::is_some(&sequence_info), ErrorRandomnessNotAvailable);
+        let tx_order = transaction::tx_order(&option::destroy_some(sequence_info));
+
+        let public_key = public_key();
+        assert!(!vector::is_empty(&public_key), ErrorRandomnessNotAvailable);
+        let alpha_string = vrf_input(block_number(tx_order));
+        assert!(ecvrf::verify(&randomness.output, &alpha_string, &public_key, &randomness.proof), ErrorInvalidRandomness);
+
+        // All transactions in a block share the block output, derive the transaction output with the tx hash
+        let output = randomness.output;
+        vector::append(&mut output, tx_context::tx_hash());
+        let output = hash::sha3_256(output);
+        // Cache the verified output, avoid to verify the proof repeatedly in the same transaction
+        let system = signer::module_signer<VerifiedRandomness>();
+        tx_context::add_attribute_via_system(&system, VerifiedRandomness { output: copy output });
+        output
+    }
+
+    #[test_only]
+    public fun set_verified_output_for_testing(output: vector<u8>) {
+        let system = signer::module_signer<VerifiedRandomness>();
+        tx_context::add_attribute_via_system(&system, VerifiedRandomness { output });
+    }
+
+    #[test]
+    fun test_seed() {
+        assert!(!is_available(), 1);
+        set_verified_output_for_testing(x"0102030405060708");
+        assert!(is_available(), 2);
+
+        let seed1 = seed();
+        let seed2 = seed();
+        assert!(vector::length(&seed1) == 32, 3);
+        assert!(seed1 != seed2, 4);
+        assert!(derive_seed(b"domain") == derive_seed(b"domain"), 5);
+        assert!(derive_seed(b"domain") != derive_seed(b"other"), 6);
+
+        let value = u64_range(10, 20);
+        assert!(value >= 10 && value < 20, 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorRandomnessNotAvailable, location = Self)]
+    fun test_seed_not_available() {
+        seed();
+    }
+
+    #[test]
+    fun test_set_public_key() {
+        assert!(vector::is_empty(&public_key()), 1);
+        set_public_key(x"01");
+        assert!(public_key() == x"01", 2);
+        set_public_key(x"02");
+        assert!(public_key() == x"02", 3);
+    }
+
+    #[test]
+    fun test_block_number() {
+        assert!(block_number(0) == 0, 1);
+        assert!(block_number(RANDOMNESS_BLOCK_SIZE - 1) == 0, 2);
+        assert!(block_number(RANDOMNESS_BLOCK_SIZE) == 1, 3);
+    }
+}
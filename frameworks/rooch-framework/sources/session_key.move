@@ -199,7 +199,7 @@ module rooch_framework::session_key {
         let tx_meta = tx_context::tx_meta();
         
         let function_call_meta_option = tx_meta::function_meta(&tx_meta);
-        // session key can not be used to execute script or publish module
+        // session key can not be used to execute script, multi call or publish module
         // only support function call now
         if (option::is_none(&function_call_meta_option)){
             return false
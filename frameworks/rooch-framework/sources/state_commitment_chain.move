@@ -0,0 +1,292 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The on-chain State Commitment Chain(SCC).
+/// The proposer submits a block after the transactions of the block are submitted to the DA,
+/// every block commits the tx accumulator root and the state root after the last transaction of the block.
+/// The submission is a normal transaction, the proposer account pays the gas.
+module rooch_framework::state_commitment_chain {
+    use std::option::{Self, Option};
+    use moveos_std::event;
+    use moveos_std::object::{Self, Object};
+    use moveos_std::signer;
+    use rooch_framework::accumulator_proof;
+    use rooch_framework::onchain_config;
+
+    const ErrorNotProposer: u64 = 1;
+    const ErrorInvalidBlockNumber: u64 = 2;
+    const ErrorInvalidPrevAccumulatorRoot: u64 = 3;
+    const ErrorBlockConflict: u64 = 4;
+
+    #[data_struct]
+    /// The block committed by the proposer, the layout is the same as the `Block` of the proposer.
+    struct Block has copy, drop, store {
+        /// The index of the block
+        block_number: u128,
+        /// How many transactions in the block
+        batch_size: u64,
+        /// The tx order of the last transaction in the block
+        tx_order_end: u64,
+        /// The hash of the batch, made by DA
+        batch_hash: vector<u8>,
+        /// The previous tx accumulator root of the block
+        prev_tx_accumulator_root: vector<u8>,
+        /// The tx accumulator root after the last transaction append to the accumulator
+        tx_accumulator_root: vector<u8>,
+        /// The last transaction's state root
+        state_root: vector<u8>,
+    }
+
+    /// The committed blocks are stored as the fields of this object, the key is the block number.
+    struct StateCommitmentChain has key {
+        /// The proposer account, the sequencer is the proposer if it is not set.
+        proposer: Option<address>,
+        /// The number of the last committed block.
+        last_block_number: Option<u128>,
+    }
+
+    struct BlockCommittedEvent has copy, drop {
+        block_number: u128,
+        batch_size: u64,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+    }
+
+    struct ProposerUpdatedEvent has copy, drop {
+        proposer: address,
+    }
+
+    /// Set the proposer account, only the admin can call it.
+    public entry fun set_proposer_entry(account: &signer, proposer: address) {
+        onchain_config::ensure_admin(account);
+        set_proposer(proposer);
+    }
+
+    fun set_proposer(proposer: address) {
+        let scc = borrow_mut_scc();
+        object::borrow_mut(scc).proposer = option::some(proposer);
+        event::emit(ProposerUpdatedEvent { proposer });
+    }
+
+    /// The proposer account, it is the sequencer if the proposer is not set.
+    public fun proposer(): address {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (object::exists_object(object_id)) {
+            let scc = object::borrow(object::borrow_object<StateCommitmentChain>(object_id));
+            if (option::is_some(&scc.proposer)) {
+                return *option::borrow(&scc.proposer)
+            };
+        };
+        onchain_config::sequencer()
+    }
+
+    /// Submit a block to the SCC, only the proposer can call it.
+    /// Submitting a block which is already committed with the same content is a no-op,
+    /// so the proposer can resubmit the block safely after restart.
+    public entry fun submit_block_entry(
+        account: &signer,
+        block_number: u128,
+        batch_size: u64,
+        tx_order_end: u64,
+        batch_hash: vector<u8>,
+        prev_tx_accumulator_root: vector<u8>,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+    ) {
+        assert!(signer::address_of(account) == proposer(), ErrorNotProposer);
+        let block = Block {
+            block_number,
+            batch_size,
+            tx_order_end,
+            batch_hash,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            state_root,
+        };
+        submit_block(block);
+    }
+
+    fun submit_block(block: Block) {
+        let scc = borrow_mut_scc();
+        let block_number = block.block_number;
+        if (object::contains_field(scc, block_number)) {
+            let committed: &Block = object::borrow_field(scc, block_number);
+            assert!(*committed == block, ErrorBlockConflict);
+            return
+        };
+
+        let last_block_number = object::borrow(scc).last_block_number;
+        // The first block can start from any number, the proposer may start from an offset.
+        if (option::is_some(&last_block_number)) {
+            let last_block_number = option::destroy_some(last_block_number);
+            assert!(block_number == last_block_number + 1, ErrorInvalidBlockNumber);
+            let last_block: &Block = object::borrow_field(scc, last_block_number);
+            assert!(last_block.tx_accumulator_root == block.prev_tx_accumulator_root, ErrorInvalidPrevAccumulatorRoot);
+        };
+
+        event::emit(BlockCommittedEvent {
+            block_number,
+            batch_size: block.batch_size,
+            tx_accumulator_root: block.tx_accumulator_root,
+            state_root: block.state_root,
+        });
+        object::add_field(scc, block_number, block);
+        object::borrow_mut(scc).last_block_number = option::some(block_number);
+    }
+
+    fun borrow_mut_scc(): &mut Object<StateCommitmentChain> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            let scc = object::new_named_object(StateCommitmentChain {
+                proposer: option::none(),
+                last_block_number: option::none(),
+            });
+            object::to_shared(scc);
+        };
+        object::borrow_mut_object_shared<StateCommitmentChain>(object_id)
+    }
+
+    /// Get the committed block by the block number.
+    public fun get_block(block_number: u128): Option<Block> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        let scc = object::borrow_object<StateCommitmentChain>(object_id);
+        if (!object::contains_field(scc, block_number)) {
+            return option::none()
+        };
+        option::some(*object::borrow_field<StateCommitmentChain, u128, Block>(scc, block_number))
+    }
+
+    /// The number of the last committed block, returns none if no block is committed.
+    public fun last_block_number(): Option<u128> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        object::borrow(object::borrow_object<StateCommitmentChain>(object_id)).last_block_number
+    }
+
+    /// Verify the transaction at `tx_order` is included in the committed block by the inclusion proof,
+    /// the proof should be anchored at the last transaction of the block.
+    public fun verify_transaction(block_number: u128, tx_order: u64, tx_hash: vector<u8>, siblings: vector<vector<u8>>): bool {
+        let block = get_block(block_number);
+        if (option::is_none(&block)) {
+            return false
+        };
+        let block = option::destroy_some(block);
+        if (tx_order > block.tx_order_end) {
+            return false
+        };
+        accumulator_proof::verify(block.tx_accumulator_root, tx_hash, tx_order, &siblings)
+    }
+
+    public fun block_number(self: &Block): u128 {
+        self.block_number
+    }
+
+    public fun batch_size(self: &Block): u64 {
+        self.batch_size
+    }
+
+    public fun tx_order_end(self: &Block): u64 {
+        self.tx_order_end
+    }
+
+    public fun batch_hash(self: &Block): vector<u8> {
+        self.batch_hash
+    }
+
+    public fun prev_tx_accumulator_root(self: &Block): vector<u8> {
+        self.prev_tx_accumulator_root
+    }
+
+    public fun tx_accumulator_root(self: &Block): vector<u8> {
+        self.tx_accumulator_root
+    }
+
+    public fun state_root(self: &Block): vector<u8> {
+        self.state_root
+    }
+
+    #[test_only]
+    fun new_block_for_testing(block_number: u128, prev_tx_accumulator_root: vector<u8>, tx_accumulator_root: vector<u8>): Block {
+        Block {
+            block_number,
+            batch_size: 1,
+            tx_order_end: (block_number as u64),
+            batch_hash: x"01",
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            state_root: x"02",
+        }
+    }
+
+    #[test]
+    fun test_submit_block() {
+        assert!(option::is_none(&last_block_number()), 1);
+        submit_block(new_block_for_testing(10, x"00", x"0a"));
+        submit_block(new_block_for_testing(11, x"0a", x"0b"));
+        assert!(last_block_number() == option::some(11), 2);
+        assert!(tx_accumulator_root(option::borrow(&get_block(10))) == x"0a", 3);
+        assert!(option::is_none(&get_block(12)), 4);
+
+        // Resubmit the committed block is a no-op
+        submit_block(new_block_for_testing(10, x"00", x"0a"));
+        assert!(last_block_number() == option::some(11), 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorBlockConflict, location = Self)]
+    fun test_submit_conflict_block() {
+        submit_block(new_block_for_testing(0, x"00", x"0a"));
+        submit_block(new_block_for_testing(0, x"00", x"0b"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidBlockNumber, location = Self)]
+    fun test_submit_block_gap() {
+        submit_block(new_block_for_testing(0, x"00", x"0a"));
+        submit_block(new_block_for_testing(2, x"0a", x"0b"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidPrevAccumulatorRoot, location = Self)]
+    fun test_submit_block_invalid_prev_root() {
+        submit_block(new_block_for_testing(0, x"00", x"0a"));
+        submit_block(new_block_for_testing(1, x"0c", x"0b"));
+    }
+
+    #[test]
+    fun test_verify_transaction() {
+        // The accumulator with 3 leaves: sha3_256(b"tx0"), sha3_256(b"tx1"), sha3_256(b"tx2")
+        let root = x"336b21c1439788a9ee25e9c2d73885b3319c7511db085c1879f33a2143898c70";
+        let leaf0 = x"02061f0e031cc0cb3a3c7edad514464a904827653ad4749c84b173f3873b2c88";
+        let leaf1 = x"8a74d0de385f8904abb576d386a6fd0e4e973433c8a2763963be8f9c872a86e7";
+        let node2 = x"9eb1607fbe6622bac7a0da47ce956e8d39fc73669162e8f9c52fd5a5b27ed397";
+        let block = new_block_for_testing(0, x"00", root);
+        block.tx_order_end = 2;
+        submit_block(block);
+
+        assert!(verify_transaction(0, 0, leaf0, vector[leaf1, node2]), 1);
+        assert!(!verify_transaction(0, 1, leaf0, vector[leaf1, node2]), 2);
+        assert!(!verify_transaction(1, 0, leaf0, vector[leaf1, node2]), 3);
+    }
+
+    #[test]
+    fun test_set_proposer() {
+        rooch_framework::genesis::init_for_test();
+        assert!(proposer() == onchain_config::sequencer(), 1);
+        set_proposer(@0x42);
+        assert!(proposer() == @0x42, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorNotProposer, location = Self)]
+    fun test_submit_block_not_proposer() {
+        rooch_framework::genesis::init_for_test();
+        let account = moveos_std::account::create_signer_for_testing(@0x42);
+        submit_block_entry(&account, 0, 1, 0, x"01", x"00", x"0a", x"02");
+    }
+}
@@ -22,6 +22,13 @@ module rooch_framework::transaction {
         tx_accumulator_num_nodes: u64,
     }
 
+    /// The expiration of the transaction, it is written to the tx context by the VM
+    /// when the transaction has an expiration timestamp.
+    struct TransactionExpiration has copy, drop, store{
+        /// The transaction is invalid once the on-chain timestamp reaches it, in second.
+        expiration_timestamp_secs: u64,
+    }
+
     public fun tx_order(self: &TransactionSequenceInfo): u64 {
         self.tx_order
     }
@@ -37,4 +44,8 @@ module rooch_framework::transaction {
     public fun tx_timestamp(self: &TransactionSequenceInfo): u64 {
         self.tx_timestamp
     }
-}
\ No newline at end of file
+
+    public fun expiration_timestamp_secs(self: &TransactionExpiration): u64 {
+        self.expiration_timestamp_secs
+    }
+}
@@ -101,7 +101,7 @@ module rooch_framework::transaction_fee {
 
         let system_fee_coin_store = borrow_mut_or_init_gas_revenue_store(SystemFeeAddress);
         coin_store::deposit(system_fee_coin_store, used_gas_coin);
-        //Return the remaining gas coin to the sender
+        //Return the remaining gas coin to the gas payment account, the sender or the fee payer of a sponsored transaction
         total_paid_gas_coin
     }
 
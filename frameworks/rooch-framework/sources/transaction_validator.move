@@ -18,7 +18,7 @@ module rooch_framework::transaction_validator {
     use rooch_framework::chain_id;
     use rooch_framework::transaction_fee;
     use rooch_framework::gas_coin;
-    use rooch_framework::transaction::{Self, TransactionSequenceInfo};
+    use rooch_framework::transaction::{Self, TransactionSequenceInfo, TransactionExpiration};
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
     use rooch_framework::address_mapping;
@@ -47,6 +47,19 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_bad_chain_id(),
         );
 
+        // === validate the expiration ===
+        // The global time is only updated to the transaction timestamp in pre_execute, after the validation,
+        // so compare with the timestamp of the transaction itself if it is sequenced.
+        // The sequencer also rejects the expired transaction with the timestamp it assigns to the transaction.
+        let expiration = tx_context::get_attribute<TransactionExpiration>();
+        if (option::is_some(&expiration)) {
+            let expiration = option::destroy_some(expiration);
+            assert!(
+                tx_timestamp_seconds() < transaction::expiration_timestamp_secs(&expiration),
+                auth_validator::error_validate_transaction_expired(),
+            );
+        };
+
         // === validate the sequence number ===
         let tx_sequence_number = tx_context::sequence_number();
         assert!(
@@ -60,13 +73,6 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_sequence_number_too_old(),
         );
 
-        // Check that the transaction's sequence number matches the
-        // current sequence number. Otherwise sequence number is too new.
-        assert!(
-            tx_sequence_number == account_sequence_number,
-            auth_validator::error_validate_sequence_number_too_new(),
-        );
-
         // === validate gas ===
         let max_gas_amount = tx_context::max_gas_amount();
         let gas = transaction_fee::calculate_gas(max_gas_amount);
@@ -77,7 +83,9 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_max_gas_amount_exceeded(),
         );
 
-        let gas_balance = gas_coin::balance(sender);
+        // The gas is paid by the fee payer if the transaction is sponsored
+        let gas_payment_account = tx_context::tx_gas_payment_account();
+        let gas_balance = gas_coin::balance(gas_payment_account);
 
         // we do not need to check the gas balance in local or dev chain
         if(!chain_id::is_local_or_dev()){
@@ -109,9 +117,65 @@ module rooch_framework::transaction_validator {
         //The bitcoin address must exist
         assert!(option::is_some(&bitcoin_address), auth_validator::error_validate_account_does_not_exist());
         let bitcoin_address = option::destroy_some(bitcoin_address);
+
+        // Check that the transaction's sequence number matches the
+        // current sequence number. Otherwise sequence number is too new.
+        // This check is the last one, so the mempool can validate the future transaction
+        // and only accept the sequence number too new error.
+        assert!(
+            tx_sequence_number == account_sequence_number,
+            auth_validator::error_validate_sequence_number_too_new(),
+        );
         auth_validator::new_tx_validate_result(auth_validator_id, auth_validator, session_key, bitcoin_address)
     }
 
+    /// The timestamp of the current transaction in seconds.
+    /// It is the timestamp assigned by the sequencer, or the current on-chain time if the transaction is not sequenced yet.
+    fun tx_timestamp_seconds(): u64 {
+        let tx_sequence_info = tx_context::get_attribute<TransactionSequenceInfo>();
+        if (option::is_some(&tx_sequence_info)) {
+            let tx_sequence_info = option::destroy_some(tx_sequence_info);
+            transaction::tx_timestamp(&tx_sequence_info) / 1000
+        } else {
+            timestamp::now_seconds()
+        }
+    }
+
+    /// This function is for Rooch to validate the fee payer's authenticator of a sponsored transaction.
+    /// The fee payer co-signs the transaction hash and pays the gas on behalf of the sender.
+    /// Only the Bitcoin auth validator is supported for the fee payer.
+    /// If the authenticator is invaid, abort this function.
+    public(friend) fun validate_fee_payer(
+        chain_id: u64,
+        fee_payer: address,
+        auth_validator_id: u64,
+        authenticator_payload: vector<u8>
+    ) {
+        assert!(
+            chain_id == chain_id::chain_id(),
+            auth_validator::error_validate_bad_chain_id(),
+        );
+        assert!(
+            auth_validator_id == bitcoin_validator::auth_validator_id(),
+            auth_validator::error_validate_invalid_authenticator(),
+        );
+        // The fee payer must be an existing account, it can not be created by the sponsored transaction
+        assert!(
+            account::exists_at(fee_payer),
+            auth_validator::error_validate_account_does_not_exist(),
+        );
+        bitcoin_validator::validate_fee_payer(fee_payer, authenticator_payload);
+
+        if(!chain_id::is_local_or_dev()){
+            let max_gas_amount = tx_context::max_gas_amount();
+            let gas = transaction_fee::calculate_gas(max_gas_amount);
+            assert!(
+                gas_coin::balance(fee_payer) >= gas,
+                auth_validator::error_validate_cant_pay_gas_deposit(),
+            );
+        };
+    }
+
     /// Transaction pre_execute function.
     /// Execute before the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to auto create account and address maping.
@@ -148,6 +212,7 @@ module rooch_framework::transaction_validator {
     /// Transaction post_execute function.
     /// Execute after the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to update the sender's sequence number and pay the gas fee.
+    /// The remaining gas is refunded to the gas payment account, which is the fee payer for sponsored transactions.
     fun post_execute(
     ) {
         let sender = tx_context::sender();
@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::vrf::ecvrf::{ECVRFProof, ECVRFPublicKey};
+use fastcrypto::vrf::VRFProof;
+use move_binary_format::errors::PartialVMResult;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// The size of the ECVRF output in bytes.
+pub const ECVRF_OUTPUT_SIZE: usize = 64;
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function `ecvrf::verify(output: &vector<u8>, alpha_string: &vector<u8>, public_key: &vector<u8>, proof: &vector<u8>): bool;`
+ *   gas cost: ecvrf_verify_cost_base                          | base cost for function call and fixed opers
+ *              + ecvrf_verify_cost_per_byte * alpha_string.len()   | cost depends on length of the input
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 4);
+
+    let proof_bytes = pop_arg!(args, VectorRef);
+    let proof_bytes_ref = proof_bytes.as_bytes_ref();
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let alpha_string = pop_arg!(args, VectorRef);
+    let alpha_string_ref = alpha_string.as_bytes_ref();
+    let output = pop_arg!(args, VectorRef);
+    let output_ref = output.as_bytes_ref();
+
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap() * NumBytes::new(alpha_string_ref.len() as u64);
+
+    let Ok(output) = <[u8; ECVRF_OUTPUT_SIZE]>::try_from(output_ref.as_slice()) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let Ok(public_key) = bcs::from_bytes::<ECVRFPublicKey>(&public_key_bytes_ref) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let Ok(proof) = bcs::from_bytes::<ECVRFProof>(&proof_bytes_ref) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let result = proof
+        .verify_output(&alpha_string_ref, &public_key, &output)
+        .is_ok();
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+    if !gas_params.verify.is_empty() {
+        natives.push(("verify", make_native(gas_params.verify, native_verify)));
+    }
+
+    make_module_natives(natives)
+}
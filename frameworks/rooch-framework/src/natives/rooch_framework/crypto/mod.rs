@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ecdsa_k1;
+pub mod ecvrf;
 pub mod ed25519;
@@ -8,9 +8,12 @@ module rooch_nursery::brc20 {
     use moveos_std::table::{Self, Table};
     use moveos_std::simple_map::{Self, SimpleMap};
     use moveos_std::string_utils;
-    #[test_only]
+    use moveos_std::event;
+    use moveos_std::event_queue::{Self, Subscriber};
     use moveos_std::json;
+    use bitcoin_move::ord::{Self, Inscription, InscriptionID, BRC20InscriptionEvent};
 
+    friend rooch_nursery::genesis;
 
     //TODO should we register the BRC20 as a CoinInfo?
     struct BRC20CoinInfo has store, copy{
@@ -23,19 +26,47 @@ module rooch_nursery::brc20 {
 
     struct BRC20Balance has store{
         info: BRC20CoinInfo,
-        balance: Table<address, u256>, 
-    } 
+        /// The available balance, which can be used to inscribe a transfer inscription
+        balance: Table<address, u256>,
+        /// The transferable balance, locked by transfer inscriptions that have not been sent yet
+        transferable: Table<address, u256>,
+        /// The valid transfer inscriptions that have not been sent yet
+        transfer_inscriptions: Table<InscriptionID, TransferInscription>,
+    }
+
+    /// The amount locked by a transfer inscription, it is spent when the inscription is sent for the first time.
+    struct TransferInscription has store, drop {
+        owner: address,
+        amt: u256,
+    }
+
+    /// Emitted every time the available or transferable balance of an owner changes.
+    /// The indexer records these events as the BRC20 balance history.
+    struct BRC20BalanceChangeEvent has copy, drop, store {
+        tick: String,
+        owner: address,
+        available: u256,
+        transferable: u256,
+    }
 
     struct BRC20Store has key {
         coins: Table<String, BRC20Balance>,
     }
 
+    /// The subscriber of the BRC20 inscription events emitted by the ord module
+    struct BRC20EventStore has key {
+        subscriber: Object<Subscriber<BRC20InscriptionEvent>>,
+    }
+
     public(friend) fun genesis_init(_genesis_account: &signer){
         let brc20_store = BRC20Store{
             coins: table::new(),
         }; 
         let obj = object::new_named_object(brc20_store);
         object::to_shared(obj);
+        let subscriber = event_queue::subscribe<BRC20InscriptionEvent>(ord::brc20_event_queue_name());
+        let event_store_obj = object::new_named_object(BRC20EventStore{ subscriber });
+        object::to_shared(event_store_obj);
     }
 
     fun borrow_store() : &mut BRC20Store {
@@ -168,7 +199,7 @@ module rooch_nursery::brc20 {
         let lim = option::destroy_with_default(string_utils::parse_decimal_option(&deploy.lim, dec), 0u256);
         let max = option::destroy_some(max_opt);
         let coin_info = BRC20CoinInfo{ tick, max, lim, dec , supply: 0u256};
-        let balance_info = BRC20Balance{ info: coin_info, balance:table::new() };
+        let balance_info = BRC20Balance{ info: coin_info, balance: table::new(), transferable: table::new(), transfer_inscriptions: table::new() };
         table::add(&mut brc20_store.coins, tick, balance_info);
         true
     }
@@ -225,6 +256,7 @@ module rooch_nursery::brc20 {
         coin_info.supply = new_total_supply;
         let balance = table::borrow_mut_with_default(&mut balance_info.balance, mint.to, 0);
         *balance = *balance + amt;
+        emit_balance_change(balance_info, mint.to);
         true
     }
 
@@ -251,44 +283,88 @@ module rooch_nursery::brc20 {
         transfer_op
     }
 
-    fun execute_transfer(transfer: TransferOp): bool{
-        let from = transfer.from;
-        let to = transfer.to;
+    /// Inscribing a transfer inscription moves `amt` from the available balance to the transferable balance of the inscriber.
+    /// The amount is locked by the inscription until it is sent.
+    fun execute_inscribe_transfer(transfer: TransferOp, inscription_id: InscriptionID): bool{
+        let owner = transfer.from;
         let brc20_store = borrow_store();
         if(!table::contains(&brc20_store.coins, transfer.tick)){
             std::debug::print(&string::utf8(b"brc20 does not exist"));
             return false
         };
-        
+
         let balance_info = table::borrow_mut(&mut brc20_store.coins, transfer.tick);
-        let coin_info = &balance_info.info;
-        let amt_opt = string_utils::parse_decimal_option(&transfer.amt, coin_info.dec);
+        if(table::contains(&balance_info.transfer_inscriptions, inscription_id)){
+            std::debug::print(&string::utf8(b"brc20 transfer inscription already exists"));
+            return false
+        };
+        let amt_opt = string_utils::parse_decimal_option(&transfer.amt, balance_info.info.dec);
         if(option::is_none(&amt_opt)){
             return false
         };
         let amt = option::destroy_some(amt_opt);
 
-        let from_balance = table::borrow_mut_with_default(&mut balance_info.balance, from, 0);
-        if(*from_balance < amt){
-            std::debug::print(&string::utf8(b"brc20 insufficient balance"));
-            false
-        }else{
-            *from_balance = *from_balance - amt;
-            let to_balance = table::borrow_mut_with_default(&mut balance_info.balance, to, 0);
-            *to_balance = *to_balance + amt;
-            true
-        }
+        let available = table::borrow_mut_with_default(&mut balance_info.balance, owner, 0);
+        if(*available < amt){
+            std::debug::print(&string::utf8(b"brc20 insufficient available balance"));
+            return false
+        };
+        *available = *available - amt;
+        let transferable = table::borrow_mut_with_default(&mut balance_info.transferable, owner, 0);
+        *transferable = *transferable + amt;
+        table::add(&mut balance_info.transfer_inscriptions, inscription_id, TransferInscription{ owner, amt });
+        emit_balance_change(balance_info, owner);
+        true
+    }
+
+    /// Sending a valid transfer inscription for the first time moves the amount locked by the inscription
+    /// from the transferable balance of the inscriber to the available balance of the receiver.
+    /// If the inscription is sent back to the inscriber, the amount returns to its available balance.
+    /// The inscription is consumed, sending it again or sending an invalid transfer inscription does nothing.
+    fun execute_transfer(transfer: TransferOp, inscription_id: InscriptionID): bool{
+        let to = transfer.to;
+        let brc20_store = borrow_store();
+        if(!table::contains(&brc20_store.coins, transfer.tick)){
+            std::debug::print(&string::utf8(b"brc20 does not exist"));
+            return false
+        };
+
+        let balance_info = table::borrow_mut(&mut brc20_store.coins, transfer.tick);
+        if(!table::contains(&balance_info.transfer_inscriptions, inscription_id)){
+            std::debug::print(&string::utf8(b"brc20 transfer inscription is invalid or already sent"));
+            return false
+        };
+        let TransferInscription{ owner, amt } = table::remove(&mut balance_info.transfer_inscriptions, inscription_id);
+        let owner_transferable = table::borrow_mut_with_default(&mut balance_info.transferable, owner, 0);
+        *owner_transferable = *owner_transferable - amt;
+        let to_balance = table::borrow_mut_with_default(&mut balance_info.balance, to, 0);
+        *to_balance = *to_balance + amt;
+        emit_balance_change(balance_info, owner);
+        if(owner != to){
+            emit_balance_change(balance_info, to);
+        };
+        true
     }
 
-    public(friend) fun process_utxo_op(op: Op) : bool {
+    fun emit_balance_change(balance_info: &BRC20Balance, owner: address){
+        let available = *table::borrow_with_default(&balance_info.balance, owner, &0u256);
+        let transferable = *table::borrow_with_default(&balance_info.transferable, owner, &0u256);
+        event::emit(BRC20BalanceChangeEvent{
+            tick: balance_info.info.tick,
+            owner,
+            available,
+            transferable,
+        });
+    }
+
+    fun process_utxo_op(op: Op, inscription_id: InscriptionID) : bool {
         let result = if(is_transfer(&op)){
             let transfer_op_opt = as_transfer(&op);
             if(option::is_some(&transfer_op_opt)){
                 let transfer_op = option::destroy_some(transfer_op_opt);
-                execute_transfer(transfer_op)
+                execute_transfer(transfer_op, inscription_id)
             }else{
                 std::debug::print(&string::utf8(b"invalid transfer op"));
-                std::debug::print(&op);
                 false
             }
         }else{
@@ -299,13 +375,11 @@ module rooch_nursery::brc20 {
         result
     }
 
-    public(friend) fun process_inscribe_op(op: Op) :bool {
-        
+    fun process_inscribe_op(op: Op, inscription_id: InscriptionID) :bool {
         let result = if(is_deploy(&op)){
             let deploy_op_opt = as_deploy(&op);
             if(option::is_none(&deploy_op_opt)){
                 std::debug::print(&string::utf8(b"invalid deploy op"));
-                std::debug::print(&op);
                 false
             }else{
                 let deploy_op = option::destroy_some(deploy_op_opt);
@@ -315,7 +389,6 @@ module rooch_nursery::brc20 {
             let mint_op_opt = as_mint(&op);
             if(option::is_none(&mint_op_opt)){
                 std::debug::print(&string::utf8(b"invalid mint op"));
-                std::debug::print(&op);
                 false
             }else{
                 let mint_op = option::destroy_some(mint_op_opt);
@@ -325,24 +398,47 @@ module rooch_nursery::brc20 {
             let transfer_op_opt = as_transfer(&op);
             if(option::is_none(&transfer_op_opt)){
                 std::debug::print(&string::utf8(b"invalid transfer op"));
-                std::debug::print(&op);
                 false
             }else{
                 let transfer_op = option::destroy_some(transfer_op_opt);
-                execute_transfer(transfer_op)
+                execute_inscribe_transfer(transfer_op, inscription_id)
             }
         }else{
             std::debug::print(&string::utf8(b"unknown brc20 op"));
             false
         };
-        if(!result){
-            std::debug::print(&string::utf8(b"failed to progress brc20 op"));
-            std::debug::print(&op);
-        };
         drop_op(op);
         result
     }
 
+    /// Process the BRC20 inscription events emitted by the ord module.
+    /// A new inscription is processed as a deploy, mint or inscribe-transfer operation,
+    /// sending an inscription is processed as a transfer operation.
+    public entry fun process_brc20_event(batch_size: u64) {
+        let event_store_obj_id = object::named_object_id<BRC20EventStore>();
+        let event_store_obj = object::borrow_mut_object_shared<BRC20EventStore>(event_store_obj_id);
+        let event_store = object::borrow_mut(event_store_obj);
+        let count = 0;
+        while (count < batch_size) {
+            let event_opt = event_queue::consume(&mut event_store.subscriber);
+            if (option::is_none(&event_opt)) {
+                break
+            };
+            let event = option::destroy_some(event_opt);
+            let (inscription_obj_id, from, to, event_type) = ord::unpack_brc20_inscription_event(event);
+            let inscription_obj = object::borrow_object<Inscription>(inscription_obj_id);
+            let inscription = object::borrow(inscription_obj);
+            let inscription_id = *ord::id(inscription);
+            let op = new_op(from, to, json::to_map(ord::body(inscription)));
+            if (event_type == ord::inscription_event_type_new()) {
+                process_inscribe_op(op, inscription_id);
+            } else {
+                process_utxo_op(op, inscription_id);
+            };
+            count = count + 1;
+        };
+    }
+
     //=== Brc20 store ===
 
     public fun get_tick_info(brc20_store_obj:&Object<BRC20Store>, tick: &String) : Option<BRC20CoinInfo> {
@@ -355,6 +451,7 @@ module rooch_nursery::brc20 {
         }
     }
 
+    /// Get the available balance of the `address`, the amount locked by transfer inscriptions is not included.
     public fun get_balance(brc20_store_obj:&Object<BRC20Store>, tick: &String, address: address) : u256 {
         let tick = string_utils::to_lower_case(tick);
         let brc20_store = object::borrow(brc20_store_obj);
@@ -366,6 +463,18 @@ module rooch_nursery::brc20 {
         }
     }
 
+    /// Get the transferable balance of the `address`, which is locked by transfer inscriptions that have not been sent yet.
+    public fun get_transferable_balance(brc20_store_obj:&Object<BRC20Store>, tick: &String, address: address) : u256 {
+        let tick = string_utils::to_lower_case(tick);
+        let brc20_store = object::borrow(brc20_store_obj);
+        if(table::contains(&brc20_store.coins, tick)){
+            let balance_info = table::borrow(&brc20_store.coins, tick);
+            *table::borrow_with_default(&balance_info.transferable, address, &0u256)
+        }else{
+            0u256
+        }
+    }
+
     #[test]
     fun test_deploy_op(){
         let deploy_op_json = b"{\"p\":\"brc-20\",\"op\":\"deploy\",\"tick\":\"ordi\",\"max\":\"21000000\",\"lim\":\"1000\"}";
@@ -430,6 +539,7 @@ module rooch_nursery::brc20 {
         let deployer = @0x42;
         let minter = @0x43;
         let transfer_to = @0x44;
+        let inscription_id = ord::new_inscription_id(@0x1001, 0);
         let op = Op { from: deployer, to: deployer, json_map: json::to_map(deploy_op_json) };
         let deploy_op = option::destroy_some(as_deploy(&op));
         assert!(execute_deploy(deploy_op), 1);
@@ -442,20 +552,102 @@ module rooch_nursery::brc20 {
         drop_op(op);
         
         let transfer_op_json = b"{\"p\":\"brc-20\",\"op\":\"transfer\",\"tick\":\"ordi\",\"amt\":\"1000\"}";
+        let op = Op { from: minter, to: minter, json_map: json::to_map(transfer_op_json) };
+        let transfer_op = option::destroy_some(as_transfer(&op));
+        assert!(execute_inscribe_transfer(transfer_op, inscription_id), 3);
+        drop_op(op);
+
+        let brc20_store = borrow_store();
+        let balance_info = table::borrow(&brc20_store.coins, string::utf8(b"ordi"));
+        assert!(*table::borrow(&balance_info.balance, minter) == 0u256, 4);
+        assert!(*table::borrow(&balance_info.transferable, minter) == 1000000000000000000000u256, 5);
+
         let op = Op { from: minter, to: transfer_to, json_map: json::to_map(transfer_op_json) };
         let transfer_op = option::destroy_some(as_transfer(&op));
-        assert!(execute_transfer(transfer_op), 3);
+        assert!(execute_transfer(transfer_op, inscription_id), 6);
+        // The transfer inscription is consumed by the first send
+        let transfer_op = option::destroy_some(as_transfer(&op));
+        assert!(!execute_transfer(transfer_op, inscription_id), 11);
         drop_op(op);
         
         let brc20_store = borrow_store();
         let balance_info = table::borrow(&brc20_store.coins, string::utf8(b"ordi"));
         let coin_info = &balance_info.info;
-        assert!(coin_info.supply == 1000000000000000000000u256, 4);
+        assert!(coin_info.supply == 1000000000000000000000u256, 7);
         let balance1 = *table::borrow(&balance_info.balance, minter);
-        assert!(balance1 == 0u256, 5);
+        assert!(balance1 == 0u256, 8);
+        let transferable1 = *table::borrow(&balance_info.transferable, minter);
+        assert!(transferable1 == 0u256, 9);
         let balance2 = *table::borrow(&balance_info.balance, transfer_to);
-        assert!(balance2 == 1000000000000000000000u256, 6);
-        
+        assert!(balance2 == 1000000000000000000000u256, 10);
+        assert!(!table::contains(&balance_info.transfer_inscriptions, inscription_id), 12);
+    }
+
+    #[test(genesis_account=@0x4)]
+    fun test_brc20_inscribe_transfer_insufficient(genesis_account: &signer){
+        genesis_init(genesis_account);
+
+        let deploy_op_json = b"{\"p\":\"brc-20\",\"op\":\"deploy\",\"tick\":\"ordi\",\"max\":\"21000000\",\"lim\":\"1000\"}";
+        let deployer = @0x42;
+        let op = Op { from: deployer, to: deployer, json_map: json::to_map(deploy_op_json) };
+        assert!(execute_deploy(option::destroy_some(as_deploy(&op))), 1);
+        drop_op(op);
 
+        let transfer_op_json = b"{\"p\":\"brc-20\",\"op\":\"transfer\",\"tick\":\"ordi\",\"amt\":\"1\"}";
+        let inscription_id = ord::new_inscription_id(@0x1001, 0);
+        let op = Op { from: deployer, to: deployer, json_map: json::to_map(transfer_op_json) };
+        assert!(!execute_inscribe_transfer(option::destroy_some(as_transfer(&op)), inscription_id), 2);
+        // Sending an invalid transfer inscription should fail too
+        let op2 = Op { from: deployer, to: @0x43, json_map: json::to_map(transfer_op_json) };
+        assert!(!execute_transfer(option::destroy_some(as_transfer(&op2)), inscription_id), 3);
+        drop_op(op);
+        drop_op(op2);
+
+    }
+
+    #[test(genesis_account=@0x4)]
+    fun test_brc20_transfer_inscription_amount_is_isolated(genesis_account: &signer){
+        genesis_init(genesis_account);
+
+        let deploy_op_json = b"{\"p\":\"brc-20\",\"op\":\"deploy\",\"tick\":\"ordi\",\"max\":\"21000000\",\"lim\":\"1000\"}";
+        let minter = @0x43;
+        let receiver = @0x44;
+        let op = Op { from: minter, to: minter, json_map: json::to_map(deploy_op_json) };
+        assert!(execute_deploy(option::destroy_some(as_deploy(&op))), 1);
+        drop_op(op);
+
+        let mint_op_json = b"{\"p\":\"brc-20\",\"op\":\"mint\",\"tick\":\"ordi\",\"amt\":\"1000\"}";
+        let op = Op { from: minter, to: minter, json_map: json::to_map(mint_op_json) };
+        assert!(execute_mint(option::destroy_some(as_mint(&op))), 2);
+        drop_op(op);
+
+        // A valid transfer inscription locks 600 of the 1000
+        let valid_id = ord::new_inscription_id(@0x1001, 0);
+        let transfer_600_json = b"{\"p\":\"brc-20\",\"op\":\"transfer\",\"tick\":\"ordi\",\"amt\":\"600\"}";
+        let op = Op { from: minter, to: minter, json_map: json::to_map(transfer_600_json) };
+        assert!(execute_inscribe_transfer(option::destroy_some(as_transfer(&op)), valid_id), 3);
+        drop_op(op);
+
+        // The second one is invalid, the available balance is only 400
+        let invalid_id = ord::new_inscription_id(@0x1002, 0);
+        let op = Op { from: minter, to: minter, json_map: json::to_map(transfer_600_json) };
+        assert!(!execute_inscribe_transfer(option::destroy_some(as_transfer(&op)), invalid_id), 4);
+        drop_op(op);
+
+        // The invalid inscription can not spend the amount locked by the valid one
+        let op = Op { from: minter, to: receiver, json_map: json::to_map(transfer_600_json) };
+        assert!(!execute_transfer(option::destroy_some(as_transfer(&op)), invalid_id), 5);
+        // The valid one moves exactly the locked amount, whatever the amount in the sent body is
+        let transfer_1_json = b"{\"p\":\"brc-20\",\"op\":\"transfer\",\"tick\":\"ordi\",\"amt\":\"1\"}";
+        let op2 = Op { from: minter, to: receiver, json_map: json::to_map(transfer_1_json) };
+        assert!(execute_transfer(option::destroy_some(as_transfer(&op2)), valid_id), 6);
+        drop_op(op);
+        drop_op(op2);
+
+        let brc20_store = borrow_store();
+        let balance_info = table::borrow(&brc20_store.coins, string::utf8(b"ordi"));
+        assert!(*table::borrow(&balance_info.balance, minter) == 400000000000000000000u256, 7);
+        assert!(*table::borrow(&balance_info.transferable, minter) == 0u256, 8);
+        assert!(*table::borrow(&balance_info.balance, receiver) == 600000000000000000000u256, 9);
     }
 }
\ No newline at end of file
@@ -0,0 +1,438 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The CosmWasm contract lifecycle on Rooch.
+/// The contract code is stored once and identified by the code id, the contract is instantiated
+/// from the code id with its own account, and the messages returned by the contract are dispatched
+/// back to Move, including the reply to the contract.
+/// The contract storage is kept in Move as a BCS encoded key-value list, and only `rgas` is supported as the funds denom.
+module rooch_nursery::cosmwasm_contract {
+    use std::vector;
+    use std::option::{Self, Option};
+    use std::string::{Self, String};
+
+    use moveos_std::address;
+    use moveos_std::bcs;
+    use moveos_std::event;
+    use moveos_std::hash;
+    use moveos_std::json;
+    use moveos_std::object::{Self, Object};
+    use moveos_std::result::{Self, Result, ok, err};
+    use moveos_std::signer;
+    use moveos_std::account;
+    use moveos_std::table::{Self, Table};
+
+    use rooch_framework::account_coin_store;
+    use rooch_framework::gas_coin::{Self, RGas};
+
+    use rooch_nursery::cosmwasm_std::{Self, Coin, Error, Response, SubMsg};
+    use rooch_nursery::cosmwasm_vm;
+
+    const ErrorCodeNotFound: u64 = 1;
+    const ErrorContractNotFound: u64 = 2;
+    const ErrorContractAlreadyExists: u64 = 3;
+    const ErrorDispatchDepthExceeded: u64 = 4;
+    const ErrorSubMessageFailed: u64 = 5;
+    const ErrorInvalidCode: u64 = 6;
+    const ErrorContractCallFailed: u64 = 7;
+
+    /// The denom is not supported, only `rgas` is supported
+    const ErrorUnsupportedDenom: u32 = 101;
+    /// The balance of the sender is not enough for the funds
+    const ErrorInsufficientFunds: u32 = 102;
+    /// The message returned by the contract can not be decoded
+    const ErrorInvalidMessage: u32 = 103;
+
+    const DENOM_RGAS: vector<u8> = b"rgas";
+    /// The max depth of the nested message dispatch
+    const MAX_DISPATCH_DEPTH: u64 = 8;
+
+    struct CodeInfo has store, drop {
+        checksum: vector<u8>,
+        creator: address,
+        code: vector<u8>,
+    }
+
+    struct Contract has store, drop {
+        code_id: u64,
+        creator: address,
+        admin: Option<address>,
+        label: String,
+        /// The BCS encoded key-value list of the contract storage
+        state: vector<u8>,
+    }
+
+    struct CosmWasmStore has key {
+        next_code_id: u64,
+        codes: Table<u64, CodeInfo>,
+        code_ids: Table<vector<u8>, u64>,
+        contracts: Table<address, Contract>,
+    }
+
+    #[data_struct]
+    struct AccountBalance has copy, drop {
+        address: address,
+        amount: vector<Coin>,
+    }
+
+    #[data_struct]
+    /// The balances snapshot for the contract bank querier
+    struct QuerierBalances has copy, drop {
+        balances: vector<AccountBalance>,
+    }
+
+    struct StoreCodeEvent has copy, drop {
+        code_id: u64,
+        checksum: vector<u8>,
+        creator: address,
+    }
+
+    struct InstantiateEvent has copy, drop {
+        contract: address,
+        code_id: u64,
+        creator: address,
+        label: String,
+    }
+
+    struct ContractResponseEvent has copy, drop {
+        contract: address,
+        attributes: vector<cosmwasm_std::Attribute>,
+        events: vector<cosmwasm_std::Event>,
+    }
+
+    fun borrow_mut_store(): &mut CosmWasmStore {
+        let store_id = object::named_object_id<CosmWasmStore>();
+        if (!object::exists_object(store_id)) {
+            let store = CosmWasmStore {
+                next_code_id: 1,
+                codes: table::new(),
+                code_ids: table::new(),
+                contracts: table::new(),
+            };
+            object::to_shared(object::new_named_object(store));
+        };
+        let store_obj: &mut Object<CosmWasmStore> = object::borrow_mut_object_shared(store_id);
+        object::borrow_mut(store_obj)
+    }
+
+    /// Store the contract code and return the code id.
+    /// The same code is only stored once, the code id of the stored code is returned.
+    public fun store_code(sender: &signer, code: vector<u8>): u64 {
+        let checksum = cosmwasm_vm::store_code(code);
+        assert!(result::is_ok(&checksum), ErrorInvalidCode);
+        let checksum = result::unwrap(checksum);
+
+        let store = borrow_mut_store();
+        if (table::contains(&store.code_ids, checksum)) {
+            return *table::borrow(&store.code_ids, checksum)
+        };
+        let code_id = store.next_code_id;
+        store.next_code_id = code_id + 1;
+        let creator = signer::address_of(sender);
+        table::add(&mut store.codes, code_id, CodeInfo { checksum, creator, code });
+        table::add(&mut store.code_ids, checksum, code_id);
+        event::emit(StoreCodeEvent { code_id, checksum, creator });
+        code_id
+    }
+
+    /// Instantiate a contract from the code id, returns the contract address.
+    /// The `msg` is the JSON encoded instantiate message of the contract.
+    public fun instantiate(sender: &signer, code_id: u64, msg: vector<u8>, funds: vector<Coin>, label: String, admin: Option<address>): Result<address, Error> {
+        instantiate_internal(signer::address_of(sender), code_id, msg, funds, label, admin, 0)
+    }
+
+    /// Execute the contract, the `msg` is the JSON encoded execute message of the contract.
+    public fun execute(sender: &signer, contract: address, msg: vector<u8>, funds: vector<Coin>): Result<Response, Error> {
+        execute_internal(signer::address_of(sender), contract, msg, funds, 0)
+    }
+
+    /// Query the contract, the `msg` is the JSON encoded query message of the contract,
+    /// returns the JSON encoded query result.
+    public fun query(contract: address, msg: vector<u8>): Result<vector<u8>, Error> {
+        let store = borrow_mut_store();
+        assert!(table::contains(&store.contracts, contract), ErrorContractNotFound);
+        let contract_info = table::borrow(&store.contracts, contract);
+        let code = table::borrow(&store.codes, contract_info.code_id).code;
+        let state = contract_info.state;
+        let env = cosmwasm_std::new_env(contract);
+        cosmwasm_vm::query_contract(code, state, &env, msg, balances_snapshot(contract, contract))
+    }
+
+    /// The contract address is derived from the code checksum, the creator and the label,
+    /// so the same creator can not instantiate the same code with the same label twice.
+    public fun contract_address(checksum: vector<u8>, creator: address, label: String): address {
+        let seed = bcs::to_bytes(&checksum);
+        vector::append(&mut seed, bcs::to_bytes(&creator));
+        vector::append(&mut seed, bcs::to_bytes(&label));
+        address::from_bytes(hash::sha3_256(seed))
+    }
+
+    public fun code_checksum(code_id: u64): vector<u8> {
+        let store = borrow_mut_store();
+        assert!(table::contains(&store.codes, code_id), ErrorCodeNotFound);
+        table::borrow(&store.codes, code_id).checksum
+    }
+
+    public fun contract_code_id(contract: address): u64 {
+        let store = borrow_mut_store();
+        assert!(table::contains(&store.contracts, contract), ErrorContractNotFound);
+        table::borrow(&store.contracts, contract).code_id
+    }
+
+    public fun contract_exists(contract: address): bool {
+        let store = borrow_mut_store();
+        table::contains(&store.contracts, contract)
+    }
+
+    public entry fun store_code_entry(sender: &signer, code: vector<u8>) {
+        store_code(sender, code);
+    }
+
+    /// Instantiate the contract with the JSON message string and `amount` of RGas as the funds.
+    public entry fun instantiate_entry(sender: &signer, code_id: u64, msg: String, label: String, amount: u256) {
+        let result = instantiate(sender, code_id, string::into_bytes(msg), rgas_funds(amount), label, option::none());
+        result::assert_ok(result, ErrorContractCallFailed);
+    }
+
+    /// Execute the contract with the JSON message string and `amount` of RGas as the funds.
+    public entry fun execute_entry(sender: &signer, contract: address, msg: String, amount: u256) {
+        let result = execute(sender, contract, string::into_bytes(msg), rgas_funds(amount));
+        result::assert_ok(result, ErrorContractCallFailed);
+    }
+
+    fun rgas_funds(amount: u256): vector<Coin> {
+        if (amount == 0) {
+            vector::empty()
+        } else {
+            vector::singleton(cosmwasm_std::new_coin(string::utf8(DENOM_RGAS), (amount as u128)))
+        }
+    }
+
+    fun system_signer(addr: address): signer {
+        let module_signer = signer::module_signer<CosmWasmStore>();
+        account::create_signer_for_system(&module_signer, addr)
+    }
+
+    /// Returns the balances visible to the contract querier.
+    fun balances_snapshot(sender: address, contract: address): vector<u8> {
+        let balances = vector::singleton(account_balance(contract));
+        if (sender != contract) {
+            vector::push_back(&mut balances, account_balance(sender));
+        };
+        json::to_json(&QuerierBalances { balances })
+    }
+
+    fun account_balance(addr: address): AccountBalance {
+        let balance = gas_coin::balance(addr);
+        let max_u128 = 340282366920938463463374607431768211455u256;
+        let amount = if (balance > max_u128) { max_u128 } else { balance };
+        AccountBalance {
+            address: addr,
+            amount: vector::singleton(cosmwasm_std::new_coin(string::utf8(DENOM_RGAS), (amount as u128))),
+        }
+    }
+
+    /// Check the funds before any transfer, so an invalid funds can be returned as an error
+    fun check_funds(sender: address, funds: &vector<Coin>): Option<Error> {
+        let total = 0u256;
+        let i = 0;
+        let len = vector::length(funds);
+        while (i < len) {
+            let coin = vector::borrow(funds, i);
+            if (cosmwasm_std::coin_denom(coin) != string::utf8(DENOM_RGAS)) {
+                return option::some(cosmwasm_std::new_error(ErrorUnsupportedDenom, cosmwasm_std::coin_denom(coin)))
+            };
+            total = total + (cosmwasm_std::coin_amount(coin) as u256);
+            i = i + 1;
+        };
+        if (gas_coin::balance(sender) < total) {
+            return option::some(cosmwasm_std::new_error(ErrorInsufficientFunds, string::utf8(b"insufficient funds")))
+        };
+        option::none()
+    }
+
+    fun transfer_funds(from: address, to: address, funds: &vector<Coin>) {
+        let from_signer = system_signer(from);
+        let i = 0;
+        let len = vector::length(funds);
+        while (i < len) {
+            let amount = (cosmwasm_std::coin_amount(vector::borrow(funds, i)) as u256);
+            if (amount > 0) {
+                account_coin_store::transfer<RGas>(&from_signer, to, amount);
+            };
+            i = i + 1;
+        }
+    }
+
+    fun instantiate_internal(sender: address, code_id: u64, msg: vector<u8>, funds: vector<Coin>, label: String, admin: Option<address>, depth: u64): Result<address, Error> {
+        let funds_error = check_funds(sender, &funds);
+        if (option::is_some(&funds_error)) {
+            return err(option::destroy_some(funds_error))
+        };
+
+        let store = borrow_mut_store();
+        assert!(table::contains(&store.codes, code_id), ErrorCodeNotFound);
+        let code_info = table::borrow(&store.codes, code_id);
+        let code = code_info.code;
+        let contract = contract_address(code_info.checksum, sender, label);
+        assert!(!table::contains(&store.contracts, contract), ErrorContractAlreadyExists);
+
+        if (!account::exists_at(contract)) {
+            let module_signer = signer::module_signer<CosmWasmStore>();
+            account::create_account_by_system(&module_signer, contract);
+        };
+        transfer_funds(sender, contract, &funds);
+
+        let env = cosmwasm_std::new_env(contract);
+        let info = cosmwasm_std::new_message_info(sender, funds);
+        let (result, state) = cosmwasm_vm::instantiate_contract(code, vector::empty(), &env, &info, msg, balances_snapshot(sender, contract));
+        if (result::is_err(&result)) {
+            transfer_funds(contract, sender, &funds);
+            return err(result::unwrap_err(result))
+        };
+
+        let store = borrow_mut_store();
+        table::add(&mut store.contracts, contract, Contract {
+            code_id,
+            creator: sender,
+            admin,
+            label,
+            state,
+        });
+        event::emit(InstantiateEvent { contract, code_id, creator: sender, label });
+
+        handle_response(contract, result::unwrap(result), depth);
+        ok(contract)
+    }
+
+    fun execute_internal(sender: address, contract: address, msg: vector<u8>, funds: vector<Coin>, depth: u64): Result<Response, Error> {
+        let funds_error = check_funds(sender, &funds);
+        if (option::is_some(&funds_error)) {
+            return err(option::destroy_some(funds_error))
+        };
+
+        let store = borrow_mut_store();
+        assert!(table::contains(&store.contracts, contract), ErrorContractNotFound);
+        let contract_info = table::borrow(&store.contracts, contract);
+        let code = table::borrow(&store.codes, contract_info.code_id).code;
+        let state = contract_info.state;
+
+        transfer_funds(sender, contract, &funds);
+        let env = cosmwasm_std::new_env(contract);
+        let info = cosmwasm_std::new_message_info(sender, funds);
+        let (result, new_state) = cosmwasm_vm::execute_contract(code, state, &env, &info, msg, balances_snapshot(sender, contract));
+        if (result::is_err(&result)) {
+            transfer_funds(contract, sender, &funds);
+            return result
+        };
+
+        save_state(contract, new_state);
+        ok(handle_response(contract, result::unwrap(result), depth))
+    }
+
+    fun reply_internal(contract: address, reply: &cosmwasm_std::Reply, depth: u64): Response {
+        let store = borrow_mut_store();
+        let contract_info = table::borrow(&store.contracts, contract);
+        let code = table::borrow(&store.codes, contract_info.code_id).code;
+        let state = contract_info.state;
+
+        let env = cosmwasm_std::new_env(contract);
+        let (result, new_state) = cosmwasm_vm::reply_contract(code, state, &env, reply, balances_snapshot(contract, contract));
+        // The state changes of the sub message can not be reverted, so the failed reply aborts the transaction.
+        assert!(result::is_ok(&result), ErrorSubMessageFailed);
+        save_state(contract, new_state);
+        handle_response(contract, result::unwrap(result), depth)
+    }
+
+    fun save_state(contract: address, state: vector<u8>) {
+        let store = borrow_mut_store();
+        table::borrow_mut(&mut store.contracts, contract).state = state;
+    }
+
+    /// Dispatch the messages of the response and send the replies to the contract.
+    /// Returns the response with the data overwritten by the replies.
+    fun handle_response(contract: address, response: Response, depth: u64): Response {
+        event::emit(ContractResponseEvent {
+            contract,
+            attributes: cosmwasm_std::response_attributes(&response),
+            events: cosmwasm_std::response_events(&response),
+        });
+
+        let messages = cosmwasm_std::response_messages(&response);
+        let i = 0;
+        let len = vector::length(&messages);
+        if (len > 0) {
+            assert!(depth < MAX_DISPATCH_DEPTH, ErrorDispatchDepthExceeded);
+        };
+        while (i < len) {
+            let sub_msg = vector::borrow(&messages, i);
+            let result = dispatch_message(contract, sub_msg, depth + 1);
+            let reply_result = if (result::is_ok(&result)) {
+                if (cosmwasm_std::reply_on_success(sub_msg)) {
+                    option::some(cosmwasm_std::new_sub_msg_response())
+                } else {
+                    option::none()
+                }
+            } else {
+                let error = result::unwrap_err(result);
+                // A failed sub message without reply fails the whole transaction, as CosmWasm does.
+                assert!(cosmwasm_std::reply_on_error(sub_msg), ErrorSubMessageFailed);
+                option::some(cosmwasm_std::new_sub_msg_error(cosmwasm_std::error_message(&error)))
+            };
+            if (option::is_some(&reply_result)) {
+                let reply = cosmwasm_std::new_reply(cosmwasm_std::sub_msg_id(sub_msg), string::utf8(b""), 0, option::destroy_some(reply_result));
+                let reply_response = reply_internal(contract, &reply, depth + 1);
+                let data = cosmwasm_std::response_data(&reply_response);
+                if (!vector::is_empty(&data)) {
+                    cosmwasm_std::set_data(&mut response, data);
+                };
+            };
+            i = i + 1;
+        };
+        response
+    }
+
+    /// Dispatch the sub message on behalf of the contract.
+    /// An error is only returned when the dispatch has no side effect, so the contract can handle it in the reply.
+    fun dispatch_message(contract: address, sub_msg: &SubMsg, depth: u64): Result<Response, Error> {
+        let msg = cosmwasm_std::deserialize_cosmos_msg(cosmwasm_std::sub_msg_msg(sub_msg));
+        if (option::is_none(&msg)) {
+            return err(cosmwasm_std::new_error(ErrorInvalidMessage, string::utf8(b"invalid cosmos message")))
+        };
+        let msg = option::destroy_some(msg);
+
+        let bank_send = cosmwasm_std::cosmos_msg_bank_send(&msg);
+        if (option::is_some(&bank_send)) {
+            let (to_address, amount) = cosmwasm_std::unpack_bank_send_msg(option::destroy_some(bank_send));
+            let funds_error = check_funds(contract, &amount);
+            if (option::is_some(&funds_error)) {
+                return err(option::destroy_some(funds_error))
+            };
+            transfer_funds(contract, address::from_bech32_string(&to_address), &amount);
+            return ok(cosmwasm_std::new_response())
+        };
+
+        let wasm_execute = cosmwasm_std::cosmos_msg_wasm_execute(&msg);
+        if (option::is_some(&wasm_execute)) {
+            let (contract_addr, execute_msg, funds) = cosmwasm_std::unpack_wasm_execute_msg(option::destroy_some(wasm_execute));
+            return execute_internal(contract, address::from_bech32_string(&contract_addr), execute_msg, funds, depth)
+        };
+
+        let wasm_instantiate = cosmwasm_std::cosmos_msg_wasm_instantiate(&msg);
+        if (option::is_some(&wasm_instantiate)) {
+            let (admin, code_id, instantiate_msg, funds, label) = cosmwasm_std::unpack_wasm_instantiate_msg(option::destroy_some(wasm_instantiate));
+            let admin = if (option::is_some(&admin)) {
+                option::some(address::from_bech32_string(option::borrow(&admin)))
+            } else {
+                option::none()
+            };
+            let result = instantiate_internal(contract, code_id, instantiate_msg, funds, label, admin, depth);
+            if (result::is_err(&result)) {
+                return err(result::unwrap_err(result))
+            };
+            return ok(cosmwasm_std::new_response())
+        };
+
+        err(cosmwasm_std::new_error(ErrorInvalidMessage, string::utf8(b"empty cosmos message")))
+    }
+}
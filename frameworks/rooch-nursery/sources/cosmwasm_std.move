@@ -124,6 +124,37 @@ module rooch_nursery::cosmwasm_std {
         value: u8,
     }
 
+    // Messages dispatched by the contract, only the messages supported by Rooch are listed
+    #[data_struct]
+    struct BankSendMsg has store, copy, drop {
+        to_address: String,
+        amount: vector<Coin>,
+    }
+
+    #[data_struct]
+    struct WasmExecuteMsg has store, copy, drop {
+        contract_addr: String,
+        msg: vector<u8>,
+        funds: vector<Coin>,
+    }
+
+    #[data_struct]
+    struct WasmInstantiateMsg has store, copy, drop {
+        admin: Option<String>,
+        code_id: u64,
+        msg: vector<u8>,
+        funds: vector<Coin>,
+        label: String,
+    }
+
+    #[data_struct]
+    /// The message of a `SubMsg`, exactly one of the fields is set.
+    struct CosmosMsg has store, copy, drop {
+        bank_send: Option<BankSendMsg>,
+        wasm_execute: Option<WasmExecuteMsg>,
+        wasm_instantiate: Option<WasmInstantiateMsg>,
+    }
+
     #[data_struct]
     struct StdResult has copy, drop {
         ok: Option<Response>,
@@ -184,6 +215,90 @@ module rooch_nursery::cosmwasm_std {
         Coin { denom, amount }
     }
 
+    public fun coin_denom(coin: &Coin): String {
+        coin.denom
+    }
+
+    public fun coin_amount(coin: &Coin): u128 {
+        coin.amount
+    }
+
+    public fun response_messages(response: &Response): vector<SubMsg> {
+        response.messages
+    }
+
+    public fun response_attributes(response: &Response): vector<Attribute> {
+        response.attributes
+    }
+
+    public fun response_events(response: &Response): vector<Event> {
+        response.events
+    }
+
+    public fun response_data(response: &Response): vector<u8> {
+        response.data
+    }
+
+    public fun sub_msg_id(sub_msg: &SubMsg): u64 {
+        sub_msg.id
+    }
+
+    public fun sub_msg_msg(sub_msg: &SubMsg): vector<u8> {
+        sub_msg.msg
+    }
+
+    public fun sub_msg_reply_on(sub_msg: &SubMsg): u8 {
+        sub_msg.reply_on.value
+    }
+
+    public fun reply_on_success(sub_msg: &SubMsg): bool {
+        sub_msg.reply_on.value == REPLY_ON_SUCCESS || sub_msg.reply_on.value == REPLY_ALWAYS
+    }
+
+    public fun reply_on_error(sub_msg: &SubMsg): bool {
+        sub_msg.reply_on.value == REPLY_ON_ERROR || sub_msg.reply_on.value == REPLY_ALWAYS
+    }
+
+    public fun error_code(error: &Error): u32 {
+        error.code
+    }
+
+    public fun error_message(error: &Error): String {
+        error.message
+    }
+
+    /// Decode the message of a `SubMsg` returned by `cosmwasm_vm`
+    public fun deserialize_cosmos_msg(raw: vector<u8>): Option<CosmosMsg> {
+        json::from_json_option<CosmosMsg>(raw)
+    }
+
+    public fun cosmos_msg_bank_send(msg: &CosmosMsg): Option<BankSendMsg> {
+        msg.bank_send
+    }
+
+    public fun cosmos_msg_wasm_execute(msg: &CosmosMsg): Option<WasmExecuteMsg> {
+        msg.wasm_execute
+    }
+
+    public fun cosmos_msg_wasm_instantiate(msg: &CosmosMsg): Option<WasmInstantiateMsg> {
+        msg.wasm_instantiate
+    }
+
+    public fun unpack_bank_send_msg(msg: BankSendMsg): (String, vector<Coin>) {
+        let BankSendMsg { to_address, amount } = msg;
+        (to_address, amount)
+    }
+
+    public fun unpack_wasm_execute_msg(msg: WasmExecuteMsg): (String, vector<u8>, vector<Coin>) {
+        let WasmExecuteMsg { contract_addr, msg, funds } = msg;
+        (contract_addr, msg, funds)
+    }
+
+    public fun unpack_wasm_instantiate_msg(msg: WasmInstantiateMsg): (Option<String>, u64, vector<u8>, vector<Coin>, String) {
+        let WasmInstantiateMsg { admin, code_id, msg, funds, label } = msg;
+        (admin, code_id, msg, funds, label)
+    }
+
     public fun new_sub_msg(id: u64, msg: vector<u8>, gas_limit: Option<u64>, reply_on: u8): SubMsg {
         SubMsg {
             id,
@@ -273,6 +388,17 @@ module rooch_nursery::cosmwasm_std {
         }
     }
 
+    /// Returns the environment of the contract at `contract` in the current transaction
+    public fun new_env(contract: address): Env {
+        let env = current_env();
+        env.contract = ContractInfo { address: contract };
+        env
+    }
+
+    public fun new_message_info(sender: address, funds: vector<Coin>): MessageInfo {
+        MessageInfo { sender, funds }
+    }
+
     public fun current_message_info(): MessageInfo {
         let sender = tx_context::sender();
         
@@ -4,8 +4,10 @@
 module rooch_nursery::cosmwasm_vm {
     use std::string::{Self, String};
     use std::option::{Self, Option};
-    
+    use std::vector;
+
     use moveos_std::features;
+    use moveos_std::json;
     use moveos_std::table;
     use moveos_std::object::{ObjectID};
     use moveos_std::result::{Result, ok};
@@ -13,6 +15,16 @@ module rooch_nursery::cosmwasm_vm {
     use rooch_nursery::cosmwasm_std::{Response, Error, Env, MessageInfo, Reply,
         new_error, new_error_result, serialize_env, serialize_message_info, serialize_message, deserialize_stdresult};
 
+    friend rooch_nursery::cosmwasm_contract;
+
+    /// The contract response can not be deserialized
+    const ErrorDeserialize: u32 = 1;
+
+    const ENTRY_POINT_INSTANTIATE: u8 = 0;
+    const ENTRY_POINT_EXECUTE: u8 = 1;
+    const ENTRY_POINT_QUERY: u8 = 2;
+    const ENTRY_POINT_REPLY: u8 = 3;
+
     struct Instance has key, store {
         code_checksum: vector<u8>,
         store: table::Table<String, vector<u8>>
@@ -142,6 +154,62 @@ module rooch_nursery::cosmwasm_vm {
         }
     }
 
+    /// Validate and compile the contract code, returns the checksum of the code.
+    public(friend) fun store_code(code: vector<u8>): Result<vector<u8>, Error> {
+        features::ensure_wasm_enabled();
+
+        let (checksum, error_code) = native_store_code(code);
+        if (error_code == 0) {
+            ok(checksum)
+        } else {
+            new_error_result(error_code, string::utf8(b"native_store_code_error"))
+        }
+    }
+
+    /// Instantiate the contract on `state`, returns the `Response` and the new state.
+    /// The state is only valid when the call succeeds.
+    public(friend) fun instantiate_contract(code: vector<u8>, state: vector<u8>, env: &Env, info: &MessageInfo, msg: vector<u8>, balances: vector<u8>): (Result<Response, Error>, vector<u8>) {
+        call_contract(ENTRY_POINT_INSTANTIATE, code, state, serialize_env(env), serialize_message_info(info), msg, balances)
+    }
+
+    /// Execute the contract on `state`, returns the `Response` and the new state.
+    /// The state is only valid when the call succeeds.
+    public(friend) fun execute_contract(code: vector<u8>, state: vector<u8>, env: &Env, info: &MessageInfo, msg: vector<u8>, balances: vector<u8>): (Result<Response, Error>, vector<u8>) {
+        call_contract(ENTRY_POINT_EXECUTE, code, state, serialize_env(env), serialize_message_info(info), msg, balances)
+    }
+
+    /// Send the `reply` to the contract, returns the `Response` and the new state.
+    /// The state is only valid when the call succeeds.
+    public(friend) fun reply_contract(code: vector<u8>, state: vector<u8>, env: &Env, reply: &Reply, balances: vector<u8>): (Result<Response, Error>, vector<u8>) {
+        call_contract(ENTRY_POINT_REPLY, code, state, serialize_env(env), vector::empty(), serialize_message(reply), balances)
+    }
+
+    /// Query the contract on `state`, returns the raw query result.
+    public(friend) fun query_contract(code: vector<u8>, state: vector<u8>, env: &Env, msg: vector<u8>, balances: vector<u8>): Result<vector<u8>, Error> {
+        features::ensure_wasm_enabled();
+
+        let (payload, _state, error_code) = native_call_contract(ENTRY_POINT_QUERY, code, state, serialize_env(env), vector::empty(), msg, balances);
+        if (error_code == 0) {
+            ok(payload)
+        } else {
+            new_error_result(error_code, string::utf8(payload))
+        }
+    }
+
+    fun call_contract(entry_point: u8, code: vector<u8>, state: vector<u8>, env: vector<u8>, info: vector<u8>, msg: vector<u8>, balances: vector<u8>): (Result<Response, Error>, vector<u8>) {
+        features::ensure_wasm_enabled();
+
+        let (payload, new_state, error_code) = native_call_contract(entry_point, code, state, env, info, msg, balances);
+        if (error_code != 0) {
+            return (new_error_result(error_code, string::utf8(payload)), new_state)
+        };
+        let response = json::from_json_option<Response>(payload);
+        if (option::is_none(&response)) {
+            return (new_error_result(ErrorDeserialize, string::utf8(b"deserialize_response_error")), new_state)
+        };
+        (ok(option::destroy_some(response)), new_state)
+    }
+
     // Native function declarations
     native fun native_create_instance(code: vector<u8>, store_handle: ObjectID): (vector<u8>, u32);
     native fun native_destroy_instance(code_checksum: vector<u8>): u32;
@@ -150,5 +218,7 @@ module rooch_nursery::cosmwasm_vm {
     native fun native_call_query_raw(code_checksum: vector<u8>, store_handle: ObjectID, env: vector<u8>, msg: vector<u8>): (vector<u8>, u32);
     native fun native_call_migrate_raw(code_checksum: vector<u8>, store_handle: ObjectID, env: vector<u8>, msg: vector<u8>): (vector<u8>, u32);
     native fun native_call_reply_raw(code_checksum: vector<u8>, store_handle: ObjectID, env: vector<u8>, msg: vector<u8>): (vector<u8>, u32);
+    native fun native_store_code(code: vector<u8>): (vector<u8>, u32);
+    native fun native_call_contract(entry_point: u8, code: vector<u8>, state: vector<u8>, env: vector<u8>, info: vector<u8>, msg: vector<u8>, balances: vector<u8>): (vector<u8>, vector<u8>, u32);
     native fun native_call_sudo_raw(code_checksum: vector<u8>, store_handle: ObjectID, env: vector<u8>, msg: vector<u8>):(vector<u8>, u32);
 }
\ No newline at end of file
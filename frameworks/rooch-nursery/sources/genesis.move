@@ -5,6 +5,7 @@ module rooch_nursery::genesis {
     use rooch_nursery::ethereum;
     use rooch_nursery::tick_info;
     use rooch_nursery::inscribe_factory;
+    use rooch_nursery::brc20;
 
     const ErrorInvalidChainId: u64 = 1;
 
@@ -15,6 +16,7 @@ module rooch_nursery::genesis {
         ethereum::genesis_init(genesis_account);
         tick_info::genesis_init();
         inscribe_factory::genesis_init();
+        brc20::genesis_init(genesis_account);
     }
 
     #[test_only]
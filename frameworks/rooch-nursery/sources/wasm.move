@@ -1,10 +1,15 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+/// The WASM plugin API.
+/// The instances live in the current session, they are released when the transaction finished.
+/// The fuel consumed by the WASM code is charged to the transaction gas,
+/// and the linear memory of an instance is limited by the gas schedule.
 module rooch_nursery::wasm {
 
     use std::option::{Self,Option};
     use moveos_std::features;
+    use moveos_std::cbor;
 
     struct WASMInstance {
         id: u64
@@ -66,6 +71,34 @@ module rooch_nursery::wasm {
         option::some(ret_val)
     }
 
+    /// Call the function with the CBOR encoded `args`, and decode the CBOR encoded result.
+    /// The function must accept a pointer to the length-prefixed arguments and return a pointer to the length-prefixed result.
+    public fun call<Args, Ret: drop>(instance: &mut WASMInstance, func_name: vector<u8>, args: &Args): Ret {
+        let ret = call_raw(instance, func_name, cbor::to_cbor(args));
+        cbor::from_cbor<Ret>(ret)
+    }
+
+    /// Call the function with the raw CBOR bytes of the arguments and return the raw CBOR bytes of the result.
+    public fun call_raw(instance: &mut WASMInstance, func_name: vector<u8>, args_cbor: vector<u8>): vector<u8> {
+        features::ensure_wasm_enabled();
+
+        let (ret, error_code) = native_call_wasm_function(instance.id, func_name, args_cbor);
+        assert!(error_code == 0, error_code);
+
+        ret
+    }
+
+    public fun call_raw_option(instance: &mut WASMInstance, func_name: vector<u8>, args_cbor: vector<u8>): Option<vector<u8>> {
+        features::ensure_wasm_enabled();
+
+        let (ret, error_code) = native_call_wasm_function(instance.id, func_name, args_cbor);
+        if (error_code > 0) {
+            return option::none()
+        };
+
+        option::some(ret)
+    }
+
     public fun read_data_length(instance: &WASMInstance, data_ptr: u64): u32 {
         native_read_data_length(instance.id, data_ptr)
     }
@@ -88,6 +121,8 @@ module rooch_nursery::wasm {
 
     native fun native_execute_wasm_function(instance_id: u64, func_name: vector<u8>, args: vector<u64>): (u64, u64);
 
+    native fun native_call_wasm_function(instance_id: u64, func_name: vector<u8>, args_cbor: vector<u8>): (vector<u8>, u64);
+
     native fun native_read_data_length(instance_id: u64, data_ptr: u64): u32;
 
     native fun native_read_data_from_heap(instance_id: u64, data_ptr: u32, data_length: u32): vector<u8>;
@@ -97,6 +132,35 @@ module rooch_nursery::wasm {
     #[test_only]
     use std::vector;
 
+    #[test]
+    fun test_call_with_cbor() {
+        features::init_and_enable_all_features_for_test();
+
+        // The echo function returns the arguments as the result
+        let wasm_code: vector<u8> = b"(module (memory (export \"memory\") 1) (func (export \"stackAlloc\") (param i32) (result i32) (i32.const 1024)) (func (export \"echo\") (param i32) (result i32) (local.get 0)))";
+        let wasm_instance = create_wasm_instance(wasm_code);
+
+        let args = vector[1u64, 2u64, 3u64];
+        let ret: vector<u64> = call(&mut wasm_instance, b"echo", &args);
+        assert!(ret == args, 1);
+
+        let ret_option = call_raw_option(&mut wasm_instance, b"not_exists", cbor::to_cbor(&args));
+        assert!(option::is_none(&ret_option), 2);
+
+        release_wasm_instance(wasm_instance);
+    }
+
+    #[test]
+    fun test_memory_limit() {
+        features::init_and_enable_all_features_for_test();
+
+        // 1024 pages exceed the max memory pages of an instance
+        let wasm_code: vector<u8> = b"(module (memory (export \"memory\") 1024))";
+        let wasm_instance_option = create_wasm_instance_option(wasm_code);
+        assert!(option::is_none(&wasm_instance_option), 1);
+        option::destroy_none(wasm_instance_option);
+    }
+
     #[test]
     fun test_trap() {
         features::init_and_enable_all_features_for_test();
@@ -1,21 +1,26 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::Arc;
 use std::vec;
 use tracing::error;
 
-use cosmwasm_std::Checksum;
+use cosmwasm_std::{
+    from_json, BankMsg, Binary, Checksum, Coin, ContractResult, CosmosMsg, ReplyOn, Response,
+    SubMsg, WasmMsg,
+};
 use cosmwasm_vm::{
     call_execute_raw, call_instantiate_raw, call_migrate_raw, call_query_raw, call_reply_raw,
     call_sudo_raw, capabilities_from_csv, Cache, CacheOptions, Instance, InstanceOptions, Size,
-    VmResult,
+    VmError, VmResult,
 };
 use once_cell::sync::Lazy;
 use rooch_cosmwasm_vm::backend::{
-    build_mock_backend, MockStorage, MoveBackendApi, MoveBackendQuerier,
+    build_contract_backend, build_mock_backend, MockStorage, MoveBackendApi, MoveBackendQuerier,
+    QuerierBalances,
 };
+use serde_json::json;
 use smallvec::smallvec;
 
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
@@ -318,7 +323,9 @@ where
 
     match result {
         Ok(response) => {
-            let gas_used = instance.get_gas_left();
+            let gas_used = instance_options
+                .gas_limit
+                .saturating_sub(instance.get_gas_left());
             let total_gas = gas_cost + InternalGas::new(gas_used);
             Ok(NativeResult::ok(
                 total_gas,
@@ -498,6 +505,443 @@ fn native_call_sudo_raw(
     )
 }
 
+/***************************************************************************************************
+ * native_store_code
+ **************************************************************************************************/
+
+/// The code is not a valid WASM or WAT module
+const E_INVALID_CODE: u32 = 2;
+/// The contract returned an error, the error message is returned as the payload
+const E_CONTRACT_ERROR: u32 = 3;
+/// The contract called an unsupported entry point or returned an unsupported message
+const E_UNSUPPORTED: u32 = 4;
+/// The contract state or the querier balances can not be decoded
+const E_INVALID_ARGUMENT: u32 = 5;
+/// The CosmWasm VM failed to run the contract
+const E_VM_ERROR: u32 = 6;
+
+const ENTRY_POINT_INSTANTIATE: u8 = 0;
+const ENTRY_POINT_EXECUTE: u8 = 1;
+const ENTRY_POINT_QUERY: u8 = 2;
+const ENTRY_POINT_REPLY: u8 = 3;
+
+/// How many CosmWasm gas units are charged as one unit of Move internal gas
+pub const DEFAULT_COSMWASM_GAS_PER_INTERNAL_GAS: u64 = 1000;
+/// The upper bound of the CosmWasm gas limit of one contract call
+const MAX_CONTRACT_GAS_LIMIT: u64 = 1_000_000_000_000;
+
+#[derive(Debug, Clone)]
+pub struct CosmWasmStoreCodeGasParametersOption {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl CosmWasmStoreCodeGasParametersOption {
+    pub fn zeros() -> Self {
+        Self {
+            base: Some(0.into()),
+            per_byte: Some(InternalGasPerByte::zero()),
+        }
+    }
+}
+
+fn compile_code(code: &[u8]) -> Result<Checksum, (u32, String)> {
+    let bytecode = wasmer::wat2wasm(code)
+        .map_err(|e| (E_INVALID_CODE, format!("Failed to cast wat to WASM: {}", e)))?;
+    let checksum = Checksum::generate(&bytecode);
+    if WASM_CACHE.get_module(&checksum).is_err() {
+        // The cache lives in the local temp dir, so the code is compiled again when it is missing,
+        // for example after the node restarted.
+        WASM_CACHE
+            .save_wasm(&bytecode)
+            .map_err(|e| (E_INVALID_CODE, format!("Failed to save WASM: {}", e)))?;
+    }
+    Ok(checksum)
+}
+
+/// Validate and compile the contract code, return the checksum of the code.
+#[inline]
+fn native_store_code(
+    gas_params: &GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(
+        ty_args.is_empty(),
+        "native_store_code expects no type arguments"
+    );
+    debug_assert_eq!(args.len(), 1, "native_store_code expects 1 argument");
+
+    let code = pop_arg!(args, Vec<u8>);
+    let cost = gas_params
+        .native_store_code
+        .base
+        .unwrap_or_else(InternalGas::zero)
+        + gas_params
+            .native_store_code
+            .per_byte
+            .unwrap_or_else(InternalGasPerByte::zero)
+            * NumBytes::new(code.len() as u64);
+
+    match compile_code(&code) {
+        Ok(checksum) => Ok(NativeResult::ok(
+            cost,
+            smallvec![
+                Value::vector_u8(checksum.as_slice().to_vec()),
+                Value::u32(0)
+            ],
+        )),
+        Err((error_code, message)) => {
+            error!("native_store_code error: {}", message);
+            Ok(NativeResult::ok(
+                cost,
+                smallvec![Value::vector_u8(vec![]), Value::u32(error_code)],
+            ))
+        }
+    }
+}
+
+/***************************************************************************************************
+ * native_call_contract
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct CosmWasmCallContractGasParametersOption {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+    pub cosmwasm_gas_per_internal_gas: Option<u64>,
+}
+
+impl CosmWasmCallContractGasParametersOption {
+    pub fn zeros() -> Self {
+        Self {
+            base: Some(0.into()),
+            per_byte: Some(InternalGasPerByte::zero()),
+            cosmwasm_gas_per_internal_gas: Some(DEFAULT_COSMWASM_GAS_PER_INTERNAL_GAS),
+        }
+    }
+
+    fn cosmwasm_gas_per_internal_gas(&self) -> u64 {
+        match self.cosmwasm_gas_per_internal_gas {
+            Some(ratio) if ratio > 0 => ratio,
+            _ => DEFAULT_COSMWASM_GAS_PER_INTERNAL_GAS,
+        }
+    }
+}
+
+struct ContractCallOutput {
+    result: Result<Vec<u8>, (u32, String)>,
+    state: Vec<u8>,
+    gas_used: u64,
+    out_of_gas: bool,
+}
+
+fn decode_state(state: &[u8]) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, (u32, String)> {
+    if state.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    bcs::from_bytes::<Vec<(Vec<u8>, Vec<u8>)>>(state)
+        .map(|entries| entries.into_iter().collect())
+        .map_err(|e| (E_INVALID_ARGUMENT, format!("Invalid contract state: {}", e)))
+}
+
+fn encode_state(state: BTreeMap<Vec<u8>, Vec<u8>>) -> Vec<u8> {
+    let entries = state.into_iter().collect::<Vec<_>>();
+    bcs::to_bytes(&entries).expect("Serialize contract state should success")
+}
+
+fn coins_to_json(coins: &[Coin]) -> serde_json::Value {
+    json!(coins
+        .iter()
+        .map(|coin| json!({"denom": coin.denom, "amount": coin.amount.to_string()}))
+        .collect::<Vec<_>>())
+}
+
+/// Convert the CosmosMsg to the json of `rooch_nursery::cosmwasm_std::CosmosMsg`
+fn normalize_cosmos_msg(msg: CosmosMsg) -> Result<serde_json::Value, String> {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Ok(json!({
+            "bank_send": {
+                "to_address": to_address,
+                "amount": coins_to_json(&amount),
+            },
+            "wasm_execute": null,
+            "wasm_instantiate": null,
+        })),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => Ok(json!({
+            "bank_send": null,
+            "wasm_execute": {
+                "contract_addr": contract_addr,
+                "msg": msg.to_vec(),
+                "funds": coins_to_json(&funds),
+            },
+            "wasm_instantiate": null,
+        })),
+        CosmosMsg::Wasm(WasmMsg::Instantiate {
+            admin,
+            code_id,
+            msg,
+            funds,
+            label,
+        }) => Ok(json!({
+            "bank_send": null,
+            "wasm_execute": null,
+            "wasm_instantiate": {
+                "admin": admin,
+                "code_id": code_id,
+                "msg": msg.to_vec(),
+                "funds": coins_to_json(&funds),
+                "label": label,
+            },
+        })),
+        msg => Err(format!("Unsupported message: {:?}", msg)),
+    }
+}
+
+fn normalize_sub_msg(sub_msg: SubMsg) -> Result<serde_json::Value, String> {
+    let reply_on = match sub_msg.reply_on {
+        ReplyOn::Never => 0u8,
+        ReplyOn::Success => 1,
+        ReplyOn::Error => 2,
+        ReplyOn::Always => 3,
+    };
+    let msg = serde_json::to_vec(&normalize_cosmos_msg(sub_msg.msg)?).map_err(|e| e.to_string())?;
+    Ok(json!({
+        "id": sub_msg.id,
+        "msg": msg,
+        "gas_limit": sub_msg.gas_limit,
+        "reply_on": {"value": reply_on},
+    }))
+}
+
+/// Convert the contract Response to the json of `rooch_nursery::cosmwasm_std::Response`
+fn normalize_response(response: Response) -> Result<Vec<u8>, String> {
+    let attributes_to_json = |attributes: &[cosmwasm_std::Attribute]| {
+        attributes
+            .iter()
+            .map(|attr| json!({"key": attr.key, "value": attr.value}))
+            .collect::<Vec<_>>()
+    };
+    let messages = response
+        .messages
+        .into_iter()
+        .map(normalize_sub_msg)
+        .collect::<Result<Vec<_>, _>>()?;
+    let events = response
+        .events
+        .iter()
+        .map(|event| json!({"ty": event.ty, "attributes": attributes_to_json(&event.attributes)}))
+        .collect::<Vec<_>>();
+    let value = json!({
+        "messages": messages,
+        "attributes": attributes_to_json(&response.attributes),
+        "events": events,
+        "data": response.data.map(|data| data.to_vec()).unwrap_or_default(),
+    });
+    serde_json::to_vec(&value).map_err(|e| e.to_string())
+}
+
+fn normalize_result(entry_point: u8, raw: &[u8]) -> Result<Vec<u8>, (u32, String)> {
+    let invalid =
+        |e: cosmwasm_std::StdError| (E_VM_ERROR, format!("Invalid contract result: {}", e));
+    if entry_point == ENTRY_POINT_QUERY {
+        match from_json::<ContractResult<Binary>>(raw).map_err(invalid)? {
+            ContractResult::Ok(binary) => Ok(binary.to_vec()),
+            ContractResult::Err(msg) => Err((E_CONTRACT_ERROR, msg)),
+        }
+    } else {
+        match from_json::<ContractResult<Response>>(raw).map_err(invalid)? {
+            ContractResult::Ok(response) => {
+                normalize_response(response).map_err(|msg| (E_UNSUPPORTED, msg))
+            }
+            ContractResult::Err(msg) => Err((E_CONTRACT_ERROR, msg)),
+        }
+    }
+}
+
+/// Run the contract entry point on the given state.
+/// The contract storage is loaded from the state and written back only if the call succeeds.
+#[allow(clippy::too_many_arguments)]
+fn call_contract(
+    entry_point: u8,
+    code: &[u8],
+    state: &[u8],
+    env: &[u8],
+    info: &[u8],
+    msg: &[u8],
+    balances: &[u8],
+    gas_limit: u64,
+) -> ContractCallOutput {
+    let failed = |error: (u32, String)| ContractCallOutput {
+        result: Err(error),
+        state: vec![],
+        gas_used: 0,
+        out_of_gas: false,
+    };
+
+    let checksum = match compile_code(code) {
+        Ok(checksum) => checksum,
+        Err(e) => return failed(e),
+    };
+    let state_data = match decode_state(state) {
+        Ok(data) => data,
+        Err(e) => return failed(e),
+    };
+    let balances = if balances.is_empty() {
+        vec![]
+    } else {
+        match serde_json::from_slice::<QuerierBalances>(balances) {
+            Ok(balances) => balances.balances,
+            Err(e) => {
+                return failed((E_INVALID_ARGUMENT, format!("Invalid balances: {}", e)));
+            }
+        }
+    };
+
+    let (module, store) = match WASM_CACHE.get_module(&checksum) {
+        Ok(module) => module,
+        Err(e) => return failed((E_VM_ERROR, format!("Failed to get WASM module: {}", e))),
+    };
+    let backend = build_contract_backend(state_data, balances);
+    let mut instance = match Instance::from_module(store, &module, backend, gas_limit, None, None) {
+        Ok(instance) => instance,
+        Err(e) => return failed((E_VM_ERROR, format!("Failed to get WASM instance: {}", e))),
+    };
+
+    let raw = match entry_point {
+        ENTRY_POINT_INSTANTIATE => call_instantiate_raw(&mut instance, env, info, msg),
+        ENTRY_POINT_EXECUTE => call_execute_raw(&mut instance, env, info, msg),
+        ENTRY_POINT_QUERY => call_query_raw(&mut instance, env, msg),
+        ENTRY_POINT_REPLY => call_reply_raw(&mut instance, env, msg),
+        _ => {
+            return failed((
+                E_UNSUPPORTED,
+                format!("Unsupported entry point: {}", entry_point),
+            ))
+        }
+    };
+    let gas_used = gas_limit.saturating_sub(instance.get_gas_left());
+
+    let result = match raw {
+        Ok(raw) => normalize_result(entry_point, &raw),
+        Err(VmError::GasDepletion { .. }) => {
+            return ContractCallOutput {
+                result: Err((E_VM_ERROR, "Out of gas".to_string())),
+                state: vec![],
+                gas_used,
+                out_of_gas: true,
+            }
+        }
+        Err(e) => Err((E_VM_ERROR, e.to_string())),
+    };
+    // The query can not change the state, and the state of a failed call is discarded.
+    let state = match (&result, entry_point) {
+        (Ok(_), ENTRY_POINT_QUERY) | (Err(_), _) => vec![],
+        (Ok(_), _) => match instance.recycle() {
+            Some(backend) => encode_state(backend.storage.into_data()),
+            None => {
+                return ContractCallOutput {
+                    result: Err((E_VM_ERROR, "Failed to recycle the backend".to_string())),
+                    state: vec![],
+                    gas_used,
+                    out_of_gas: false,
+                }
+            }
+        },
+    };
+    ContractCallOutput {
+        result,
+        state,
+        gas_used,
+        out_of_gas: false,
+    }
+}
+
+/// Call the contract entry point on the contract state kept by Move.
+/// Return the result payload, the new contract state and the error code.
+/// On error, the payload is the error message.
+#[inline]
+fn native_call_contract(
+    gas_params: &GasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(
+        ty_args.is_empty(),
+        "native_call_contract expects no type arguments"
+    );
+    debug_assert_eq!(args.len(), 7, "native_call_contract expects 7 arguments");
+
+    let balances = pop_arg!(args, Vec<u8>);
+    let msg = pop_arg!(args, Vec<u8>);
+    let info = pop_arg!(args, Vec<u8>);
+    let env = pop_arg!(args, Vec<u8>);
+    let state = pop_arg!(args, Vec<u8>);
+    let code = pop_arg!(args, Vec<u8>);
+    let entry_point = pop_arg!(args, u8);
+
+    let call_params = &gas_params.native_call_contract;
+    let input_len = code.len() + state.len() + env.len() + info.len() + msg.len() + balances.len();
+    let mut cost = call_params.base.unwrap_or_else(InternalGas::zero)
+        + call_params
+            .per_byte
+            .unwrap_or_else(InternalGasPerByte::zero)
+            * NumBytes::new(input_len as u64);
+    let gas_balance = context.gas_balance();
+    if cost > gas_balance {
+        return Ok(NativeResult::OutOfGas { partial_cost: cost });
+    }
+
+    let ratio = call_params.cosmwasm_gas_per_internal_gas();
+    let gas_limit = u64::from(gas_balance)
+        .saturating_sub(u64::from(cost))
+        .saturating_mul(ratio)
+        .min(MAX_CONTRACT_GAS_LIMIT);
+
+    let output = call_contract(
+        entry_point,
+        &code,
+        &state,
+        &env,
+        &info,
+        &msg,
+        &balances,
+        gas_limit,
+    );
+    cost += InternalGas::new(output.gas_used / ratio);
+    if output.out_of_gas {
+        return Ok(NativeResult::OutOfGas {
+            partial_cost: gas_balance,
+        });
+    }
+
+    let (payload, error_code) = match output.result {
+        Ok(payload) => (payload, 0),
+        Err((error_code, message)) => {
+            error!("native_call_contract error: {}", message);
+            (message.into_bytes(), error_code)
+        }
+    };
+    cost += call_params
+        .per_byte
+        .unwrap_or_else(InternalGasPerByte::zero)
+        * NumBytes::new((payload.len() + output.state.len()) as u64);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![
+            Value::vector_u8(payload),
+            Value::vector_u8(output.state),
+            Value::u32(error_code)
+        ],
+    ))
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -507,6 +951,8 @@ pub struct GasParameters {
     pub common: CommonGasParametersOption,
     pub native_create_instance: CosmWasmCreateInstanceGasParametersOption,
     pub native_destroy_instance: CosmWasmDestroyInstanceGasParametersOption,
+    pub native_store_code: CosmWasmStoreCodeGasParametersOption,
+    pub native_call_contract: CosmWasmCallContractGasParametersOption,
 }
 
 impl GasParameters {
@@ -515,6 +961,8 @@ impl GasParameters {
             common: CommonGasParametersOption::zeros(),
             native_create_instance: CosmWasmCreateInstanceGasParametersOption::zeros(),
             native_destroy_instance: CosmWasmDestroyInstanceGasParametersOption::zeros(),
+            native_store_code: CosmWasmStoreCodeGasParametersOption::zeros(),
+            native_call_contract: CosmWasmCallContractGasParametersOption::zeros(),
         }
     }
 }
@@ -562,6 +1010,16 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "native_call_sudo_raw",
             make_native(gas_params.clone(), native_call_sudo_raw),
         ));
+
+        natives.push((
+            "native_store_code",
+            make_native(gas_params.clone(), native_store_code),
+        ));
+
+        natives.push((
+            "native_call_contract",
+            make_native(gas_params.clone(), native_call_contract),
+        ));
     }
 
     make_module_natives(natives)
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::cosmwasm_vm::GasParameters;
+use crate::natives::cosmwasm_vm::{GasParameters, DEFAULT_COSMWASM_GAS_PER_INTERNAL_GAS};
 use rooch_framework::natives::gas_parameter::native::MUL;
 
 rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "cosmwasm_vm", [
@@ -11,4 +11,9 @@ rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_nativ
     [.native_create_instance.base, optional "native_create_instance.base", 1000 * MUL],
     [.native_create_instance.per_byte_wasm, optional "native_create_instance.per_byte_wasm", 30 * MUL],
     [.native_destroy_instance.base, optional "native_destroy_instance.base", 1000 * MUL],
+    [.native_store_code.base, optional "native_store_code.base", 1000 * MUL],
+    [.native_store_code.per_byte, optional "native_store_code.per_byte", 30 * MUL],
+    [.native_call_contract.base, optional "native_call_contract.base", 1000 * MUL],
+    [.native_call_contract.per_byte, optional "native_call_contract.per_byte", 10 * MUL],
+    [.native_call_contract.cosmwasm_gas_per_internal_gas, optional "native_call_contract.cosmwasm_gas_per_internal_gas", DEFAULT_COSMWASM_GAS_PER_INTERNAL_GAS],
 ]);
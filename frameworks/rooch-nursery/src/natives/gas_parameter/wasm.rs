@@ -6,6 +6,8 @@ use crate::natives::wasm::GasParameters;
 rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "wasm", [
     [.create_instance_gas_parameter.base_create_instance, "create_instance_gas_parameter.base_create_instance", 10000],
     [.create_instance_gas_parameter.per_byte_instance, "create_instance_gas_parameter.per_byte_instance", 100],
+    [.create_instance_gas_parameter.per_memory_page, optional "create_instance_gas_parameter.per_memory_page", 1000],
+    [.create_instance_gas_parameter.max_memory_pages, optional "create_instance_gas_parameter.max_memory_pages", 256],
 
     [.create_cbor_value_gas_parameter.base, "create_cbor_value_gas_parameter.base", 100],
     [.create_cbor_value_gas_parameter.per_byte, "create_cbor_value_gas_parameter.per_byte", 10000],
@@ -18,6 +20,7 @@ rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_nativ
 
     [.function_execution_gas_parameter.base_create_execution, "function_execution_gas_parameter.base_create_execution", 1000],
     [.function_execution_gas_parameter.per_execution_point, "function_execution_gas_parameter.per_execution_point", 1],
+    [.function_execution_gas_parameter.max_fuel_per_call, optional "function_execution_gas_parameter.max_fuel_per_call", 500000],
 
     [.read_data_length_gas_parameter.base, "read_data_length_gas_parameter.base", 100],
     [.read_data_length_gas_parameter.per_byte, "read_data_length_gas_parameter.per_byte", 10000],
@@ -26,4 +29,7 @@ rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_nativ
     [.read_heap_data.per_byte, "read_heap_data.per_byte", 10000],
 
     [.release_wasm_instance.base, "release_wasm_instance.base", 100],
+
+    [.call_wasm_function.base, optional "call_wasm_function.base", 1000],
+    [.call_wasm_function.per_byte, optional "call_wasm_function.per_byte", 100],
 ]);
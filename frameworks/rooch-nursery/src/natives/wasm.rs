@@ -17,8 +17,10 @@ use std::ops::Deref;
 use std::vec;
 use tracing::{debug, warn};
 
+use moveos_wasm::context::NativeWASMContext;
 use moveos_wasm::wasm::{
-    create_wasm_instance, get_instance_pool, insert_wasm_instance, put_data_on_stack,
+    create_wasm_instance_with_limits, put_data_on_stack, read_data_with_length, WASMInstance,
+    DEFAULT_FUEL_LIMIT, DEFAULT_MAX_MEMORY_PAGES,
 };
 
 use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
@@ -49,6 +51,10 @@ pub const E_VM_ERROR: u64 = 99;
 pub struct WASMCreateInstanceGasParameters {
     pub base_create_instance: InternalGas,
     pub per_byte_instance: InternalGasPerByte,
+    /// The gas charged for each page (64KiB) of the linear memory the instance allocates.
+    pub per_memory_page: Option<InternalGasPerByte>,
+    /// The max pages of the linear memory of an instance.
+    pub max_memory_pages: Option<u64>,
 }
 
 impl WASMCreateInstanceGasParameters {
@@ -56,15 +62,34 @@ impl WASMCreateInstanceGasParameters {
         Self {
             base_create_instance: 0.into(),
             per_byte_instance: 0.into(),
+            per_memory_page: Some(0.into()),
+            max_memory_pages: Some(0),
         }
     }
+
+    fn max_memory_pages(&self) -> u32 {
+        match self.max_memory_pages {
+            Some(pages) if pages > 0 => pages.min(u32::MAX as u64) as u32,
+            _ => DEFAULT_MAX_MEMORY_PAGES,
+        }
+    }
+
+    fn memory_pages_cost(&self, pages: u32) -> InternalGas {
+        self.per_memory_page
+            .unwrap_or_else(InternalGasPerByte::zero)
+            * NumBytes::new(pages as u64)
+    }
+}
+
+fn wasm_context<'a>(context: &'a mut NativeContext) -> &'a mut NativeWASMContext {
+    context.extensions_mut().get_mut::<NativeWASMContext>()
 }
 
 // native_create_wasm_instance
 #[inline]
 fn native_create_wasm_instance(
     gas_params: &WASMCreateInstanceGasParameters,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -77,30 +102,27 @@ fn native_create_wasm_instance(
 
     let wasm_bytes = pop_arg!(args, Vec<u8>);
 
-    let (instance_id, error_code) = match create_wasm_instance(&wasm_bytes) {
-        Ok(instance) => {
-            match insert_wasm_instance(instance) {
-                Ok(id) => (id, 0), // No error
-                Err(e) => {
-                    warn!("insert_wasm_instance_error: {:?}", &e);
-                    (0, E_WASM_INSERT_POOL_FAILED)
-                }
+    let mut cost = gas_params.base_create_instance;
+    cost += gas_params.per_byte_instance * NumBytes::new(wasm_bytes.len() as u64);
+
+    let (instance_id, error_code) =
+        match create_wasm_instance_with_limits(&wasm_bytes, gas_params.max_memory_pages()) {
+            Ok(instance) => {
+                cost += gas_params.memory_pages_cost(instance.memory_pages());
+                let id = wasm_context(context).insert_instance(instance);
+                (id, 0) // No error
             }
-        }
-        Err(e) => {
-            warn!("create_wasm_instance_error: {:?}", &e);
-            (0, E_WASM_INSTANCE_CREATION_FAILED)
-        }
-    };
+            Err(e) => {
+                warn!("create_wasm_instance_error: {:?}", &e);
+                (0, E_WASM_INSTANCE_CREATION_FAILED)
+            }
+        };
 
     debug!(
         "native_create_wasm_instance result: instance_id:{:?}, error_code:{:?}",
         &instance_id, &error_code
     );
 
-    let mut cost = gas_params.base_create_instance;
-    cost += gas_params.per_byte_instance * NumBytes::new(wasm_bytes.len() as u64);
-
     Ok(NativeResult::ok(
         cost,
         smallvec![Value::u64(instance_id), Value::u64(error_code)],
@@ -261,14 +283,15 @@ impl WASMCreateArgsGasParameters {
 // native_create_wasm_args_in_memory
 #[inline]
 fn native_create_wasm_args_in_memory(
-    gas_params: &WASMCreateArgsGasParameters,
-    _context: &mut NativeContext,
+    gas_params: &GasParameters,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
+    let args_params = &gas_params.create_args_gas_parameter;
     if args.len() != 3 {
         return Ok(NativeResult::err(
-            gas_params.base_create_args,
+            args_params.base_create_args,
             E_INCORRECT_LENGTH_OF_ARGS,
         ));
     }
@@ -283,75 +306,76 @@ fn native_create_wasm_args_in_memory(
             Ok(v) => func_args.push(v),
             Err(_) => {
                 return Ok(NativeResult::err(
-                    gas_params.base_create_args,
+                    args_params.base_create_args,
                     E_ARG_NOT_VECTOR_U8,
                 ));
             }
         }
     }
 
-    let mut args_bytes_total = 0;
+    let args_bytes_total: usize = func_args.iter().map(|arg| arg.len()).sum();
+    let base_cost = args_params.base_create_args
+        + args_params.per_byte_args * NumBytes::new(args_bytes_total as u64);
 
-    let mut data_ptr_list = Vec::new();
-
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base_create_args,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    match pool_object.get_mut(&instance_id) {
+    let gas_balance = context.gas_balance();
+    let instance = match wasm_context(context).get_instance_mut(instance_id) {
+        Some(instance) => instance,
         None => {
             return Ok(NativeResult::err(
-                gas_params.base_create_args,
+                args_params.base_create_args,
                 E_INSTANCE_NO_EXISTS,
             ));
         }
-        Some(instance) => {
+    };
+
+    // The stackAlloc calls consume the fuel, charge it the same way as the function calls.
+    let (result, cost) = match with_metered_instance(
+        &gas_params.function_execution_gas_parameter,
+        &gas_params.create_instance_gas_parameter,
+        gas_balance,
+        base_cost,
+        instance,
+        |instance| {
+            let mut data_ptr_list = Vec::new();
             for arg in func_args.iter() {
                 let c_arg = unsafe { CString::from_vec_unchecked(arg.clone()) };
 
                 let mut arg_buffer = Vec::new();
                 // arg_buffer.append(&mut (arg.len() as u32).to_be_bytes().to_vec());
                 arg_buffer.append(&mut c_arg.into_bytes_with_nul());
-                let buffer_final_ptr = match put_data_on_stack(instance, arg_buffer.as_slice()) {
-                    Ok(v) => v,
-                    Err(e) => {
+                let buffer_final_ptr =
+                    put_data_on_stack(instance, arg_buffer.as_slice()).map_err(|e| {
                         warn!(
                             "native_create_wasm_args_in_memory->put_data_on_stack error:{:?}",
                             &e
                         );
-                        return build_err(
-                            gas_params.base_create_args,
-                            E_WASM_PUT_DATA_ON_STACK_FAILED,
-                        );
-                    }
-                };
-
+                        E_WASM_PUT_DATA_ON_STACK_FAILED
+                    })?;
                 data_ptr_list.push(buffer_final_ptr as u64);
-                args_bytes_total += arg.len();
             }
-        }
-    }
-
-    let mut cost = gas_params.base_create_args;
-    cost += gas_params.per_byte_args * NumBytes::new(args_bytes_total as u64);
+            Ok::<_, u64>(data_ptr_list)
+        },
+    ) {
+        Ok(v) => v,
+        Err(partial_cost) => return Ok(NativeResult::OutOfGas { partial_cost }),
+    };
 
-    let return_value_list = Value::vector_u64(data_ptr_list);
-    Ok(NativeResult::Success {
-        cost,
-        ret_vals: smallvec![return_value_list],
-    })
+    match result {
+        Ok(data_ptr_list) => Ok(NativeResult::Success {
+            cost,
+            ret_vals: smallvec![Value::vector_u64(data_ptr_list)],
+        }),
+        Err(error_code) => build_err(cost, error_code),
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WASMExecuteGasParameters {
     pub base_create_execution: InternalGas,
+    /// The gas charged for each point of the WASM fuel.
     pub per_execution_point: InternalGasPerByte,
+    /// The max fuel of a single call, the fuel is also bounded by the remaining gas of the transaction.
+    pub max_fuel_per_call: Option<u64>,
 }
 
 impl WASMExecuteGasParameters {
@@ -359,8 +383,61 @@ impl WASMExecuteGasParameters {
         Self {
             base_create_execution: 0.into(),
             per_execution_point: 0.into(),
+            max_fuel_per_call: Some(0),
         }
     }
+
+    /// Returns the fuel limit of the call and whether the limit is bounded by the gas balance.
+    fn fuel_limit(&self, gas_balance: InternalGas, base_cost: InternalGas) -> (u64, bool) {
+        let max_fuel = match self.max_fuel_per_call {
+            Some(fuel) if fuel > 0 => fuel,
+            _ => DEFAULT_FUEL_LIMIT,
+        };
+        let per_point = u64::from(self.per_execution_point);
+        if per_point == 0 {
+            return (max_fuel, false);
+        }
+        let remaining_gas = u64::from(gas_balance).saturating_sub(u64::from(base_cost));
+        let affordable_fuel = remaining_gas / per_point;
+        if affordable_fuel < max_fuel {
+            (affordable_fuel, true)
+        } else {
+            (max_fuel, false)
+        }
+    }
+
+    fn fuel_cost(&self, fuel_used: u64) -> InternalGas {
+        self.per_execution_point * NumBytes::new(fuel_used)
+    }
+}
+
+/// Run `f` on the instance with the fuel limit derived from the transaction's remaining gas,
+/// the consumed fuel and the grown memory pages are charged to the Move gas.
+/// Returns `Err(partial_cost)` if the fuel is exhausted by the gas balance.
+fn with_metered_instance<T>(
+    exec_params: &WASMExecuteGasParameters,
+    memory_params: &WASMCreateInstanceGasParameters,
+    gas_balance: InternalGas,
+    base_cost: InternalGas,
+    instance: &mut WASMInstance,
+    f: impl FnOnce(&mut WASMInstance) -> T,
+) -> Result<(T, InternalGas), InternalGas> {
+    let (fuel_limit, bounded_by_balance) = exec_params.fuel_limit(gas_balance, base_cost);
+    let pages_before = instance.memory_pages();
+    instance.reset_fuel(fuel_limit);
+
+    let result = f(instance);
+
+    let fuel_used = instance.fuel_used();
+    debug!("wasm fuel_used: {}, fuel_limit: {}", fuel_used, fuel_limit);
+    if instance.fuel_exhausted() && bounded_by_balance {
+        return Err(gas_balance);
+    }
+
+    let grown_pages = instance.memory_pages().saturating_sub(pages_before);
+    let cost =
+        base_cost + exec_params.fuel_cost(fuel_used) + memory_params.memory_pages_cost(grown_pages);
+    Ok((result, cost))
 }
 
 fn build_err(cost: InternalGas, abort_code: u64) -> PartialVMResult<NativeResult> {
@@ -370,12 +447,18 @@ fn build_err(cost: InternalGas, abort_code: u64) -> PartialVMResult<NativeResult
 // native_execute_wasm_function
 #[inline]
 fn native_execute_wasm_function(
-    gas_params: &WASMExecuteGasParameters,
-    _context: &mut NativeContext,
-    _ty_args: Vec<Type>,
+    gas_params: &GasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
     args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
-    let vm_result = execute_wasm_function_inner(gas_params, _context, _ty_args, args);
+    let vm_result = execute_wasm_function_inner(
+        &gas_params.function_execution_gas_parameter,
+        &gas_params.create_instance_gas_parameter,
+        context,
+        ty_args,
+        args,
+    );
     match vm_result {
         PartialVMResult::Ok(native_result) => match native_result {
             NativeResult::Success { cost, ret_vals } => {
@@ -397,7 +480,9 @@ fn native_execute_wasm_function(
             warn!("execute_wasm_function_inner vm_error: {:?}", err);
 
             Ok(NativeResult::Success {
-                cost: gas_params.base_create_execution,
+                cost: gas_params
+                    .function_execution_gas_parameter
+                    .base_create_execution,
                 ret_vals: smallvec![Value::u64(0), Value::u64(E_VM_ERROR)],
             })
         }
@@ -406,7 +491,8 @@ fn native_execute_wasm_function(
 
 fn execute_wasm_function_inner(
     gas_params: &WASMExecuteGasParameters,
-    _context: &mut NativeContext,
+    memory_params: &WASMCreateInstanceGasParameters,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -421,123 +507,192 @@ fn execute_wasm_function_inner(
     let func_name = pop_arg!(args, Vec<u8>);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
+    let gas_balance = context.gas_balance();
+    let instance = match wasm_context(context).get_instance_mut(instance_id) {
+        Some(instance) => instance,
+        None => {
+            return build_err(gas_params.base_create_execution, E_INSTANCE_NO_EXISTS);
+        }
+    };
+
+    let calling_function = match instance
+        .instance
+        .exports
+        .get_function(&String::from_utf8_lossy(func_name.as_slice()))
+    {
+        Ok(f) => f.clone(),
+        Err(err) => {
+            warn!("execute_wasm_function_inner->get_function_error:{:?}", &err);
+            return build_err(gas_params.base_create_execution, E_WASM_FUNCTION_NOT_FOUND);
+        }
+    };
+
+    // TODO: check the length of arguments for the function calling
+    let wasm_func_args = func_args
+        .iter()
+        .map(|arg| wasmer::Value::I32(*arg as i32))
+        .collect::<Vec<_>>();
+
+    let (call_result, cost) = match with_metered_instance(
+        gas_params,
+        memory_params,
+        gas_balance,
+        gas_params.base_create_execution,
+        instance,
+        |instance| calling_function.call(&mut instance.store, wasm_func_args.as_slice()),
+    ) {
         Ok(v) => v,
-        Err(e) => {
+        Err(partial_cost) => return Ok(NativeResult::OutOfGas { partial_cost }),
+    };
+
+    match call_result {
+        Ok(ret) => {
+            let return_value = match ret.deref().first() {
+                Some(v) => v,
+                None => return build_err(cost, E_EMPTY_RETURN_VALUE),
+            };
+            let offset = match return_value.i32() {
+                Some(v) => v,
+                None => return build_err(cost, E_VALUE_NOT_I32),
+            };
+
+            Ok(NativeResult::Success {
+                cost,
+                ret_vals: smallvec![Value::u64(offset as u64)],
+            })
+        }
+        Err(err) => {
             warn!(
-                "execute_wasm_function_inner->instance_pool_lock_error: {:?}",
-                &e
+                "execute_wasm_function_inner->calling_function_error:{}",
+                err.message()
             );
+            if tracing::enabled!(tracing::Level::DEBUG) {
+                debug!("trace:{:?}", err.trace());
+            }
+            build_err(cost, E_WASM_EXECUTION_FAILED)
+        }
+    }
+}
 
-            return Ok(NativeResult::err(
-                gas_params.base_create_execution,
-                E_GET_INSTANCE_POOL_FAILED,
-            ));
+#[derive(Debug, Clone)]
+pub struct WASMCallGasParameters {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl WASMCallGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: Some(0.into()),
+            per_byte: Some(0.into()),
+        }
+    }
+}
+
+/// Call the function with the CBOR encoded arguments and return the CBOR encoded result.
+/// The arguments are written to the stack of the instance with a 4 bytes big-endian length prefix,
+/// the function takes the pointer of the arguments and returns the pointer of the result,
+/// which is prefixed with the length in the same way.
+fn call_with_cbor(
+    instance: &mut WASMInstance,
+    func_name: &str,
+    args: &[u8],
+) -> Result<Vec<u8>, u64> {
+    let calling_function = match instance.instance.exports.get_function(func_name) {
+        Ok(f) => f.clone(),
+        Err(err) => {
+            warn!("call_with_cbor->get_function_error:{:?}", &err);
+            return Err(E_WASM_FUNCTION_NOT_FOUND);
         }
     };
 
-    let ret = match pool_object.get_mut(&instance_id) {
-        None => Ok(NativeResult::err(
-            gas_params.base_create_execution,
-            E_INSTANCE_NO_EXISTS,
-        )),
-        Some(instance) => {
-            match instance.instance.exports.get_function(
-                String::from_utf8_lossy(func_name.as_slice())
-                    .to_string()
-                    .as_str(),
-            ) {
-                Ok(calling_function) => {
-                    let mut gas_meter = instance.gas_meter.lock().unwrap();
-                    gas_meter.reset();
-                    drop(gas_meter);
-
-                    let mut wasm_func_args = Vec::with_capacity(func_args.len());
-                    for arg in func_args.iter() {
-                        wasm_func_args.push(wasmer::Value::I32(*arg as i32));
-                    }
+    let mut args_buffer = (args.len() as u32).to_be_bytes().to_vec();
+    args_buffer.extend_from_slice(args);
+    let args_ptr = put_data_on_stack(instance, args_buffer.as_slice()).map_err(|e| {
+        warn!("call_with_cbor->put_data_on_stack error:{:?}", &e);
+        E_WASM_PUT_DATA_ON_STACK_FAILED
+    })?;
 
-                    // TODO: check the length of arguments for the function calling
-
-                    // let trap_handler: Box<
-                    //     dyn Fn(libc::c_int, *const libc::siginfo_t, *const libc::c_void) -> bool
-                    //         + Send
-                    //         + Sync
-                    //         + 'static,
-                    // > = Box::new(|signum, siginfo, ctx| {
-                    //     warn!(
-                    //         "Trap handler called, signum:{:?}, siginfo:{:?}, context:{:?}",
-                    //         signum, siginfo, ctx
-                    //     );
-                    //     false
-                    // });
-
-                    // Set trap handler
-                    // instance.store.set_trap_handler(Some(trap_handler));
-
-                    match calling_function.call(&mut instance.store, wasm_func_args.as_slice()) {
-                        Ok(ret) => {
-                            let return_value = match ret.deref().first() {
-                                Some(v) => v,
-                                None => {
-                                    return build_err(
-                                        gas_params.base_create_execution,
-                                        E_EMPTY_RETURN_VALUE,
-                                    )
-                                }
-                            };
-                            let offset = match return_value.i32() {
-                                Some(v) => v,
-                                None => {
-                                    return build_err(
-                                        gas_params.base_create_execution,
-                                        E_VALUE_NOT_I32,
-                                    )
-                                }
-                            };
-                            let ret_val = Value::u64(offset as u64);
-
-                            let mut gas_meter = instance.gas_meter.lock().unwrap();
-                            let gas_used = gas_meter.used();
-
-                            debug!("execute_wasm_function_inner->gas_used: {:?}", gas_used);
-
-                            let mut cost = gas_params.base_create_execution;
-                            cost += gas_params.per_execution_point * NumBytes::new(gas_used);
-
-                            Ok(NativeResult::Success {
-                                cost,
-                                ret_vals: smallvec![ret_val],
-                            })
-                        }
-                        Err(err) => {
-                            warn!(
-                                "execute_wasm_function_inner->calling_function_error:{}",
-                                err.message()
-                            );
-                            if tracing::enabled!(tracing::Level::DEBUG) {
-                                debug!("trace:{:?}", err.trace());
-                            }
-                            Ok(NativeResult::err(
-                                gas_params.base_create_execution,
-                                E_WASM_EXECUTION_FAILED,
-                            ))
-                        }
-                    }
-                }
-                Err(err) => {
-                    warn!("execute_wasm_function_inner->get_function_error:{:?}", &err);
+    let ret = calling_function
+        .call(&mut instance.store, &[wasmer::Value::I32(args_ptr)])
+        .map_err(|err| {
+            warn!("call_with_cbor->calling_function_error:{}", err.message());
+            E_WASM_EXECUTION_FAILED
+        })?;
+    let ret_ptr = ret
+        .deref()
+        .first()
+        .ok_or(E_EMPTY_RETURN_VALUE)?
+        .i32()
+        .ok_or(E_VALUE_NOT_I32)?;
+
+    read_data_with_length(instance, ret_ptr as u32 as u64).map_err(|e| {
+        warn!("call_with_cbor->read_data_with_length error:{:?}", &e);
+        E_WASM_MEMORY_ACCESS_FAILED
+    })
+}
 
-                    Ok(NativeResult::err(
-                        gas_params.base_create_execution,
-                        E_WASM_FUNCTION_NOT_FOUND,
-                    ))
-                }
-            }
+// native_call_wasm_function
+#[inline]
+fn native_call_wasm_function(
+    gas_params: &GasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let call_params = &gas_params.call_wasm_function;
+    let base = call_params.base.unwrap_or_else(InternalGas::zero)
+        + gas_params
+            .function_execution_gas_parameter
+            .base_create_execution;
+    let per_byte = call_params
+        .per_byte
+        .unwrap_or_else(InternalGasPerByte::zero);
+    if args.len() != 3 {
+        return build_err(base, E_INCORRECT_LENGTH_OF_ARGS);
+    }
+
+    let func_args = pop_arg!(args, Vec<u8>);
+    let func_name = pop_arg!(args, Vec<u8>);
+    let instance_id = pop_arg!(args, u64);
+
+    let gas_balance = context.gas_balance();
+    let instance = match wasm_context(context).get_instance_mut(instance_id) {
+        Some(instance) => instance,
+        None => {
+            return Ok(NativeResult::ok(
+                base,
+                smallvec![Value::vector_u8(vec![]), Value::u64(E_INSTANCE_NO_EXISTS)],
+            ))
         }
     };
-    ret
+
+    let func_name = String::from_utf8_lossy(func_name.as_slice()).to_string();
+    let base_cost = base + per_byte * NumBytes::new(func_args.len() as u64);
+    let (result, mut cost) = match with_metered_instance(
+        &gas_params.function_execution_gas_parameter,
+        &gas_params.create_instance_gas_parameter,
+        gas_balance,
+        base_cost,
+        instance,
+        |instance| call_with_cbor(instance, func_name.as_str(), func_args.as_slice()),
+    ) {
+        Ok(v) => v,
+        Err(partial_cost) => return Ok(NativeResult::OutOfGas { partial_cost }),
+    };
+
+    let (ret_bytes, error_code) = match result {
+        Ok(ret_bytes) => {
+            cost += per_byte * NumBytes::new(ret_bytes.len() as u64);
+            (ret_bytes, 0)
+        }
+        Err(error_code) => (vec![], error_code),
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(ret_bytes), Value::u64(error_code)],
+    ))
 }
 
 #[derive(Debug, Clone)]
@@ -559,7 +714,7 @@ impl WASMReadAddLength {
 #[inline]
 fn native_read_data_length(
     gas_params: &WASMReadAddLength,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -573,17 +728,7 @@ fn native_read_data_length(
     let data_ptr = pop_arg!(args, u64);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    let ret = match pool_object.get_mut(&instance_id) {
+    let ret = match wasm_context(context).get_instance_mut(instance_id) {
         None => Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS)),
         Some(instance) => {
             let memory = match instance.instance.exports.get_memory("memory") {
@@ -631,7 +776,7 @@ impl WASMReadHeapData {
 #[inline]
 fn native_read_data_from_heap(
     gas_params: &WASMReadHeapData,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -646,17 +791,7 @@ fn native_read_data_from_heap(
     let data_ptr = pop_arg!(args, u32);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    let ret = match pool_object.get_mut(&instance_id) {
+    let ret = match wasm_context(context).get_instance_mut(instance_id) {
         None => Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS)),
         Some(instance) => {
             let memory = match instance.instance.exports.get_memory("memory") {
@@ -729,7 +864,7 @@ impl WASMReleaseInstance {
 #[inline]
 fn native_release_wasm_instance(
     gas_params: &WASMReleaseInstance,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -751,32 +886,10 @@ fn native_release_wasm_instance(
 
     let instance_id = val.value_as::<u64>()?;
 
-    {
-        let instance_pool = get_instance_pool();
-        let mut pool_object = match instance_pool.lock() {
-            Ok(v) => v,
-            Err(_) => {
-                return Ok(NativeResult::err(
-                    gas_params.base,
-                    E_GET_INSTANCE_POOL_FAILED,
-                ))
-            }
-        };
-        if pool_object.get_mut(&instance_id).is_none() {
-            return Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS));
-        };
+    if wasm_context(context).remove_instance(instance_id).is_none() {
+        return Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS));
     }
 
-    match moveos_wasm::wasm::remove_instance(instance_id) {
-        Ok(_) => {}
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-
     Ok(NativeResult::Success {
         cost: gas_params.base,
         ret_vals: smallvec![Value::bool(true)],
@@ -797,6 +910,7 @@ pub struct GasParameters {
     pub read_data_length_gas_parameter: WASMReadAddLength,
     pub read_heap_data: WASMReadHeapData,
     pub release_wasm_instance: WASMReleaseInstance,
+    pub call_wasm_function: WASMCallGasParameters,
 }
 
 impl GasParameters {
@@ -810,11 +924,13 @@ impl GasParameters {
             read_data_length_gas_parameter: WASMReadAddLength::zeros(),
             read_heap_data: WASMReadHeapData::zeros(),
             release_wasm_instance: WASMReleaseInstance::zeros(),
+            call_wasm_function: WASMCallGasParameters::zeros(),
         }
     }
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let all_gas_params = gas_params.clone();
     let natives = [
         (
             "native_create_wasm_instance",
@@ -836,17 +952,15 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
         ),
         (
             "native_create_wasm_args_in_memory",
-            make_native(
-                gas_params.create_args_gas_parameter,
-                native_create_wasm_args_in_memory,
-            ),
+            make_native(all_gas_params.clone(), native_create_wasm_args_in_memory),
         ),
         (
             "native_execute_wasm_function",
-            make_native(
-                gas_params.function_execution_gas_parameter,
-                native_execute_wasm_function,
-            ),
+            make_native(all_gas_params.clone(), native_execute_wasm_function),
+        ),
+        (
+            "native_call_wasm_function",
+            make_native(all_gas_params, native_call_wasm_function),
         ),
         (
             "native_read_data_length",
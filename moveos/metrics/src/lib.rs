@@ -26,6 +26,7 @@ mod guards;
 pub mod histogram;
 pub mod metered_channel;
 pub mod monitored_mpsc;
+pub mod otel;
 pub use guards::*;
 pub mod metrics_util;
 #[cfg(test)]
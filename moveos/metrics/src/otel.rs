@@ -0,0 +1,212 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export the `tracing` spans as OpenTelemetry traces, so a request can be followed
+//! from the RPC through sequencing, execution, indexing, DA batching and notification.
+//! The log filter of the global subscriber can be replaced at runtime as well.
+
+use anyhow::anyhow;
+use futures::future::BoxFuture;
+use once_cell::sync::OnceCell;
+use opentelemetry::trace::{TraceError, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry_sdk::trace::{Sampler, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use parking_lot::Mutex;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::{Layered, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, Layer, Registry};
+
+type FilteredRegistry = Layered<reload::Layer<Targets, Registry>, Registry>;
+type BoxedLayer = Box<dyn Layer<FilteredRegistry> + Send + Sync>;
+
+static LOG_FILTER_HANDLE: OnceCell<reload::Handle<Targets, Registry>> = OnceCell::new();
+static OTEL_LAYER_HANDLE: OnceCell<reload::Handle<Option<BoxedLayer>, FilteredRegistry>> =
+    OnceCell::new();
+static TRACER_PROVIDER: Mutex<Option<TracerProvider>> = Mutex::new(None);
+
+const TRACER_NAME: &str = "rooch";
+// the default level of `tracing_subscriber::fmt` if `RUST_LOG` is not set
+const DEFAULT_LOG_LEVEL: LevelFilter = tracing_subscriber::fmt::Subscriber::DEFAULT_MAX_LEVEL;
+
+/// Where the spans are exported to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceExporter {
+    /// The OTLP/HTTP endpoint of a collector, e.g. `http://localhost:4318/v1/traces`
+    Otlp(String),
+    /// Append the spans to a local file as JSON lines, mostly for tests
+    File(PathBuf),
+}
+
+/// Install the global subscriber with the fmt layer, like `tracing_subscriber::fmt::try_init`,
+/// plus a slot for the OpenTelemetry layer installed by `install_trace_exporter`.
+/// The log filter is read from `RUST_LOG` the same way as `try_init`, it can be replaced by `set_log_filter`.
+/// Does nothing if a global subscriber is already installed.
+pub fn init_tracing_subscriber() {
+    let targets = match std::env::var("RUST_LOG") {
+        Ok(var) => Targets::from_str(&var)
+            .map_err(|e| {
+                eprintln!("Ignoring `RUST_LOG={:?}`: {}", var, e);
+            })
+            .unwrap_or_default(),
+        Err(std::env::VarError::NotPresent) => Targets::new().with_default(DEFAULT_LOG_LEVEL),
+        Err(e) => {
+            eprintln!("Ignoring `RUST_LOG`: {}", e);
+            Targets::new().with_default(DEFAULT_LOG_LEVEL)
+        }
+    };
+    let (filter_layer, filter_handle) = reload::Layer::new(targets);
+    let (otel_layer, otel_handle) = reload::Layer::new(None::<BoxedLayer>);
+    if tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(otel_layer)
+        .with(tracing_subscriber::fmt::layer())
+        .try_init()
+        .is_ok()
+    {
+        let _ = LOG_FILTER_HANDLE.set(filter_handle);
+        let _ = OTEL_LAYER_HANDLE.set(otel_handle);
+    }
+}
+
+/// Replace the log filter of the global subscriber, the syntax is the same as `RUST_LOG`,
+/// e.g. `info,rooch_da=debug`.
+pub fn set_log_filter(filter: &str) -> anyhow::Result<()> {
+    let handle = LOG_FILTER_HANDLE.get().ok_or_else(|| {
+        anyhow!("The tracing subscriber is not installed by `init_tracing_subscriber`")
+    })?;
+    let targets = Targets::from_str(filter)?;
+    handle.reload(targets)?;
+    Ok(())
+}
+
+/// The current log filter of the global subscriber, `None` if it is not installed by `init_tracing_subscriber`.
+pub fn log_filter() -> Option<String> {
+    LOG_FILTER_HANDLE
+        .get()
+        .and_then(|handle| handle.with_current(|targets| targets.to_string()).ok())
+}
+
+/// Export the spans to `exporter`, replacing the previous exporter if any.
+/// `sample_ratio` is the ratio of the root spans to sample, the child spans follow their parent.
+pub fn install_trace_exporter(
+    exporter: TraceExporter,
+    service_name: &str,
+    sample_ratio: f64,
+) -> anyhow::Result<()> {
+    let handle = OTEL_LAYER_HANDLE.get().ok_or_else(|| {
+        anyhow!("The tracing subscriber is not installed by `init_tracing_subscriber`")
+    })?;
+    let builder = TracerProvider::builder()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            sample_ratio,
+        ))))
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            service_name.to_string(),
+        )]));
+    let provider = match exporter {
+        TraceExporter::Otlp(endpoint) => {
+            let exporter = opentelemetry_otlp::SpanExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint)
+                .build()?;
+            builder
+                .with_batch_exporter(exporter, runtime::Tokio)
+                .build()
+        }
+        TraceExporter::File(path) => builder
+            .with_simple_exporter(FileSpanExporter::new(&path)?)
+            .build(),
+    };
+    let tracer = provider.tracer(TRACER_NAME);
+    handle.reload(Some(
+        Box::new(tracing_opentelemetry::layer().with_tracer(tracer)) as BoxedLayer,
+    ))?;
+    if let Some(old_provider) = TRACER_PROVIDER.lock().replace(provider) {
+        if let Err(e) = old_provider.shutdown() {
+            warn!("Failed to shutdown the previous tracer provider: {:?}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Flush the pending spans and stop exporting.
+pub fn shutdown_trace_exporter() {
+    if let Some(handle) = OTEL_LAYER_HANDLE.get() {
+        let _ = handle.reload(None);
+    }
+    if let Some(provider) = TRACER_PROVIDER.lock().take() {
+        if let Err(e) = provider.shutdown() {
+            warn!("Failed to shutdown the tracer provider: {:?}", e);
+        }
+    }
+}
+
+/// Write every span as a JSON line.
+#[derive(Debug)]
+pub struct FileSpanExporter {
+    writer: BufWriter<File>,
+}
+
+impl FileSpanExporter {
+    pub fn new(path: &Path) -> anyhow::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    fn write_batch(&mut self, batch: Vec<SpanData>) -> std::io::Result<()> {
+        for span in batch {
+            let attributes: serde_json::Map<String, serde_json::Value> = span
+                .attributes
+                .iter()
+                .map(|kv| (kv.key.to_string(), kv.value.to_string().into()))
+                .collect();
+            let line = serde_json::json!({
+                "name": span.name,
+                "trace_id": span.span_context.trace_id().to_string(),
+                "span_id": span.span_context.span_id().to_string(),
+                "parent_span_id": span.parent_span_id.to_string(),
+                "start_time_unix_nano": unix_nanos(span.start_time),
+                "end_time_unix_nano": unix_nanos(span.end_time),
+                "attributes": attributes,
+            });
+            serde_json::to_writer(&mut self.writer, &line)?;
+            self.writer.write_all(b"\n")?;
+        }
+        self.writer.flush()
+    }
+}
+
+impl SpanExporter for FileSpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        let result = self
+            .write_batch(batch)
+            .map_err(|e| TraceError::from(e.to_string()));
+        Box::pin(futures::future::ready(result))
+    }
+
+    fn shutdown(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default()
+}
@@ -3,4 +3,5 @@
 
 mod test_closure_metric;
 mod test_metered_channel;
+mod test_otel;
 mod test_registry_service;
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::otel::{
+    init_tracing_subscriber, install_trace_exporter, log_filter, set_log_filter,
+    shutdown_trace_exporter, TraceExporter,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[test]
+fn file_trace_exporter() {
+    let path = std::env::temp_dir().join(format!("rooch-trace-{}.jsonl", Uuid::new_v4()));
+    init_tracing_subscriber();
+    install_trace_exporter(TraceExporter::File(path.clone()), "rooch-test", 1.0).unwrap();
+
+    {
+        let request =
+            tracing::info_span!("rpc.request", rpc.method = "rooch_executeRawTransaction");
+        let _request = request.enter();
+        let execute = tracing::info_span!(
+            "executor.execute",
+            tx_hash = "0x01",
+            tx_order = tracing::field::Empty
+        );
+        execute.record("tx_order", 1u64);
+        let _execute = execute.enter();
+    }
+    shutdown_trace_exporter();
+
+    let spans: HashMap<String, serde_json::Value> = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let span: serde_json::Value = serde_json::from_str(line).unwrap();
+            (span["name"].as_str().unwrap().to_string(), span)
+        })
+        .collect();
+    let request = &spans["rpc.request"];
+    let execute = &spans["executor.execute"];
+    assert_eq!(execute["trace_id"], request["trace_id"]);
+    assert_eq!(execute["parent_span_id"], request["span_id"]);
+    assert_eq!(execute["attributes"]["tx_hash"], "0x01");
+    assert_eq!(execute["attributes"]["tx_order"], "1");
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn reload_log_filter() {
+    init_tracing_subscriber();
+    // keep the info level for the other tests sharing the global subscriber
+    set_log_filter("info,rooch_da=debug").unwrap();
+    assert!(log_filter().unwrap().contains("rooch_da=debug"));
+    assert!(set_log_filter("rooch_da=verbose").is_err());
+    assert!(log_filter().unwrap().contains("rooch_da=debug"));
+}
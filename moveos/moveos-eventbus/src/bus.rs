@@ -6,7 +6,7 @@ use coerce::actor::message::{Handler, Message};
 use coerce::actor::{Actor, ActorRefErr, LocalActorRef};
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
 #[derive(thiserror::Error, Debug)]
@@ -306,6 +306,49 @@ impl EventBus {
         Ok(())
     }
 
+    /// Gets the subscribers of every event, including the channel, callback and actor subscribers.
+    /// The events are identified by the debug format of their `TypeId`.
+    pub fn status(&self) -> anyhow::Result<BTreeMap<String, Vec<String>>> {
+        let mut status: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        {
+            let senders = self.senders.read().map_err(|_| {
+                EventBusError::LockerReadError(format_err!("read the locker with poisoned error"))
+            })?;
+            for (event_type_id, subscribers) in senders.iter() {
+                status
+                    .entry(format!("{:?}", event_type_id))
+                    .or_default()
+                    .extend(subscribers.keys().map(|s| format!("channel:{}", s)));
+            }
+        }
+        {
+            let callbacks = self.callbacks.read().map_err(|_| {
+                EventBusError::LockerReadError(format_err!("read the locker with poisoned error"))
+            })?;
+            for (event_type_id, subscribers) in callbacks.iter() {
+                status
+                    .entry(format!("{:?}", event_type_id))
+                    .or_default()
+                    .extend(subscribers.keys().map(|s| format!("callback:{}", s)));
+            }
+        }
+        {
+            let actors = self.actors.read().map_err(|_| {
+                EventBusError::LockerReadError(format_err!("read the locker with poisoned error"))
+            })?;
+            for (event_type_id, subscribers) in actors.iter() {
+                status
+                    .entry(format!("{:?}", event_type_id))
+                    .or_default()
+                    .extend(subscribers.keys().map(|s| format!("actor:{}", s)));
+            }
+        }
+        for subscribers in status.values_mut() {
+            subscribers.sort();
+        }
+        Ok(status)
+    }
+
     /// Removes a specific subscriber's registration.
     pub fn remove_subscriber<T: 'static + Send>(&self, subscriber: &str) -> anyhow::Result<()> {
         let event_type_id = TypeId::of::<T>();
@@ -9,3 +9,4 @@ pub mod misc;
 pub mod profiler;
 pub mod render;
 pub mod report;
+pub mod tracer;
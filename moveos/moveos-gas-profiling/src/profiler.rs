@@ -514,15 +514,26 @@ impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
     }
 }
 
-pub fn new_gas_profiler<G>(action: MoveAction, base_gas_meter: G) -> GasProfiler<G> {
+/// Create the gas profiler for the action, only the function call action is supported.
+pub fn new_gas_profiler<G>(
+    action: MoveAction,
+    base_gas_meter: G,
+) -> anyhow::Result<GasProfiler<G>> {
     match action {
-        MoveAction::Script(_) => unreachable!("Script payload is not supported yet"),
-        MoveAction::Function(call) => GasProfiler::new_function(
+        MoveAction::Function(call) => Ok(GasProfiler::new_function(
             base_gas_meter,
             call.function_id.module_id,
             call.function_id.function_name,
             call.ty_args,
-        ),
-        MoveAction::ModuleBundle(_) => unreachable!("ModuleBundle payload is not supported yet"),
+        )),
+        MoveAction::Script(_) => {
+            anyhow::bail!("Script payload is not supported by the gas profiler")
+        }
+        MoveAction::ModuleBundle(_) => {
+            anyhow::bail!("ModuleBundle payload is not supported by the gas profiler")
+        }
+        MoveAction::MultiCall(_) => {
+            anyhow::bail!("MultiCall payload is not supported by the gas profiler")
+        }
     }
 }
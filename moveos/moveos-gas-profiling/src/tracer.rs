@@ -0,0 +1,721 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The execution tracer records the call frames, the executed bytecodes, the arguments, the locals
+//! and the native return values of a transaction, it wraps the gas meter like the `GasProfiler`.
+
+use move_binary_format::file_format::CodeOffset;
+use move_binary_format::file_format_common::Opcodes;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::gas_algebra::{InternalGas, NumArgs, NumBytes};
+use move_core_types::language_storage::ModuleId;
+use move_core_types::u256::U256;
+use move_vm_types::gas::{GasMeter, SimpleInstruction};
+use move_vm_types::natives::function::PartialVMResult;
+use move_vm_types::views::{TypeView, ValueView, ValueVisitor};
+use moveos_common::types::{ClassifiedGasMeter, GasStatement, SwitchableGasMeter};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{FieldKey, ObjectChange, ObjectState, StateChangeSet};
+use moveos_types::state_resolver::{StateKV, StatelessResolver};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceStep {
+    /// The global index of the step in the transaction
+    pub step: u64,
+    pub op: String,
+    /// The target offset of the branch instructions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_target: Option<CodeOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TraceEvent {
+    Step(TraceStep),
+    Call(TraceFrame),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceFrame {
+    /// The full function name, or the section name of the top level frames
+    pub name: String,
+    pub ty_args: Vec<String>,
+    pub args: Vec<String>,
+    pub is_native: bool,
+    /// The step range [step_start, step_end) of the frame, including the callee frames
+    pub step_start: u64,
+    pub step_end: u64,
+    pub gas_used: u64,
+    pub events: Vec<TraceEvent>,
+    /// The owned locals when the frame returns, the moved locals are not included
+    pub locals: Vec<String>,
+    /// The return values, only available for the native functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_values: Option<Vec<String>>,
+    #[serde(skip)]
+    balance_at_entry: u64,
+}
+
+impl TraceFrame {
+    fn new(name: String, ty_args: Vec<String>, args: Vec<String>, step: u64, balance: u64) -> Self {
+        Self {
+            name,
+            ty_args,
+            args,
+            is_native: false,
+            step_start: step,
+            step_end: step,
+            gas_used: 0,
+            events: vec![],
+            locals: vec![],
+            return_values: None,
+            balance_at_entry: balance,
+        }
+    }
+}
+
+/// An object field read by the object runtime from the state store
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ObjectRead {
+    pub state_root: H256,
+    pub field_key: FieldKey,
+    pub exists: bool,
+}
+
+/// A StatelessResolver wrapper which records the object fields read by the object runtime
+pub struct ObjectReadRecorder<'a, R> {
+    resolver: &'a R,
+    reads: RwLock<Vec<ObjectRead>>,
+}
+
+impl<'a, R> ObjectReadRecorder<'a, R>
+where
+    R: StatelessResolver,
+{
+    pub fn new(resolver: &'a R) -> Self {
+        Self {
+            resolver,
+            reads: RwLock::new(vec![]),
+        }
+    }
+
+    pub fn reads(&self) -> Vec<ObjectRead> {
+        self.reads.read().unwrap().clone()
+    }
+}
+
+impl<R> StatelessResolver for ObjectReadRecorder<'_, R>
+where
+    R: StatelessResolver,
+{
+    fn get_field_at(
+        &self,
+        state_root: H256,
+        key: &FieldKey,
+    ) -> anyhow::Result<Option<ObjectState>> {
+        let state = self.resolver.get_field_at(state_root, key)?;
+        self.reads.write().unwrap().push(ObjectRead {
+            state_root,
+            field_key: *key,
+            exists: state.is_some(),
+        });
+        Ok(state)
+    }
+
+    fn list_fields_at(
+        &self,
+        state_root: H256,
+        cursor: Option<FieldKey>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<StateKV>> {
+        self.resolver.list_fields_at(state_root, cursor, limit)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectWrite {
+    pub object_id: ObjectID,
+    /// `new`, `modify`, `delete`, or `meta` if only the metadata is changed
+    pub op: String,
+}
+
+impl ObjectWrite {
+    pub fn from_change_set(change_set: &StateChangeSet) -> Vec<ObjectWrite> {
+        let mut writes = vec![];
+        for change in change_set.changes.values() {
+            Self::collect(change, &mut writes);
+        }
+        writes
+    }
+
+    fn collect(change: &ObjectChange, writes: &mut Vec<ObjectWrite>) {
+        let op = match &change.value {
+            Some(Op::New(_)) => "new",
+            Some(Op::Modify(_)) => "modify",
+            Some(Op::Delete) => "delete",
+            None => "meta",
+        };
+        writes.push(ObjectWrite {
+            object_id: change.metadata.id.clone(),
+            op: op.to_string(),
+        });
+        for field in change.fields.values() {
+            Self::collect(field, writes);
+        }
+    }
+}
+
+/// The execution trace of a transaction, it can be exported as JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    pub tx_hash: H256,
+    /// The state root the transaction is executed on
+    pub state_root: H256,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub gas_used: u64,
+    pub total_steps: u64,
+    pub frames: Vec<TraceFrame>,
+    pub object_reads: Vec<ObjectRead>,
+    pub object_writes: Vec<ObjectWrite>,
+}
+
+#[derive(Debug, Default)]
+struct TraceState {
+    stack: Vec<TraceFrame>,
+    finished: Vec<TraceFrame>,
+    step: u64,
+    /// The latest gas balance seen by the tracer, the clones of the tracer share the state but not the gas meter
+    balance: u64,
+}
+
+impl TraceState {
+    fn record_event(&mut self, event: TraceEvent) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.events.push(event);
+        }
+    }
+
+    fn pop_frame(&mut self, balance: u64) -> Option<TraceFrame> {
+        self.balance = balance;
+        let mut frame = self.stack.pop()?;
+        frame.step_end = self.step;
+        frame.gas_used = frame.balance_at_entry.saturating_sub(balance);
+        Some(frame)
+    }
+
+    fn close_all(&mut self, balance: u64) {
+        while let Some(frame) = self.pop_frame(balance) {
+            match self.stack.last_mut() {
+                Some(parent) => parent.events.push(TraceEvent::Call(frame)),
+                None => self.finished.push(frame),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionTracer<G> {
+    base: G,
+    state: Arc<RwLock<TraceState>>,
+}
+
+macro_rules! delegate_mut {
+    ($(
+        fn $fn: ident $(<$($lt: lifetime),*>)? (&mut self $(, $arg: ident : $ty: ty)* $(,)?) -> $ret_ty: ty;
+    )*) => {
+        $(fn $fn $(<$($lt)*>)? (&mut self, $($arg: $ty),*) -> $ret_ty {
+            self.base.$fn($($arg),*)
+        })*
+    };
+}
+
+macro_rules! record_step {
+    ($(
+        [$op: expr]
+        fn $fn: ident $(<$($lt: lifetime),*>)? (&mut self $(, $arg: ident : $ty: ty)* $(,)?) -> PartialVMResult<()>;
+    )*) => {
+        $(fn $fn $(<$($lt)*>)? (&mut self, $($arg: $ty),*) -> PartialVMResult<()> {
+            #[allow(unused)]
+            use Opcodes::*;
+
+            self.record_step($op, None);
+            self.base.$fn($($arg),*)
+        })*
+    };
+}
+
+impl<G: GasMeter> ExecutionTracer<G> {
+    pub fn new(base: G) -> Self {
+        let balance = base.balance_internal().into();
+        Self {
+            base,
+            state: Arc::new(RwLock::new(TraceState {
+                balance,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Start a new top level frame, the previous top level frame is finished.
+    /// The VM does not notify the entry function call, so the caller names the top level frames.
+    pub fn enter_section(&self, name: impl Into<String>) {
+        let mut state = self.state.write().unwrap();
+        let balance = state.balance;
+        state.close_all(balance);
+        let step = state.step;
+        state
+            .stack
+            .push(TraceFrame::new(name.into(), vec![], vec![], step, balance));
+    }
+
+    /// Finish the trace and return the top level frames and the total steps
+    pub fn finish(&self) -> (Vec<TraceFrame>, u64) {
+        let mut state = self.state.write().unwrap();
+        let balance = state.balance;
+        state.close_all(balance);
+        (std::mem::take(&mut state.finished), state.step)
+    }
+
+    fn record_step(&mut self, op: Opcodes, branch_target: Option<CodeOffset>) {
+        let mut state = self.state.write().unwrap();
+        state.balance = self.base.balance_internal().into();
+        let step = state.step;
+        state.step += 1;
+        state.record_event(TraceEvent::Step(TraceStep {
+            step,
+            op: format!("{:?}", op),
+            branch_target,
+        }));
+    }
+
+    fn push_frame(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        ty_args: Vec<String>,
+        args: Vec<String>,
+    ) {
+        let balance = self.base.balance_internal().into();
+        let mut state = self.state.write().unwrap();
+        let step = state.step;
+        state.stack.push(TraceFrame::new(
+            format!("{}::{}", module_id.short_str_lossless(), func_name),
+            ty_args,
+            args,
+            step,
+            balance,
+        ));
+    }
+}
+
+impl<G: GasMeter> GasMeter for ExecutionTracer<G> {
+    delegate_mut! {
+        fn charge_ld_const_after_deserialization(&mut self, val: impl ValueView)
+            -> PartialVMResult<()>;
+
+        fn charge_native_function_before_execution(
+            &mut self,
+            ty_args: impl ExactSizeIterator<Item = impl TypeView> + Clone,
+            args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        ) -> PartialVMResult<()>;
+
+        fn charge_load_resource(
+            &mut self,
+            addr: AccountAddress,
+            ty: impl TypeView,
+            val: Option<impl ValueView>,
+            bytes_loaded: NumBytes,
+        ) -> PartialVMResult<()>;
+    }
+
+    record_step! {
+        [POP]
+        fn charge_pop(&mut self, popped_val: impl ValueView) -> PartialVMResult<()>;
+
+        [LD_CONST]
+        fn charge_ld_const(&mut self, size: NumBytes) -> PartialVMResult<()>;
+
+        [COPY_LOC]
+        fn charge_copy_loc(&mut self, val: impl ValueView) -> PartialVMResult<()>;
+
+        [MOVE_LOC]
+        fn charge_move_loc(&mut self, val: impl ValueView) -> PartialVMResult<()>;
+
+        [ST_LOC]
+        fn charge_store_loc(&mut self, val: impl ValueView) -> PartialVMResult<()>;
+
+        [PACK]
+        fn charge_pack(
+            &mut self,
+            is_generic: bool,
+            args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        ) -> PartialVMResult<()>;
+
+        [UNPACK]
+        fn charge_unpack(
+            &mut self,
+            is_generic: bool,
+            args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        ) -> PartialVMResult<()>;
+
+        [READ_REF]
+        fn charge_read_ref(&mut self, val: impl ValueView) -> PartialVMResult<()>;
+
+        [WRITE_REF]
+        fn charge_write_ref(
+            &mut self,
+            new_val: impl ValueView,
+            old_val: impl ValueView,
+        ) -> PartialVMResult<()>;
+
+        [EQ]
+        fn charge_eq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()>;
+
+        [NEQ]
+        fn charge_neq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()>;
+
+        [
+            match (is_mut, is_generic) {
+                (false, false) => IMM_BORROW_GLOBAL,
+                (false, true) => IMM_BORROW_GLOBAL_GENERIC,
+                (true, false) => MUT_BORROW_GLOBAL,
+                (true, true) => MUT_BORROW_GLOBAL_GENERIC
+            }
+        ]
+        fn charge_borrow_global(
+            &mut self,
+            is_mut: bool,
+            is_generic: bool,
+            ty: impl TypeView,
+            is_success: bool,
+        ) -> PartialVMResult<()>;
+
+        [if is_generic { EXISTS_GENERIC } else { EXISTS }]
+        fn charge_exists(
+            &mut self,
+            is_generic: bool,
+            ty: impl TypeView,
+            exists: bool,
+        ) -> PartialVMResult<()>;
+
+        [if is_generic { MOVE_FROM_GENERIC } else { MOVE_FROM }]
+        fn charge_move_from(
+            &mut self,
+            is_generic: bool,
+            ty: impl TypeView,
+            val: Option<impl ValueView>,
+        ) -> PartialVMResult<()>;
+
+        [if is_generic { MOVE_TO_GENERIC } else { MOVE_TO }]
+        fn charge_move_to(
+            &mut self,
+            is_generic: bool,
+            ty: impl TypeView,
+            val: impl ValueView,
+            is_success: bool,
+        ) -> PartialVMResult<()>;
+
+        [VEC_PACK]
+        fn charge_vec_pack<'a>(
+            &mut self,
+            ty: impl TypeView + 'a,
+            args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        ) -> PartialVMResult<()>;
+
+        [VEC_LEN]
+        fn charge_vec_len(&mut self, ty: impl TypeView) -> PartialVMResult<()>;
+
+        [if is_mut { VEC_MUT_BORROW } else { VEC_IMM_BORROW }]
+        fn charge_vec_borrow(
+            &mut self,
+            is_mut: bool,
+            ty: impl TypeView,
+            is_success: bool,
+        ) -> PartialVMResult<()>;
+
+        [VEC_PUSH_BACK]
+        fn charge_vec_push_back(
+            &mut self,
+            ty: impl TypeView,
+            val: impl ValueView,
+        ) -> PartialVMResult<()>;
+
+        [VEC_POP_BACK]
+        fn charge_vec_pop_back(
+            &mut self,
+            ty: impl TypeView,
+            val: Option<impl ValueView>,
+        ) -> PartialVMResult<()>;
+
+        [VEC_UNPACK]
+        fn charge_vec_unpack(
+            &mut self,
+            ty: impl TypeView,
+            expect_num_elements: NumArgs,
+            elems: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        ) -> PartialVMResult<()>;
+
+        [VEC_SWAP]
+        fn charge_vec_swap(&mut self, ty: impl TypeView) -> PartialVMResult<()>;
+    }
+
+    fn balance_internal(&self) -> InternalGas {
+        self.base.balance_internal()
+    }
+
+    fn charge_simple_instr(&mut self, instr: SimpleInstruction) -> PartialVMResult<()> {
+        self.record_step(instr.to_opcode(), None);
+        self.base.charge_simple_instr(instr)
+    }
+
+    fn charge_br_true(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.record_step(Opcodes::BR_TRUE, target_offset);
+        self.base.charge_br_true(target_offset)
+    }
+
+    fn charge_br_false(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.record_step(Opcodes::BR_FALSE, target_offset);
+        self.base.charge_br_false(target_offset)
+    }
+
+    fn charge_branch(&mut self, target_offset: CodeOffset) -> PartialVMResult<()> {
+        self.record_step(Opcodes::BRANCH, Some(target_offset));
+        self.base.charge_branch(target_offset)
+    }
+
+    fn charge_call(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.record_step(Opcodes::CALL, None);
+        let arg_values = args.clone().map(|arg| render_value(&arg)).collect();
+        self.push_frame(module_id, func_name, vec![], arg_values);
+        self.base
+            .charge_call(module_id, func_name, args, num_locals)
+    }
+
+    fn charge_call_generic(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        ty_args: impl ExactSizeIterator<Item = impl TypeView> + Clone,
+        args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.record_step(Opcodes::CALL_GENERIC, None);
+        let ty_tags = ty_args
+            .clone()
+            .map(|ty| ty.to_type_tag().to_canonical_string())
+            .collect();
+        let arg_values = args.clone().map(|arg| render_value(&arg)).collect();
+        self.push_frame(module_id, func_name, ty_tags, arg_values);
+        self.base
+            .charge_call_generic(module_id, func_name, ty_args, args, num_locals)
+    }
+
+    fn charge_native_function(
+        &mut self,
+        amount: InternalGas,
+        ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView> + Clone>,
+    ) -> PartialVMResult<()> {
+        let return_values = ret_vals
+            .clone()
+            .map(|vals| vals.map(|val| render_value(&val)).collect());
+        let res = self.base.charge_native_function(amount, ret_vals);
+        // The native function frame is pushed by `charge_call`, and the native function has no `Ret` instruction.
+        let balance = self.base.balance_internal().into();
+        let mut state = self.state.write().unwrap();
+        if state.stack.len() > 1 {
+            if let Some(mut frame) = state.pop_frame(balance) {
+                frame.is_native = true;
+                frame.return_values = return_values;
+                state.record_event(TraceEvent::Call(frame));
+            }
+        }
+        res
+    }
+
+    fn charge_drop_frame(
+        &mut self,
+        locals: impl Iterator<Item = impl ValueView> + Clone,
+    ) -> PartialVMResult<()> {
+        let local_values = locals.clone().map(|val| render_value(&val)).collect();
+        let res = self.base.charge_drop_frame(locals);
+        // The frame is dropped after the `Ret` instruction, the top level frame is finished by `enter_section` or `finish`.
+        let balance = self.base.balance_internal().into();
+        let mut state = self.state.write().unwrap();
+        if let Some(frame) = state.stack.last_mut() {
+            frame.locals = local_values;
+        }
+        if state.stack.len() > 1 {
+            if let Some(frame) = state.pop_frame(balance) {
+                state.record_event(TraceEvent::Call(frame));
+            }
+        }
+        res
+    }
+}
+
+impl<G: GasMeter + ClassifiedGasMeter> ClassifiedGasMeter for ExecutionTracer<G> {
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.base.charge_execution(gas_cost)
+    }
+
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.base.charge_io_write(data_size)
+    }
+
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.base.check_constrains(max_gas_amount)
+    }
+
+    fn gas_statement(&self) -> GasStatement {
+        self.base.gas_statement()
+    }
+}
+
+impl<G: GasMeter + SwitchableGasMeter> SwitchableGasMeter for ExecutionTracer<G> {
+    fn stop_metering(&mut self) {
+        self.base.stop_metering()
+    }
+
+    fn start_metering(&mut self) {
+        self.base.start_metering()
+    }
+
+    fn is_metering(&self) -> bool {
+        self.base.is_metering()
+    }
+}
+
+/// Render a runtime value in a compact form, structs are rendered as `{..}` and references as `&..`.
+pub fn render_value(value: &impl ValueView) -> String {
+    let mut renderer = ValueRenderer::default();
+    value.visit(&mut renderer);
+    renderer.finish()
+}
+
+#[derive(Default)]
+struct ValueRenderer {
+    out: String,
+    /// The open containers, (depth, closing bracket)
+    containers: Vec<(usize, char)>,
+    need_separator: bool,
+}
+
+impl ValueRenderer {
+    fn begin(&mut self, depth: usize) {
+        while let Some((container_depth, closer)) = self.containers.last().cloned() {
+            if container_depth < depth {
+                break;
+            }
+            self.out.push(closer);
+            self.containers.pop();
+            self.need_separator = true;
+        }
+        if self.need_separator {
+            self.out.push_str(", ");
+        }
+    }
+
+    fn primitive(&mut self, depth: usize, value: impl ToString) {
+        self.begin(depth);
+        self.out.push_str(&value.to_string());
+        self.need_separator = true;
+    }
+
+    fn container(&mut self, depth: usize, opener: char, closer: char) -> bool {
+        self.begin(depth);
+        self.out.push(opener);
+        self.containers.push((depth, closer));
+        self.need_separator = false;
+        true
+    }
+
+    fn finish(mut self) -> String {
+        while let Some((_, closer)) = self.containers.pop() {
+            self.out.push(closer);
+        }
+        self.out
+    }
+}
+
+impl ValueVisitor for ValueRenderer {
+    fn visit_u8(&mut self, depth: usize, val: u8) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_u16(&mut self, depth: usize, val: u16) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_u32(&mut self, depth: usize, val: u32) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_u64(&mut self, depth: usize, val: u64) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_u128(&mut self, depth: usize, val: u128) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_u256(&mut self, depth: usize, val: U256) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_bool(&mut self, depth: usize, val: bool) {
+        self.primitive(depth, val)
+    }
+
+    fn visit_address(&mut self, depth: usize, val: AccountAddress) {
+        self.primitive(depth, val.to_hex_literal())
+    }
+
+    fn visit_struct(&mut self, depth: usize, _len: usize) -> bool {
+        self.container(depth, '{', '}')
+    }
+
+    fn visit_vec(&mut self, depth: usize, _len: usize) -> bool {
+        self.container(depth, '[', ']')
+    }
+
+    fn visit_ref(&mut self, depth: usize, _is_global: bool) -> bool {
+        self.begin(depth);
+        self.out.push('&');
+        self.need_separator = false;
+        true
+    }
+
+    fn visit_vec_u8(&mut self, depth: usize, vals: &[u8]) {
+        self.primitive(depth, format!("0x{}", hex::encode(vals)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_vm_types::values::{Struct, Value};
+
+    #[test]
+    fn test_render_value() {
+        assert_eq!(render_value(&Value::u64(42)), "42");
+        assert_eq!(render_value(&Value::vector_u8(vec![1, 2])), "0x0102");
+        let value = Value::struct_(Struct::pack(vec![
+            Value::bool(true),
+            Value::struct_(Struct::pack(vec![Value::u8(1), Value::u8(2)])),
+            Value::address(AccountAddress::ONE),
+        ]));
+        assert_eq!(render_value(&value), "{true, {1, 2}, 0x1}");
+    }
+}
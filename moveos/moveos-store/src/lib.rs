@@ -7,7 +7,7 @@
 use crate::config_store::{ConfigDBStore, ConfigStore, STARTUP_INFO_KEY};
 use crate::event_store::{EventDBStore, EventStore};
 use crate::state_store::statedb::StateDBStore;
-use crate::state_store::{nodes_to_write_batch, NodeDBStore};
+use crate::state_store::{nodes_to_write_batch, NodeDBStore, NodeRecorder};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::{Error, Result};
@@ -115,6 +115,23 @@ impl MoveOSStore {
         Ok(store)
     }
 
+    /// Returns a MoveOSStore on the same instance which records every state node read by the `recorder`.
+    /// The state cache is not shared with this store, so all the state reads go to the node store.
+    /// The `registry` should be a new one, the metrics of the state store are registered to it.
+    pub fn with_node_recorder(&self, recorder: NodeRecorder, registry: &Registry) -> Self {
+        let store_config = MoveOSStoreConfig::default();
+        let node_store = self.node_store.with_recorder(recorder);
+        let state_store =
+            StateDBStore::new(node_store.clone(), registry, store_config.state_cache_size);
+        Self {
+            node_store,
+            event_store: self.event_store.clone(),
+            transaction_store: self.transaction_store.clone(),
+            config_store: self.config_store.clone(),
+            state_store,
+        }
+    }
+
     pub fn mock_moveos_store() -> Result<(Self, DataDirPath)> {
         let tmpdir = moveos_config::temp_dir();
         let registry = prometheus::Registry::new();
@@ -9,13 +9,76 @@ use anyhow::Result;
 use moveos_types::h256::H256;
 use raw_store::rocks::batch::WriteBatch;
 use raw_store::CodecKVStore;
-use raw_store::{derive_store, WriteOp};
+use raw_store::{ColumnFamily, ColumnFamilyName, InnerStore, SchemaStore, StoreInstance, WriteOp};
 use smt::{NodeReader, NodeWriter};
 use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
 
-derive_store!(NodeDBStore, H256, Vec<u8>, STATE_NODE_COLUMN_FAMILY_NAME);
+/// Record the state nodes read from the NodeDBStore,
+/// the recorded nodes are the witness to re-execute a transaction without the full state.
+#[derive(Clone, Default)]
+pub struct NodeRecorder {
+    nodes: Arc<RwLock<BTreeMap<H256, Vec<u8>>>>,
+}
+
+impl NodeRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, hash: H256, node: Vec<u8>) {
+        self.nodes
+            .write()
+            .expect("NodeRecorder lock should not be poisoned")
+            .insert(hash, node);
+    }
+
+    /// The recorded nodes
+    pub fn nodes(&self) -> BTreeMap<H256, Vec<u8>> {
+        self.nodes
+            .read()
+            .expect("NodeRecorder lock should not be poisoned")
+            .clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct NodeDBStore {
+    store: InnerStore<Self>,
+    recorder: Option<NodeRecorder>,
+}
+
+impl ColumnFamily for NodeDBStore {
+    type Key = H256;
+    type Value = Vec<u8>;
+
+    fn name() -> ColumnFamilyName {
+        STATE_NODE_COLUMN_FAMILY_NAME
+    }
+}
+
+impl SchemaStore for NodeDBStore {
+    fn get_store(&self) -> &InnerStore<Self> {
+        &self.store
+    }
+}
 
 impl NodeDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        Self {
+            store: InnerStore::new(instance),
+            recorder: None,
+        }
+    }
+
+    /// Returns a NodeDBStore on the same instance which records every node read by the `recorder`.
+    pub fn with_recorder(&self, recorder: NodeRecorder) -> Self {
+        Self {
+            store: self.store.clone(),
+            recorder: Some(recorder),
+        }
+    }
+
     pub fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
         self.put_raw(key.as_bytes().to_vec(), node)
     }
@@ -42,7 +105,11 @@ pub fn nodes_to_write_batch(nodes: BTreeMap<H256, Vec<u8>>) -> WriteBatch {
 
 impl NodeReader for NodeDBStore {
     fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
-        self.get_raw(hash.as_bytes())
+        let node = self.get_raw(hash.as_bytes())?;
+        if let (Some(recorder), Some(node)) = (&self.recorder, &node) {
+            recorder.record(*hash, node.clone());
+        }
+        Ok(node)
     }
 }
 
@@ -1,10 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::NodeRecorder;
 use crate::{MoveOSStore, StoreMeta};
 use anyhow::Result;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
+use moveos_types::state_resolver::StatelessResolver;
 use moveos_types::test_utils::random_state_change_set;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::RocksDB;
@@ -67,6 +69,40 @@ async fn test_statedb_state_root() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_node_recorder() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let mut change_set = random_state_change_set();
+    moveos_store
+        .get_state_store()
+        .apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+    let key = *change_set.changes.keys().next().unwrap();
+
+    let recorder = NodeRecorder::new();
+    let recording_store =
+        moveos_store.with_node_recorder(recorder.clone(), &prometheus::Registry::new());
+    let state = recording_store
+        .get_state_store()
+        .get_field_at(state_root, &key)?;
+    assert!(state.is_some());
+    let nodes = recorder.nodes();
+    assert!(!nodes.is_empty());
+
+    // The recorded nodes are enough to read the same field without the full state
+    let (witness_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    witness_store.get_state_node_store().write_nodes(nodes)?;
+    assert_eq!(
+        witness_store
+            .get_state_store()
+            .get_field_at(state_root, &key)?,
+        state
+    );
+    Ok(())
+}
+
 // #[tokio::test]
 // async fn test_child_state_db_dump_and_apply() -> Result<()> {
 //     let mut moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 pub const MODULE_NAME: &IdentStr = ident_str!("features");
 pub const VALUE_SIZE_GAS_FEATURE: u64 = 7;
 pub const COMPATIBILITY_CHECKER_V2: u64 = 8;
+pub const SAT_RANGE_TRACKING: u64 = 9;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct FeatureStore {
@@ -42,6 +43,10 @@ impl FeatureStore {
     pub fn has_compatibility_checker_v2(&self) -> bool {
         self.contains_feature(COMPATIBILITY_CHECKER_V2)
     }
+
+    pub fn has_sat_range_tracking(&self) -> bool {
+        self.contains_feature(SAT_RANGE_TRACKING)
+    }
 }
 
 impl MoveStructType for FeatureStore {
@@ -194,6 +194,25 @@ impl MoveStructState for TxContext {
     }
 }
 
+/// The account that pays the gas of the transaction, see `moveos_std::tx_context::GasPaymentAccount`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasPaymentAccount {
+    pub account: AccountAddress,
+    pub pay_gas_by_module_account: bool,
+}
+
+impl MoveStructType for GasPaymentAccount {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = TX_CONTEXT_MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
+}
+
+impl MoveStructState for GasPaymentAccount {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::Address, MoveTypeLayout::Bool])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use move_core_types::value::MoveValue;
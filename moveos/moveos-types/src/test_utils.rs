@@ -9,7 +9,10 @@ use crate::{
         table::TablePlaceholder,
     },
     state::{FieldKey, ObjectChange, ObjectState, StateChangeSet},
-    transaction::{FunctionCall, MoveAction, ScriptCall, VerifiedMoveAction},
+    transaction::{
+        FunctionCall, MoveAction, MultiCallArgument, MultiCallFunction, ScriptCall,
+        VerifiedMoveAction,
+    },
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -28,6 +31,8 @@ pub enum MoveActionType {
     Function,
     //Publish Move modules
     ModuleBundle,
+    //Execute multiple Move functions
+    MultiCall,
 }
 
 impl MoveActionType {
@@ -36,6 +41,7 @@ impl MoveActionType {
             MoveActionType::Script => 0,
             MoveActionType::Function => 1,
             MoveActionType::ModuleBundle => 2,
+            MoveActionType::MultiCall => 3,
         }
     }
 }
@@ -76,6 +82,8 @@ pub fn random_move_action_type() -> MoveActionType {
     let n = rng.gen_range(1..=100);
     if n % 5 == 0 {
         MoveActionType::ModuleBundle
+    } else if n % 7 == 0 {
+        MoveActionType::MultiCall
     } else if n % 3 == 0 {
         MoveActionType::Script
     } else {
@@ -88,6 +96,8 @@ pub fn random_move_action_with_action_type(action_type: u8) -> MoveAction {
         random_move_action_script()
     } else if MoveActionType::Function.action_type() == action_type {
         random_move_action_function()
+    } else if MoveActionType::MultiCall.action_type() == action_type {
+        random_move_action_multi_call()
     } else {
         random_move_action_module_bundle()
     }
@@ -101,6 +111,21 @@ pub fn random_move_action_function() -> MoveAction {
     MoveAction::Function(random_function_call())
 }
 
+pub fn random_move_action_multi_call() -> MoveAction {
+    let mut calls = vec![];
+    let mut rng = thread_rng();
+    for n in 0..rng.gen_range(1..=5) {
+        let mut call = MultiCallFunction::from(random_function_call());
+        if n > 0 {
+            call.args
+                .push(MultiCallArgument::new_result(rng.gen_range(0..n), 0));
+        }
+        calls.push(call);
+    }
+
+    MoveAction::new_multi_call(calls)
+}
+
 pub fn random_move_action_module_bundle() -> MoveAction {
     let mut module_bundle = vec![];
     let mut rng = thread_rng();
@@ -111,6 +111,116 @@ impl Arbitrary for FunctionCall {
     }
 }
 
+/// The max number of functions in a `MultiCall`
+pub const MAX_MULTI_CALL_FUNCTIONS: usize = 32;
+
+/// The argument of a function in a `MultiCall`
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub enum MultiCallArgument {
+    /// BCS serialized argument, resolved the same way as the `FunctionCall` arguments
+    Pure(Vec<u8>),
+    /// The `result_index`th return value of the `call_index`th function in the same `MultiCall`
+    Result { call_index: u16, result_index: u16 },
+}
+
+impl MultiCallArgument {
+    pub fn new_result(call_index: u16, result_index: u16) -> Self {
+        Self::Result {
+            call_index,
+            result_index,
+        }
+    }
+}
+
+impl Display for MultiCallArgument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MultiCallArgument::Pure(arg) => write!(f, "0x{}", hex::encode(arg)),
+            MultiCallArgument::Result {
+                call_index,
+                result_index,
+            } => write!(f, "result({}, {})", call_index, result_index),
+        }
+    }
+}
+
+/// A Move function call in a `MultiCall`
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MultiCallFunction {
+    pub function_id: FunctionId,
+    pub ty_args: Vec<TypeTag>,
+    pub args: Vec<MultiCallArgument>,
+}
+
+impl MultiCallFunction {
+    pub fn new(
+        function_id: FunctionId,
+        ty_args: Vec<TypeTag>,
+        args: Vec<MultiCallArgument>,
+    ) -> Self {
+        Self {
+            function_id,
+            ty_args,
+            args,
+        }
+    }
+}
+
+impl From<FunctionCall> for MultiCallFunction {
+    fn from(call: FunctionCall) -> Self {
+        Self {
+            function_id: call.function_id,
+            ty_args: call.ty_args,
+            args: call.args.into_iter().map(MultiCallArgument::Pure).collect(),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl Arbitrary for MultiCallFunction {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (
+            any::<FunctionCall>(),
+            prop::collection::vec(any::<MultiCallArgument>(), 0..5),
+        )
+            .prop_map(|(call, args)| MultiCallFunction {
+                function_id: call.function_id,
+                ty_args: call.ty_args,
+                args,
+            })
+            .boxed()
+    }
+}
+
+/// Call multiple Move functions in one transaction, the functions are executed in order in the same session.
+/// The return values of the previous functions can be used as the arguments of the later functions.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MultiCall {
+    pub calls: Vec<MultiCallFunction>,
+}
+
+impl MultiCall {
+    pub fn new(calls: Vec<MultiCallFunction>) -> Self {
+        Self { calls }
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl Arbitrary for MultiCall {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        prop::collection::vec(any::<MultiCallFunction>(), 0..3)
+            .prop_map(|calls| MultiCall { calls })
+            .boxed()
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub enum MoveAction {
@@ -120,6 +230,8 @@ pub enum MoveAction {
     Function(FunctionCall),
     //Publish Move modules
     ModuleBundle(Vec<Vec<u8>>),
+    //Execute multiple Move functions in order
+    MultiCall(MultiCall),
 }
 
 impl MoveAction {
@@ -128,6 +240,7 @@ impl MoveAction {
             MoveAction::Script(_) => 0,
             MoveAction::Function(_) => 1,
             MoveAction::ModuleBundle(_) => 2,
+            MoveAction::MultiCall(_) => 3,
         }
     }
 
@@ -136,6 +249,7 @@ impl MoveAction {
             MoveAction::Script(_) => "Script".to_string(),
             MoveAction::Function(_) => "Function".to_string(),
             MoveAction::ModuleBundle(_) => "ModuleBundle".to_string(),
+            MoveAction::MultiCall(_) => "MultiCall".to_string(),
         }
     }
 
@@ -153,6 +267,9 @@ impl MoveAction {
             args,
         })
     }
+    pub fn new_multi_call(calls: Vec<MultiCallFunction>) -> Self {
+        Self::MultiCall(MultiCall { calls })
+    }
     pub fn new_script_call(code: Vec<u8>, ty_args: Vec<TypeTag>, args: Vec<Vec<u8>>) -> Self {
         Self::Script(ScriptCall {
             code,
@@ -180,6 +297,7 @@ impl From<VerifiedMoveAction> for MoveAction {
                 module_bundle,
                 init_function_modules: _init_function_modules,
             } => MoveAction::ModuleBundle(module_bundle),
+            VerifiedMoveAction::MultiCall { multi_call } => MoveAction::MultiCall(multi_call),
         }
     }
 }
@@ -190,6 +308,12 @@ impl From<FunctionCall> for MoveAction {
     }
 }
 
+impl From<MultiCall> for MoveAction {
+    fn from(multi_call: MultiCall) -> Self {
+        MoveAction::MultiCall(multi_call)
+    }
+}
+
 impl From<ScriptCall> for MoveAction {
     fn from(call: ScriptCall) -> Self {
         MoveAction::Script(call)
@@ -229,6 +353,21 @@ impl Display for MoveAction {
                 }
                 write!(f, "MoveAction::ModuleBundle( {:?} )", module_list)
             }
+            MoveAction::MultiCall(multi_call) => {
+                let mut call_list = vec![];
+                for call in multi_call.calls.iter() {
+                    let arg_list = call
+                        .args
+                        .iter()
+                        .map(|arg| arg.to_string())
+                        .collect::<Vec<_>>();
+                    call_list.push(format!(
+                        "FunctionCall( function_id: {},  type_args: {:?}, args: {:?})",
+                        call.function_id, call.ty_args, arg_list
+                    ));
+                }
+                write!(f, "MoveAction::MultiCall( {:?} )", call_list)
+            }
         }
     }
 }
@@ -247,6 +386,9 @@ pub enum VerifiedMoveAction {
         module_bundle: Vec<Vec<u8>>,
         init_function_modules: Vec<ModuleId>,
     },
+    MultiCall {
+        multi_call: MultiCall,
+    },
 }
 
 impl Display for VerifiedMoveAction {
@@ -276,6 +418,9 @@ impl Display for VerifiedMoveAction {
                     init_function_modules.len()
                 )
             }
+            VerifiedMoveAction::MultiCall { multi_call } => {
+                write!(f, "MultiCall(calls: {})", multi_call.calls.len())
+            }
         }
     }
 }
@@ -579,7 +724,9 @@ impl TransactionExecutionInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::MoveAction;
+    use super::{MoveAction, MultiCallArgument, MultiCallFunction};
+    use crate::move_types::FunctionId;
+    use move_core_types::{account_address::AccountAddress, language_storage::ModuleId};
     use proptest::prelude::*;
 
     proptest! {
@@ -590,4 +737,29 @@ mod tests {
             assert_eq!(input, deserialized);
         }
     }
+
+    #[test]
+    fn test_multi_call_bcs_layout() {
+        let function_id = FunctionId::new(
+            ModuleId::new(AccountAddress::ONE, "empty".parse().unwrap()),
+            "empty".parse().unwrap(),
+        );
+        let action = MoveAction::new_multi_call(vec![
+            MultiCallFunction::new(
+                function_id.clone(),
+                vec![],
+                vec![MultiCallArgument::Pure(vec![1])],
+            ),
+            MultiCallFunction::new(
+                function_id,
+                vec![],
+                vec![MultiCallArgument::new_result(0, 1)],
+            ),
+        ]);
+        let bytes = bcs::to_bytes(&action).unwrap();
+        // The MultiCall variant index, keep it compatible with the TypeScript SDK
+        assert_eq!(bytes[0], 3);
+        assert_eq!(bytes[bytes.len() - 5..], [1, 0, 0, 1, 0]);
+        assert_eq!(action.action_type(), 3);
+    }
 }
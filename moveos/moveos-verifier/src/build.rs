@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::lint::{LintConfig, LintDiagnostic};
 use crate::metadata::{
-    run_extended_checks, RuntimeModuleMetadataV1, DATA_STRUCT_ATTRIBUTE,
-    DATA_STRUCT_FUNC_ATTRIBUTE, PRIVATE_GENERICS_ATTRIBUTE,
+    run_extended_checks, run_extended_checks_with_lints, RuntimeModuleMetadataV1,
+    DATA_STRUCT_ATTRIBUTE, DATA_STRUCT_FUNC_ATTRIBUTE, PRIVATE_GENERICS_ATTRIBUTE,
 };
 use codespan_reporting::diagnostic::Severity;
 use itertools::Itertools;
@@ -308,6 +309,27 @@ pub fn run_verifier<P: AsRef<Path>>(
     build_config: BuildConfig,
     package: &mut CompiledPackage,
 ) -> anyhow::Result<bool> {
+    run_verifier_inner(package_path, build_config, package, None)?;
+    Ok(true)
+}
+
+/// Run the verifier together with the lints configured by `lint_config`,
+/// returns the lint diagnostics. The build fails if any lint is denied.
+pub fn run_verifier_with_lints<P: AsRef<Path>>(
+    package_path: P,
+    build_config: BuildConfig,
+    package: &mut CompiledPackage,
+    lint_config: &LintConfig,
+) -> anyhow::Result<Vec<LintDiagnostic>> {
+    run_verifier_inner(package_path, build_config, package, Some(lint_config))
+}
+
+fn run_verifier_inner<P: AsRef<Path>>(
+    package_path: P,
+    build_config: BuildConfig,
+    package: &mut CompiledPackage,
+    lint_config: Option<&LintConfig>,
+) -> anyhow::Result<Vec<LintDiagnostic>> {
     let model = build_model(
         package_path.as_ref(),
         build_config.additional_named_addresses,
@@ -316,7 +338,10 @@ pub fn run_verifier<P: AsRef<Path>>(
     )
     .unwrap();
 
-    let runtime_metadata = run_extended_checks(&model);
+    let (runtime_metadata, lints) = match lint_config {
+        Some(lint_config) => run_extended_checks_with_lints(&model, lint_config),
+        None => (run_extended_checks(&model), vec![]),
+    };
 
     if model.diag_count(Severity::Warning) > 0 {
         let mut error_writer = StandardStream::stderr(ColorChoice::Auto);
@@ -335,7 +360,7 @@ pub fn run_verifier<P: AsRef<Path>>(
         runtime_metadata,
     );
 
-    Ok(true)
+    Ok(lints)
 }
 
 pub fn inject_runtime_metadata<P: AsRef<Path>>(
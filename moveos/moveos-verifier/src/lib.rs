@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod build;
+pub mod lint;
 pub mod metadata;
+pub mod upgrade;
 pub mod verifier;
 
 pub mod check_complexity;
@@ -0,0 +1,583 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Warning-level lints for Rooch-specific hazards in Move packages.
+//! The lints run in the same pass as the extended checks, and can be configured
+//! in the `[lints]` section of `Move.toml`:
+//!
+//! ```toml
+//! [lints]
+//! unchecked_take_object = "deny"
+//! missing_view = "warn"
+//! ```
+
+use anyhow::{bail, Result};
+use move_binary_format::file_format::Visibility;
+use move_model::ast::Attribute;
+use move_model::model::{FunctionEnv, GlobalEnv, Loc, ModuleEnv};
+use move_model::ty::{PrimitiveType, ReferenceKind, Type};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::metadata::PRIVATE_GENERICS_ATTRIBUTE;
+
+pub const LINTS_SECTION: &str = "lints";
+pub const VIEW_ATTRIBUTE: &str = "view";
+
+const OBJECT_STRUCT: &str = "0x2::object::Object";
+const OBJECT_ID_STRUCT: &str = "0x2::object::ObjectID";
+const TABLE_STRUCT: &str = "0x2::table::Table";
+const TAKE_OBJECT_EXTEND_FUNCTION: &str = "0x2::object::take_object_extend";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lint {
+    /// An entry function takes `&mut Object<T>` without a signer, the object may be a shared object.
+    SharedObjectMutation,
+    /// A table stores vectors as values, the vectors can grow unbounded.
+    TableVectorValue,
+    /// A public function takes out an object by id via `object::take_object_extend` without a signer.
+    UncheckedTakeObject,
+    /// A public function is read-only but not marked with `#[view]`.
+    MissingView,
+    /// The `#[private_generics]` annotation has no effect on a non-public function.
+    UnusedPrivateGenerics,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 5] = [
+        Lint::SharedObjectMutation,
+        Lint::TableVectorValue,
+        Lint::UncheckedTakeObject,
+        Lint::MissingView,
+        Lint::UnusedPrivateGenerics,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::SharedObjectMutation => "shared_object_mutation",
+            Lint::TableVectorValue => "table_vector_value",
+            Lint::UncheckedTakeObject => "unchecked_take_object",
+            Lint::MissingView => "missing_view",
+            Lint::UnusedPrivateGenerics => "unused_private_generics",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Lint::SharedObjectMutation => {
+                "Entry function takes `&mut Object<T>` without a signer, anyone can pass a shared object to it"
+            }
+            Lint::TableVectorValue => {
+                "Table stores vectors as values, the vectors can grow unbounded and make the access expensive"
+            }
+            Lint::UncheckedTakeObject => {
+                "Public function takes out an object by id via `object::take_object_extend` without checking the owner"
+            }
+            Lint::MissingView => "Public read-only function is not marked with `#[view]`",
+            Lint::UnusedPrivateGenerics => {
+                "`#[private_generics]` has no effect on a non-public function"
+            }
+        }
+    }
+
+    /// The `missing_view` lint is noisy on existing packages, so it is opt-in.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            Lint::MissingView => LintLevel::Allow,
+            _ => LintLevel::Warn,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match Lint::ALL.iter().find(|lint| lint.name() == s) {
+            Some(lint) => Ok(*lint),
+            None => bail!("Unknown lint: {}", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl FromStr for LintLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => bail!(
+                "Invalid lint level: {}, expected one of allow, warn, deny",
+                s
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    levels: BTreeMap<Lint, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels
+            .get(&lint)
+            .copied()
+            .unwrap_or_else(|| lint.default_level())
+    }
+
+    pub fn set_level(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Load the lint config from the `[lints]` section of the `Move.toml` in the package.
+    pub fn from_package<P: AsRef<Path>>(package_path: P) -> Result<Self> {
+        let manifest = std::fs::read_to_string(package_path.as_ref().join("Move.toml"))?;
+        Self::from_manifest_str(&manifest)
+    }
+
+    pub fn from_manifest_str(manifest: &str) -> Result<Self> {
+        let manifest = manifest.parse::<toml::Value>()?;
+        let mut config = LintConfig::default();
+        let Some(section) = manifest.get(LINTS_SECTION) else {
+            return Ok(config);
+        };
+        let Some(table) = section.as_table() else {
+            bail!(
+                "The [{}] section of Move.toml must be a table",
+                LINTS_SECTION
+            );
+        };
+        for (name, level) in table {
+            let lint = Lint::from_str(name)?;
+            let Some(level) = level.as_str() else {
+                bail!("The level of lint {} must be a string", name);
+            };
+            config.set_level(lint, LintLevel::from_str(level)?);
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintDiagnostic {
+    pub lint: Lint,
+    pub level: LintLevel,
+    pub message: String,
+    pub file: String,
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column number
+    pub column: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl FromStr for LintFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(LintFormat::Text),
+            "json" => Ok(LintFormat::Json),
+            "sarif" => Ok(LintFormat::Sarif),
+            _ => Err(format!(
+                "Invalid lint format: {}, expected one of text, json, sarif",
+                s
+            )),
+        }
+    }
+}
+
+/// Render the lint diagnostics as JSON or SARIF, returns None for the text format,
+/// the text diagnostics are reported with the compiler diagnostics.
+pub fn render_lints(
+    diagnostics: &[LintDiagnostic],
+    format: LintFormat,
+) -> Result<Option<serde_json::Value>> {
+    match format {
+        LintFormat::Text => Ok(None),
+        LintFormat::Json => Ok(Some(serde_json::to_value(diagnostics)?)),
+        LintFormat::Sarif => Ok(Some(to_sarif(diagnostics))),
+    }
+}
+
+fn to_sarif(diagnostics: &[LintDiagnostic]) -> serde_json::Value {
+    let rules = Lint::ALL
+        .iter()
+        .map(|lint| {
+            serde_json::json!({
+                "id": lint.name(),
+                "shortDescription": {"text": lint.description()},
+            })
+        })
+        .collect::<Vec<_>>();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            serde_json::json!({
+                "ruleId": diagnostic.lint.name(),
+                "level": match diagnostic.level {
+                    LintLevel::Deny => "error",
+                    _ => "warning",
+                },
+                "message": {"text": diagnostic.message},
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": diagnostic.file},
+                        "region": {
+                            "startLine": diagnostic.line,
+                            "startColumn": diagnostic.column,
+                        },
+                    },
+                }],
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "rooch-move-lint",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+// ----------------------------------------------------------------------------------
+// Lint checks
+
+pub(crate) struct Linter<'a> {
+    env: &'a GlobalEnv,
+    config: &'a LintConfig,
+    pub(crate) diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+    pub(crate) fn new(env: &'a GlobalEnv, config: &'a LintConfig) -> Self {
+        Self {
+            env,
+            config,
+            diagnostics: vec![],
+        }
+    }
+
+    pub(crate) fn check_module(&mut self, module: &ModuleEnv) {
+        self.check_table_vector_value(module);
+        for ref fun in module.get_functions() {
+            self.check_shared_object_mutation(fun);
+            self.check_unchecked_take_object(fun);
+            self.check_missing_view(fun);
+            self.check_unused_private_generics(fun);
+        }
+    }
+
+    fn report(&mut self, lint: Lint, loc: &Loc, message: String) {
+        let level = self.config.level(lint);
+        if level == LintLevel::Allow {
+            return;
+        }
+        let message = format!("[lint::{}] {}", lint, message);
+        match level {
+            LintLevel::Deny => self.env.error(loc, &message),
+            _ => self.env.diag(
+                codespan_reporting::diagnostic::Severity::Warning,
+                loc,
+                &message,
+            ),
+        }
+        let (file, line, column) = match self.env.get_file_and_location(loc) {
+            Some((file, location)) => (file, location.line.0 + 1, location.column.0 + 1),
+            None => (String::new(), 0, 0),
+        };
+        self.diagnostics.push(LintDiagnostic {
+            lint,
+            level,
+            message,
+            file,
+            line,
+            column,
+        });
+    }
+
+    fn struct_name(&self, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Struct(mid, sid, _) => Some(
+                self.env
+                    .get_struct(mid.qualified(*sid))
+                    .get_full_name_with_address(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn is_signer_param(ty: &Type) -> bool {
+        match ty {
+            Type::Primitive(PrimitiveType::Signer) => true,
+            Type::Reference(_, bt) => matches!(bt.as_ref(), Type::Primitive(PrimitiveType::Signer)),
+            _ => false,
+        }
+    }
+
+    fn has_signer_param(fun: &FunctionEnv) -> bool {
+        fun.get_parameter_types().iter().any(Self::is_signer_param)
+    }
+
+    fn has_mut_ref(ty: &Type) -> bool {
+        match ty {
+            Type::Reference(ReferenceKind::Mutable, _) => true,
+            Type::Tuple(tys) => tys.iter().any(Self::has_mut_ref),
+            _ => false,
+        }
+    }
+
+    fn has_attribute(&self, fun: &FunctionEnv, attr_name: &str) -> bool {
+        fun.get_attributes().iter().any(|attr| {
+            if let Attribute::Apply(_, name, _) = attr {
+                self.env.symbol_pool().string(*name).as_str() == attr_name
+            } else {
+                false
+            }
+        })
+    }
+
+    fn check_shared_object_mutation(&mut self, fun: &FunctionEnv) {
+        if !fun.is_entry() || Self::has_signer_param(fun) {
+            return;
+        }
+        for ty in fun.get_parameter_types() {
+            if let Type::Reference(ReferenceKind::Mutable, bt) = &ty {
+                if self.struct_name(bt).as_deref() == Some(OBJECT_STRUCT) {
+                    self.report(
+                        Lint::SharedObjectMutation,
+                        &fun.get_loc(),
+                        format!(
+                            "entry function `{}` takes `{}` without a signer, anyone can call it with a shared object",
+                            fun.get_full_name_str(),
+                            ty.display(&self.env.get_type_display_ctx())
+                        ),
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    fn check_table_vector_value(&mut self, module: &ModuleEnv) {
+        for struct_env in module.get_structs() {
+            for field in struct_env.get_fields() {
+                let ty = field.get_type();
+                let Type::Struct(_, _, ty_args) = &ty else {
+                    continue;
+                };
+                if self.struct_name(&ty).as_deref() != Some(TABLE_STRUCT) {
+                    continue;
+                }
+                if let Some(Type::Vector(_)) = ty_args.get(1) {
+                    self.report(
+                        Lint::TableVectorValue,
+                        &struct_env.get_loc(),
+                        format!(
+                            "field `{}` of struct `{}` stores vectors in a table, the vectors can grow unbounded, consider a nested table",
+                            field.get_name().display(self.env.symbol_pool()),
+                            struct_env.get_full_name_str()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_unchecked_take_object(&mut self, fun: &FunctionEnv) {
+        if !(fun.is_entry() || fun.visibility() == Visibility::Public)
+            || Self::has_signer_param(fun)
+        {
+            return;
+        }
+        let takes_object_id = fun
+            .get_parameter_types()
+            .iter()
+            .any(|ty| self.struct_name(ty).as_deref() == Some(OBJECT_ID_STRUCT));
+        if !takes_object_id {
+            return;
+        }
+        let Some(called) = fun.get_called_functions() else {
+            return;
+        };
+        let calls_take_object = called.iter().any(|qid| {
+            let callee = self.env.get_function(*qid);
+            format!(
+                "{}::{}",
+                callee.module_env.get_full_name_str(),
+                callee.get_name_str()
+            ) == TAKE_OBJECT_EXTEND_FUNCTION
+        });
+        if calls_take_object {
+            self.report(
+                Lint::UncheckedTakeObject,
+                &fun.get_loc(),
+                format!(
+                    "function `{}` takes out an object by the given id via `object::take_object_extend` without a signer, check the owner of the object",
+                    fun.get_full_name_str()
+                ),
+            );
+        }
+    }
+
+    fn check_missing_view(&mut self, fun: &FunctionEnv) {
+        if fun.visibility() != Visibility::Public
+            || fun.is_entry()
+            || fun.is_native()
+            || fun.is_inline()
+            || fun.get_return_count() == 0
+            || self.has_attribute(fun, VIEW_ATTRIBUTE)
+        {
+            return;
+        }
+        if fun
+            .get_parameter_types()
+            .iter()
+            .any(|ty| Self::has_mut_ref(ty) || Self::is_signer_param(ty))
+            || Self::has_mut_ref(&fun.get_result_type())
+        {
+            return;
+        }
+        // The function is read-only if none of the functions it calls, directly or indirectly,
+        // takes or returns a mutable reference.
+        let Some(called) = fun.get_transitive_closure_of_called_functions() else {
+            return;
+        };
+        let mutates = called.iter().any(|qid| {
+            let callee = self.env.get_function(*qid);
+            callee.get_parameter_types().iter().any(Self::has_mut_ref)
+                || Self::has_mut_ref(&callee.get_result_type())
+        });
+        if !mutates {
+            self.report(
+                Lint::MissingView,
+                &fun.get_loc(),
+                format!(
+                    "public function `{}` is read-only, consider marking it with `#[view]`",
+                    fun.get_full_name_str()
+                ),
+            );
+        }
+    }
+
+    fn check_unused_private_generics(&mut self, fun: &FunctionEnv) {
+        if fun.visibility() == Visibility::Public
+            || !self.has_attribute(fun, PRIVATE_GENERICS_ATTRIBUTE)
+        {
+            return;
+        }
+        self.report(
+            Lint::UnusedPrivateGenerics,
+            &fun.get_loc(),
+            format!(
+                "`#[private_generics]` on non-public function `{}` has no effect, only the module itself or its friends can call it",
+                fun.get_full_name_str()
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_config_from_manifest() {
+        let manifest = r#"
+[package]
+name = "example"
+version = "0.0.1"
+
+[lints]
+unchecked_take_object = "deny"
+missing_view = "warn"
+table_vector_value = "allow"
+"#;
+        let config = LintConfig::from_manifest_str(manifest).unwrap();
+        assert_eq!(config.level(Lint::UncheckedTakeObject), LintLevel::Deny);
+        assert_eq!(config.level(Lint::MissingView), LintLevel::Warn);
+        assert_eq!(config.level(Lint::TableVectorValue), LintLevel::Allow);
+        assert_eq!(config.level(Lint::SharedObjectMutation), LintLevel::Warn);
+
+        let config = LintConfig::from_manifest_str("[package]\nname = \"example\"").unwrap();
+        assert_eq!(config, LintConfig::default());
+        assert_eq!(config.level(Lint::MissingView), LintLevel::Allow);
+
+        assert!(LintConfig::from_manifest_str("[lints]\nunknown_lint = \"warn\"").is_err());
+        assert!(LintConfig::from_manifest_str("[lints]\nmissing_view = \"error\"").is_err());
+    }
+
+    #[test]
+    fn test_render_lints() {
+        let diagnostics = vec![LintDiagnostic {
+            lint: Lint::UncheckedTakeObject,
+            level: LintLevel::Deny,
+            message: "message".to_string(),
+            file: "sources/example.move".to_string(),
+            line: 10,
+            column: 5,
+        }];
+        assert!(render_lints(&diagnostics, LintFormat::Text)
+            .unwrap()
+            .is_none());
+
+        let json = render_lints(&diagnostics, LintFormat::Json)
+            .unwrap()
+            .unwrap();
+        let parsed: Vec<LintDiagnostic> = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, diagnostics);
+
+        let sarif = render_lints(&diagnostics, LintFormat::Sarif)
+            .unwrap()
+            .unwrap();
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "unchecked_take_object");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            10
+        );
+        assert_eq!(
+            sarif["runs"][0]["tool"]["driver"]["rules"]
+                .as_array()
+                .unwrap()
+                .len(),
+            Lint::ALL.len()
+        );
+    }
+}
@@ -5,6 +5,7 @@
 #![allow(clippy::map_clone)]
 
 use crate::build::ROOCH_METADATA_KEY;
+use crate::lint::{LintConfig, LintDiagnostic, Linter};
 use crate::verifier::INIT_FN_NAME_IDENTIFIER;
 use itertools::Itertools;
 use move_binary_format::binary_views::BinaryIndexedView;
@@ -117,23 +118,43 @@ pub fn get_metadata_from_compiled_module(
 /// from module to extended runtime metadata. Any errors during context checking are reported to
 /// `env`. This is invoked after general build succeeds.
 pub fn run_extended_checks(env: &GlobalEnv) -> BTreeMap<ModuleId, RuntimeModuleMetadataV1> {
-    let mut checker = ExtendedChecker::new(env);
+    let mut checker = ExtendedChecker::new(env, None);
     checker.run();
     checker.output
 }
 
+/// Run the extended checks together with the lints in the same pass.
+/// The lint diagnostics are reported to `env` as warnings or errors according to the lint level,
+/// and also returned for the machine-readable output.
+pub fn run_extended_checks_with_lints(
+    env: &GlobalEnv,
+    lint_config: &LintConfig,
+) -> (
+    BTreeMap<ModuleId, RuntimeModuleMetadataV1>,
+    Vec<LintDiagnostic>,
+) {
+    let mut checker = ExtendedChecker::new(env, Some(lint_config));
+    checker.run();
+    (checker.output, checker.lints)
+}
+
 #[derive(Debug)]
 struct ExtendedChecker<'a> {
     env: &'a GlobalEnv,
     /// Computed runtime metadata
     output: BTreeMap<ModuleId, RuntimeModuleMetadataV1>,
+    /// The lints are only run when the config is provided
+    lint_config: Option<&'a LintConfig>,
+    lints: Vec<LintDiagnostic>,
 }
 
 impl<'a> ExtendedChecker<'a> {
-    fn new(env: &'a GlobalEnv) -> Self {
+    fn new(env: &'a GlobalEnv, lint_config: Option<&'a LintConfig>) -> Self {
         Self {
             env,
             output: BTreeMap::default(),
+            lint_config,
+            lints: vec![],
         }
     }
 
@@ -146,6 +167,11 @@ impl<'a> ExtendedChecker<'a> {
                 self.check_global_storage_access(module);
                 self.check_gas_free_function(module);
                 self.check_data_struct(module);
+                if let Some(lint_config) = self.lint_config {
+                    let mut linter = Linter::new(self.env, lint_config);
+                    linter.check_module(module);
+                    self.lints.append(&mut linter.diagnostics);
+                }
             }
         }
     }
@@ -0,0 +1,653 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured diff between the published version and the new version of a package.
+//! Every change is classified as breaking, risky or compatible, so upgrades can be gated in review.
+
+use crate::metadata::{get_metadata_from_compiled_module, RuntimeModuleMetadataV1};
+use move_binary_format::file_format::{AbilitySet, Visibility};
+use move_binary_format::normalized::{Function, Module, Struct};
+use move_binary_format::CompiledModule;
+use move_core_types::language_storage::ModuleId;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeLevel {
+    /// The change does not affect the existing callers.
+    Compatible,
+    /// The change is accepted by the chain, but may change the behavior for the existing callers.
+    Risky,
+    /// The change breaks the existing callers or is rejected by the chain.
+    Breaking,
+}
+
+impl fmt::Display for ChangeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeLevel::Compatible => write!(f, "compatible"),
+            ChangeLevel::Risky => write!(f, "risky"),
+            ChangeLevel::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+impl FromStr for ChangeLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "compatible" => Ok(ChangeLevel::Compatible),
+            "risky" => Ok(ChangeLevel::Risky),
+            "breaking" => Ok(ChangeLevel::Breaking),
+            _ => Err(format!(
+                "Invalid change level: {}, expected one of compatible, risky, breaking",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    ModuleAdded,
+    ModuleRemoved,
+    StructAdded,
+    StructRemoved,
+    StructAbilities,
+    StructTypeParameters,
+    StructFields,
+    FunctionAdded,
+    FunctionRemoved,
+    FunctionSignature,
+    FunctionVisibility,
+    FunctionEntry,
+    FriendAdded,
+    FriendRemoved,
+    PrivateGenerics,
+    DataStruct,
+    DataStructFunc,
+    GasFree,
+    Attributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeChange {
+    pub module: String,
+    /// The struct, function, friend or metadata entry, None for the module level changes.
+    pub item: Option<String>,
+    pub kind: ChangeKind,
+    pub level: ChangeLevel,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeReport {
+    pub changes: Vec<UpgradeChange>,
+}
+
+impl UpgradeReport {
+    /// The most severe level of all changes, Compatible if there is no change.
+    pub fn level(&self) -> ChangeLevel {
+        self.changes
+            .iter()
+            .map(|change| change.level)
+            .max()
+            .unwrap_or(ChangeLevel::Compatible)
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.level() == ChangeLevel::Breaking
+    }
+
+    fn push(
+        &mut self,
+        module: &ModuleId,
+        item: Option<String>,
+        kind: ChangeKind,
+        level: ChangeLevel,
+        message: String,
+    ) {
+        self.changes.push(UpgradeChange {
+            module: module.short_str_lossless(),
+            item,
+            kind,
+            level,
+            message,
+        });
+    }
+}
+
+impl fmt::Display for UpgradeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        for change in &self.changes {
+            match &change.item {
+                Some(item) => writeln!(
+                    f,
+                    "[{}] {}::{}: {}",
+                    change.level, change.module, item, change.message
+                )?,
+                None => writeln!(
+                    f,
+                    "[{}] {}: {}",
+                    change.level, change.module, change.message
+                )?,
+            }
+        }
+        write!(f, "Upgrade level: {}", self.level())
+    }
+}
+
+/// Diff the modules of the published package with the modules of the new package.
+pub fn diff_packages(
+    old_modules: &[CompiledModule],
+    new_modules: &[CompiledModule],
+) -> UpgradeReport {
+    let old_modules = old_modules
+        .iter()
+        .map(|module| (module.self_id(), module))
+        .collect::<BTreeMap<_, _>>();
+    let new_modules = new_modules
+        .iter()
+        .map(|module| (module.self_id(), module))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut report = UpgradeReport::default();
+    for (module_id, old_module) in &old_modules {
+        match new_modules.get(module_id) {
+            Some(new_module) => diff_module(&mut report, module_id, old_module, new_module),
+            None => report.push(
+                module_id,
+                None,
+                ChangeKind::ModuleRemoved,
+                ChangeLevel::Breaking,
+                "module is removed, the published module can not be deleted".to_owned(),
+            ),
+        }
+    }
+    for module_id in new_modules.keys() {
+        if !old_modules.contains_key(module_id) {
+            report.push(
+                module_id,
+                None,
+                ChangeKind::ModuleAdded,
+                ChangeLevel::Compatible,
+                "module is added".to_owned(),
+            );
+        }
+    }
+    report
+}
+
+fn diff_module(
+    report: &mut UpgradeReport,
+    module_id: &ModuleId,
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+) {
+    if old_module == new_module {
+        return;
+    }
+    let old = Module::new(old_module);
+    let new = Module::new(new_module);
+
+    for (name, old_struct) in &old.structs {
+        match new.structs.get(name) {
+            Some(new_struct) => {
+                diff_struct(report, module_id, name.as_str(), old_struct, new_struct)
+            }
+            None => report.push(
+                module_id,
+                Some(name.to_string()),
+                ChangeKind::StructRemoved,
+                ChangeLevel::Breaking,
+                "struct is removed".to_owned(),
+            ),
+        }
+    }
+    for name in new.structs.keys() {
+        if !old.structs.contains_key(name) {
+            report.push(
+                module_id,
+                Some(name.to_string()),
+                ChangeKind::StructAdded,
+                ChangeLevel::Compatible,
+                "struct is added".to_owned(),
+            );
+        }
+    }
+
+    for (name, old_function) in &old.exposed_functions {
+        match new.exposed_functions.get(name) {
+            Some(new_function) => {
+                diff_function(report, module_id, name.as_str(), old_function, new_function)
+            }
+            None => {
+                let level = removed_function_level(old_function);
+                report.push(
+                    module_id,
+                    Some(name.to_string()),
+                    ChangeKind::FunctionRemoved,
+                    level,
+                    format!(
+                        "{} function is removed or became private",
+                        function_kind(old_function)
+                    ),
+                )
+            }
+        }
+    }
+    for (name, new_function) in &new.exposed_functions {
+        if !old.exposed_functions.contains_key(name) {
+            // A public function can never be removed or hidden again once published.
+            let level = if new_function.visibility == Visibility::Public {
+                ChangeLevel::Risky
+            } else {
+                ChangeLevel::Compatible
+            };
+            report.push(
+                module_id,
+                Some(name.to_string()),
+                ChangeKind::FunctionAdded,
+                level,
+                format!("{} function is added", function_kind(new_function)),
+            );
+        }
+    }
+
+    let old_friends = old.friends.iter().collect::<BTreeSet<_>>();
+    let new_friends = new.friends.iter().collect::<BTreeSet<_>>();
+    for friend in old_friends.difference(&new_friends) {
+        report.push(
+            module_id,
+            Some(friend.short_str_lossless()),
+            ChangeKind::FriendRemoved,
+            ChangeLevel::Breaking,
+            "friend is removed".to_owned(),
+        );
+    }
+    for friend in new_friends.difference(&old_friends) {
+        report.push(
+            module_id,
+            Some(friend.short_str_lossless()),
+            ChangeKind::FriendAdded,
+            ChangeLevel::Risky,
+            "friend is added, the friend module can call the friend functions".to_owned(),
+        );
+    }
+
+    let old_metadata = get_metadata_from_compiled_module(old_module).unwrap_or_default();
+    let new_metadata = get_metadata_from_compiled_module(new_module).unwrap_or_default();
+    diff_metadata(report, module_id, &old_metadata, &new_metadata);
+}
+
+fn diff_struct(
+    report: &mut UpgradeReport,
+    module_id: &ModuleId,
+    name: &str,
+    old_struct: &Struct,
+    new_struct: &Struct,
+) {
+    let item = Some(name.to_owned());
+    if old_struct.abilities != new_struct.abilities {
+        let level = if old_struct.abilities.is_subset(new_struct.abilities) {
+            ChangeLevel::Risky
+        } else {
+            ChangeLevel::Breaking
+        };
+        report.push(
+            module_id,
+            item.clone(),
+            ChangeKind::StructAbilities,
+            level,
+            format!(
+                "abilities changed from {} to {}",
+                format_abilities(old_struct.abilities),
+                format_abilities(new_struct.abilities)
+            ),
+        );
+    }
+    if old_struct.type_parameters != new_struct.type_parameters {
+        report.push(
+            module_id,
+            item.clone(),
+            ChangeKind::StructTypeParameters,
+            ChangeLevel::Breaking,
+            "type parameters changed".to_owned(),
+        );
+    }
+    if old_struct.fields != new_struct.fields {
+        report.push(
+            module_id,
+            item,
+            ChangeKind::StructFields,
+            ChangeLevel::Breaking,
+            format!(
+                "fields changed from {{{}}} to {{{}}}",
+                format_fields(old_struct),
+                format_fields(new_struct)
+            ),
+        );
+    }
+}
+
+fn diff_function(
+    report: &mut UpgradeReport,
+    module_id: &ModuleId,
+    name: &str,
+    old_function: &Function,
+    new_function: &Function,
+) {
+    let item = Some(name.to_owned());
+    if old_function.visibility != new_function.visibility {
+        let level = match (old_function.visibility, new_function.visibility) {
+            // Widen the visibility
+            (Visibility::Private, _) | (Visibility::Friend, Visibility::Public) => {
+                ChangeLevel::Risky
+            }
+            _ => ChangeLevel::Breaking,
+        };
+        report.push(
+            module_id,
+            item.clone(),
+            ChangeKind::FunctionVisibility,
+            level,
+            format!(
+                "visibility changed from {} to {}",
+                format_visibility(old_function.visibility),
+                format_visibility(new_function.visibility)
+            ),
+        );
+    }
+    if old_function.is_entry != new_function.is_entry {
+        let (level, message) = if old_function.is_entry {
+            (
+                ChangeLevel::Risky,
+                "entry is removed, the transactions calling it will fail",
+            )
+        } else {
+            (ChangeLevel::Compatible, "entry is added")
+        };
+        report.push(
+            module_id,
+            item.clone(),
+            ChangeKind::FunctionEntry,
+            level,
+            message.to_owned(),
+        );
+    }
+    if old_function.type_parameters != new_function.type_parameters
+        || old_function.parameters != new_function.parameters
+        || old_function.return_ != new_function.return_
+    {
+        report.push(
+            module_id,
+            item,
+            ChangeKind::FunctionSignature,
+            removed_function_level(old_function),
+            format!(
+                "signature changed from `{}` to `{}`",
+                format_signature(old_function),
+                format_signature(new_function)
+            ),
+        );
+    }
+}
+
+/// Removing or changing a public or friend function breaks the linking,
+/// removing or changing a private entry function only breaks the off-chain callers.
+fn removed_function_level(function: &Function) -> ChangeLevel {
+    match function.visibility {
+        Visibility::Public | Visibility::Friend => ChangeLevel::Breaking,
+        Visibility::Private => ChangeLevel::Risky,
+    }
+}
+
+fn diff_metadata(
+    report: &mut UpgradeReport,
+    module_id: &ModuleId,
+    old: &RuntimeModuleMetadataV1,
+    new: &RuntimeModuleMetadataV1,
+) {
+    // Adding private generics restricts the existing callers, removing it loosens the check.
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::PrivateGenerics,
+        &old.private_generics_indices,
+        &new.private_generics_indices,
+        [
+            ChangeLevel::Breaking,
+            ChangeLevel::Risky,
+            ChangeLevel::Breaking,
+        ],
+        "#[private_generics]",
+    );
+    // Removing a data struct breaks the callers passing it to the data_struct functions.
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::DataStruct,
+        &old.data_struct_map,
+        &new.data_struct_map,
+        [
+            ChangeLevel::Compatible,
+            ChangeLevel::Breaking,
+            ChangeLevel::Breaking,
+        ],
+        "#[data_struct]",
+    );
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::DataStructFunc,
+        &old.data_struct_func_map,
+        &new.data_struct_func_map,
+        [
+            ChangeLevel::Breaking,
+            ChangeLevel::Risky,
+            ChangeLevel::Breaking,
+        ],
+        "#[data_struct(T)]",
+    );
+    // Gas free functions change who pays for the transactions.
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::GasFree,
+        &old.gas_free_function_map,
+        &new.gas_free_function_map,
+        [ChangeLevel::Risky, ChangeLevel::Risky, ChangeLevel::Risky],
+        "#[gas_free]",
+    );
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::Attributes,
+        &old.fun_attributes,
+        &new.fun_attributes,
+        [
+            ChangeLevel::Compatible,
+            ChangeLevel::Compatible,
+            ChangeLevel::Compatible,
+        ],
+        "function attributes",
+    );
+    diff_map(
+        report,
+        module_id,
+        ChangeKind::Attributes,
+        &old.struct_attributes,
+        &new.struct_attributes,
+        [
+            ChangeLevel::Compatible,
+            ChangeLevel::Compatible,
+            ChangeLevel::Compatible,
+        ],
+        "struct attributes",
+    );
+}
+
+/// Diff a metadata map, `levels` are the levels of the added, removed and changed entries.
+fn diff_map<V: PartialEq + Debug>(
+    report: &mut UpgradeReport,
+    module_id: &ModuleId,
+    kind: ChangeKind,
+    old: &BTreeMap<String, V>,
+    new: &BTreeMap<String, V>,
+    levels: [ChangeLevel; 3],
+    annotation: &str,
+) {
+    let [added_level, removed_level, changed_level] = levels;
+    for (key, old_value) in old {
+        match new.get(key) {
+            Some(new_value) if new_value != old_value => report.push(
+                module_id,
+                Some(key.clone()),
+                kind,
+                changed_level,
+                format!(
+                    "{} changed from {:?} to {:?}",
+                    annotation, old_value, new_value
+                ),
+            ),
+            Some(_) => {}
+            None => report.push(
+                module_id,
+                Some(key.clone()),
+                kind,
+                removed_level,
+                format!("{} is removed", annotation),
+            ),
+        }
+    }
+    for (key, new_value) in new {
+        if !old.contains_key(key) {
+            report.push(
+                module_id,
+                Some(key.clone()),
+                kind,
+                added_level,
+                format!("{} is added: {:?}", annotation, new_value),
+            );
+        }
+    }
+}
+
+fn function_kind(function: &Function) -> &'static str {
+    match (function.visibility, function.is_entry) {
+        (Visibility::Public, _) => "public",
+        (Visibility::Friend, _) => "friend",
+        (Visibility::Private, true) => "entry",
+        (Visibility::Private, false) => "private",
+    }
+}
+
+fn format_visibility(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public",
+        Visibility::Friend => "public(friend)",
+        Visibility::Private => "private",
+    }
+}
+
+fn format_abilities(abilities: AbilitySet) -> String {
+    let abilities = abilities
+        .into_iter()
+        .map(|ability| format!("{:?}", ability).to_lowercase())
+        .collect::<Vec<_>>();
+    format!("[{}]", abilities.join(", "))
+}
+
+fn format_fields(s: &Struct) -> String {
+    s.fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, field.type_))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_signature(function: &Function) -> String {
+    let type_parameters = function
+        .type_parameters
+        .iter()
+        .enumerate()
+        .map(|(idx, abilities)| format!("T{}: {}", idx, format_abilities(*abilities)))
+        .collect::<Vec<_>>();
+    let parameters = function
+        .parameters
+        .iter()
+        .map(|ty| ty.to_string())
+        .collect::<Vec<_>>();
+    let return_ = function
+        .return_
+        .iter()
+        .map(|ty| ty.to_string())
+        .collect::<Vec<_>>();
+    format!(
+        "<{}>({}): ({})",
+        type_parameters.join(", "),
+        parameters.join(", "),
+        return_.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::{empty_module, IdentifierIndex, ModuleHandle};
+    use move_core_types::identifier::Identifier;
+
+    fn module_with_name(name: &str) -> CompiledModule {
+        let mut module = empty_module();
+        module.identifiers[0] = Identifier::new(name).unwrap();
+        module
+    }
+
+    #[test]
+    fn test_diff_packages() {
+        let old_a = module_with_name("a");
+        let b = module_with_name("b");
+        let c = module_with_name("c");
+
+        let report = diff_packages(&[old_a.clone(), b.clone()], &[old_a.clone(), b.clone()]);
+        assert!(report.changes.is_empty());
+        assert_eq!(report.level(), ChangeLevel::Compatible);
+
+        // declare `b` as a friend of `a`
+        let mut new_a = old_a.clone();
+        new_a.identifiers.push(Identifier::new("b").unwrap());
+        new_a.friend_decls.push(ModuleHandle {
+            address: new_a.module_handles[0].address,
+            name: IdentifierIndex((new_a.identifiers.len() - 1) as u16),
+        });
+
+        let report = diff_packages(&[old_a.clone(), b.clone()], &[new_a.clone(), c]);
+        let kinds = report
+            .changes
+            .iter()
+            .map(|change| (change.kind, change.level))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                (ChangeKind::FriendAdded, ChangeLevel::Risky),
+                (ChangeKind::ModuleRemoved, ChangeLevel::Breaking),
+                (ChangeKind::ModuleAdded, ChangeLevel::Compatible),
+            ]
+        );
+        assert!(report.is_breaking());
+
+        let report = diff_packages(&[new_a], &[old_a]);
+        assert_eq!(report.changes[0].kind, ChangeKind::FriendRemoved);
+        assert!(report.is_breaking());
+    }
+}
@@ -302,7 +302,7 @@ fn struct_full_name_from_sid(
     format!("0x{}::{}::{}", module_address, module_name, struct_name)
 }
 
-fn check_transaction_input_type<S>(ety: &Type, session: &Session<S>) -> bool
+pub fn check_transaction_input_type<S>(ety: &Type, session: &Session<S>) -> bool
 where
     S: TransactionCache,
 {
@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use better_any::{Tid, TidAble};
+
+use crate::wasm::WASMInstance;
+
+/// The native context extension which holds the WASM instances created in a Move session.
+/// The instances are dropped with the session, so an instance never outlives the transaction
+/// which created it, and the instance ids are allocated deterministically.
+#[derive(Default, Tid)]
+pub struct NativeWASMContext {
+    next_instance_id: u64,
+    instances: BTreeMap<u64, WASMInstance>,
+}
+
+impl NativeWASMContext {
+    pub fn insert_instance(&mut self, instance: WASMInstance) -> u64 {
+        self.next_instance_id += 1;
+        let instance_id = self.next_instance_id;
+        self.instances.insert(instance_id, instance);
+        instance_id
+    }
+
+    pub fn get_instance_mut(&mut self, instance_id: u64) -> Option<&mut WASMInstance> {
+        self.instances.get_mut(&instance_id)
+    }
+
+    pub fn remove_instance(&mut self, instance_id: u64) -> Option<WASMInstance> {
+        self.instances.remove(&instance_id)
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.instances.len()
+    }
+}
@@ -7,6 +7,7 @@ use wasmer::RuntimeError;
 pub struct GasMeter {
     gas_limit: u64,
     gas_used: u64,
+    exhausted: bool,
 }
 
 impl GasMeter {
@@ -14,23 +15,46 @@ impl GasMeter {
         Self {
             gas_limit,
             gas_used: 0,
+            exhausted: false,
         }
     }
 
     pub fn reset(&mut self) {
         self.gas_used = 0;
+        self.exhausted = false;
+    }
+
+    /// Reset the meter and set the fuel limit for the next call.
+    pub fn reset_with_limit(&mut self, gas_limit: u64) {
+        self.reset();
+        self.gas_limit = gas_limit;
     }
 
     pub fn charge(&mut self, amount: u64) -> Result<(), RuntimeError> {
-        if self.gas_used + amount > self.gas_limit {
-            Err(RuntimeError::new("GAS limit exceeded"))
-        } else {
-            self.gas_used += amount;
-            Ok(())
+        match self.gas_used.checked_add(amount) {
+            Some(gas_used) if gas_used <= self.gas_limit => {
+                self.gas_used = gas_used;
+                Ok(())
+            }
+            _ => {
+                // Consume the remaining fuel, the execution is aborted.
+                self.gas_used = self.gas_limit;
+                self.exhausted = true;
+                Err(RuntimeError::new("GAS limit exceeded"))
+            }
         }
     }
 
     pub fn used(&mut self) -> u64 {
         self.gas_used
     }
+
+    pub fn limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Whether the last call was aborted because the fuel limit is exceeded.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
 }
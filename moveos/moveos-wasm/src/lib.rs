@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod context;
 pub mod cost_function;
 pub mod gas_meter;
 pub mod middlewares;
+pub mod tunables;
 pub mod wasm;
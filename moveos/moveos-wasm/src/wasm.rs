@@ -1,12 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::BTreeMap;
 use std::ops::Deref;
 use std::sync::{Arc, Mutex};
 
-use once_cell::sync::Lazy;
-use rand;
 use tracing::{debug, error, warn};
 use wasmer::Value::I32;
 use wasmer::*;
@@ -16,8 +13,14 @@ use wasmer_compiler_singlepass::Singlepass;
 use crate::cost_function::cost_function;
 use crate::gas_meter::GasMeter;
 use crate::middlewares::gas_metering::GasMiddleware;
+use crate::tunables::LimitingTunables;
 
-const GAS_LIMIT: u64 = 500000;
+/// The default fuel limit of a single call, the caller should set the limit before the call
+/// via `WASMInstance::reset_fuel`.
+pub const DEFAULT_FUEL_LIMIT: u64 = 500000;
+
+/// The default max memory pages of an instance, 64KiB per page.
+pub const DEFAULT_MAX_MEMORY_PAGES: u32 = 256;
 
 pub struct WASMInstance {
     pub bytecode: Vec<u8>,
@@ -40,49 +43,40 @@ impl WASMInstance {
             gas_meter,
         }
     }
-}
 
-/*
-TODO:
-The WASMInstance must be protected by the locker which owned by the signer
-    while we enable the parallel tx execution.
-The way we're doing it now is by using this big global lock, but it's too broad.
- */
-static mut GLOBAL_INSTANCE_POOL: Lazy<Arc<Mutex<BTreeMap<u64, WASMInstance>>>> =
-    Lazy::new(|| Arc::new(Mutex::new(BTreeMap::new())));
-
-pub fn insert_wasm_instance(instance: WASMInstance) -> anyhow::Result<u64> {
-    loop {
-        unsafe {
-            let instance_id: u64 = rand::random();
-            let mut instance_pool = match GLOBAL_INSTANCE_POOL.lock() {
-                Ok(pool_guard) => pool_guard,
-                Err(_) => {
-                    return Err(anyhow::Error::msg("get global instance pool failed"));
-                }
-            };
-            if instance_pool.get(&instance_id).is_none() {
-                instance_pool.insert(instance_id, instance);
-                return Ok(instance_id);
-            }
-        }
+    /// Reset the fuel meter and set the fuel limit of the next call.
+    pub fn reset_fuel(&mut self, fuel_limit: u64) {
+        let mut gas_meter = self
+            .gas_meter
+            .lock()
+            .expect("getting gas meter mutex failed");
+        gas_meter.reset_with_limit(fuel_limit);
     }
-}
 
-pub fn get_instance_pool() -> Arc<Mutex<BTreeMap<u64, WASMInstance>>> {
-    unsafe { GLOBAL_INSTANCE_POOL.clone() }
-}
+    /// The fuel consumed since the last `reset_fuel`.
+    pub fn fuel_used(&self) -> u64 {
+        let mut gas_meter = self
+            .gas_meter
+            .lock()
+            .expect("getting gas meter mutex failed");
+        gas_meter.used()
+    }
 
-pub fn remove_instance(instance_id: u64) -> anyhow::Result<()> {
-    unsafe {
-        let mut instance_pool = match GLOBAL_INSTANCE_POOL.lock() {
-            Ok(pool_guard) => pool_guard,
-            Err(_) => {
-                return Err(anyhow::Error::msg("get global instance pool failed"));
-            }
-        };
-        instance_pool.remove(&instance_id);
-        Ok(())
+    /// Whether the last call was aborted because the fuel limit is exceeded.
+    pub fn fuel_exhausted(&self) -> bool {
+        let gas_meter = self
+            .gas_meter
+            .lock()
+            .expect("getting gas meter mutex failed");
+        gas_meter.is_exhausted()
+    }
+
+    /// The current size of the linear memory, in pages.
+    pub fn memory_pages(&self) -> u32 {
+        match self.instance.exports.get_memory("memory") {
+            Ok(memory) => memory.view(&self.store).size().0,
+            Err(_) => 0,
+        }
     }
 }
 
@@ -257,14 +251,44 @@ pub fn get_data_from_heap(
     data
 }
 
+/// Read the data which is prefixed with a 4 bytes big-endian length at `ptr_offset`.
+pub fn read_data_with_length(instance: &WASMInstance, ptr_offset: u64) -> anyhow::Result<Vec<u8>> {
+    let memory = match instance.instance.exports.get_memory("memory") {
+        Ok(v) => v,
+        Err(_) => return Err(anyhow::Error::msg("memory not found")),
+    };
+    let memory_view = memory.view(&instance.store);
+    let mut length_bytes: [u8; 4] = [0; 4];
+    memory_view.read(ptr_offset, length_bytes.as_mut_slice())?;
+    let length = u32::from_be_bytes(length_bytes) as u64;
+    let data_offset = ptr_offset + 4;
+    if data_offset.saturating_add(length) > memory_view.data_size() {
+        return Err(anyhow::Error::msg(format!(
+            "the data length {} at offset {} is out of the memory bounds",
+            length, ptr_offset
+        )));
+    }
+    let mut data = vec![0; length as usize];
+    memory_view.read(data_offset, &mut data)?;
+    Ok(data)
+}
+
 fn charge(env: FunctionEnvMut<Env>, amount: i64) -> Result<(), wasmer::RuntimeError> {
     let mut gas_meter = env.data().gas_meter.lock().unwrap();
     gas_meter.charge(amount as u64)
 }
 
 pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
+    create_wasm_instance_with_limits(code, DEFAULT_MAX_MEMORY_PAGES)
+}
+
+/// Create a WASM instance whose linear memory is limited to `max_memory_pages`.
+pub fn create_wasm_instance_with_limits(
+    code: &[u8],
+    max_memory_pages: u32,
+) -> anyhow::Result<WASMInstance> {
     // Create the GasMeter
-    let gas_meter = Arc::new(Mutex::new(GasMeter::new(GAS_LIMIT)));
+    let gas_meter = Arc::new(Mutex::new(GasMeter::new(DEFAULT_FUEL_LIMIT)));
 
     // Create and configure the compiler
     let mut compiler = Singlepass::new();
@@ -273,8 +297,16 @@ pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
     let gas_middleware = GasMiddleware::new(Some(Arc::new(cost_function)));
     compiler.push_middleware(Arc::new(gas_middleware));
 
+    // Limit the memory pages of the instance
+    let tunables = LimitingTunables::new(
+        BaseTunables::for_target(&Target::default()),
+        Pages(max_memory_pages),
+    );
+    let mut engine: Engine = compiler.into();
+    engine.set_tunables(tunables);
+
     // Create the store
-    let mut store = Store::new(compiler);
+    let mut store = Store::new(engine);
 
     let bytecode = match wasmer::wat2wasm(code) {
         Ok(m) => m,
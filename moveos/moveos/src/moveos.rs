@@ -12,12 +12,11 @@ use move_binary_format::errors::VMError;
 use move_binary_format::errors::{vm_status_of_result, Location, PartialVMError, VMResult};
 use move_binary_format::file_format::FunctionDefinitionIndex;
 use move_binary_format::CompiledModule;
-use move_core_types::identifier::IdentStr;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::vm_status::{KeptVMStatus, VMStatus};
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::Identifier, vm_status::StatusCode,
+    account_address::AccountAddress, identifier::Identifier, vm_status::StatusCode,
 };
 use move_vm_runtime::config::VMConfig;
 use move_vm_runtime::data_cache::TransactionCache;
@@ -28,20 +27,19 @@ use moveos_store::event_store::EventDBStore;
 use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::transaction_store::TransactionDBStore;
 use moveos_store::{load_feature_store_object, MoveOSStore};
-use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::moveos_std::gas_schedule::{GasScheduleConfig, GasScheduleUpdated};
 use moveos_types::moveos_std::object::ObjectMeta;
+pub use moveos_types::moveos_std::tx_context::GasPaymentAccount;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
-use moveos_types::state::{MoveStructState, MoveStructType, ObjectState};
+use moveos_types::state::ObjectState;
 use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver};
 use moveos_types::transaction::{FunctionCall, VMErrorInfo};
 use moveos_types::transaction::{
     MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction, VerifiedMoveOSTransaction,
 };
 use parking_lot::RwLock;
-use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(thiserror::Error, Debug)]
@@ -52,27 +50,6 @@ pub enum VMPanicError {
     SystemCallPanicError(Error),
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-pub struct GasPaymentAccount {
-    pub account: AccountAddress,
-    pub pay_gas_by_module_account: bool,
-}
-
-impl MoveStructType for GasPaymentAccount {
-    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
-    const MODULE_NAME: &'static IdentStr = ident_str!("tx_context");
-    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
-}
-
-impl MoveStructState for GasPaymentAccount {
-    fn struct_layout() -> move_core_types::value::MoveStructLayout {
-        move_core_types::value::MoveStructLayout::new(vec![
-            move_core_types::value::MoveTypeLayout::Address,
-            move_core_types::value::MoveTypeLayout::Bool,
-        ])
-    }
-}
-
 #[derive(Default)]
 pub struct MoveOSConfig {
     pub vm_config: VMConfig,
@@ -48,6 +48,7 @@ use moveos_types::{
     transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
 };
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
+use moveos_wasm::context::NativeWASMContext;
 use parking_lot::RwLock;
 use std::collections::BTreeSet;
 use std::rc::Rc;
@@ -194,6 +195,7 @@ where
         ));
         extensions.add(NativeModuleContext::new(remote));
         extensions.add(NativeEventContext::default());
+        extensions.add(NativeWASMContext::default());
 
         // The VM code loader has bugs around module upgrade. After a module upgrade, the internal
         // cache needs to be flushed to work around those bugs.
@@ -300,6 +302,10 @@ where
                     init_function_modules,
                 })
             }
+            MoveAction::MultiCall(multi_call) => {
+                self.verify_multi_call(&multi_call)?;
+                Ok(VerifiedMoveAction::MultiCall { multi_call })
+            }
         }
     }
 
@@ -467,6 +473,7 @@ where
 
                 self.execute_init_modules(modules_with_init)
             }
+            VerifiedMoveAction::MultiCall { multi_call } => self.execute_multi_call(multi_call),
         };
 
         if action_result.is_ok() {
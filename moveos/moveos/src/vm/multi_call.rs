@@ -0,0 +1,414 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::moveos_vm::MoveOSSession;
+use super::tx_argument_resolver::{as_struct_no_panic, is_object, is_signer};
+use move_binary_format::{
+    access::ModuleAccess,
+    errors::{Location, PartialVMError, PartialVMResult, VMResult},
+    file_format::Visibility,
+};
+use move_core_types::{language_storage::TypeTag, vm_status::StatusCode};
+use move_vm_runtime::session::LoadedFunctionInstantiation;
+use move_vm_types::loaded_data::runtime_types::Type;
+use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
+use moveos_types::{
+    moveos_std::object::{ObjectID, SYSTEM_OWNER_ADDRESS},
+    state::MoveState,
+    state_resolver::MoveOSResolver,
+    transaction::{MultiCall, MultiCallArgument, MultiCallFunction, MAX_MULTI_CALL_FUNCTIONS},
+};
+use moveos_verifier::{
+    error_code::ErrorCode, metadata::get_metadata_from_compiled_module,
+    verifier::check_transaction_input_type,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+impl<'r, 'l, S, G> MoveOSSession<'r, 'l, S, G>
+where
+    S: MoveOSResolver,
+    G: SwitchableGasMeter + ClassifiedGasMeter,
+{
+    /// Verify a MultiCall.
+    /// Every function must be a public or entry function without private generics, every `MultiCallArgument::Pure`
+    /// must be a valid transaction argument, and every `MultiCallArgument::Result` must
+    /// reference a return value of a previous function with the same type as the parameter.
+    /// A return value can be moved at most once, and can not be used after it is moved.
+    /// A return value without `drop` ability must be moved by a later function.
+    pub(crate) fn verify_multi_call(&self, multi_call: &MultiCall) -> VMResult<()> {
+        if multi_call.calls.is_empty() || multi_call.calls.len() > MAX_MULTI_CALL_FUNCTIONS {
+            return Err(
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message(format!(
+                        "MultiCall must contain 1 to {} functions, got:{}",
+                        MAX_MULTI_CALL_FUNCTIONS,
+                        multi_call.calls.len()
+                    ))
+                    .finish(Location::Undefined),
+            );
+        }
+
+        // The return types of the verified functions
+        let mut return_types: Vec<Vec<Type>> = vec![];
+        // The return values that have been moved
+        let mut moved_results = BTreeSet::new();
+
+        for call in multi_call.calls.iter() {
+            let location = Location::Module(call.function_id.module_id.clone());
+            self.check_multi_call_function(call)
+                .map_err(|e| e.finish(location.clone()))?;
+            let loaded_function = self.session.load_function(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args.as_slice(),
+            )?;
+            let parameters = Self::instantiate_types(&loaded_function, &loaded_function.parameters)
+                .map_err(|e| e.finish(location.clone()))?;
+
+            let non_signer_parameters = parameters
+                .into_iter()
+                .filter(|ty| !is_signer(ty))
+                .collect::<Vec<_>>();
+            if non_signer_parameters.len() != call.args.len() {
+                return Err(
+                    PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                        .with_message(format!(
+                            "Invalid argument length, expect:{}, got:{}",
+                            non_signer_parameters.len(),
+                            call.args.len()
+                        ))
+                        .finish(location),
+                );
+            }
+
+            // The return values borrowed by the current function
+            let mut borrowed_results = BTreeSet::new();
+            for (idx, (ty, arg)) in non_signer_parameters
+                .iter()
+                .zip(call.args.iter())
+                .enumerate()
+            {
+                match arg {
+                    MultiCallArgument::Pure(arg) => {
+                        if !check_transaction_input_type(ty, &self.session) {
+                            return Err(PartialVMError::new(StatusCode::ABORTED)
+                                .with_sub_status(ErrorCode::INVALID_ENTRY_FUNC_SIGNATURE.into())
+                                .with_message(format!(
+                                    "The type of the {} argument is not allowed",
+                                    idx
+                                ))
+                                .finish(location));
+                        }
+                        let _resolved_arg =
+                            self.construct_arg(ty, arg.clone(), false, location.clone())?;
+                    }
+                    MultiCallArgument::Result {
+                        call_index,
+                        result_index,
+                    } => {
+                        let result_type = return_types
+                            .get(*call_index as usize)
+                            .and_then(|types| types.get(*result_index as usize))
+                            .ok_or_else(|| {
+                                PartialVMError::new(StatusCode::INDEX_OUT_OF_BOUNDS).with_message(
+                                    format!(
+                                        "Invalid result reference, call_index:{}, result_index:{}",
+                                        call_index, result_index
+                                    ),
+                                )
+                            })
+                            .map_err(|e| e.finish(location.clone()))?;
+                        let result_key = (*call_index, *result_index);
+                        let (param_type, is_reference) = match ty {
+                            Type::Reference(inner) | Type::MutableReference(inner) => {
+                                (inner.as_ref(), true)
+                            }
+                            _ => (ty, false),
+                        };
+                        if self.type_tag(param_type, &location)?
+                            != self.type_tag(result_type, &location)?
+                        {
+                            return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                                .with_message(format!(
+                                    "The type of result({}, {}) mismatch the parameter type",
+                                    call_index, result_index
+                                ))
+                                .finish(location));
+                        }
+                        if self.is_object_vector(param_type) {
+                            return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                                .with_message(format!(
+                                    "The result({}, {}) of Object vector can not be used as argument",
+                                    call_index, result_index
+                                ))
+                                .finish(location));
+                        }
+                        if moved_results.contains(&result_key) {
+                            return Err(PartialVMError::new(StatusCode::MOVELOC_UNAVAILABLE_ERROR)
+                                .with_message(format!(
+                                    "The result({}, {}) has been moved",
+                                    call_index, result_index
+                                ))
+                                .finish(location));
+                        }
+                        let abilities = self.session.get_type_abilities(result_type)?;
+                        if is_reference {
+                            borrowed_results.insert(result_key);
+                        } else if !abilities.has_copy() {
+                            if borrowed_results.contains(&result_key) {
+                                return Err(PartialVMError::new(
+                                    StatusCode::MOVELOC_UNAVAILABLE_ERROR,
+                                )
+                                .with_message(format!(
+                                    "The result({}, {}) is borrowed and moved in the same call",
+                                    call_index, result_index
+                                ))
+                                .finish(location));
+                            }
+                            moved_results.insert(result_key);
+                        }
+                    }
+                }
+            }
+
+            let returns = Self::instantiate_types(&loaded_function, &loaded_function.return_)
+                .map_err(|e| e.finish(location.clone()))?;
+            for ty in returns.iter() {
+                if matches!(ty, Type::Reference(_) | Type::MutableReference(_)) {
+                    return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                        .with_message("MultiCall function can not return reference".to_string())
+                        .finish(location));
+                }
+            }
+            return_types.push(returns);
+        }
+
+        for (call_index, types) in return_types.iter().enumerate() {
+            for (result_index, ty) in types.iter().enumerate() {
+                let result_key = (call_index as u16, result_index as u16);
+                if !moved_results.contains(&result_key)
+                    && !self.session.get_type_abilities(ty)?.has_drop()
+                {
+                    return Err(PartialVMError::new(
+                        StatusCode::UNSAFE_RET_UNUSED_VALUES_WITHOUT_DROP,
+                    )
+                    .with_message(format!(
+                        "The result({}, {}) without drop ability is not used",
+                        call_index, result_index
+                    ))
+                    .finish(Location::Module(
+                        multi_call.calls[call_index].function_id.module_id.clone(),
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute a verified MultiCall, all functions are executed in the current session and share the gas meter.
+    /// The return values are kept as serialized Move values, the values changed through a mutable reference
+    /// are written back, so the later functions always get the latest values.
+    pub(crate) fn execute_multi_call(&mut self, multi_call: MultiCall) -> VMResult<()> {
+        let mut results: Vec<Vec<Vec<u8>>> = vec![];
+        for call in multi_call.calls {
+            let loaded_function = self.session.load_function(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args.as_slice(),
+            )?;
+            let location = Location::Module(call.function_id.module_id.clone());
+            let parameters = Self::instantiate_types(&loaded_function, &loaded_function.parameters)
+                .map_err(|e| e.finish(location.clone()))?;
+
+            let mut args = call.args.into_iter();
+            let mut serialized_args = vec![];
+            // The parameter index to the result borrowed by mutable reference
+            let mut mutable_results = BTreeMap::new();
+            for (param_index, ty) in parameters.iter().enumerate() {
+                if is_signer(ty) {
+                    serialized_args.push(self.construct_arg(ty, vec![], true, location.clone())?);
+                    continue;
+                }
+                let arg = match args.next() {
+                    Some(MultiCallArgument::Pure(arg)) => {
+                        self.construct_arg(ty, arg, true, location.clone())?
+                    }
+                    // The return values are the Move values, pass them to the function directly
+                    Some(MultiCallArgument::Result {
+                        call_index,
+                        result_index,
+                    }) => {
+                        let arg = results
+                            .get(call_index as usize)
+                            .and_then(|values| values.get(result_index as usize))
+                            .cloned()
+                            .ok_or_else(|| {
+                                PartialVMError::new(StatusCode::INDEX_OUT_OF_BOUNDS)
+                                    .with_message(format!(
+                                        "Invalid result reference, call_index:{}, result_index:{}",
+                                        call_index, result_index
+                                    ))
+                                    .finish(location.clone())
+                            })?;
+                        self.check_object_result(ty, &arg, &location)?;
+                        if matches!(ty, Type::MutableReference(_)) {
+                            mutable_results
+                                .insert(param_index, (call_index as usize, result_index as usize));
+                        }
+                        arg
+                    }
+                    None => {
+                        return Err(
+                            PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                                .with_message("argument length mismatch, too few args".to_string())
+                                .finish(location),
+                        );
+                    }
+                };
+                serialized_args.push(arg);
+            }
+
+            let return_values = self.session.execute_function_bypass_visibility(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args,
+                serialized_args,
+                &mut self.gas_meter,
+            )?;
+            for (param_index, value, _layout) in return_values.mutable_reference_outputs {
+                if let Some((call_index, result_index)) =
+                    mutable_results.get(&(param_index as usize))
+                {
+                    results[*call_index][*result_index] = value;
+                }
+            }
+            results.push(
+                return_values
+                    .return_values
+                    .into_iter()
+                    .map(|(value, _layout)| value)
+                    .collect(),
+            );
+            // Release the object arguments, so the later functions can use the same objects.
+            self.object_runtime
+                .write()
+                .release_arguments()
+                .map_err(|e| e.finish(location))?;
+        }
+        Ok(())
+    }
+
+    fn check_multi_call_function(&self, call: &MultiCallFunction) -> PartialVMResult<()> {
+        let module = self
+            .vm
+            .load_module(&call.function_id.module_id, &self.remote)
+            .map_err(|e| e.to_partial())?;
+        let function_def = module
+            .function_defs()
+            .iter()
+            .find(|fdef| {
+                module.identifier_at(module.function_handle_at(fdef.function).name)
+                    == call.function_id.function_name.as_ident_str()
+            })
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::FUNCTION_RESOLUTION_FAILURE)
+                    .with_message(format!("Function {} not found", call.function_id))
+            })?;
+        if function_def.visibility != Visibility::Public && !function_def.is_entry {
+            return Err(PartialVMError::new(
+                StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION,
+            )
+            .with_message(format!(
+                "MultiCall only supports public or entry function, {} is not",
+                call.function_id
+            )));
+        }
+        // The private generics function can only be called by the module that defines the type argument,
+        // the transaction has no module, so it can not call the private generics function.
+        let full_function_name = format!(
+            "{}::{}::{}",
+            module.address().to_hex_literal(),
+            module.name(),
+            call.function_id.function_name
+        );
+        if get_metadata_from_compiled_module(&module).is_some_and(|metadata| {
+            metadata
+                .private_generics_indices
+                .contains_key(&full_function_name)
+        }) {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::INVALID_PRIVATE_GENERICS_TYPE.into())
+                .with_message(format!(
+                    "MultiCall does not support private generics function {}",
+                    call.function_id
+                )));
+        }
+        Ok(())
+    }
+
+    /// Check the Object result passed by value or mutable reference, the same as the Object argument of the transaction.
+    fn check_object_result(&self, ty: &Type, arg: &[u8], location: &Location) -> VMResult<()> {
+        let is_mutable_reference = match ty {
+            Type::Reference(_) => return Ok(()),
+            Type::MutableReference(_) => true,
+            _ => false,
+        };
+        if !as_struct_no_panic(&self.session, ty).is_some_and(|st| is_object(&st)) {
+            return Ok(());
+        }
+        let object_id = ObjectID::from_bytes(arg.to_vec()).map_err(|e| {
+            PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                .with_message(format!("Invalid object id: {:?}", e))
+                .finish(location.clone())
+        })?;
+        let sender = self.tx_context().sender();
+        let mut object_runtime = self.object_runtime.write();
+        let (rt_obj, _) = object_runtime
+            .load_object(self, &object_id)
+            .map_err(|e| e.finish(location.clone()))?;
+        let metadata = rt_obj.metadata().map_err(|e| e.finish(location.clone()))?;
+        if metadata.is_frozen() {
+            return Err(PartialVMError::new(StatusCode::NO_ACCOUNT_ROLE)
+                .with_message(format!("Object is frozen, object id:{:?}", object_id))
+                .finish(location.clone()));
+        }
+        // The new object created by the previous function is owned by the system before it is transferred.
+        let is_owned = metadata.owner == sender
+            || (metadata.owner == SYSTEM_OWNER_ADDRESS && !metadata.is_shared());
+        if !is_owned && !(is_mutable_reference && metadata.is_shared()) {
+            return Err(PartialVMError::new(StatusCode::NO_ACCOUNT_ROLE)
+                .with_message(format!(
+                    "Object owner mismatch, object owner:{:?}, sender:{:?}",
+                    metadata.owner, sender
+                ))
+                .finish(location.clone()));
+        }
+        Ok(())
+    }
+
+    fn is_object_vector(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Vector(inner) => {
+                as_struct_no_panic(&self.session, inner).is_some_and(|st| is_object(&st))
+                    || self.is_object_vector(inner)
+            }
+            _ => false,
+        }
+    }
+
+    fn type_tag(&self, ty: &Type, location: &Location) -> VMResult<TypeTag> {
+        self.session
+            .get_type_tag(ty)
+            .map_err(|e| e.to_partial().finish(location.clone()))
+    }
+
+    fn instantiate_types(
+        func: &LoadedFunctionInstantiation,
+        types: &[Type],
+    ) -> PartialVMResult<Vec<Type>> {
+        types
+            .iter()
+            .map(|ty| ty.subst(&func.type_arguments))
+            .collect()
+    }
+}
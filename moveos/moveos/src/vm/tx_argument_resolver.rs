@@ -158,7 +158,7 @@ where
         Ok(res_args)
     }
 
-    fn construct_arg(
+    pub(crate) fn construct_arg(
         &self,
         ty: &Type,
         arg: Vec<u8>,
@@ -454,7 +454,7 @@ where
     }
 }
 
-fn is_signer(t: &Type) -> bool {
+pub(crate) fn is_signer(t: &Type) -> bool {
     matches!(t, Type::Signer) || matches!(t, Type::Reference(r) if matches!(**r, Type::Signer))
 }
 
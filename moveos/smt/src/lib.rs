@@ -1,9 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use function_name::named;
-use jellyfish_merkle::hash::SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE;
+use jellyfish_merkle::hash::{SMTHash, SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE};
 use jellyfish_merkle::{
     iterator::JellyfishMerkleIterator,
     node_type::{Node, NodeKey},
@@ -96,6 +96,19 @@ impl NodeWriter for InMemoryNodeStore {
     }
 }
 
+/// Check the `node` bytes is the encoded tree node of the `hash`.
+/// The nodes from an untrusted source, such as a state witness, should be checked before reading.
+pub fn verify_node<K: Key, V: Value>(hash: &H256, node: &[u8]) -> Result<()> {
+    let node_hash: H256 = Node::<K, V>::decode(node)?.merkle_hash().into();
+    ensure!(
+        node_hash == *hash,
+        "Node hash mismatch, expect: {:?}, actual: {:?}",
+        hash,
+        node_hash
+    );
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct TreeChangeSet {
     pub state_root: H256,
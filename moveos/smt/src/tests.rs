@@ -57,3 +57,26 @@ fn test_smt() {
     let iter = smt.iter(changeset3.state_root, None).unwrap();
     assert_eq!(iter.count(), 2);
 }
+
+#[test]
+fn test_verify_node() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store, &registry);
+    let genesis_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+    let changeset = smt
+        .puts(
+            genesis_root,
+            vec![
+                (H256::random(), Some("value1".to_string())),
+                (H256::random(), Some("value2".to_string())),
+            ],
+        )
+        .unwrap();
+    for (hash, node) in &changeset.nodes {
+        assert!(verify_node::<H256, String>(hash, node).is_ok());
+    }
+    let (hash, _) = changeset.nodes.first_key_value().unwrap();
+    let (_, other_node) = changeset.nodes.last_key_value().unwrap();
+    assert!(verify_node::<H256, String>(hash, other_node).is_err());
+}
@@ -25,4 +25,26 @@ describe('BCS', () => {
 
     expect(bytesEqual(bcs4, bcs5)).toBeTruthy()
   })
+
+  it('MultiCall', () => {
+    const functionId = {
+      moduleId: {
+        address: new Ed25519Keypair().getRoochAddress().toHexAddress(),
+        name: 'empty',
+      },
+      name: 'empty',
+    }
+    const action = bcs.MoveAction.serialize({
+      kind: 'MultiCall',
+      calls: [
+        { functionId, typeArgs: [], args: [{ Pure: [1] }] },
+        { functionId, typeArgs: [], args: [{ Result: { callIndex: 0, resultIndex: 1 } }] },
+      ],
+    }).toBytes()
+
+    // The MultiCall variant index
+    expect(action[0]).toBe(3)
+    // The Result argument: variant index 1, call_index 0u16, result_index 1u16
+    expect(bytesEqual(action.slice(-5), new Uint8Array([1, 0, 0, 1, 0]))).toBeTruthy()
+  })
 })
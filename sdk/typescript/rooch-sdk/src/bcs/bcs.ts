@@ -176,10 +176,32 @@ export const CallFunction = bcs.struct('FunctionCall', {
   args: bcs.vector(bcs.vector(bcs.u8())),
 })
 
+export const MultiCallArgument = bcs.enum('MultiCallArgument', {
+  Pure: bcs.vector(bcs.u8()),
+  Result: bcs.struct('MultiCallResult', {
+    callIndex: bcs.u16(),
+    resultIndex: bcs.u16(),
+  }),
+})
+
+export const MultiCallFunction = bcs.struct('MultiCallFunction', {
+  functionId: FunctionId,
+  typeArgs: bcs.vector(TypeTag),
+  args: bcs.vector(MultiCallArgument),
+})
+
+export const MultiCall = bcs.struct('MultiCall', {
+  calls: bcs.vector(MultiCallFunction),
+})
+
 export const MoveAction = enumKind(
   bcs.enum('MoveAction', {
     ScriptCall,
     CallFunction,
+    ModuleBundle: bcs.struct('ModuleBundle', {
+      modules: bcs.vector(bcs.vector(bcs.u8())),
+    }),
+    MultiCall,
   }),
 )
 
@@ -11,6 +11,9 @@ import {
   FunctionId,
   ModuleId,
   MoveAction,
+  MultiCall,
+  MultiCallArgument,
+  MultiCallFunction,
   MultiChainAddress,
   ObjectId,
   raw,
@@ -42,6 +45,9 @@ export const RoochBcs = {
   FunctionId,
   ScriptCall,
   CallFunction,
+  MultiCallArgument,
+  MultiCallFunction,
+  MultiCall,
   MoveAction,
   RoochTransactionData,
   Authenticator,
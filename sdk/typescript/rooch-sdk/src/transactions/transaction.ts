@@ -6,7 +6,7 @@ import { Authenticator } from '../crypto/index.js'
 import { address, Bytes, u64 } from '../types/index.js'
 
 import { MoveAction, TransactionData } from './transactionData.js'
-import { CallFunctionArgs } from './types.js'
+import { CallFunctionArgs, MultiCallArgs } from './types.js'
 
 export class Transaction {
   private data: TransactionData | undefined
@@ -25,6 +25,18 @@ export class Transaction {
     )
   }
 
+  multiCall(
+    input: {
+      info?: string
+    } & MultiCallArgs,
+  ) {
+    this.info = input.info
+    this.data = new TransactionData(
+      MoveAction.newMultiCall(input),
+      input.maxGas ? BigInt(input.maxGas) : undefined,
+    )
+  }
+
   getInfo() {
     return this.info
   }
@@ -5,7 +5,7 @@ import { sha3_256 } from '../utils/index.js'
 import { normalizeRoochAddress } from '../address/index.js'
 import { Args, bcs, Serializer } from '../bcs/index.js'
 import { address, Bytes, identifier, u8, u64 } from '../types/index.js'
-import { CallFunctionArgs, CallScript } from './types.js'
+import { CallFunctionArgs, CallScript, MultiCallArgs, MultiCallResult } from './types.js'
 
 export class CallFunction {
   address: string
@@ -43,7 +43,36 @@ export class CallFunction {
   }
 }
 
-type MoveActionType = CallFunction | CallScript
+export class MultiCall {
+  calls: { func: CallFunction; args: (Args | MultiCallResult)[] }[]
+
+  constructor(input: MultiCallArgs) {
+    this.calls = input.calls.map((item) => ({
+      func: new CallFunction({ ...item, args: [] }),
+      args: item.args || [],
+    }))
+  }
+
+  encodeCalls() {
+    return this.calls.map(({ func, args }) => ({
+      functionId: {
+        moduleId: {
+          address: func.address,
+          name: func.module,
+        },
+        name: func.function,
+      },
+      typeArgs: func.typeArgs,
+      args: args.map((item) =>
+        item instanceof Args
+          ? { Pure: Array.from(item.encode()) }
+          : { Result: { callIndex: item.callIndex, resultIndex: item.resultIndex } },
+      ),
+    }))
+  }
+}
+
+type MoveActionType = CallFunction | CallScript | MultiCall
 
 export class MoveAction {
   scheme: number
@@ -61,6 +90,10 @@ export class MoveAction {
   static newCallScript(input: CallScript) {
     return new MoveAction(2, input)
   }
+
+  static newMultiCall(input: MultiCallArgs) {
+    return new MoveAction(3, new MultiCall(input))
+  }
 }
 
 export class TransactionData {
@@ -85,6 +118,19 @@ export class TransactionData {
   }
 
   encode() {
+    if (this.action.val instanceof MultiCall) {
+      return bcs.RoochTransactionData.serialize({
+        sender: this.sender!,
+        sequenceNumber: this.sequenceNumber!,
+        chainId: this.chainId!,
+        maxGas: this.maxGas!,
+        action: {
+          kind: 'MultiCall',
+          calls: this.action.val.encodeCalls(),
+        },
+      })
+    }
+
     const call = this.action.val as CallFunction
 
     return bcs.RoochTransactionData.serialize({
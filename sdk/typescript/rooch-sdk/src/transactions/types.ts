@@ -25,6 +25,21 @@ export type CallFunctionArgs = {
   typeArgs?: TypeTag[]
 } & FunctionArgs
 
+export type MultiCallResult = {
+  callIndex: number
+  resultIndex: number
+}
+
+export type MultiCallFunctionArgs = {
+  args?: (Args | MultiCallResult)[]
+  typeArgs?: TypeTag[]
+} & FunctionArgs
+
+export type MultiCallArgs = {
+  calls: MultiCallFunctionArgs[]
+  maxGas?: number
+}
+
 export type TypeArgs =
   | {
       address: string